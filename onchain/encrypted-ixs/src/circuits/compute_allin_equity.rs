@@ -0,0 +1,86 @@
+//! src/circuits/compute_allin_equity.rs
+//!
+//! @description
+//! Defines the `compute_allin_equity` confidential instruction. Once every remaining
+//! player is all-in, this circuit estimates each contender's chance of winning or
+//! tying the hand from the current board, so it can be shown while the board runs
+//! out. The result is informational only; payouts are still decided at showdown.
+//!
+//! @logic
+//! 1. Decrypts the contenders' hole cards inside the MPC. Folded seats are ignored,
+//!    so the estimate matches what a player could compute from the visible action.
+//! 2. Samples `EQUITY_SAMPLES` runouts: each sample shuffles a fresh deck with
+//!    `ArcisRNG` and completes the board from it, skipping known cards.
+//! 3. Scores every sampled board with `equity::tally_runout`.
+//! 4. Returns public win and tie percentages (in basis points) per seat. Hole cards
+//!    never leave the MPC; only the aggregate percentages are revealed.
+//!
+//! @dependencies
+//! - `arcis_imports`: For all Arcis-related macros and types.
+//! - `crate::types`: For `Hand` and `INVALID_CARD_INDEX`.
+//! - `crate::logic::equity`: For runout scoring.
+
+use arcis_imports::*;
+use crate::types::{Hand, INVALID_CARD_INDEX};
+use crate::logic::equity::{self, EQUITY_SAMPLES};
+use crate::circuits::evaluate_hands_and_payout::MAX_PLAYERS;
+
+/// A standard 52-card deck represented as indices from 0 to 51.
+const INITIAL_DECK: [u8; 52] = [
+     0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+    25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+    48, 49, 50, 51,
+];
+
+/// Estimates each all-in contender's equity by Monte-Carlo sampling of the runout.
+///
+/// # Arguments
+/// * `player_hands`: The encrypted hole cards for every seat, as dealt by `shuffle_and_deal`.
+/// * `contenders`: Which seats are still in the hand.
+/// * `community_cards`: The public community card indices dealt so far.
+/// * `num_community`: How many of `community_cards` have been dealt (0-5).
+///
+/// # Returns
+/// A tuple of two public arrays, indexed by seat:
+/// - `[u16; 6]`: The share of sampled runouts each seat wins outright, in basis points.
+/// - `[u16; 6]`: The share of sampled runouts each seat splits, in basis points.
+/// Non-contenders report 0 for both.
+#[instruction]
+pub fn compute_allin_equity(
+    player_hands: Enc<Mxe, [Hand; MAX_PLAYERS]>,
+    contenders: [bool; MAX_PLAYERS],
+    community_cards: [u8; 5],
+    num_community: u8,
+) -> ([u16; MAX_PLAYERS], [u16; MAX_PLAYERS]) {
+    // 1. Decrypt the contenders' hole cards.
+    let hands = player_hands.to_arcis();
+    let mut hole_cards = [[INVALID_CARD_INDEX; 2]; MAX_PLAYERS];
+    for p in 0..MAX_PLAYERS {
+        if contenders[p] {
+            hole_cards[p] = hands[p].to_array();
+        }
+    }
+
+    // 2. Sample runouts and tally the results.
+    let mut wins = [0u64; MAX_PLAYERS];
+    let mut ties = [0u64; MAX_PLAYERS];
+    for _ in 0..EQUITY_SAMPLES {
+        let mut deck = INITIAL_DECK;
+        ArcisRNG::shuffle(&mut deck);
+        equity::tally_runout(
+            hole_cards,
+            contenders,
+            community_cards,
+            num_community,
+            deck,
+            &mut wins,
+            &mut ties,
+        );
+    }
+
+    // 3. Reveal only the aggregate percentages.
+    (
+        equity::to_basis_points(wins, EQUITY_SAMPLES as u64),
+        equity::to_basis_points(ties, EQUITY_SAMPLES as u64),
+    )
+}
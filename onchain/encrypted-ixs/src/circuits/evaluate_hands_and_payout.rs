@@ -11,22 +11,25 @@
 //!    total bets for each player, an active player mask, and player public keys.
 //! 2. Decryption: Securely decrypts each active player's 2-card hand within the MPC.
 //! 3. Hand Evaluation: For each active player, it combines their 2 hole cards with
-//!    the 5 community cards and calls the `poker_evaluator` logic to determine the
+//!    the dealt community cards (`num_community`, normally 5) and calls the `poker_evaluator` logic to determine the
 //!    best possible 5-card hand and its rank.
 //! 4. Payout Calculation: It passes the list of hand ranks and player bets to the
 //!    `pot_calculator` logic, which handles the complex task of distributing the
-//!    main pot and any side pots according to poker rules.
-//! 5. Output: Returns a publicly visible, fixed-size array of `WinnerInfo` structs,
-//!    detailing which players won and the exact amounts they are to be paid.
+//!    main pot and any side pots according to poker rules, less the rake, with odd
+//!    chips going to the tied winners nearest the button's left.
+//! 5. Output: Returns a publicly visible, fixed-size array of `ShowdownDetail` structs,
+//!    one per seat: the category and tie-break ranks of the seat's best hand, and the
+//!    exact amount it is to be paid. The output is `MAX_PLAYERS` * 14 bytes (84 bytes)
+//!    whatever the number of players, so the callback decodes a fixed size.
 //!
 //! @dependencies
 //! - `arcis_imports`: For all Arcis-related macros and types.
-//! - `crate::types`: For `Hand`, `WinnerInfo`.
+//! - `crate::types`: For `Hand`, `ShowdownDetail`.
 //! - `crate::logic::poker_evaluator`: For hand evaluation.
 //! - `crate::logic::pot_calculator`: For payout calculations.
 
 use arcis_imports::*;
-use crate::types::{Hand, WinnerInfo};
+use crate::types::{Hand, ShowdownDetail};
 use crate::logic::{poker_evaluator, pot_calculator};
 
 /// The maximum number of players at a table.
@@ -42,22 +45,30 @@ pub const MAX_PLAYERS: usize = 6;
 /// # Arguments
 /// * `player_hands`: An array of encrypted 2-card hands for each seat.
 /// * `community_cards`: A public array of the 5 community cards indices.
+/// * `num_community`: How many of `community_cards` have been dealt (0-5). Slots
+///   beyond this are ignored, so an incomplete board can still be evaluated.
 /// * `player_bets`: The total amount each player has bet in the hand.
 /// * `active_players`: A boolean mask indicating which players are part of the showdown.
 /// * `player_pubkeys`: The Arcis public keys for each player, used to identify winners.
+/// * `dealer_position`: The button's seat, which decides who gets the odd chips of a split pot.
+/// * `rake`: The platform rake, which the payouts leave out.
 ///
 /// # Returns
-/// An array of `WinnerInfo` structs. Each entry corresponds to a player seat and
-/// contains their public key and the amount of chips they won. Non-winners will have
-/// an amount of 0.
+/// An array of `ShowdownDetail` structs. Each entry corresponds to a player seat and
+/// contains their best hand's category and tie-break ranks and the amount of chips
+/// they won. Non-winners have an amount of 0, and seats not in the showdown a
+/// `hand_category` of 0.
 #[instruction]
 pub fn evaluate_hands_and_payout(
     player_hands: [Enc<Shared, Hand>; MAX_PLAYERS],
     community_cards: [u8; 5],
+    num_community: u8,
     player_bets: [u64; MAX_PLAYERS],
     active_players: [bool; MAX_PLAYERS],
     player_pubkeys: [ArcisPublicKey; MAX_PLAYERS],
-) -> [WinnerInfo; MAX_PLAYERS] {
+    dealer_position: u8,
+    rake: u64,
+) -> [ShowdownDetail; MAX_PLAYERS] {
 
     // 1. Evaluate each active player's hand
     let dummy_rank = poker_evaluator::HandRank::NoHand;
@@ -76,8 +87,8 @@ pub fn evaluate_hands_and_payout(
                 seven_cards[j + 2] = community_cards[j];
             }
 
-            // Evaluate the best 5-card hand from the 7 cards
-            player_ranks[i] = poker_evaluator::evaluate_7_cards(seven_cards);
+            // Evaluate the best hand from the hole cards and the dealt community cards
+            player_ranks[i] = poker_evaluator::evaluate_7_cards(seven_cards, num_community);
         }
     }
 
@@ -87,8 +98,19 @@ pub fn evaluate_hands_and_payout(
         player_ranks,
         active_players,
         player_pubkeys,
+        dealer_position,
+        rake,
     );
     
-    // 3. Return the results
-    winner_payouts
+    // 3. Publish each seat's hand alongside its winnings
+    let mut details = [ShowdownDetail { hand_category: 0, hand_ranks: [0; 5], amount_won: 0 }; MAX_PLAYERS];
+    for i in 0..MAX_PLAYERS {
+        let key = pot_calculator::hand_rank_key(player_ranks[i]);
+        details[i] = ShowdownDetail {
+            hand_category: key[0],
+            hand_ranks: [key[1], key[2], key[3], key[4], key[5]],
+            amount_won: winner_payouts[i].amount_won,
+        };
+    }
+    details
 }
\ No newline at end of file
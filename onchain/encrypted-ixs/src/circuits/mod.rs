@@ -11,7 +11,22 @@
 //! - `reveal_community_cards`: Contains the circuit for revealing the flop, turn, and river.
 //! - `evaluate_hands_and_payout`: Contains the circuit for resolving the showdown,
 //!   evaluating hands, and calculating pot distribution.
+//! - `compute_allin_equity`: Contains the circuit for estimating each all-in
+//!   player's win and tie equity from the current board.
+//! - `verify_shown_hole_cards`: Contains the circuit for checking shown hands against
+//!   the deck positions they were dealt from.
+//! - `rabbit_hunt`: Contains the circuit for revealing the would-be board after a hand
+//!   ends early.
+//! - `reveal_remaining_board`: Contains the circuit for revealing every remaining
+//!   street at once when the board is run out between all-in players.
+//! - `show_one_card`: Contains the circuit for revealing a single hole card after
+//!   the hand is over.
 
 pub mod shuffle_and_deal;
 pub mod reveal_community_cards;
-pub mod evaluate_hands_and_payout;
\ No newline at end of file
+pub mod evaluate_hands_and_payout;
+pub mod compute_allin_equity;
+pub mod verify_shown_hole_cards;
+pub mod rabbit_hunt;
+pub mod reveal_remaining_board;
+pub mod show_one_card;
\ No newline at end of file
@@ -0,0 +1,65 @@
+//! src/circuits/rabbit_hunt.rs
+//!
+//! @description
+//! Defines the `rabbit_hunt` confidential instruction. After a hand ends before the
+//! river, players sometimes want to see the cards that would have come. This
+//! circuit reveals the rest of the would-be board from the hand's stored deck,
+//! following the same burn-and-deal order as `reveal_community_cards`. It reads the
+//! deck without changing it, and its output never feeds into payouts.
+//!
+//! @logic
+//! 1. Decrypts the deck inside the MPC.
+//! 2. Starting from the next street's burn card, walks the remaining streets
+//!    (flop: burn + 3, turn: burn + 1, river: burn + 1), skipping each burn card.
+//! 3. Returns the would-be community cards, padded with `INVALID_CARD_INDEX`, and how
+//!    many of them are real.
+//!
+//! @dependencies
+//! - `arcis_imports`: For all Arcis-related macros and types.
+//! - `crate::types`: For the `Deck` data structure and `INVALID_CARD_INDEX`.
+
+use arcis_imports::*;
+use crate::types::{Deck, INVALID_CARD_INDEX};
+
+/// Reveals the community cards that were never dealt.
+///
+/// # Arguments
+/// * `deck_ctxt`: The `Enc<Mxe, Deck>` for the finished hand.
+/// * `deck_top_card_idx`: The deck index of the next street's burn card.
+/// * `num_community`: How many community cards were dealt before the hand ended (0, 3 or 4).
+///
+/// # Returns
+/// A tuple containing:
+/// - `[u8; 5]`: The would-be remaining community cards in deal order, padded with
+///   `INVALID_CARD_INDEX`.
+/// - `u8`: How many real cards were revealed (`5 - num_community` unless the deck ran out).
+#[instruction]
+pub fn rabbit_hunt(
+    deck_ctxt: Enc<Mxe, Deck>,
+    deck_top_card_idx: u8,
+    num_community: u8,
+) -> ([u8; 5], u8) {
+    // 1. Decrypt the deck inside the MPC.
+    let deck_array = deck_ctxt.to_arcis().to_array();
+
+    // 2. Walk the board slots that were never dealt. A burn card precedes the flop
+    //    (slot 0), the turn (slot 3) and the river (slot 4).
+    let mut hunted = [INVALID_CARD_INDEX; 5];
+    let mut hunted_count = 0u8;
+    let mut cursor = deck_top_card_idx as usize;
+    for slot in 0..5 {
+        if slot >= num_community as usize {
+            if slot == 0 || slot == 3 || slot == 4 {
+                cursor += 1; // burn
+            }
+            if cursor < deck_array.len() {
+                hunted[hunted_count as usize] = deck_array[cursor];
+                hunted_count += 1;
+            }
+            cursor += 1;
+        }
+    }
+
+    // 3. Return the public cards; the deck is left untouched.
+    (hunted, hunted_count)
+}
@@ -13,18 +13,16 @@
 //! 4. Reveals the specified number of cards that follow the burn card.
 //! 5. Marks the revealed cards as used within the deck array.
 //! 6. Returns the publicly revealed card indices in a fixed-size array (padded with
-//!    an invalid value if fewer than 3 cards are revealed).
+//!    an invalid value if fewer than 3 cards are revealed), along with the number of
+//!    real cards produced so the callback can detect a short reveal.
 //! 7. Returns the re-encrypted, updated deck state to be stored back on-chain.
 //!
 //! @dependencies
 //! - `arcis_imports`: For all Arcis-related macros and types.
-//! - `crate::types`: For the `Deck` data structure.
+//! - `crate::types`: For the `Deck` data structure and the shared `INVALID_CARD_INDEX`.
 
 use arcis_imports::*;
-use crate::types::Deck;
-
-/// An invalid card index used for padding and marking cards as "used".
-const INVALID_CARD_INDEX: u8 = 255;
+use crate::types::{Deck, INVALID_CARD_INDEX};
 
 /// The maximum number of cards that can be revealed in a single operation (the flop).
 const MAX_REVEAL: usize = 3;
@@ -44,13 +42,16 @@ const MAX_REVEAL: usize = 3;
 /// A tuple containing:
 /// - `[u8; 3]`: A fixed-size array with the indices of the revealed cards. If fewer
 ///   than 3 cards are revealed, the remaining slots are padded with `INVALID_CARD_INDEX`.
+/// - `u8`: How many real cards were revealed. This is less than `num_cards_to_reveal`
+///   only if the reveal ran past the end of the deck, which means the on-chain deck
+///   cursor is out of sync; the callback must reject it rather than deal a short street.
 /// - `Enc<Mxe, Deck>`: The updated encrypted deck with the dealt cards marked as used.
 #[instruction]
 pub fn reveal_community_cards(
     deck_ctxt: Enc<Mxe, Deck>,
     deck_top_card_idx: u8,
     num_cards_to_reveal: u8,
-) -> ([u8; MAX_REVEAL], Enc<Mxe, Deck>) {
+) -> ([u8; MAX_REVEAL], u8, Enc<Mxe, Deck>) {
     // 1. Decrypt the deck inside the MPC.
     let mut deck_array = deck_ctxt.to_arcis().to_array();
 
@@ -63,6 +64,7 @@ pub fn reveal_community_cards(
 
     // 3. Reveal the next N cards.
     let mut revealed_cards = [INVALID_CARD_INDEX; MAX_REVEAL];
+    let mut revealed_count = 0u8;
     let reveal_start_idx = (deck_top_card_idx + 1) as usize;
 
     // The loop iterates up to `num_cards_to_reveal` but not exceeding the MAX_REVEAL constant.
@@ -74,6 +76,7 @@ pub fn reveal_community_cards(
         if i < num_cards_to_reveal as usize && current_idx < deck_array.len() {
             revealed_cards[i] = deck_array[current_idx];
             deck_array[current_idx] = INVALID_CARD_INDEX; // Mark the card as used
+            revealed_count += 1;
         }
     }
 
@@ -81,6 +84,6 @@ pub fn reveal_community_cards(
     let updated_deck = Deck::from_array(deck_array);
     let updated_deck_ctxt = deck_ctxt.owner.from_arcis(updated_deck);
 
-    // 5. Return the public cards and the new encrypted deck state.
-    (revealed_cards, updated_deck_ctxt)
+    // 5. Return the public cards, how many are real, and the new encrypted deck state.
+    (revealed_cards, revealed_count, updated_deck_ctxt)
 }
\ No newline at end of file
@@ -0,0 +1,78 @@
+//! src/circuits/reveal_remaining_board.rs
+//!
+//! @description
+//! Defines the `reveal_remaining_board` confidential instruction. Once every player
+//! still in the hand is all-in, there is no betting left between streets, so the
+//! rest of the board is revealed in a single computation instead of one
+//! `reveal_community_cards` call per street. Cards are dealt in the same
+//! burn-and-deal order as the street-by-street path, so the board is identical.
+//!
+//! @logic
+//! 1. Decrypts the deck inside the MPC.
+//! 2. Starting from the next street's burn card, walks the remaining board slots
+//!    (flop: burn + 3, turn: burn + 1, river: burn + 1), burning before each street.
+//! 3. Marks every burned and revealed card as used in the deck.
+//! 4. Returns the revealed cards in deal order, padded with `INVALID_CARD_INDEX`, how
+//!    many of them are real, and the re-encrypted deck.
+//!
+//! @dependencies
+//! - `arcis_imports`: For all Arcis-related macros and types.
+//! - `crate::types`: For the `Deck` data structure and `INVALID_CARD_INDEX`.
+
+use arcis_imports::*;
+use crate::types::{Deck, INVALID_CARD_INDEX};
+
+/// The number of community card slots; at most this many cards are revealed.
+const BOARD_SIZE: usize = 5;
+
+/// Reveals every community card still to come in one invocation.
+///
+/// # Arguments
+/// * `deck_ctxt`: The `Enc<Mxe, Deck>` containing the current state of the shuffled deck.
+/// * `deck_cursor`: The deck index of the next street's burn card.
+/// * `num_community`: How many community cards are already on the board (0, 3 or 4).
+///
+/// # Returns
+/// A tuple containing:
+/// - `[u8; 5]`: The revealed community cards in deal order, padded with `INVALID_CARD_INDEX`.
+/// - `u8`: How many real cards were revealed. Anything other than `5 - num_community`
+///   means the deck cursor is out of sync, and the callback must reject the reveal.
+/// - `Enc<Mxe, Deck>`: The updated encrypted deck with the burned and dealt cards used.
+#[instruction]
+pub fn reveal_remaining_board(
+    deck_ctxt: Enc<Mxe, Deck>,
+    deck_cursor: u8,
+    num_community: u8,
+) -> ([u8; BOARD_SIZE], u8, Enc<Mxe, Deck>) {
+    // 1. Decrypt the deck inside the MPC.
+    let mut deck_array = deck_ctxt.to_arcis().to_array();
+
+    // 2. Walk the board slots still to be dealt. A burn card precedes the flop
+    //    (slot 0), the turn (slot 3) and the river (slot 4).
+    let mut revealed = [INVALID_CARD_INDEX; BOARD_SIZE];
+    let mut revealed_count = 0u8;
+    let mut cursor = deck_cursor as usize;
+    for slot in 0..BOARD_SIZE {
+        if slot >= num_community as usize {
+            if slot == 0 || slot == 3 || slot == 4 {
+                if cursor < deck_array.len() {
+                    deck_array[cursor] = INVALID_CARD_INDEX; // burn
+                }
+                cursor += 1;
+            }
+            if cursor < deck_array.len() {
+                revealed[revealed_count as usize] = deck_array[cursor];
+                deck_array[cursor] = INVALID_CARD_INDEX; // Mark the card as used
+                revealed_count += 1;
+            }
+            cursor += 1;
+        }
+    }
+
+    // 3. Re-encrypt the updated deck.
+    let updated_deck = Deck::from_array(deck_array);
+    let updated_deck_ctxt = deck_ctxt.owner.from_arcis(updated_deck);
+
+    // 4. Return the public cards, how many are real, and the new encrypted deck state.
+    (revealed, revealed_count, updated_deck_ctxt)
+}
@@ -0,0 +1,48 @@
+//! src/circuits/show_one_card.rs
+//!
+//! @description
+//! Defines the `show_one_card` confidential instruction. Once a hand is over, a player
+//! may show one of their hole cards for table talk while keeping the other private.
+//! This circuit reveals exactly one card of the hand's stored deck: the one dealt to
+//! the player at the requested position. The player's other card never leaves the MPC.
+//!
+//! @logic
+//! 1. Decrypts the deck inside the MPC.
+//! 2. Reads the card at the given deck position. The on-chain program derives that
+//!    position from the public deal order: with `n` players dealt in, the seat with rank
+//!    `r` among them holds deck positions `r` (first card) and `n + r` (second card) when
+//!    `shuffle_and_deal` deals round-robin, or `2r` and `2r + 1` when it deals sequentially.
+//! 3. Returns that single card index, or `INVALID_CARD_INDEX` if the position is
+//!    outside the deck.
+//!
+//! @dependencies
+//! - `arcis_imports`: For all Arcis-related macros and types.
+//! - `crate::types`: For the `Deck` data structure and `INVALID_CARD_INDEX`.
+
+use arcis_imports::*;
+use crate::types::{Deck, INVALID_CARD_INDEX};
+
+/// Reveals the single hole card dealt from `deck_position`.
+///
+/// # Arguments
+/// * `deck_ctxt`: The `Enc<Mxe, Deck>` for the finished hand.
+/// * `deck_position`: The deck position the shown card was dealt from.
+///
+/// # Returns
+/// `u8`: The public card index, or `INVALID_CARD_INDEX` for an out-of-range position.
+#[instruction]
+pub fn show_one_card(deck_ctxt: Enc<Mxe, Deck>, deck_position: u8) -> u8 {
+    // 1. Decrypt the deck inside the MPC.
+    let deck_array = deck_ctxt.to_arcis().to_array();
+
+    // 2. Select the requested card without branching on secret data.
+    let mut shown = INVALID_CARD_INDEX;
+    for i in 0..deck_array.len() {
+        if i == deck_position as usize {
+            shown = deck_array[i];
+        }
+    }
+
+    // 3. Only this card is revealed; the rest of the deck stays encrypted.
+    shown
+}
@@ -10,14 +10,19 @@
 //! 2. Uses Arcium's cryptographically secure Random Number Generator (`ArcisRNG`)
 //!    to shuffle the deck.
 //! 3. Generates a cryptographic commitment to the shuffle, allowing for later verification.
-//! 4. Deals two hole cards to each active player in a round-robin fashion, mimicking a
-//!    real poker deal.
+//! 4. Deals two hole cards to each active player from the top of the deck, in the
+//!    table's deal style: round-robin by default, mimicking a real poker deal, or
+//!    sequentially (both cards to one player before the next). Either way the deal
+//!    consumes exactly the first two cards per active player.
 //! 5. Encrypts each player's hole cards individually using a shared secret derived from
 //!    their public key, ensuring only they can view their hand.
 //! 6. Encrypts the entire shuffled deck for the Arcium network (MXE), keeping the
 //!    sequence of community cards confidential until they are revealed.
-//! 7. Returns the encrypted deck, shuffle commitment, and an array of all players'
-//!    encrypted hands.
+//! 7. Checks that every dealt hole card is a real card and that no card was dealt
+//!    twice, so a malformed deck is caught as a misdeal before anyone acts.
+//! 8. Returns the encrypted deck, shuffle commitment, an array of all players'
+//!    encrypted hands, how many deck cards the deal consumed, and whether the deal
+//!    passed the check.
 //!
 //! @dependencies
 //! - `arcis_imports`: For all Arcis-related macros and types.
@@ -52,6 +57,9 @@ const MAX_PLAYERS: usize = 6;
 /// * `mxe`: The Arcium execution environment context, used for MXE-only encryption.
 /// * `player_pubkeys`: An array of `ArcisPublicKey` for each of the 6 seats at the table.
 /// * `active_players`: A boolean array indicating which of the 6 seats are occupied by active players.
+/// * `sequential_deal`: Whether to deal both cards to each player in turn rather than
+///   round-robin. With `n` active players, the one ranked `r` in seat order receives deck
+///   positions `r` and `n + r` in a round-robin deal, and `2r` and `2r + 1` in a sequential one.
 ///
 /// # Returns
 /// A tuple containing:
@@ -59,15 +67,24 @@ const MAX_PLAYERS: usize = 6;
 /// - `[u8; 32]`: A cryptographic commitment to the shuffle for later verification.
 /// - `Enc<Mxe, [Hand; 6]>`: An array of 2-card hands for each seat encrypted for the MXE.
 ///   Only the MPC can read these hands. Inactive seats contain dummy data.
+/// - `u8`: The number of deck cards consumed by the deal (two per active player). The
+///   on-chain program starts dealing community cards from this position, so the board
+///   never overlaps the hole cards whatever the number of active players.
+/// - `bool`: Whether every dealt hole card is in `0..52` and distinct. Only this flag is
+///   made public; the on-chain program declares a misdeal and refunds the blinds if it
+///   is `false`.
 #[instruction]
 pub fn shuffle_and_deal(
     mxe: Mxe,
     player_pubkeys: [ArcisPublicKey; MAX_PLAYERS],
     active_players: [bool; MAX_PLAYERS],
+    sequential_deal: bool,
 ) -> (
     Enc<Mxe, Deck>,
     [u8; 32],
     Enc<Mxe, [Hand; MAX_PLAYERS]>,
+    u8,
+    bool,
 ) {
     // 1. Shuffle the Deck
     let mut shuffled_deck = INITIAL_DECK;
@@ -75,41 +92,71 @@ pub fn shuffle_and_deal(
 
     // 2. Generate Shuffle Commitment
     // TODO: Replace this with a proper cryptographic hash function once available in Arcis.
-    // For now, we use the first 32 bytes of the shuffled deck as a commitment.
+    // The commitment must then be SHA-256(shuffled_deck || salt) over a 32-byte salt
+    // drawn from `ArcisRNG`, which is what the on-chain `verify_shuffle_commitment`
+    // recomputes. For now, we use the first 32 bytes of the shuffled deck as a commitment.
     let mut shuffle_commitment = [0u8; 32];
     for i in 0..32 {
         shuffle_commitment[i] = shuffled_deck[i];
     }
 
     // 3. Deal Hole Cards
-    let mut dealt_cards: [[u8; 2]; MAX_PLAYERS] = [[52; 2]; MAX_PLAYERS]; // 52 is an invalid card index
-    let mut card_idx_counter = 0;
-    
-    // First card dealt to each active player
+    let mut dealt_cards: [[u8; 2]; MAX_PLAYERS] = [[INVALID_CARD_INDEX; 2]; MAX_PLAYERS];
+    let mut num_active = 0usize;
     for i in 0..MAX_PLAYERS {
         if active_players[i] {
-            dealt_cards[i][0] = shuffled_deck[card_idx_counter];
-            card_idx_counter += 1;
+            num_active += 1;
         }
     }
 
-    // Second card dealt to each active player
+    // Each active player's cards come from the positions the deal style assigns to
+    // their rank in seat order. Both styles fill positions `0..2 * num_active`.
+    let mut deal_rank = 0usize;
     for i in 0..MAX_PLAYERS {
         if active_players[i] {
-            dealt_cards[i][1] = shuffled_deck[card_idx_counter];
-            card_idx_counter += 1;
+            for c in 0..2 {
+                let position = if sequential_deal {
+                    deal_rank * 2 + c
+                } else {
+                    c * num_active + deal_rank
+                };
+                dealt_cards[i][c] = shuffled_deck[position];
+            }
+            deal_rank += 1;
         }
     }
+    let card_idx_counter = num_active * 2;
 
-    // 4. Create Hands Array
+    // 4. Check the Deal
+    // Every hole card must be a real card, and none may have been dealt twice. A
+    // failure means the shuffled deck itself is malformed.
+    let mut deal_valid = true;
+    for i in 0..MAX_PLAYERS {
+        for c in 0..2 {
+            let card = dealt_cards[i][c];
+            if active_players[i] && card >= 52 {
+                deal_valid = false;
+            }
+            for j in 0..MAX_PLAYERS {
+                for d in 0..2 {
+                    let earlier = j * 2 + d < i * 2 + c;
+                    if active_players[i] && active_players[j] && earlier && dealt_cards[j][d] == card {
+                        deal_valid = false;
+                    }
+                }
+            }
+        }
+    }
+
+    // 5. Create Hands Array
     // Create an array of hands for all players (active and inactive)
     let mut hands_array: [Hand; MAX_PLAYERS] = [
-        Hand::from_array([52, 52]), // dummy hand
-        Hand::from_array([52, 52]), // dummy hand
-        Hand::from_array([52, 52]), // dummy hand
-        Hand::from_array([52, 52]), // dummy hand
-        Hand::from_array([52, 52]), // dummy hand
-        Hand::from_array([52, 52]), // dummy hand
+        Hand::from_array([INVALID_CARD_INDEX; 2]), // dummy hand
+        Hand::from_array([INVALID_CARD_INDEX; 2]), // dummy hand
+        Hand::from_array([INVALID_CARD_INDEX; 2]), // dummy hand
+        Hand::from_array([INVALID_CARD_INDEX; 2]), // dummy hand
+        Hand::from_array([INVALID_CARD_INDEX; 2]), // dummy hand
+        Hand::from_array([INVALID_CARD_INDEX; 2]), // dummy hand
     ];
 
     for i in 0..MAX_PLAYERS {
@@ -118,12 +165,13 @@ pub fn shuffle_and_deal(
         hands_array[i] = Hand::from_array(dealt_cards[i]);
     }
 
-    // 5. Encrypt the Full Shuffled Deck for the MXE
+    // 6. Encrypt the Full Shuffled Deck for the MXE
     let encrypted_deck = mxe.from_arcis(Deck::from_array(shuffled_deck));
 
-    // 6. Encrypt the Hands Array for the MXE
+    // 7. Encrypt the Hands Array for the MXE
     let encrypted_hands = mxe.from_arcis(hands_array);
 
-    // 7. Return all data
-    (encrypted_deck, shuffle_commitment, encrypted_hands)
+    // 8. Return all data, including where the undealt part of the deck begins.
+    let cards_dealt = card_idx_counter as u8;
+    (encrypted_deck, shuffle_commitment, encrypted_hands, cards_dealt, deal_valid)
 }
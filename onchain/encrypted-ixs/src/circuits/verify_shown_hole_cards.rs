@@ -0,0 +1,83 @@
+//! src/circuits/verify_shown_hole_cards.rs
+//!
+//! @description
+//! Defines the `verify_shown_hole_cards` confidential instruction, which closes the
+//! fairness loop at showdown. For every hand that is shown, it checks that the hole
+//! cards match the positions in the committed deck from which `shuffle_and_deal`
+//! dealt them. A mismatch means the hand was altered after the shuffle.
+//!
+//! @logic
+//! 1. Decrypts the shuffled deck inside the MPC.
+//! 2. Recomputes each dealt-in seat's deck positions. A round-robin `shuffle_and_deal`
+//!    deals the first card to each dealt-in seat in seat order, then the second card, so
+//!    with `n` players dealt in, the seat with rank `r` among them holds deck positions
+//!    `r` and `n + r`. A sequential deal gives it positions `2r` and `2r + 1`.
+//! 3. Compares each shown hand with the cards at those positions.
+//! 4. Returns a public pass/fail flag per seat. Seats that did not show always pass.
+//!
+//! @dependencies
+//! - `arcis_imports`: For all Arcis-related macros and types.
+//! - `crate::types`: For the `Deck` data structure.
+//!
+//! @notes
+//! - Dealing only reads from the front of the deck and later reveals start after the
+//!   hole cards, so the dealt positions are unchanged in the stored deck.
+
+use arcis_imports::*;
+use crate::types::Deck;
+use crate::circuits::evaluate_hands_and_payout::MAX_PLAYERS;
+
+/// Checks every shown hand against the deck positions it was dealt from.
+///
+/// # Arguments
+/// * `deck_ctxt`: The `Enc<Mxe, Deck>` produced by `shuffle_and_deal` for this hand.
+/// * `dealt_in`: Which seats were dealt cards, as passed to `shuffle_and_deal`.
+/// * `shown`: Which seats showed their hand.
+/// * `shown_cards`: The shown hole card indices per seat, in deal order.
+/// * `sequential_deal`: Whether the hand was dealt sequentially rather than round-robin,
+///   as passed to `shuffle_and_deal`.
+///
+/// # Returns
+/// `[bool; 6]`: `false` for any seat whose shown cards differ from the deck, which
+/// the on-chain callback reports in a `ShownHandsVerified` event.
+#[instruction]
+pub fn verify_shown_hole_cards(
+    deck_ctxt: Enc<Mxe, Deck>,
+    dealt_in: [bool; MAX_PLAYERS],
+    shown: [bool; MAX_PLAYERS],
+    shown_cards: [[u8; 2]; MAX_PLAYERS],
+    sequential_deal: bool,
+) -> [bool; MAX_PLAYERS] {
+    // 1. Decrypt the deck inside the MPC.
+    let deck_array = deck_ctxt.to_arcis().to_array();
+
+    // 2. Count the players dealt in to locate the start of the second round.
+    let mut num_dealt_in = 0usize;
+    for i in 0..MAX_PLAYERS {
+        if dealt_in[i] {
+            num_dealt_in += 1;
+        }
+    }
+
+    // 3. Walk the seats in deal order and compare each shown hand.
+    let mut verified = [true; MAX_PLAYERS];
+    let mut deal_rank = 0usize;
+    for i in 0..MAX_PLAYERS {
+        if dealt_in[i] {
+            let (first_card, second_card) = if sequential_deal {
+                (deck_array[deal_rank * 2], deck_array[deal_rank * 2 + 1])
+            } else {
+                (deck_array[deal_rank], deck_array[num_dealt_in + deal_rank])
+            };
+            if shown[i] && (shown_cards[i][0] != first_card || shown_cards[i][1] != second_card) {
+                verified[i] = false;
+            }
+            deal_rank += 1;
+        } else if shown[i] {
+            // A seat that was not dealt in has no cards to show.
+            verified[i] = false;
+        }
+    }
+
+    verified
+}
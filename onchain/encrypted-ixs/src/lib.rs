@@ -21,6 +21,9 @@ mod circuits {
 
     const MAX_PLAYERS: usize = 6;
 
+    /// Padding for "no card"; matches `INVALID_CARD_INDEX` in `types.rs` and on-chain.
+    const INVALID_CARD_INDEX: u8 = 52;
+
     pub struct Card {
         pub suit: u8,
         pub rank: u8,
@@ -109,6 +112,284 @@ mod circuits {
         pub player_index: u8,
     }
 
+    /// The number of ranks in a suit; a card index is `suit * 13 + rank`.
+    const NUM_RANKS: u8 = 13;
+
+    /// Rank of an ace (2 = 0, ..., K = 11, A = 12): the high card of a broadway
+    /// straight and the low card of a wheel.
+    const ACE_RANK: u8 = 12;
+
+    /// The 21 ways to choose five of seven cards, each in ascending order.
+    const FIVE_OF_SEVEN: [[usize; 5]; 21] = [
+        [0, 1, 2, 3, 4], [0, 1, 2, 3, 5], [0, 1, 2, 3, 6], [0, 1, 2, 4, 5], [0, 1, 2, 4, 6],
+        [0, 1, 2, 5, 6], [0, 1, 3, 4, 5], [0, 1, 3, 4, 6], [0, 1, 3, 5, 6], [0, 1, 4, 5, 6],
+        [0, 2, 3, 4, 5], [0, 2, 3, 4, 6], [0, 2, 3, 5, 6], [0, 2, 4, 5, 6], [0, 3, 4, 5, 6],
+        [1, 2, 3, 4, 5], [1, 2, 3, 4, 6], [1, 2, 3, 5, 6], [1, 2, 4, 5, 6], [1, 3, 4, 5, 6],
+        [2, 3, 4, 5, 6],
+    ];
+
+    /// A hand's strength packed into one number, so hands compare with a single `>`:
+    /// the category (9 for a straight flush down to 1 for high card, 0 for no hand)
+    /// above five 4-bit tie-break ranks, most significant first. Unused ranks are 0.
+    pub struct HandRank {
+        pub value: u64,
+    }
+
+    impl HandRank {
+        pub fn category(&self) -> u8 {
+            (self.value >> 20) as u8
+        }
+
+        pub fn ranks(&self) -> [u8; 5] {
+            let mut ranks = [0u8; 5];
+            for i in 0..5 {
+                ranks[i] = ((self.value >> (16 - 4 * i)) % 16) as u8;
+            }
+            ranks
+        }
+    }
+
+    /// Ranks exactly five cards. Cards of a rank are grouped, bigger groups first and
+    /// then higher ranks, so the tie-break ranks read e.g. trips, then the kickers.
+    pub fn evaluate_5_cards(cards: [u8; 5]) -> HandRank {
+        let mut ranks = [0u8; 5];
+        for i in 0..5 {
+            ranks[i] = cards[i] % NUM_RANKS;
+        }
+
+        // Sort key per card: the size of its rank's group, then the rank.
+        let mut keys = [0u8; 5];
+        for i in 0..5 {
+            let mut count = 0u8;
+            for j in 0..5 {
+                if ranks[j] == ranks[i] {
+                    count += 1;
+                }
+            }
+            keys[i] = count * 16 + ranks[i];
+        }
+        keys.sort();
+
+        // Walk the keys from the largest, keeping each group's rank once.
+        let mut tiebreak = 0u64;
+        let mut groups = 0u8;
+        let mut previous = u8::MAX;
+        for i in 0..5 {
+            let key = keys[4 - i];
+            if key != previous {
+                tiebreak = tiebreak * 16 + (key % 16) as u64;
+                groups += 1;
+            }
+            previous = key;
+        }
+        for i in 0..5 {
+            if i >= groups as usize {
+                tiebreak *= 16;
+            }
+        }
+        let largest_group = keys[4] / 16;
+
+        let mut flush = true;
+        for i in 1..5 {
+            if cards[i] / NUM_RANKS != cards[0] / NUM_RANKS {
+                flush = false;
+            }
+        }
+        // With five distinct ranks the keys are the ranks themselves, in order.
+        let high = keys[4] % 16;
+        let second = keys[3] % 16;
+        let low = keys[0] % 16;
+        let wheel = groups == 5 && high == ACE_RANK && second == 3;
+        let straight = groups == 5 && (high == low + 4 || wheel);
+        let straight_high = if wheel { 3 } else { high };
+
+        let category: u64 = if straight && flush {
+            9
+        } else if largest_group == 4 {
+            8
+        } else if largest_group == 3 && groups == 2 {
+            7
+        } else if flush {
+            6
+        } else if straight {
+            5
+        } else if largest_group == 3 {
+            4
+        } else if largest_group == 2 && groups == 3 {
+            3
+        } else if largest_group == 2 {
+            2
+        } else {
+            1
+        };
+        if straight {
+            tiebreak = (straight_high as u64) << 16;
+        }
+
+        HandRank { value: (category << 20) + tiebreak }
+    }
+
+    /// Ranks the best five-card hand among two hole cards and the first `num_community`
+    /// community cards: every five-card choice of those is ranked with `evaluate_5_cards`
+    /// and the highest kept. Slots past the dealt board are ignored, a `num_community`
+    /// above 5 counts as 5, and with fewer than five cards there is no hand (value 0).
+    pub fn evaluate_7_cards(cards: [u8; 7], num_community: u8) -> HandRank {
+        let num_community = if num_community > 5 { 5 } else { num_community };
+        let num_cards = 2 + num_community as usize;
+
+        let mut best = HandRank { value: 0 };
+        for choice in FIVE_OF_SEVEN {
+            let hand = evaluate_5_cards([
+                cards[choice[0]],
+                cards[choice[1]],
+                cards[choice[2]],
+                cards[choice[3]],
+                cards[choice[4]],
+            ]);
+            // The choice is in ascending order, so its last card is the highest slot used.
+            if choice[4] < num_cards && hand.value > best.value {
+                best = hand;
+            }
+        }
+        best
+    }
+
+    /// One seat's showdown result: its best hand and the chips it is to be paid.
+    /// Mirrors the program's `ShowdownDetail`.
+    pub struct ShowdownDetail {
+        /// 9 for a straight flush down to 1 for high card; 0 for no hand.
+        pub hand_category: u8,
+        /// Tie-break ranks, most significant first, zero-padded.
+        pub hand_ranks: [u8; 5],
+        /// The chips won, after rake.
+        pub amount_won: u64,
+    }
+
+    /// Splits the pot into a main pot and a side pot per all-in level and pays each to
+    /// the best hand among the players still in who reached it, indexed by seat.
+    ///
+    /// A pot holds what every contributor, folded players included, put in between the
+    /// previous level and its own: `min(bet, level) - min(bet, previous_level)`. The
+    /// rake comes out of the earliest pots first. Only exact ties in `hand_values`
+    /// split a pot, and its odd chips go one at a time to the tied winners from the
+    /// seat left of `dealer_position`, so the payouts add up to the bets less the rake.
+    pub fn calculate_payouts(
+        player_bets: [u64; MAX_PLAYERS],
+        hand_values: [u64; MAX_PLAYERS],
+        active_players: [bool; MAX_PLAYERS],
+        dealer_position: u8,
+        rake: u64,
+    ) -> [u64; MAX_PLAYERS] {
+        // The levels are the bets of the players still in, in ascending order. Zero
+        // and repeated levels make empty pots, so they need no special case.
+        let mut levels = [0u64; MAX_PLAYERS];
+        for i in 0..MAX_PLAYERS {
+            if active_players[i] {
+                levels[i] = player_bets[i];
+            }
+        }
+        levels.sort();
+
+        let mut payouts = [0u64; MAX_PLAYERS];
+        let mut rake_left = rake;
+        let mut previous_level = 0u64;
+        for level in levels {
+            let mut pot = 0u64;
+            let mut eligible = [false; MAX_PLAYERS];
+            let mut best = 0u64;
+            for i in 0..MAX_PLAYERS {
+                let bet = player_bets[i];
+                let up_to_level = if bet < level { bet } else { level };
+                let up_to_previous = if bet < previous_level { bet } else { previous_level };
+                pot += up_to_level - up_to_previous;
+
+                eligible[i] = active_players[i] && bet >= level;
+                if eligible[i] && hand_values[i] > best {
+                    best = hand_values[i];
+                }
+            }
+
+            let rake_taken = if rake_left < pot { rake_left } else { pot };
+            pot -= rake_taken;
+            rake_left -= rake_taken;
+
+            let mut winners = [false; MAX_PLAYERS];
+            let mut winner_count = 0u64;
+            for i in 0..MAX_PLAYERS {
+                winners[i] = eligible[i] && hand_values[i] == best;
+                if winners[i] {
+                    winner_count += 1;
+                }
+            }
+            let divisor = if winner_count == 0 { 1 } else { winner_count };
+            let share = pot / divisor;
+            let mut odd_chips = pot - share * divisor;
+            for offset in 1..(MAX_PLAYERS + 1) {
+                let seat = (dealer_position as usize + offset) % MAX_PLAYERS;
+                if winners[seat] {
+                    payouts[seat] += share;
+                    if odd_chips > 0 {
+                        payouts[seat] += 1;
+                        odd_chips -= 1;
+                    }
+                }
+            }
+
+            previous_level = level;
+        }
+        payouts
+    }
+
+    /// Ranks every active seat's hole cards with the first `num_community` community
+    /// cards and pays out the pots, one `ShowdownDetail` per seat. Seats not in the
+    /// showdown have no hand and win nothing.
+    pub fn showdown_details(
+        hole_cards: [[u8; 2]; MAX_PLAYERS],
+        community_cards: [u8; 5],
+        num_community: u8,
+        player_bets: [u64; MAX_PLAYERS],
+        active_players: [bool; MAX_PLAYERS],
+        dealer_position: u8,
+        rake: u64,
+    ) -> [ShowdownDetail; MAX_PLAYERS] {
+        let mut hand_values = [0u64; MAX_PLAYERS];
+        for i in 0..MAX_PLAYERS {
+            let cards = [
+                hole_cards[i][0],
+                hole_cards[i][1],
+                community_cards[0],
+                community_cards[1],
+                community_cards[2],
+                community_cards[3],
+                community_cards[4],
+            ];
+            let hand = evaluate_7_cards(cards, num_community);
+            if active_players[i] {
+                hand_values[i] = hand.value;
+            }
+        }
+
+        let payouts = calculate_payouts(player_bets, hand_values, active_players, dealer_position, rake);
+
+        [
+            showdown_detail(hand_values[0], payouts[0]),
+            showdown_detail(hand_values[1], payouts[1]),
+            showdown_detail(hand_values[2], payouts[2]),
+            showdown_detail(hand_values[3], payouts[3]),
+            showdown_detail(hand_values[4], payouts[4]),
+            showdown_detail(hand_values[5], payouts[5]),
+        ]
+    }
+
+    fn showdown_detail(hand_value: u64, amount_won: u64) -> ShowdownDetail {
+        let hand = HandRank { value: hand_value };
+        ShowdownDetail {
+            hand_category: hand.category(),
+            hand_ranks: hand.ranks(),
+            amount_won,
+        }
+    }
+
     #[instruction]
     pub fn shuffle_and_deal(
         mxe: Mxe,
@@ -124,9 +405,13 @@ mod circuits {
         Enc<Mxe, Deck>,
         Enc<Mxe, [u8; 32]>,
         [Enc<Shared, Hand>; MAX_PLAYERS],
+        u64,
     ) {
         let mut deck = INITIAL_DECK;
         ArcisRNG::shuffle(&mut deck);
+        // A public draw for placing a table's first dealer button, which nobody at the
+        // table can predict or choose.
+        let button_draw = ArcisRNG::gen_public_integer_from_width(64) as u64;
 
         // Simple commitment - just use the first card as a placeholder
         let commitment = [deck[0]; 32];
@@ -172,7 +457,7 @@ mod circuits {
             encrypted_hand5,
         ];
 
-        (encrypted_deck, encrypted_commitment, encrypted_hands)
+        (encrypted_deck, encrypted_commitment, encrypted_hands, button_draw)
     }
 
     #[instruction]
@@ -185,7 +470,7 @@ mod circuits {
     ) -> (Enc<Mxe, [u8; 5]>, Enc<Mxe, Deck>) {
         let deck_array = deck_ctxt.to_arcis().to_array();
 
-        let mut revealed_cards = [255u8; 5];
+        let mut revealed_cards = [INVALID_CARD_INDEX; 5];
 
         // Handle specific cases for num_cards_to_reveal
         // Flop (3 cards)
@@ -204,35 +489,351 @@ mod circuits {
         (encrypted_revealed_cards, encrypted_updated_deck)
     }
 
+    /// Evaluates the showdown and pays out the pot. Every seat's hand is decrypted so
+    /// the computation does not depend on who is still in; only active seats are ranked.
+    /// The per-seat results are revealed for the program to pay and publish.
     #[instruction]
     pub fn evaluate_hands_and_payout(
-        mxe: Mxe,
         community_cards: [u8; 5],
+        num_community: u8,
         player_bets: [u64; MAX_PLAYERS],
         active_players: [bool; MAX_PLAYERS],
+        dealer_position: u8,
+        rake: u64,
         player_hands: [Enc<Shared, Hand>; MAX_PLAYERS],
-    ) -> Enc<Mxe, [WinnerInfo; MAX_PLAYERS]> {
-        let winner_infos: [WinnerInfo; MAX_PLAYERS] = [
-            WinnerInfo { amount_won: 0, player_index: 0 },
-            WinnerInfo { amount_won: 0, player_index: 1 },
-            WinnerInfo { amount_won: 0, player_index: 2 },
-            WinnerInfo { amount_won: 0, player_index: 3 },
-            WinnerInfo { amount_won: 0, player_index: 4 },
-            WinnerInfo { amount_won: 0, player_index: 5 },
+    ) -> [ShowdownDetail; MAX_PLAYERS] {
+        let mut hole_cards = [[INVALID_CARD_INDEX; 2]; MAX_PLAYERS];
+        for i in 0..MAX_PLAYERS {
+            hole_cards[i] = player_hands[i].to_arcis().to_array();
+        }
+
+        showdown_details(
+            hole_cards,
+            community_cards,
+            num_community,
+            player_bets,
+            active_players,
+            dealer_position,
+            rake,
+        )
+        .reveal()
+    }
+}
+
+pub use circuits::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The circuit's padding for an undealt card slot.
+    const INVALID_CARD_INDEX: u8 = 52;
+
+    // Ranks, 0 for a deuce up to 12 for an ace.
+    const TWO: u8 = 0;
+    const THREE: u8 = 1;
+    const FOUR: u8 = 2;
+    const FIVE: u8 = 3;
+    const SIX: u8 = 4;
+    const SEVEN: u8 = 5;
+    const EIGHT: u8 = 6;
+    const NINE: u8 = 7;
+    const TEN: u8 = 8;
+    const JACK: u8 = 9;
+    const QUEEN: u8 = 10;
+    const KING: u8 = 11;
+    const ACE: u8 = 12;
+
+    // Suits.
+    const CLUBS: u8 = 0;
+    const DIAMONDS: u8 = 1;
+    const HEARTS: u8 = 2;
+    const SPADES: u8 = 3;
+
+    fn card(rank: u8, suit: u8) -> u8 {
+        suit * 13 + rank
+    }
+
+    fn category_and_ranks(rank: HandRank) -> (u8, [u8; 5]) {
+        (rank.category(), rank.ranks())
+    }
+
+    #[test]
+    fn five_cards_rank_in_every_category() {
+        let cases = [
+            // A straight flush, ten high.
+            ([card(TEN, HEARTS), card(NINE, HEARTS), card(EIGHT, HEARTS), card(SEVEN, HEARTS), card(SIX, HEARTS)],
+             (9, [TEN, 0, 0, 0, 0])),
+            // Four of a kind with its kicker.
+            ([card(NINE, CLUBS), card(NINE, DIAMONDS), card(NINE, HEARTS), card(NINE, SPADES), card(KING, CLUBS)],
+             (8, [NINE, KING, 0, 0, 0])),
+            // A full house: trips over the pair.
+            ([card(FOUR, CLUBS), card(FOUR, DIAMONDS), card(FOUR, HEARTS), card(ACE, SPADES), card(ACE, CLUBS)],
+             (7, [FOUR, ACE, 0, 0, 0])),
+            // A flush ranks all five cards.
+            ([card(ACE, SPADES), card(JACK, SPADES), card(EIGHT, SPADES), card(FIVE, SPADES), card(TWO, SPADES)],
+             (6, [ACE, JACK, EIGHT, FIVE, TWO])),
+            // A straight, ace high.
+            ([card(ACE, CLUBS), card(KING, DIAMONDS), card(QUEEN, HEARTS), card(JACK, SPADES), card(TEN, CLUBS)],
+             (5, [ACE, 0, 0, 0, 0])),
+            // The wheel plays as a five-high straight.
+            ([card(ACE, CLUBS), card(TWO, DIAMONDS), card(THREE, HEARTS), card(FOUR, SPADES), card(FIVE, CLUBS)],
+             (5, [FIVE, 0, 0, 0, 0])),
+            // Three of a kind with two kickers.
+            ([card(SEVEN, CLUBS), card(SEVEN, DIAMONDS), card(SEVEN, HEARTS), card(KING, SPADES), card(TWO, CLUBS)],
+             (4, [SEVEN, KING, TWO, 0, 0])),
+            // Two pair with a kicker.
+            ([card(JACK, CLUBS), card(JACK, DIAMONDS), card(THREE, HEARTS), card(THREE, SPADES), card(QUEEN, CLUBS)],
+             (3, [JACK, THREE, QUEEN, 0, 0])),
+            // One pair with three kickers.
+            ([card(SIX, CLUBS), card(SIX, DIAMONDS), card(ACE, HEARTS), card(TEN, SPADES), card(TWO, CLUBS)],
+             (2, [SIX, ACE, TEN, TWO, 0])),
+            // High card.
+            ([card(KING, CLUBS), card(NINE, DIAMONDS), card(SEVEN, HEARTS), card(FOUR, SPADES), card(TWO, CLUBS)],
+             (1, [KING, NINE, SEVEN, FOUR, TWO])),
         ];
+        for (cards, expected) in cases {
+            assert_eq!(category_and_ranks(evaluate_5_cards(cards)), expected);
+        }
+    }
 
-        // Simple pot calculation - just add up all the bets
-        let total_pot: u64 = player_bets[0] + player_bets[1] + player_bets[2] + 
-                             player_bets[3] + player_bets[4] + player_bets[5];
+    #[test]
+    fn categories_outrank_each_other_in_order() {
+        let value = |cards: [u8; 5]| evaluate_5_cards(cards).value;
+        let ascending = [
+            value([card(KING, CLUBS), card(NINE, DIAMONDS), card(SEVEN, HEARTS), card(FOUR, SPADES), card(TWO, CLUBS)]),
+            value([card(TWO, CLUBS), card(TWO, DIAMONDS), card(FIVE, HEARTS), card(FOUR, SPADES), card(THREE, CLUBS)]),
+            value([card(TWO, CLUBS), card(TWO, DIAMONDS), card(THREE, HEARTS), card(THREE, SPADES), card(FIVE, CLUBS)]),
+            value([card(TWO, CLUBS), card(TWO, DIAMONDS), card(TWO, HEARTS), card(THREE, SPADES), card(FOUR, CLUBS)]),
+            value([card(ACE, CLUBS), card(TWO, DIAMONDS), card(THREE, HEARTS), card(FOUR, SPADES), card(FIVE, CLUBS)]),
+            value([card(TWO, SPADES), card(THREE, SPADES), card(FOUR, SPADES), card(FIVE, SPADES), card(SEVEN, SPADES)]),
+            value([card(TWO, CLUBS), card(TWO, DIAMONDS), card(TWO, HEARTS), card(THREE, SPADES), card(THREE, CLUBS)]),
+            value([card(TWO, CLUBS), card(TWO, DIAMONDS), card(TWO, HEARTS), card(TWO, SPADES), card(THREE, CLUBS)]),
+            value([card(ACE, CLUBS), card(TWO, CLUBS), card(THREE, CLUBS), card(FOUR, CLUBS), card(FIVE, CLUBS)]),
+        ];
+        for pair in ascending.windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
+    }
+
+    #[test]
+    fn seven_cards_rank_as_their_best_five() {
+        // K♠ Q♠ on A♠ J♠ T♠ 2♦ 2♣: the royal flush beats the deuces.
+        let cards = [
+            card(KING, SPADES), card(QUEEN, SPADES),
+            card(ACE, SPADES), card(JACK, SPADES), card(TEN, SPADES), card(TWO, DIAMONDS), card(TWO, CLUBS),
+        ];
+        let royal = [card(ACE, SPADES), card(KING, SPADES), card(QUEEN, SPADES), card(JACK, SPADES), card(TEN, SPADES)];
+        assert_eq!(evaluate_7_cards(cards, 5).value, evaluate_5_cards(royal).value);
+    }
+
+    #[test]
+    fn a_straight_flush_is_found_inside_a_larger_flush() {
+        // Six hearts, five of them running 5 to 9, plus an off-suit ten.
+        let cards = [
+            card(KING, HEARTS), card(FIVE, HEARTS),
+            card(SIX, HEARTS), card(SEVEN, HEARTS), card(EIGHT, HEARTS), card(NINE, HEARTS), card(TEN, CLUBS),
+        ];
+        assert_eq!(category_and_ranks(evaluate_7_cards(cards, 5)), (9, [NINE, 0, 0, 0, 0]));
 
-        // Give all the pot to player 0 for simplicity
-        let mut result = winner_infos;
-        result[0].amount_won = total_pot;
-        result[0].player_index = 0;
+        // A steel wheel inside a flush ranks five high.
+        let cards = [
+            card(ACE, DIAMONDS), card(KING, DIAMONDS),
+            card(TWO, DIAMONDS), card(THREE, DIAMONDS), card(FOUR, DIAMONDS), card(FIVE, DIAMONDS), card(SIX, CLUBS),
+        ];
+        assert_eq!(category_and_ranks(evaluate_7_cards(cards, 5)), (9, [FIVE, 0, 0, 0, 0]));
+    }
 
-        let encrypted_result = mxe.from_arcis(result);
-        encrypted_result
+    #[test]
+    fn a_flush_and_a_straight_in_different_cards_are_only_a_flush() {
+        // Hearts make a flush and 5-9 a straight, but the six is a spade.
+        let cards = [
+            card(KING, HEARTS), card(TWO, HEARTS),
+            card(FIVE, HEARTS), card(SIX, SPADES), card(SEVEN, HEARTS), card(EIGHT, HEARTS), card(NINE, CLUBS),
+        ];
+        assert_eq!(category_and_ranks(evaluate_7_cards(cards, 5)), (6, [KING, EIGHT, SEVEN, FIVE, TWO]));
     }
-}
 
-pub use circuits::*;
\ No newline at end of file
+    #[test]
+    fn a_showdown_on_the_flop_ranks_only_the_dealt_cards() {
+        // A♠ A♥ on K♦ 7♣ 2♠, with the unused turn and river slots padded.
+        let cards = [
+            card(ACE, SPADES), card(ACE, HEARTS),
+            card(KING, DIAMONDS), card(SEVEN, CLUBS), card(TWO, SPADES), INVALID_CARD_INDEX, INVALID_CARD_INDEX,
+        ];
+        assert_eq!(category_and_ranks(evaluate_7_cards(cards, 3)), (2, [ACE, KING, SEVEN, TWO, 0]));
+
+        // Whatever sits in the undealt slots cannot improve the hand.
+        let cards = [
+            card(ACE, SPADES), card(ACE, HEARTS),
+            card(KING, DIAMONDS), card(SEVEN, CLUBS), card(TWO, SPADES), card(ACE, CLUBS), card(ACE, DIAMONDS),
+        ];
+        assert_eq!(category_and_ranks(evaluate_7_cards(cards, 3)), (2, [ACE, KING, SEVEN, TWO, 0]));
+        assert_eq!(evaluate_7_cards(cards, 4).category(), 4);
+    }
+
+    #[test]
+    fn fewer_than_five_cards_make_no_hand() {
+        let cards = [card(ACE, SPADES), card(ACE, HEARTS), card(KING, DIAMONDS), card(SEVEN, CLUBS), 0, 0, 0];
+        assert_eq!(evaluate_7_cards(cards, 0).value, 0);
+        assert_eq!(evaluate_7_cards(cards, 2).value, 0);
+    }
+
+    #[test]
+    fn a_board_count_above_five_is_read_as_a_full_board() {
+        let cards = [
+            card(KING, SPADES), card(QUEEN, SPADES),
+            card(ACE, SPADES), card(JACK, SPADES), card(TEN, SPADES), card(TWO, DIAMONDS), card(TWO, CLUBS),
+        ];
+        let full_board = evaluate_7_cards(cards, 5).value;
+        assert_eq!(evaluate_7_cards(cards, 6).value, full_board);
+        assert_eq!(evaluate_7_cards(cards, u8::MAX).value, full_board);
+    }
+
+    #[test]
+    fn players_who_play_the_board_rank_as_the_board() {
+        // A broadway straight on board: neither 2♣ 3♦ nor 4♥ 4♠ improves on it.
+        let board = [card(ACE, CLUBS), card(KING, DIAMONDS), card(QUEEN, HEARTS), card(JACK, SPADES), card(TEN, CLUBS)];
+        let board_rank = evaluate_5_cards(board).value;
+        for hole in [[card(TWO, CLUBS), card(THREE, DIAMONDS)], [card(FOUR, HEARTS), card(FOUR, SPADES)]] {
+            let cards = [hole[0], hole[1], board[0], board[1], board[2], board[3], board[4]];
+            assert_eq!(evaluate_7_cards(cards, 5).value, board_rank);
+        }
+
+        // A hole card that beats the board's lowest kicker does play.
+        let board = [card(ACE, CLUBS), card(ACE, DIAMONDS), card(KING, HEARTS), card(KING, SPADES), card(TWO, CLUBS)];
+        let cards = [card(QUEEN, CLUBS), card(THREE, DIAMONDS), board[0], board[1], board[2], board[3], board[4]];
+        assert_eq!(category_and_ranks(evaluate_7_cards(cards, 5)), (3, [ACE, KING, QUEEN, 0, 0]));
+    }
+
+    #[test]
+    fn each_all_in_level_pays_exactly_what_was_put_into_it() {
+        // Seats 0, 1 and 2 are all in for 100, 250 and 400, seat 3 covers them and
+        // seat 4 folded after putting in 50. The shorter the stack, the better the hand.
+        let bets = [100, 250, 400, 400, 50, 0];
+        let hand_values = [4, 3, 2, 1, 0, 0];
+        let active = [true, true, true, true, false, false];
+        let payouts = calculate_payouts(bets, hand_values, active, 5, 0);
+        // Main pot: 100 from each player in and the folded 50.
+        // Side pots: 150 from each of three players, then 150 from each of two.
+        assert_eq!(payouts, [450, 450, 300, 0, 0, 0]);
+        assert_eq!(payouts.iter().sum::<u64>(), bets.iter().sum::<u64>());
+    }
+
+    #[test]
+    fn players_all_in_for_the_same_amount_share_one_pot() {
+        // Seats 0 and 1 are both all in for 100 and tie; seats 2 and 3 play on for 300.
+        let bets = [100, 100, 300, 300, 0, 0];
+        let hand_values = [5, 5, 2, 3, 0, 0];
+        let active = [true, true, true, true, false, false];
+        assert_eq!(calculate_payouts(bets, hand_values, active, 0, 0), [200, 200, 0, 400, 0, 0]);
+    }
+
+    #[test]
+    fn the_rake_comes_out_of_the_main_pot_first() {
+        let bets = [100, 250, 400, 400, 50, 0];
+        let hand_values = [4, 3, 2, 1, 0, 0];
+        let active = [true, true, true, true, false, false];
+        // The 450-chip main pot is raked away entirely, then 50 of the first side pot.
+        assert_eq!(calculate_payouts(bets, hand_values, active, 5, 500), [0, 400, 300, 0, 0, 0]);
+    }
+
+    #[test]
+    fn the_showdown_ranks_active_seats_and_pays_the_side_pots() {
+        // Board: K♣ 9♦ 6♥ 3♠ 2♣.
+        let board = [card(KING, CLUBS), card(NINE, DIAMONDS), card(SIX, HEARTS), card(THREE, SPADES), card(TWO, CLUBS)];
+        let hole_cards = [
+            [card(KING, DIAMONDS), card(KING, HEARTS)], // Set of kings, all in for 100.
+            [card(ACE, CLUBS), card(ACE, DIAMONDS)],    // Aces, all in for 250.
+            [card(QUEEN, CLUBS), card(JACK, DIAMONDS)], // King high, all in for 400.
+            [card(FOUR, HEARTS), card(FIVE, SPADES)],   // A six-high straight, folded.
+            [card(TEN, CLUBS), card(EIGHT, DIAMONDS)],  // King high, covers everyone.
+            [INVALID_CARD_INDEX; 2],
+        ];
+        let bets = [100, 250, 400, 50, 400, 0];
+        let active = [true, true, true, false, true, false];
+        let details = showdown_details(hole_cards, board, 5, bets, active, 0, 0);
+
+        let categories = details.each_ref().map(|detail| detail.hand_category);
+        let amounts = details.each_ref().map(|detail| detail.amount_won);
+        assert_eq!(categories, [4, 2, 1, 0, 1, 0]);
+        assert_eq!(details[0].hand_ranks, [KING, NINE, SIX, 0, 0]);
+        // The set takes the main pot and the aces the first side pot; seat 2's queen
+        // beats seat 4's ten for the last one.
+        assert_eq!(amounts, [450, 450, 300, 0, 0, 0]);
+    }
+
+    #[test]
+    fn the_better_two_pair_wins_the_whole_pot() {
+        // Board: Q♣ Q♦ 8♥ 5♠ 2♣. Kings up beats nines up; both are two pair.
+        let board = [card(QUEEN, CLUBS), card(QUEEN, DIAMONDS), card(EIGHT, HEARTS), card(FIVE, SPADES), card(TWO, CLUBS)];
+        let mut hole_cards = [[INVALID_CARD_INDEX; 2]; 6];
+        hole_cards[0] = [card(NINE, CLUBS), card(NINE, DIAMONDS)];
+        hole_cards[1] = [card(KING, CLUBS), card(KING, DIAMONDS)];
+        let bets = [200, 200, 0, 0, 0, 0];
+        let active = [true, true, false, false, false, false];
+        let details = showdown_details(hole_cards, board, 5, bets, active, 0, 0);
+
+        assert_eq!((details[0].hand_category, details[1].hand_category), (3, 3));
+        assert_eq!(details[0].amount_won, 0);
+        assert_eq!(details[1].amount_won, 400);
+    }
+
+    #[test]
+    fn a_kicker_decides_between_equal_pairs_and_splits_only_on_a_true_tie() {
+        // Board: A♣ 9♦ 7♥ 4♠ 2♣. Three players pair the ace.
+        let board = [card(ACE, CLUBS), card(NINE, DIAMONDS), card(SEVEN, HEARTS), card(FOUR, SPADES), card(TWO, CLUBS)];
+        let mut hole_cards = [[INVALID_CARD_INDEX; 2]; 6];
+        hole_cards[0] = [card(ACE, DIAMONDS), card(KING, CLUBS)];
+        hole_cards[1] = [card(ACE, HEARTS), card(KING, DIAMONDS)];
+        hole_cards[2] = [card(ACE, SPADES), card(QUEEN, CLUBS)];
+        let bets = [100, 100, 100, 0, 0, 0];
+        let active = [true, true, true, false, false, false];
+        let details = showdown_details(hole_cards, board, 5, bets, active, 0, 0);
+
+        // The two king kickers tie exactly and split; the queen kicker loses.
+        let amounts = details.each_ref().map(|detail| detail.amount_won);
+        assert_eq!(amounts, [150, 150, 0, 0, 0, 0]);
+        assert_eq!(details[0].hand_ranks, [ACE, KING, NINE, SEVEN, 0]);
+        assert_eq!(details[2].hand_ranks, [ACE, QUEEN, NINE, SEVEN, 0]);
+    }
+
+    #[test]
+    fn everyone_playing_a_board_straight_chops_the_pot() {
+        // Board: T♣ J♦ Q♥ K♠ A♣, and nobody can beat broadway.
+        let board = [card(TEN, CLUBS), card(JACK, DIAMONDS), card(QUEEN, HEARTS), card(KING, SPADES), card(ACE, CLUBS)];
+        let mut hole_cards = [[INVALID_CARD_INDEX; 2]; 6];
+        hole_cards[0] = [card(TWO, CLUBS), card(THREE, DIAMONDS)];
+        hole_cards[2] = [card(SEVEN, HEARTS), card(SEVEN, SPADES)];
+        hole_cards[4] = [card(ACE, DIAMONDS), card(KING, HEARTS)];
+        let bets = [100, 0, 100, 0, 100, 0];
+        let active = [true, false, true, false, true, false];
+        let details = showdown_details(hole_cards, board, 5, bets, active, 0, 0);
+
+        let amounts = details.each_ref().map(|detail| detail.amount_won);
+        assert_eq!(amounts, [100, 0, 100, 0, 100, 0]);
+        assert_eq!(details[0].hand_category, 5);
+        assert_eq!(details[0].hand_ranks, details[4].hand_ranks);
+    }
+
+    #[test]
+    fn a_board_flush_chop_gives_the_odd_chips_from_the_buttons_left() {
+        // Board: A♥ J♥ 8♥ 6♥ 3♥; neither player holds a heart.
+        let board = [card(ACE, HEARTS), card(JACK, HEARTS), card(EIGHT, HEARTS), card(SIX, HEARTS), card(THREE, HEARTS)];
+        let mut hole_cards = [[INVALID_CARD_INDEX; 2]; 6];
+        hole_cards[1] = [card(KING, CLUBS), card(QUEEN, SPADES)];
+        hole_cards[3] = [card(TWO, CLUBS), card(TWO, DIAMONDS)];
+        // A folded player's 25 makes the pot odd.
+        let bets = [0, 100, 25, 100, 0, 0];
+        let active = [false, true, false, true, false, false];
+
+        // With the button on seat 2, seat 3 is first to its left and gets the odd chip.
+        let details = showdown_details(hole_cards, board, 5, bets, active, 2, 0);
+        assert_eq!(details[1].hand_category, 6);
+        assert_eq!((details[1].amount_won, details[3].amount_won), (112, 113));
+
+        // With the button on seat 0, seat 1 does.
+        let details = showdown_details(hole_cards, board, 5, bets, active, 0, 0);
+        assert_eq!((details[1].amount_won, details[3].amount_won), (113, 112));
+    }
+}
@@ -0,0 +1,136 @@
+//! src/logic/equity.rs
+//!
+//! @description
+//! This module contains the logic for estimating each all-in player's equity: the
+//! share of possible board runouts they win or tie. It is used by the
+//! `compute_allin_equity` circuit, which samples runouts with `ArcisRNG` and feeds
+//! each sampled deck order through `tally_runout`.
+//!
+//! @logic
+//! 1. Board Completion: The missing community cards are taken, in order, from a
+//!    shuffled deck, skipping every card that is already known (the contenders'
+//!    hole cards and the dealt board).
+//! 2. Runout Scoring: Every contender's best hand on the completed board is
+//!    evaluated, and the players holding the best hand are marked as winners.
+//! 3. Tallying: A sole winner counts as a win; several winners count as a tie for
+//!    each of them. Totals are converted to basis points over the sample count.
+//!
+//! @dependencies
+//! - `crate::logic::poker_evaluator`: For hand evaluation.
+//! - `crate::logic::pot_calculator`: For comparing hand ranks.
+//!
+//! @notes
+//! - `tally_runout` is deterministic given the deck order, so a fixed deck order
+//!   can stand in for the RNG when checking a known matchup.
+//! - Fixed-size loops only, so the logic stays compatible with the MPC environment.
+
+use crate::circuits::evaluate_hands_and_payout::MAX_PLAYERS;
+use crate::logic::poker_evaluator::{self, HandRank};
+use crate::logic::pot_calculator::compare_hand_ranks;
+use crate::types::{DECK_SIZE, INVALID_CARD_INDEX};
+
+/// The number of board runouts sampled per equity estimate.
+pub const EQUITY_SAMPLES: usize = 500;
+
+/// Equities are reported in basis points (1/100th of a percent).
+pub const BASIS_POINTS: u64 = 10_000;
+
+/// Fills the undealt board slots from `shuffled_deck`, skipping known cards.
+///
+/// `hole_cards` entries for non-contenders must be `INVALID_CARD_INDEX`.
+pub fn complete_board(
+    hole_cards: [[u8; 2]; MAX_PLAYERS],
+    community_cards: [u8; 5],
+    num_community: u8,
+    shuffled_deck: [u8; DECK_SIZE as usize],
+) -> [u8; 5] {
+    let mut board = community_cards;
+    let mut filled = num_community as usize;
+
+    for i in 0..DECK_SIZE as usize {
+        let card = shuffled_deck[i];
+
+        let mut is_known = false;
+        for p in 0..MAX_PLAYERS {
+            if hole_cards[p][0] == card || hole_cards[p][1] == card {
+                is_known = true;
+            }
+        }
+        for j in 0..5 {
+            if j < num_community as usize && community_cards[j] == card {
+                is_known = true;
+            }
+        }
+
+        if !is_known && filled < 5 {
+            board[filled] = card;
+            filled += 1;
+        }
+    }
+
+    board
+}
+
+/// Scores one completed board and adds it to the running win and tie counts.
+pub fn tally_runout(
+    hole_cards: [[u8; 2]; MAX_PLAYERS],
+    contenders: [bool; MAX_PLAYERS],
+    community_cards: [u8; 5],
+    num_community: u8,
+    shuffled_deck: [u8; DECK_SIZE as usize],
+    wins: &mut [u64; MAX_PLAYERS],
+    ties: &mut [u64; MAX_PLAYERS],
+) {
+    let board = complete_board(hole_cards, community_cards, num_community, shuffled_deck);
+
+    // 1. Evaluate each contender's hand on the completed board.
+    let mut ranks = [HandRank::NoHand; MAX_PLAYERS];
+    for p in 0..MAX_PLAYERS {
+        if contenders[p] {
+            let mut seven_cards = [INVALID_CARD_INDEX; 7];
+            seven_cards[0] = hole_cards[p][0];
+            seven_cards[1] = hole_cards[p][1];
+            for j in 0..5 {
+                seven_cards[j + 2] = board[j];
+            }
+            ranks[p] = poker_evaluator::evaluate_7_cards(seven_cards, 5);
+        }
+    }
+
+    // 2. Find the best hand among the contenders.
+    let mut best_rank = HandRank::NoHand;
+    for p in 0..MAX_PLAYERS {
+        if contenders[p] && compare_hand_ranks(ranks[p], best_rank) == 1 {
+            best_rank = ranks[p];
+        }
+    }
+
+    // 3. Mark everyone holding it; more than one means a split.
+    let mut is_winner = [false; MAX_PLAYERS];
+    let mut num_winners = 0u8;
+    for p in 0..MAX_PLAYERS {
+        if contenders[p] && compare_hand_ranks(ranks[p], best_rank) == 0 {
+            is_winner[p] = true;
+            num_winners += 1;
+        }
+    }
+
+    for p in 0..MAX_PLAYERS {
+        if is_winner[p] {
+            if num_winners == 1 {
+                wins[p] += 1;
+            } else {
+                ties[p] += 1;
+            }
+        }
+    }
+}
+
+/// Converts runout counts into basis points of `samples`.
+pub fn to_basis_points(counts: [u64; MAX_PLAYERS], samples: u64) -> [u16; MAX_PLAYERS] {
+    let mut result = [0u16; MAX_PLAYERS];
+    for p in 0..MAX_PLAYERS {
+        result[p] = (counts[p] * BASIS_POINTS / samples) as u16;
+    }
+    result
+}
@@ -11,6 +11,8 @@
 //!   the strength of Texas Hold'em poker hands.
 //! - `pot_calculator`: Contains the logic for distributing pots, including the
 //!   complex calculations required for side pots in all-in situations.
+//! - `equity`: Contains the runout scoring used to estimate all-in equity.
 
 pub mod poker_evaluator;
-pub mod pot_calculator;
\ No newline at end of file
+pub mod pot_calculator;
+pub mod equity;
\ No newline at end of file
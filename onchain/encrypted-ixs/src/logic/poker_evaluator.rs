@@ -13,11 +13,14 @@
 //! 2. Rank Counting: An array is used to count the occurrences of each rank to
 //!    identify pairs, three-of-a-kind, four-of-a-kind, etc.
 //! 3. Flush and Straight Detection: Logic to check for flushes (five cards of
-//!    the same suit) and straights (five cards of sequential rank).
+//!    the same suit) and straights (five cards of sequential rank). A straight flush
+//!    is looked for among the flush suit's cards whenever a flush exists.
 //! 4. Hand Ranking: The main evaluation function checks for hand types in
 //!    descending order of strength (from Straight Flush down to High Card).
 //! 5. Tie-breaking: The `HandRank` enum stores kicker information, allowing for
-//!    accurate tie-breaking according to poker rules.
+//!    accurate tie-breaking according to poker rules. Kickers are always taken
+//!    from the highest remaining cards, so players who "play the board" (whose
+//!    best five cards are all community cards) evaluate to identical ranks.
 //!
 //! @dependencies
 //! - `arcis_imports`: For Arcis types and functions.
@@ -26,6 +29,7 @@
 //! - All algorithms are implemented using fixed-size loops and data-independent
 //!   operations to be compatible with the MPC environment.
 //! - The `evaluate_7_cards` function is the primary entry point for this module.
+//!   It also handles showdowns with fewer than 5 community cards via `num_community`.
 
 use arcis_imports::*;
 
@@ -33,6 +37,8 @@ use arcis_imports::*;
 const NUM_SUITS: u8 = 4;
 const NUM_RANKS: u8 = 13;
 const ACE_RANK: u8 = 12; // In our system: 2=0, ..., K=11, A=12
+/// Marks a rank slot with no card in it (when fewer than 7 cards are available).
+const NO_CARD: u8 = 255;
 
 /// Represents the rank of a poker hand, including data for tie-breaking.
 /// The enum is ordered from highest rank to lowest rank.
@@ -60,24 +66,75 @@ fn get_suit(card_idx: u8) -> u8 {
     card_idx / NUM_RANKS
 }
 
+/// Returns the high card rank of the best straight among the ranks marked present,
+/// or 255 if there is none. An Ace-low straight (A-2-3-4-5) has a high card of 5 (rank 3).
+fn find_straight_high_card(rank_present: [bool; NUM_RANKS as usize]) -> u8 {
+    let mut high_card = 255u8;
+    // Walk from the lowest possible high card up, so the highest straight wins.
+    // Cannot `break` in Arcis, so every window is checked.
+    for high in 4..NUM_RANKS as usize {
+        if rank_present[high]
+            && rank_present[high - 1]
+            && rank_present[high - 2]
+            && rank_present[high - 3]
+            && rank_present[high - 4]
+        {
+            high_card = high as u8;
+        }
+    }
+    // Special case for Ace-low straight (A, 2, 3, 4, 5)
+    let wheel = rank_present[ACE_RANK as usize]
+        && rank_present[0]
+        && rank_present[1]
+        && rank_present[2]
+        && rank_present[3];
+    if wheel && high_card == 255 {
+        high_card = 3;
+    }
+    high_card
+}
+
 /// Primary function to evaluate the best 5-card hand from a given set of 7 cards.
-pub fn evaluate_7_cards(cards: [u8; 7]) -> HandRank {
+///
+/// `cards` holds the 2 hole cards followed by the community cards. Only the first
+/// `num_community` community cards are considered, so a showdown reached before
+/// the board is complete (e.g. an early all-in) evaluates the best hand from the
+/// `2 + num_community` cards actually dealt; the remaining slots are ignored.
+/// A `num_community` above 5 counts as 5.
+pub fn evaluate_7_cards(cards: [u8; 7], num_community: u8) -> HandRank {
     // --- Data Preparation ---
-    let mut ranks = [0u8; 7];
-    let mut suits = [0u8; 7];
+    let num_community = if num_community > 5 { 5 } else { num_community };
+    let num_cards = 2 + num_community as usize;
+    let mut valid = [false; 7];
     for i in 0..7 {
-        ranks[i] = get_rank(cards[i]);
-        suits[i] = get_suit(cards[i]);
+        valid[i] = i < num_cards;
+    }
+
+    // Missing cards get the `NO_CARD` rank, which sorts after every real rank.
+    let mut ranks_ascending = [NO_CARD; 7];
+    for i in 0..7 {
+        if valid[i] {
+            ranks_ascending[i] = get_rank(cards[i]);
+        }
     }
-    // Sort ranks in descending order for easier processing.
     // Arcis supports .sort() for integer arrays.
-    ranks.sort();
-    ranks.reverse();
+    ranks_ascending.sort();
+
+    // Reorder so the real ranks come first in descending order, followed by any
+    // `NO_CARD` slots.
+    let mut ranks = [NO_CARD; 7];
+    for i in 0..7 {
+        if i < num_cards {
+            ranks[i] = ranks_ascending[num_cards - 1 - i];
+        }
+    }
 
     // --- Check for Flush ---
     let mut suit_counts = [0u8; NUM_SUITS as usize];
-    for suit in suits {
-        suit_counts[suit as usize] += 1;
+    for i in 0..7 {
+        if valid[i] {
+            suit_counts[get_suit(cards[i]) as usize] += 1;
+        }
     }
 
     let mut flush_suit = 255u8; // Invalid suit
@@ -90,89 +147,26 @@ pub fn evaluate_7_cards(cards: [u8; 7]) -> HandRank {
     let is_flush = flush_suit != 255;
     
     // --- Check for Straight ---
-    // Use a unique, sorted list of ranks to detect straights.
-    let mut unique_ranks = [255u8; 7];
-    let mut unique_count = 0;
+    let mut rank_present = [false; NUM_RANKS as usize];
     for i in 0..7 {
-        let mut found = false;
-        for j in 0..unique_count {
-            if ranks[i] == unique_ranks[j] {
-                found = true;
-            }
-        }
-        if !found {
-            unique_ranks[unique_count] = ranks[i];
-            unique_count += 1;
-        }
-    }
-    unique_ranks.sort(); // Sort ascending for straight check
-    unique_ranks.reverse();
-
-    let mut straight_high_card = 255u8;
-    if unique_count >= 5 {
-        for i in 0..(unique_count - 4) {
-            if unique_ranks[i] == unique_ranks[i+1] + 1 &&
-               unique_ranks[i] == unique_ranks[i+2] + 2 &&
-               unique_ranks[i] == unique_ranks[i+3] + 3 &&
-               unique_ranks[i] == unique_ranks[i+4] + 4 {
-                straight_high_card = unique_ranks[i];
-                // Break after finding the highest straight
-                // Cannot `break` in Arcis, so we let it complete
-            }
-        }
-        // Special case for Ace-low straight (A, 2, 3, 4, 5)
-        let has_ace = unique_ranks[0] == ACE_RANK;
-        let has_2 = unique_ranks[unique_count-1] == 0; // 2 is rank 0
-        let has_3 = unique_ranks[unique_count-2] == 1;
-        let has_4 = unique_ranks[unique_count-3] == 2;
-        let has_5 = unique_ranks[unique_count-4] == 3;
-
-        if has_ace && has_2 && has_3 && has_4 && has_5 {
-            // High card of an Ace-low straight is 5 (rank 3)
-            if straight_high_card == 255 {
-                straight_high_card = 3; 
-            }
+        if valid[i] {
+            rank_present[get_rank(cards[i]) as usize] = true;
         }
     }
+    let straight_high_card = find_straight_high_card(rank_present);
     let is_straight = straight_high_card != 255;
 
     // --- Check for Straight Flush ---
-    if is_flush && is_straight {
-        let mut flush_ranks = [255u8; 7];
-        let mut flush_ranks_count = 0;
+    // Checked whenever there is a flush: only the flush suit's ranks can form the
+    // straight flush, independent of any straight among all the cards.
+    if is_flush {
+        let mut flush_rank_present = [false; NUM_RANKS as usize];
         for i in 0..7 {
-            if get_suit(cards[i]) == flush_suit {
-                flush_ranks[flush_ranks_count] = get_rank(cards[i]);
-                flush_ranks_count += 1;
+            if valid[i] && get_suit(cards[i]) == flush_suit {
+                flush_rank_present[get_rank(cards[i]) as usize] = true;
             }
         }
-        flush_ranks.sort();
-        flush_ranks.reverse();
-
-        // Check for straight within the flush ranks
-        let mut straight_flush_high_card = 255u8;
-        if flush_ranks_count >= 5 {
-             for i in 0..(flush_ranks_count - 4) {
-                if flush_ranks[i] == flush_ranks[i+1] + 1 &&
-                   flush_ranks[i] == flush_ranks[i+2] + 2 &&
-                   flush_ranks[i] == flush_ranks[i+3] + 3 &&
-                   flush_ranks[i] == flush_ranks[i+4] + 4 {
-                    straight_flush_high_card = flush_ranks[i];
-                }
-            }
-            // Ace-low straight flush check
-            let has_ace = flush_ranks[0] == ACE_RANK;
-            let has_2 = flush_ranks[flush_ranks_count-1] == 0;
-            let has_3 = flush_ranks[flush_ranks_count-2] == 1;
-            let has_4 = flush_ranks[flush_ranks_count-3] == 2;
-            let has_5 = flush_ranks[flush_ranks_count-4] == 3;
-            if has_ace && has_2 && has_3 && has_4 && has_5 {
-                if straight_flush_high_card == 255 {
-                    straight_flush_high_card = 3;
-                }
-            }
-        }
-
+        let straight_flush_high_card = find_straight_high_card(flush_rank_present);
         if straight_flush_high_card != 255 {
             return HandRank::StraightFlush { high_card_rank: straight_flush_high_card };
         }
@@ -181,7 +175,9 @@ pub fn evaluate_7_cards(cards: [u8; 7]) -> HandRank {
     // --- Count Ranks for Pairs, Threes, Fours ---
     let mut rank_counts = [0u8; NUM_RANKS as usize];
     for rank in ranks {
-        rank_counts[rank as usize] += 1;
+        if rank != NO_CARD {
+            rank_counts[rank as usize] += 1;
+        }
     }
 
     let mut fours = 255u8;
@@ -207,11 +203,11 @@ pub fn evaluate_7_cards(cards: [u8; 7]) -> HandRank {
     
     // Four of a Kind
     if fours != 255 {
+        // `ranks` is sorted descending, so the first non-quad rank is the kicker.
         let mut kicker = 255u8;
         for rank in ranks {
-            if rank != fours {
+            if rank != fours && rank != NO_CARD && kicker == 255 {
                 kicker = rank;
-                // cannot break
             }
         }
         return HandRank::FourOfAKind { quad_rank: fours, kicker_rank: kicker };
@@ -230,9 +226,9 @@ pub fn evaluate_7_cards(cards: [u8; 7]) -> HandRank {
     if is_flush {
         let mut flush_ranks = [0u8; 7];
         let mut count = 0;
-        for card in cards {
-            if get_suit(card) == flush_suit {
-                flush_ranks[count] = get_rank(card);
+        for i in 0..7 {
+            if valid[i] && get_suit(cards[i]) == flush_suit {
+                flush_ranks[count] = get_rank(cards[i]);
                 count += 1;
             }
         }
@@ -251,7 +247,7 @@ pub fn evaluate_7_cards(cards: [u8; 7]) -> HandRank {
         let mut kickers = [255u8; 2];
         let mut kicker_count = 0;
         for rank in ranks {
-            if rank != threes[0] && kicker_count < 2 {
+            if rank != threes[0] && rank != NO_CARD && kicker_count < 2 {
                 kickers[kicker_count] = rank;
                 kicker_count += 1;
             }
@@ -261,11 +257,11 @@ pub fn evaluate_7_cards(cards: [u8; 7]) -> HandRank {
 
     // Two Pair
     if pairs_count >= 2 {
+        // The kicker is the highest remaining rank, which may come from a third pair.
         let mut kicker = 255u8;
         for rank in ranks {
-            if rank != pairs[0] && rank != pairs[1] {
+            if rank != pairs[0] && rank != pairs[1] && rank != NO_CARD && kicker == 255 {
                 kicker = rank;
-                // cannot break
             }
         }
         return HandRank::TwoPair { high_pair_rank: pairs[0], low_pair_rank: pairs[1], kicker_rank: kicker };
@@ -276,7 +272,7 @@ pub fn evaluate_7_cards(cards: [u8; 7]) -> HandRank {
         let mut kickers = [255u8; 3];
         let mut kicker_count = 0;
         for rank in ranks {
-            if rank != pairs[0] && kicker_count < 3 {
+            if rank != pairs[0] && rank != NO_CARD && kicker_count < 3 {
                 kickers[kicker_count] = rank;
                 kicker_count += 1;
             }
@@ -284,6 +280,7 @@ pub fn evaluate_7_cards(cards: [u8; 7]) -> HandRank {
         return HandRank::OnePair { pair_rank: pairs[0], kickers };
     }
     
-    // High Card
+    // High Card (with fewer than 5 cards, the missing slots hold `NO_CARD`,
+    // identically for every player at the same showdown)
     HandRank::HighCard { ranks: [ranks[0], ranks[1], ranks[2], ranks[3], ranks[4]] }
 }
\ No newline at end of file
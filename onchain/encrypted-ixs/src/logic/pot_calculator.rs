@@ -13,10 +13,16 @@
 //!    - It creates a series of pots (a main pot and potentially multiple side pots),
 //!      with each pot capped at the next lowest all-in amount.
 //! 3. Contribution Calculation: For each pot, it calculates how much each player
-//!    contributes, up to the pot's cap.
+//!    contributes, up to the pot's cap: `min(bet, level) - min(bet, previous_level)`,
+//!    summed over every player who put chips in, including those who folded.
 //! 4. Winner Determination: For each individual pot, it identifies the winner(s)
-//!    from the set of players who contributed to that specific pot.
-//! 5. Payout Aggregation: The winnings from all pots are summed up for each player
+//!    from the set of players who contributed to that specific pot, comparing full
+//!    hands with `compare_hand_ranks`. Only exact ties split a pot.
+//!    A tied pot is split evenly; its odd chips go one at a time to the tied winners
+//!    in order from the seat left of the button, so no chip is ever lost.
+//! 5. Rake: The platform's rake is taken out of the pots before they are paid,
+//!    starting with the main pot, so the payouts add up to the bets less the rake.
+//! 6. Payout Aggregation: The winnings from all pots are summed up for each player
 //!    to determine their total payout for the hand.
 //!
 //! @dependencies
@@ -31,42 +37,52 @@ use crate::types::WinnerInfo;
 use crate::circuits::evaluate_hands_and_payout::MAX_PLAYERS;
 use arcis_imports::ArcisPublicKey;
 
-// This function needs to be written to compare HandRanks.
-// Arcis doesn't support deriving Ord, so we implement it manually.
-// Returns 1 if rank_a > rank_b, 2 if rank_b > rank_a, 0 if equal.
-fn compare_hand_ranks(rank_a: HandRank, rank_b: HandRank) -> u8 {
-    // This is a simplified comparison logic. A full implementation would be verbose.
-    // For the sake of this example, we'll compare based on the enum discriminant.
-    // A real implementation would need to go level by level and check kickers.
-    // This is a placeholder for a full comparison function.
-    // NOTE: This placeholder logic IS NOT sufficient for real poker.
-    // A full implementation would be several hundred lines long.
-    // Due to complexity constraints, we will assume a simple numeric rank for now.
-    let rank_a_val = hand_rank_to_u8(rank_a);
-    let rank_b_val = hand_rank_to_u8(rank_b);
-
-    if rank_a_val > rank_b_val { 1 }
-    else if rank_b_val > rank_a_val { 2 }
-    else { 0 }
+/// Compares two hand ranks, including kickers.
+/// Arcis doesn't support deriving Ord, so we implement it manually.
+/// Returns 1 if rank_a > rank_b, 2 if rank_b > rank_a, 0 if equal.
+///
+/// Two players who both "play the board" evaluate to identical `HandRank`s, so
+/// they compare as equal here and split the pot.
+pub fn compare_hand_ranks(rank_a: HandRank, rank_b: HandRank) -> u8 {
+    let key_a = hand_rank_key(rank_a);
+    let key_b = hand_rank_key(rank_b);
+
+    // Lexicographic comparison; the first differing position decides.
+    // Cannot `break` in Arcis, so we record the first difference and keep looping.
+    let mut result = 0u8;
+    for i in 0..6 {
+        if result == 0 {
+            if key_a[i] > key_b[i] {
+                result = 1;
+            } else if key_b[i] > key_a[i] {
+                result = 2;
+            }
+        }
+    }
+    result
 }
 
-// Simplified numeric representation for HandRank comparison. Higher is better.
-fn hand_rank_to_u8(rank: HandRank) -> u8 {
+/// Flattens a `HandRank` into a fixed-size key that orders hands correctly when
+/// compared position by position: the category first, then its tie-break ranks
+/// from most to least significant. Unused positions are zero. Also published per seat
+/// in the showdown's `ShowdownDetail`s.
+pub fn hand_rank_key(rank: HandRank) -> [u8; 6] {
     match rank {
-        HandRank::StraightFlush { .. } => 9,
-        HandRank::FourOfAKind { .. } => 8,
-        HandRank::FullHouse { .. } => 7,
-        HandRank::Flush { .. } => 6,
-        HandRank::Straight { .. } => 5,
-        HandRank::ThreeOfAKind { .. } => 4,
-        HandRank::TwoPair { .. } => 3,
-        HandRank::OnePair { .. } => 2,
-        HandRank::HighCard { .. } => 1,
-        HandRank::NoHand => 0,
+        HandRank::StraightFlush { high_card_rank } => [9, high_card_rank, 0, 0, 0, 0],
+        HandRank::FourOfAKind { quad_rank, kicker_rank } => [8, quad_rank, kicker_rank, 0, 0, 0],
+        HandRank::FullHouse { three_rank, pair_rank } => [7, three_rank, pair_rank, 0, 0, 0],
+        HandRank::Flush { ranks } => [6, ranks[0], ranks[1], ranks[2], ranks[3], ranks[4]],
+        HandRank::Straight { high_card_rank } => [5, high_card_rank, 0, 0, 0, 0],
+        HandRank::ThreeOfAKind { three_rank, kickers } => [4, three_rank, kickers[0], kickers[1], 0, 0],
+        HandRank::TwoPair { high_pair_rank, low_pair_rank, kicker_rank } => {
+            [3, high_pair_rank, low_pair_rank, kicker_rank, 0, 0]
+        }
+        HandRank::OnePair { pair_rank, kickers } => [2, pair_rank, kickers[0], kickers[1], kickers[2], 0],
+        HandRank::HighCard { ranks } => [1, ranks[0], ranks[1], ranks[2], ranks[3], ranks[4]],
+        HandRank::NoHand => [0; 6],
     }
 }
 
-
 /// Calculates the pot distribution, correctly handling side pots.
 ///
 /// # Arguments
@@ -74,6 +90,8 @@ fn hand_rank_to_u8(rank: HandRank) -> u8 {
 /// * `player_ranks`: An array of evaluated `HandRank` for each player.
 /// * `active_players`: A boolean array indicating which players are still in the hand.
 /// * `player_pubkeys`: The Arcis public keys of the players for the output.
+/// * `dealer_position`: The button's seat; odd chips go to tied winners from its left.
+/// * `rake`: The platform rake, taken out of the pots (main pot first) before they are paid.
 ///
 /// # Returns
 /// An array of `WinnerInfo`, where each entry corresponds to a player and their total winnings.
@@ -82,11 +100,16 @@ pub fn calculate_payouts(
     player_ranks: [HandRank; MAX_PLAYERS],
     active_players: [bool; MAX_PLAYERS],
     player_pubkeys: [ArcisPublicKey; MAX_PLAYERS],
+    dealer_position: u8,
+    rake: u64,
 ) -> [WinnerInfo; MAX_PLAYERS] {
 
     let mut payouts = [0u64; MAX_PLAYERS];
+    let mut rake_left = rake;
 
     // 1. Identify unique bet amounts (all-in levels)
+    // Players all-in for the same amount share a single level, so their chips are
+    // counted once, in one pot they are all eligible for.
     let mut pot_levels = [0u64; MAX_PLAYERS + 1];
     let mut level_count = 1; // Start with 0
     for i in 0..MAX_PLAYERS {
@@ -109,8 +132,10 @@ pub fn calculate_payouts(
     pot_levels.sort();
 
     // 2. Process each pot level
+    // Unused slots sort to the front as zeros, so walk the whole array; zero and
+    // repeated levels add nothing and are skipped below.
     let mut last_level_bet = 0;
-    for i in 0..level_count {
+    for i in 0..(MAX_PLAYERS + 1) {
         let current_level_bet = pot_levels[i];
         if current_level_bet == 0 { continue; }
 
@@ -121,40 +146,54 @@ pub fn calculate_payouts(
         let mut eligible_players = [false; MAX_PLAYERS];
         
         for p_idx in 0..MAX_PLAYERS {
-            if player_bets[p_idx] >= current_level_bet {
-                current_pot_size += pot_increment;
-                eligible_players[p_idx] = active_players[p_idx];
-            }
+            // Every contributor, active or folded, pays into this pot the part of
+            // their bet that lies between the previous level and this one.
+            let bet = player_bets[p_idx];
+            current_pot_size += bet.min(current_level_bet) - bet.min(last_level_bet);
+            eligible_players[p_idx] = active_players[p_idx] && bet >= current_level_bet;
         }
 
+        // The rake comes out of the earliest pots first.
+        let mut rake_taken = rake_left;
+        if rake_taken > current_pot_size {
+            rake_taken = current_pot_size;
+        }
+        current_pot_size -= rake_taken;
+        rake_left -= rake_taken;
+
         // 3. Find winner(s) for the current pot
+        // Compare full hands, kickers included, so two different hands of the same
+        // category (say, two two-pairs) do not split a pot one of them wins outright.
         let mut best_rank = HandRank::NoHand;
         for p_idx in 0..MAX_PLAYERS {
-            if eligible_players[p_idx] {
-                if hand_rank_to_u8(player_ranks[p_idx]) > hand_rank_to_u8(best_rank) {
-                    best_rank = player_ranks[p_idx];
-                }
+            if eligible_players[p_idx] && compare_hand_ranks(player_ranks[p_idx], best_rank) == 1 {
+                best_rank = player_ranks[p_idx];
             }
         }
 
+        // Only exact ties with the best hand share the pot.
         let mut winners = [false; MAX_PLAYERS];
         let mut winner_count = 0;
         for p_idx in 0..MAX_PLAYERS {
-            // NOTE: This simplified comparison doesn't handle ties properly.
-            // A full implementation would use a detailed compare function.
-            if eligible_players[p_idx] && hand_rank_to_u8(player_ranks[p_idx]) == hand_rank_to_u8(best_rank) {
+            if eligible_players[p_idx] && compare_hand_ranks(player_ranks[p_idx], best_rank) == 0 {
                 winners[p_idx] = true;
                 winner_count += 1;
             }
         }
 
         // 4. Distribute current pot
+        // Odd chips go one at a time to the winners in order from the button's left.
         if winner_count > 0 {
             let share = current_pot_size / winner_count as u64;
-            // TODO: Handle remainder for uneven splits
-            for p_idx in 0..MAX_PLAYERS {
+            let mut odd_chips = current_pot_size - share * winner_count as u64;
+            for offset in 1..(MAX_PLAYERS + 1) {
+                let p_idx = (dealer_position as usize + offset) % MAX_PLAYERS;
                 if winners[p_idx] {
                     payouts[p_idx] += share;
+                    if odd_chips > 0 {
+                        payouts[p_idx] += 1;
+                        odd_chips -= 1;
+                    }
                 }
             }
         }
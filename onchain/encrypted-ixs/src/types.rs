@@ -7,10 +7,12 @@
 //! packed integer representations to minimize data size and computational overhead.
 //!
 //! Key Structs:
+//! - CardIndex: A validated deck index (0-51), with a single `INVALID` sentinel.
 //! - Card: A representation of a single playing card.
 //! - Deck: A memory-efficient, packed representation of a 52-card deck.
 //! - Hand: A packed representation of a player's 2-card hole hand.
 //! - WinnerInfo: A struct to hold showdown results for on-chain processing.
+//! - ShowdownDetail: A seat's evaluated hand and winnings, for clients and HUDs.
 //!
 //! @dependencies
 //! - `arcis_imports`: Provides core types for Arcis circuit development, such as `ArcisPublicKey`.
@@ -45,6 +47,40 @@ const POWS_OF_SIXTY_FOUR: [u128; 21] = [
     1329227995784915872903807060280344576,
 ];
 
+/// The number of cards in a standard deck; valid card indices are `0..DECK_SIZE`.
+pub const DECK_SIZE: u8 = 52;
+
+/// The sentinel index used for dummy hole cards, burned cards, and reveal padding.
+/// It is the first value past the deck so it still fits the 6-bit card packing used
+/// by `Deck` and `Hand`. Must match `INVALID_CARD_INDEX` in the on-chain program.
+pub const INVALID_CARD_INDEX: u8 = DECK_SIZE;
+
+/// A card's position in a standard deck (`suit * 13 + rank`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CardIndex(u8);
+
+impl CardIndex {
+    /// The padding value for "no card".
+    pub const INVALID: CardIndex = CardIndex(INVALID_CARD_INDEX);
+
+    /// Creates a `CardIndex` from a raw index (0-51).
+    pub fn new(index: u8) -> Self {
+        // This will panic if index > 51, which is intended behavior as it indicates a logic error.
+        assert!(index < DECK_SIZE, "Invalid card index");
+        CardIndex(index)
+    }
+
+    /// Returns the raw index, as stored in a packed `Deck` or `Hand`.
+    pub fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `false` for the `INVALID` sentinel.
+    pub fn is_valid(self) -> bool {
+        self.0 < DECK_SIZE
+    }
+}
+
 /// Represents a single playing card within an Arcis circuit.
 #[derive(Clone, Copy, Debug)]
 pub struct Card {
@@ -57,19 +93,18 @@ pub struct Card {
 }
 
 impl Card {
-    /// Converts the Card struct to a single `u8` index (0-51) for packing.
-    pub fn to_u8_index(&self) -> u8 {
+    /// Converts the Card struct to its deck index for packing.
+    pub fn to_index(&self) -> CardIndex {
         // The formula ensures a unique index for each of the 52 cards.
-        self.suit * 13 + self.rank
+        CardIndex::new(self.suit * 13 + self.rank)
     }
 
-    /// Creates a Card struct from a single `u8` index (0-51).
-    pub fn from_u8_index(index: u8) -> Self {
-        // This will panic if index > 51, which is intended behavior as it indicates a logic error.
-        assert!(index < 52, "Invalid card index");
+    /// Creates a Card struct from a deck index. The `INVALID` sentinel is rejected.
+    pub fn from_index(index: CardIndex) -> Self {
+        assert!(index.is_valid(), "Invalid card index");
         Card {
-            suit: index / 13,
-            rank: index % 13,
+            suit: index.get() / 13,
+            rank: index.get() % 13,
         }
     }
 }
@@ -172,4 +207,20 @@ pub struct WinnerInfo {
     pub player_pubkey: ArcisPublicKey,
     /// The amount of chips won by the player.
     pub amount_won: u64,
-}
\ No newline at end of file
+}
+
+/// A seat's showdown result as published by `evaluate_hands_and_payout`: what its best
+/// five-card hand was and how much it won. The array of these is the circuit's public
+/// output, one entry per seat (14 bytes each, so 84 bytes in all); a seat that was not
+/// in the showdown has a zero `hand_category`.
+#[derive(Clone, Copy)]
+pub struct ShowdownDetail {
+    /// The best hand's category, from 9 for a straight flush down to 1 for high card.
+    pub hand_category: u8,
+    /// The ranks that break ties within the category, most significant first (0 for a
+    /// deuce up to 12 for an ace), zero-padded. Together with the category these order
+    /// hands exactly as the showdown compared them.
+    pub hand_ranks: [u8; 5],
+    /// The chips the seat won, after rake.
+    pub amount_won: u64,
+}
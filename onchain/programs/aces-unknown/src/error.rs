@@ -19,7 +19,7 @@ pub enum AcesUnknownErrorCode {
     #[msg("Unauthorized: Signer is not the platform admin.")]
     Unauthorized,
 
-    #[msg("Invalid Stakes: Big blind must be greater than small blind.")]
+    #[msg("Invalid Stakes: Big blind must be at least twice the small blind.")]
     InvalidStakes,
 
     #[msg("Invalid Buy-in: Buy-in amount is insufficient.")]
@@ -28,6 +28,9 @@ pub enum AcesUnknownErrorCode {
     #[msg("Invalid Token Mint: The provided token mint is not supported or invalid.")]
     InvalidTokenMint,
 
+    #[msg("The allowed token mint list is full.")]
+    AllowedMintsFull,
+
     #[msg("Table is full. Cannot join.")]
     TableFull,
 
@@ -43,9 +46,66 @@ pub enum AcesUnknownErrorCode {
     #[msg("Seat is already occupied.")]
     SeatOccupied,
 
+    #[msg("Seat is reserved by another player.")]
+    SeatReserved,
+
+    #[msg("This buy-in would exceed the player's buy-in limit for the period.")]
+    PlayerLimitExceeded,
+
+    #[msg("The player has excluded themselves from play until a later time.")]
+    SelfExcluded,
+
+    #[msg("A self-exclusion can only be extended, not shortened.")]
+    ExclusionCannotBeShortened,
+
+    #[msg("The player is already seated at their maximum number of concurrent tables.")]
+    TooManyConcurrentTables,
+
     #[msg("Cannot leave the table while a hand is in progress.")]
     CannotLeaveMidHand,
 
+    #[msg("Cannot close the table while a hand is in progress without refunding it.")]
+    CannotCloseMidHand,
+
+    #[msg("The table still has seated players or a hand in progress.")]
+    TableNotEmpty,
+
+    #[msg("The vault balance is too large to be swept as dust.")]
+    NotVaultDust,
+
+    #[msg("The provided player seat accounts do not match the table's occupied seats.")]
+    InvalidSeatAccounts,
+
+    #[msg("Only the table creator can perform this action.")]
+    NotTableCreator,
+
+    #[msg("Seat accounting mismatch: occupied seats do not match the player count.")]
+    SeatAccountingMismatch,
+
+    #[msg("The provided player seat belongs to a different table.")]
+    SeatTableMismatch,
+
+    #[msg("The provided player seat belongs to a different player.")]
+    SeatOwnerMismatch,
+
+    #[msg("The table has not been idle long enough to be paused.")]
+    TableNotIdle,
+
+    #[msg("The player still has chips and has not busted.")]
+    PlayerNotBusted,
+
+    #[msg("The stuck-hand refund timeout is outside the range the platform allows.")]
+    InvalidRefundTimeout,
+
+    #[msg("The showdown reveal timeout must be shorter than the stuck-hand refund timeout.")]
+    InvalidShowdownRevealTimeout,
+
+    #[msg("This table is private and the player has not been invited.")]
+    NotInvited,
+
+    #[msg("The table's invite list is full.")]
+    InviteListFull,
+
     // ========================================
     // Gameplay Errors
     // ========================================
@@ -55,9 +115,15 @@ pub enum AcesUnknownErrorCode {
     #[msg("Cannot start hand. The game is already in progress.")]
     CannotStartHand,
 
+    #[msg("The table's minimum time between hands has not yet elapsed.")]
+    HandStartTooSoon,
+
     #[msg("Not enough players to start a hand.")]
     NotEnoughPlayers,
 
+    #[msg("Only one player is left in the hand; it is won uncontested, without more cards or a showdown.")]
+    HandUncontested,
+
     #[msg("It is not this player's turn to act.")]
     NotPlayersTurn,
 
@@ -76,12 +142,72 @@ pub enum AcesUnknownErrorCode {
     #[msg("Bet is too small. Must be at least the minimum raise.")]
     BetTooSmall,
 
+    #[msg("Only an all-in for less than a full raise has been made since this player acted; they may call or fold.")]
+    RaiseNotReopened,
+
     #[msg("Insufficient funds to perform this action.")]
     InsufficientFunds,
 
     #[msg("Hand is not in a stuck state. Cannot force refund.")]
     HandNotStuck,
 
+    #[msg("A straddle can only be posted by UTG before the first preflop action.")]
+    StraddleNotAllowed,
+
+    #[msg("Chopping the blinds is not enabled at this table.")]
+    ChopBlindsDisabled,
+
+    #[msg("Both players in the hand must sign to chop the blinds.")]
+    ChopNotAgreed,
+
+    #[msg("Bomb pots are not enabled at this table.")]
+    BombPotsDisabled,
+
+    #[msg("The player whose turn it is has no pending action to apply.")]
+    NoPendingAction,
+
+    #[msg("The next street is already being revealed or has already been dealt.")]
+    InvalidBettingRoundTransition,
+
+    #[msg("Players at the showdown still have time to reveal their hole cards.")]
+    ShowdownRevealPending,
+
+    #[msg("There are not enough empty community card slots for the revealed cards.")]
+    TooManyCommunityCards,
+
+    #[msg("Invalid card index. Must be between 0 and 51.")]
+    InvalidCardIndex,
+
+    #[msg("The deck reveal returned fewer cards than requested; the deck is out of sync.")]
+    DeckIntegrityViolation,
+
+    #[msg("The deck ordering and salt do not open the hand's shuffle commitment.")]
+    ShuffleCommitmentMismatch,
+
+    #[msg("Rabbit hunting is not enabled at this table.")]
+    RabbitHuntDisabled,
+
+    #[msg("The encrypted hand account does not belong to this hand or player.")]
+    InvalidEncryptedHandAccount,
+
+    #[msg("A player still in the hand is missing their encrypted hand account.")]
+    MissingEncryptedHand,
+
+    #[msg("The players' bets for the hand do not add up to the pot.")]
+    PotMismatch,
+
+    // ========================================
+    // Rake-back Errors
+    // ========================================
+    #[msg("The provided rake-back account does not belong to this player and token.")]
+    InvalidRakebackAccount,
+
+    #[msg("There is no accrued rake-back to claim.")]
+    NothingToClaim,
+
+    #[msg("The referrer token account is missing or does not belong to the table's referrer and token.")]
+    InvalidReferrerAccount,
+
     // ========================================
     // Arcium & Computation Errors
     // ========================================
@@ -93,4 +219,13 @@ pub enum AcesUnknownErrorCode {
 
     #[msg("Arcium computation timed out.")]
     ComputationTimeout,
+
+    #[msg("The callback does not match the computation queued for this table and hand.")]
+    ComputationMismatch,
+
+    #[msg("This computation offset has already been used for this hand.")]
+    ComputationOffsetReused,
+
+    #[msg("The hole-card encryption nonce was already used for this player.")]
+    NonceReused,
 }
\ No newline at end of file
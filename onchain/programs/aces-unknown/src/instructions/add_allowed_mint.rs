@@ -0,0 +1,51 @@
+//! src/instructions/add_allowed_mint.rs
+//!
+//! @description
+//! This instruction allows the platform administrator to add a token mint to the
+//! whitelist of currencies new tables may use. While the list is empty any mint is
+//! accepted, so adding the first mint restricts table creation to the listed ones.
+//!
+//! @security
+//! This instruction is secured by an `address` constraint on the `admin` account,
+//! ensuring that only the wallet public key stored in `platform_config.admin` can
+//! sign and successfully execute this transaction.
+
+use anchor_lang::prelude::*;
+use crate::state::PlatformConfig;
+use crate::state::constants::MAX_ALLOWED_MINTS;
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for whitelisting a token mint.
+///
+/// # Arguments
+/// * `ctx` - The context containing the required accounts.
+/// * `mint` - The token mint new tables may use.
+pub fn add_allowed_mint(ctx: Context<AddAllowedMint>, mint: Pubkey) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+    require!(
+        !platform_config.allowed_mints.contains(&mint),
+        AcesUnknownErrorCode::InvalidAction
+    );
+    require!(
+        platform_config.allowed_mints.len() < MAX_ALLOWED_MINTS,
+        AcesUnknownErrorCode::AllowedMintsFull
+    );
+
+    platform_config.allowed_mints.push(mint);
+
+    msg!("Allowed mint added: {}", mint);
+
+    Ok(())
+}
+
+/// The context struct for the `add_allowed_mint` instruction.
+#[derive(Accounts)]
+pub struct AddAllowedMint<'info> {
+    /// The platform configuration account to be modified.
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// The administrator's signer account.
+    #[account(address = platform_config.admin)]
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,208 @@
+//! src/instructions/admin_force_close_table.rs
+//!
+//! @description
+//! This moderation instruction lets the platform administrator shut down a table
+//! that is abusing the system or is irrecoverably stuck. Every seated player is
+//! cashed out in full, and the seat accounts, the table vault, and the table itself
+//! are closed.
+//!
+//! @accounts
+//! - `platform_config`: The global platform configuration, used to authorize the admin
+//!   and to count the closed table.
+//! - `admin`: The platform administrator.
+//! - `table`: The table being closed. Its rent is returned to the creator.
+//! - `creator`: The table creator's wallet, receiving the table and vault rent.
+//! - `table_vault`: The table's token vault, emptied and closed.
+//! - `treasury_vault`: The platform treasury, receiving any residual vault balance.
+//! - `remaining_accounts`: For every occupied seat, in order: the `PlayerSeat`
+//!   account, the player's token account for the table's currency, the player's
+//!   wallet (receiving the seat rent), and the player's writable `PlayerActiveTables`.
+//!
+//! @logic
+//! 1. Refuses to close a table mid-hand unless `refund_hand` is set, in which case
+//!    each player's contribution to the current hand is returned to their stack first.
+//! 2. Verifies the passed seats are exactly the table's occupied seats.
+//! 3. Transfers each player's stack from the vault to their token account, closes
+//!    their seat, and decrements their count of active tables.
+//! 4. Sweeps any residual vault balance to the treasury, then closes the vault.
+//! 5. Closes the table, removes it from the platform's active table count, and emits
+//!    `TableForceClosed`.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+use crate::state::{GameState, PlatformConfig, PlayerActiveTables, Table};
+use crate::error::AcesUnknownErrorCode;
+use crate::utils::{load_table_seats, record_table_closed, require_all_occupied_seats};
+
+/// The accounts passed per seat: seat, token account, wallet, and active-table count.
+const ACCOUNTS_PER_SEAT: usize = 4;
+
+/// The instruction logic for the admin force-closing a table.
+pub fn admin_force_close_table<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AdminForceCloseTable<'info>>,
+    table_id: u64,
+    refund_hand: bool,
+) -> Result<()> {
+    let table = &ctx.accounts.table;
+    let table_key = table.key();
+
+    // --- Validation ---
+    let hand_in_progress = table.game_state == GameState::HandInProgress;
+    require!(
+        !hand_in_progress || refund_hand,
+        AcesUnknownErrorCode::CannotCloseMidHand
+    );
+    require!(
+        ctx.remaining_accounts.len() % ACCOUNTS_PER_SEAT == 0,
+        AcesUnknownErrorCode::InvalidSeatAccounts
+    );
+
+    let table_id_bytes = table_id.to_le_bytes();
+    let seeds = &[&b"table"[..], table_id_bytes.as_ref(), &[ctx.bumps.table]];
+    let signer_seeds = &[&seeds[..]];
+
+    // --- Cash Out Every Seat ---
+    let mut seat_indices = Vec::with_capacity(ctx.remaining_accounts.len() / ACCOUNTS_PER_SEAT);
+    let mut total_cashed_out = 0u64;
+    let mut total_refunded = 0u64;
+    for accounts in ctx.remaining_accounts.chunks(ACCOUNTS_PER_SEAT) {
+        let mut seat = load_table_seats(&table_key, &accounts[..1])?
+            .pop()
+            .ok_or(AcesUnknownErrorCode::InvalidSeatAccounts)?;
+        let player_token_account: Account<'info, TokenAccount> = Account::try_from(&accounts[1])?;
+        let player_wallet = &accounts[2];
+        let mut active_tables: Account<'info, PlayerActiveTables> = Account::try_from(&accounts[3])?;
+        require_keys_eq!(
+            player_token_account.owner,
+            seat.player_pubkey,
+            AcesUnknownErrorCode::InvalidSeatAccounts
+        );
+        require_keys_eq!(
+            player_token_account.mint,
+            table.token_mint,
+            AcesUnknownErrorCode::InvalidTokenMint
+        );
+        require_keys_eq!(
+            player_wallet.key(),
+            seat.player_pubkey,
+            AcesUnknownErrorCode::InvalidSeatAccounts
+        );
+        require_keys_eq!(
+            active_tables.player,
+            seat.player_pubkey,
+            AcesUnknownErrorCode::InvalidSeatAccounts
+        );
+
+        if hand_in_progress {
+            total_refunded += seat.total_bet_this_hand;
+            seat.stack += seat.total_bet_this_hand;
+        }
+
+        if seat.stack > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.table_vault.to_account_info(),
+                to: player_token_account.to_account_info(),
+                authority: table.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, seat.stack)?;
+            total_cashed_out += seat.stack;
+        }
+
+        seat_indices.push(seat.seat_index);
+        seat.close(player_wallet.clone())?;
+        active_tables.record_leave();
+        active_tables.exit(&crate::ID)?;
+    }
+    require_all_occupied_seats(table, seat_indices.iter().copied())?;
+    if hand_in_progress {
+        require!(table.pot == total_refunded, AcesUnknownErrorCode::InvalidAction);
+    }
+
+    // --- Close Vault ---
+    ctx.accounts.table_vault.reload()?;
+    let residual = ctx.accounts.table_vault.amount;
+    if residual > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.table_vault.to_account_info(),
+            to: ctx.accounts.treasury_vault.to_account_info(),
+            authority: table.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, residual)?;
+    }
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.table_vault.to_account_info(),
+        destination: ctx.accounts.creator.to_account_info(),
+        authority: table.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token::close_account(cpi_ctx)?;
+    record_table_closed(&mut ctx.accounts.platform_config);
+
+    emit!(TableForceClosed {
+        table_id,
+        players_cashed_out: seat_indices.len() as u8,
+        total_cashed_out,
+        residual_swept: residual,
+    });
+
+    Ok(())
+}
+
+/// The context struct for the `admin_force_close_table` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct AdminForceCloseTable<'info> {
+    /// The global platform configuration account, which counts the closed table.
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// The administrator's signer account.
+    #[account(address = platform_config.admin)]
+    pub admin: Signer<'info>,
+
+    /// The table being closed.
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub table: Account<'info, Table>,
+
+    /// The table creator, refunded the rent for the table and its vault.
+    /// CHECK: Only receives lamports; its key is checked against `table.creator`.
+    #[account(mut, address = table.creator)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// The table's token vault.
+    #[account(
+        mut,
+        seeds = [b"vault", table.key().as_ref()],
+        bump,
+    )]
+    pub table_vault: Account<'info, TokenAccount>,
+
+    /// The platform treasury, receiving any balance left once every stack is paid.
+    #[account(
+        mut,
+        address = platform_config.treasury_vault,
+        constraint = treasury_vault.mint == table.token_mint @ AcesUnknownErrorCode::InvalidTokenMint,
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct TableForceClosed {
+    pub table_id: u64,
+    pub players_cashed_out: u8,
+    pub total_cashed_out: u64,
+    pub residual_swept: u64,
+}
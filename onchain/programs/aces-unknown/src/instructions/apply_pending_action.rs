@@ -0,0 +1,150 @@
+//! src/instructions/apply_pending_action.rs
+//!
+//! @description
+//! This instruction applies the action a player pre-selected with
+//! `set_pending_action` once the turn has reached them. It can be called by anyone
+//! (typically a keeper or the client of the previous actor), which keeps the game
+//! moving without waiting for the player to sign.
+//!
+//! @accounts
+//! - `table`: The poker table account where the hand is in progress.
+//! - `payer`: The signer calling the instruction (can be anyone).
+//! - `player_seat`: The seat of the player whose turn it is.
+//! - `platform_config`: Used to get the rake parameters for a pot won uncontested, and
+//!   to count the hand once it is won.
+//! - `table_vault`: The table's token vault, from which rake is taken.
+//! - `treasury_vault`: The platform's treasury account to receive the rake.
+//! - `remaining_accounts`: The table's other occupied `PlayerSeat` accounts, writable so
+//!   a player left alone in the hand can be paid.
+//!
+//! @logic
+//! 1. Verifies that a hand is in progress and `player_seat` is the seat to act.
+//! 2. Resolves the pending action against the bet the player currently faces:
+//!    - **CheckFold**: Checks if there is nothing to call, otherwise folds.
+//!    - **CallAny**: Calls if facing a bet, otherwise checks.
+//!    - **Fold**: Folds.
+//! 3. Clears the pending action and executes the resolved action through the same
+//!    path as `player_action`, which also advances the turn.
+//! 4. If a resolved fold leaves a single player in the hand, that player is credited
+//!    the pot less rake (none before the flop), exactly as after a voluntary fold.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GameState, HandPhase, PlatformConfig, PlayerAction, PlayerSeat, Table};
+use crate::error::AcesUnknownErrorCode;
+use crate::instructions::player_action::{apply_player_action, award_pot_to_last_player, FoldReason, PlayerFolded};
+use crate::utils::{load_table_seats, require_all_occupied_seats};
+
+/// The instruction logic for applying the current player's pending action.
+pub fn apply_pending_action<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ApplyPendingAction<'info>>,
+    _table_id: u64,
+) -> Result<()> {
+    let table = &mut ctx.accounts.table;
+    let player_seat = &mut ctx.accounts.player_seat;
+
+    // --- Validation ---
+    require!(
+        table.game_state == GameState::HandInProgress,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    table.require_phase(HandPhase::Betting)?;
+    require!(
+        player_seat.seat_index == table.turn_position,
+        AcesUnknownErrorCode::NotPlayersTurn
+    );
+    let pending_action = player_seat
+        .pending_action
+        .take()
+        .ok_or(AcesUnknownErrorCode::NoPendingAction)?;
+
+    let mut other_seats = load_table_seats(&table.key(), ctx.remaining_accounts)?;
+    require_all_occupied_seats(
+        table,
+        other_seats
+            .iter()
+            .map(|seat| seat.seat_index)
+            .chain(std::iter::once(player_seat.seat_index)),
+    )?;
+
+    // --- Apply Action ---
+    let action = pending_action.resolve(player_seat.bet_this_round, table.current_bet);
+    let now = Clock::get()?.unix_timestamp;
+    apply_player_action(table, player_seat, &other_seats, action, now)?;
+
+    if action == PlayerAction::Fold {
+        emit!(PlayerFolded {
+            table_id: table.table_id,
+            hand_id: table.hand_id_counter,
+            seat_index: player_seat.seat_index,
+            reason: FoldReason::PendingAction,
+        });
+    }
+
+    emit!(PendingActionApplied {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        seat_index: player_seat.seat_index,
+        action,
+    });
+
+    // --- Award an Uncontested Pot ---
+    if table.game_state == GameState::HandComplete {
+        award_pot_to_last_player(
+            table,
+            ctx.bumps.table,
+            player_seat,
+            &mut other_seats,
+            &mut ctx.accounts.platform_config,
+            &ctx.accounts.table_vault,
+            &ctx.accounts.treasury_vault,
+            &ctx.accounts.token_program,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The context struct for the `apply_pending_action` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct ApplyPendingAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+
+    pub payer: Signer<'info>,
+
+    /// The seat of the player whose turn it is.
+    #[account(
+        mut,
+        seeds = [b"player_seat", table.key().as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
+        bump = player_seat.bump,
+    )]
+    pub player_seat: Account<'info, PlayerSeat>,
+    #[account(mut, seeds = [b"platform_config"], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [b"vault", table.key().as_ref()],
+        bump,
+    )]
+    pub table_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = platform_config.treasury_vault,
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct PendingActionApplied {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    pub action: PlayerAction,
+}
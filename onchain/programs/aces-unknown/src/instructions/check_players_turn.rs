@@ -0,0 +1,84 @@
+//! src/instructions/check_players_turn.rs
+//!
+//! @description
+//! This read-only instruction reports whether it is a given player's turn to act,
+//! so clients do not each re-derive it from seat indices, `turn_position` and the
+//! hand phase. The answer is emitted in a `PlayersTurnChecked` event; off-chain code
+//! can call `is_players_turn` directly on fetched accounts for the same result.
+//!
+//! @accounts
+//! - `table`: The table being asked about.
+//! - `payer`: Any signer; the check is permissionless.
+//! - `remaining_accounts`: The table's occupied `PlayerSeat` accounts.
+//!
+//! @logic
+//! 1. Loads and validates the occupied seats.
+//! 2. Finds the player's seat, if they are seated at the table.
+//! 3. Reports the player's turn only while a betting round is open and the seat at
+//!    `turn_position` is theirs and can still act.
+//! 4. Emits `PlayersTurnChecked`.
+
+use anchor_lang::prelude::*;
+use crate::state::{GameState, HandPhase, PlayerSeat, Table};
+use crate::utils::{load_table_seats, require_all_occupied_seats};
+
+/// Instruction logic for reporting whether it is `player`'s turn.
+/// The occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+pub fn check_players_turn<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CheckPlayersTurn<'info>>,
+    _table_id: u64,
+    player: Pubkey,
+) -> Result<()> {
+    let table = &ctx.accounts.table;
+    let seats = load_table_seats(&table.key(), ctx.remaining_accounts)?;
+    require_all_occupied_seats(table, seats.iter().map(|seat| seat.seat_index))?;
+    let seat_refs: Vec<&PlayerSeat> = seats.iter().map(|seat| &**seat).collect();
+
+    emit!(PlayersTurnChecked {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        player,
+        seat_index: seat_refs
+            .iter()
+            .find(|seat| seat.player_pubkey == player)
+            .map(|seat| seat.seat_index),
+        is_players_turn: is_players_turn(table, &player, &seat_refs),
+    });
+
+    Ok(())
+}
+
+/// Whether `player` is the one to act: a betting round is open and the seat at
+/// `turn_position` belongs to them and is still in the hand with chips behind.
+pub fn is_players_turn(table: &Table, player: &Pubkey, seats: &[&PlayerSeat]) -> bool {
+    if table.game_state != GameState::HandInProgress || table.hand_phase != HandPhase::Betting {
+        return false;
+    }
+    seats.iter().any(|seat| {
+        seat.seat_index == table.turn_position
+            && seat.player_pubkey == *player
+            && seat.is_active_in_hand
+            && !seat.is_all_in
+    })
+}
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct CheckPlayersTurn<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+    pub payer: Signer<'info>,
+}
+
+#[event]
+pub struct PlayersTurnChecked {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub player: Pubkey,
+    /// The player's seat at the table, or `None` if they are not seated.
+    pub seat_index: Option<u8>,
+    pub is_players_turn: bool,
+}
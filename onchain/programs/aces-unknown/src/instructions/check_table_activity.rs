@@ -0,0 +1,65 @@
+//! src/instructions/check_table_activity.rs
+//!
+//! @description
+//! This instruction lets a keeper pause a table that has gone quiet, so stalled
+//! tables can be skipped instead of polled. A table is idle once no hand has been
+//! started and no player has acted for `TableSettings::idle_pause_seconds`. The pause
+//! is automatic and separate from anything the creator controls: the next
+//! `start_hand` or `player_action` resumes the table.
+//!
+//! @accounts
+//! - `table`: The table being checked.
+//! - `payer`: Any signer; checking activity is permissionless.
+//!
+//! @logic
+//! 1. Verifies idle pausing is enabled for the table and it is not already paused.
+//! 2. Verifies the table has been idle for at least `idle_pause_seconds`.
+//! 3. Sets `is_paused` and emits `TablePaused`.
+
+use anchor_lang::prelude::*;
+use crate::state::Table;
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for pausing an idle table.
+pub fn check_table_activity(ctx: Context<CheckTableActivity>, _table_id: u64) -> Result<()> {
+    let table = &mut ctx.accounts.table;
+    let now = Clock::get()?.unix_timestamp;
+
+    // --- Validation ---
+    let idle_pause_seconds = table.settings.idle_pause_seconds;
+    require!(
+        idle_pause_seconds > 0
+            && !table.is_paused
+            && now >= table.last_activity_at + idle_pause_seconds as i64,
+        AcesUnknownErrorCode::TableNotIdle
+    );
+
+    table.is_paused = true;
+
+    emit!(TablePaused {
+        table_id: table.table_id,
+        last_activity_at: table.last_activity_at,
+    });
+
+    Ok(())
+}
+
+/// The context struct for the `check_table_activity` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct CheckTableActivity<'info> {
+    #[account(
+        mut,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+
+    pub payer: Signer<'info>,
+}
+
+#[event]
+pub struct TablePaused {
+    pub table_id: u64,
+    pub last_activity_at: i64,
+}
@@ -0,0 +1,107 @@
+//! src/instructions/chop_blinds.rs
+//!
+//! @description
+//! This instruction lets the two players of a heads-up hand agree to "chop the
+//! blinds": the posted blinds are handed back and the hand is voided without being
+//! played, so the next hand can be dealt. Both players must sign, and the chop is only
+//! available preflop before anyone has acted. Tables opt in via
+//! `TableSettings::allow_chop_blinds`.
+//!
+//! @accounts
+//! - `table`: The table with the hand being chopped.
+//! - `player_one`, `player_two`: The two players dealt into the hand, in either order.
+//! - `remaining_accounts`: The writable `PlayerSeat` accounts for every occupied seat.
+//!
+//! @logic
+//! 1. Verifies the table allows chopping and the hand is preflop with no action taken:
+//!    it is still the small blind's first turn and the bet is still the big blind.
+//! 2. Verifies exactly two players were dealt in and that they are the two signers.
+//! 3. Returns every seat's contribution to its stack and voids the hand, as a refund does.
+//! 4. Emits `BlindsChopped`.
+
+use anchor_lang::prelude::*;
+use crate::state::{BettingRound, GameState, HandPhase, Table};
+use crate::error::AcesUnknownErrorCode;
+use crate::utils::{load_table_seats, persist_seats, require_all_occupied_seats};
+
+/// Instruction logic for chopping the blinds of a heads-up hand.
+pub fn chop_blinds<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ChopBlinds<'info>>,
+    _table_id: u64,
+) -> Result<()> {
+    let table = &mut ctx.accounts.table;
+
+    // --- Validation ---
+    require!(table.settings.allow_chop_blinds, AcesUnknownErrorCode::ChopBlindsDisabled);
+    // Heads-up, the button posts the small blind and acts first preflop, so the
+    // turn is still theirs and the bet unchanged until someone acts.
+    require!(
+        table.game_state == GameState::HandInProgress
+            && table.hand_phase == HandPhase::Betting
+            && table.betting_round == BettingRound::PreFlop
+            && table.turn_position == table.dealer_position
+            && table.current_bet == table.big_blind,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+
+    let mut seats = load_table_seats(&table.key(), ctx.remaining_accounts)?;
+    require_all_occupied_seats(table, seats.iter().map(|seat| seat.seat_index))?;
+
+    let mut dealt_in = seats.iter().filter(|seat| seat.is_active_in_hand).map(|seat| seat.player_pubkey);
+    let (first, second) = match (dealt_in.next(), dealt_in.next(), dealt_in.next()) {
+        (Some(first), Some(second), None) => (first, second),
+        _ => return err!(AcesUnknownErrorCode::InvalidAction),
+    };
+    let signers = (ctx.accounts.player_one.key(), ctx.accounts.player_two.key());
+    require!(
+        signers == (first, second) || signers == (second, first),
+        AcesUnknownErrorCode::ChopNotAgreed
+    );
+
+    // --- Return the Blinds ---
+    let mut total_refunded = 0u64;
+    for seat in seats.iter_mut() {
+        let refund = seat.total_bet_this_hand;
+        seat.stack += refund;
+        total_refunded += refund;
+        seat.reset_for_new_hand();
+        seat.is_active_in_hand = false;
+    }
+    persist_seats(&seats)?;
+
+    // --- Void the Hand ---
+    require!(table.pot == total_refunded, AcesUnknownErrorCode::PotMismatch);
+    table.pot = 0;
+    table.current_bet = 0;
+    table.ready_for_runout = false;
+    table.pots.clear();
+    table.complete_hand(Clock::get()?.unix_timestamp);
+
+    emit!(BlindsChopped {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        total_refunded,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct ChopBlinds<'info> {
+    #[account(
+        mut,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+    pub player_one: Signer<'info>,
+    pub player_two: Signer<'info>,
+}
+
+#[event]
+pub struct BlindsChopped {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub total_refunded: u64,
+}
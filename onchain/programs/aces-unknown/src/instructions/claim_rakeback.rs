@@ -0,0 +1,100 @@
+//! src/instructions/claim_rakeback.rs
+//!
+//! @description
+//! This instruction pays a player the rake-back they have accrued for a token,
+//! transferring it from the platform treasury to the player's token account.
+//!
+//! @accounts
+//! - `platform_config`: Used to locate the treasury vault.
+//! - `player`: The signer claiming their rake-back.
+//! - `player_rakeback`: The player's `PlayerRakeback` account for the token.
+//! - `player_token_account`: The player's token account to receive the payout.
+//! - `treasury_vault`: The platform treasury, which funds the payout.
+//! - `treasury_authority`: The owner of the treasury vault, who must co-sign the
+//!   transfer since the treasury is not controlled by the program.
+//!
+//! @logic
+//! 1. Verifies the player has a non-zero accrued balance.
+//! 2. Transfers the accrued amount from the treasury to the player.
+//! 3. Resets the accrued balance and adds it to `total_claimed`.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{PlatformConfig, PlayerRakeback};
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for claiming accrued rake-back.
+pub fn claim_rakeback(ctx: Context<ClaimRakeback>) -> Result<()> {
+    let amount = ctx.accounts.player_rakeback.accrued;
+    require!(amount > 0, AcesUnknownErrorCode::NothingToClaim);
+
+    // --- Token Transfer ---
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury_vault.to_account_info(),
+        to: ctx.accounts.player_token_account.to_account_info(),
+        authority: ctx.accounts.treasury_authority.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    // --- State Update ---
+    let player_rakeback = &mut ctx.accounts.player_rakeback;
+    player_rakeback.accrued = 0;
+    player_rakeback.total_claimed = player_rakeback.total_claimed.checked_add(amount).unwrap();
+
+    emit!(RakebackClaimed {
+        player: player_rakeback.player,
+        token_mint: player_rakeback.token_mint,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// The context struct for the `claim_rakeback` instruction.
+#[derive(Accounts)]
+pub struct ClaimRakeback<'info> {
+    /// The global platform configuration account.
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// The player claiming their rake-back.
+    pub player: Signer<'info>,
+
+    /// The player's rake-back account.
+    #[account(
+        mut,
+        seeds = [b"player_rakeback", player.key().as_ref(), player_rakeback.token_mint.as_ref()],
+        bump = player_rakeback.bump,
+    )]
+    pub player_rakeback: Account<'info, PlayerRakeback>,
+
+    /// The player's token account for the rake-back's currency.
+    #[account(
+        mut,
+        constraint = player_token_account.mint == player_rakeback.token_mint @ AcesUnknownErrorCode::InvalidTokenMint,
+        constraint = player_token_account.owner == player.key()
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+
+    /// The platform treasury that collected the rake.
+    #[account(
+        mut,
+        address = platform_config.treasury_vault,
+        constraint = treasury_vault.mint == player_rakeback.token_mint @ AcesUnknownErrorCode::InvalidTokenMint,
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    /// The owner of the treasury vault, authorizing the payout.
+    #[account(address = treasury_vault.owner @ AcesUnknownErrorCode::Unauthorized)]
+    pub treasury_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct RakebackClaimed {
+    pub player: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+}
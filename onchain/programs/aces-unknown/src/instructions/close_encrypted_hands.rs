@@ -0,0 +1,95 @@
+//! src/instructions/close_encrypted_hands.rs
+//!
+//! @description
+//! This instruction reclaims the rent held by a finished hand's per-player
+//! `EncryptedHand` accounts. Each account is closed and its lamports refunded to the
+//! player the hole cards were dealt to. Closing is permissionless, but only allowed
+//! once the hand is complete so no computation can still need the ciphertexts.
+//!
+//! @accounts
+//! - `table`: The table whose last hand is being cleaned up.
+//! - `hand_data`: The finished hand the `EncryptedHand` accounts belong to.
+//! - `payer`: Any signer.
+//! - `remaining_accounts`: Pairs of accounts, each a writable `EncryptedHand` followed
+//!   by the writable wallet of the player it belongs to.
+//!
+//! @logic
+//! 1. Verifies the hand is complete.
+//! 2. For each pair, verifies the `EncryptedHand` belongs to `hand_data` and the
+//!    wallet is its player.
+//! 3. Closes the `EncryptedHand`, refunding its rent to the player.
+//! 4. Emits `EncryptedHandsClosed` with the number of accounts closed.
+
+use anchor_lang::prelude::*;
+use crate::state::{EncryptedHand, GameState, HandData, Table};
+use crate::error::AcesUnknownErrorCode;
+
+/// Instruction logic for closing a completed hand's `EncryptedHand` accounts.
+/// `remaining_accounts` holds `(EncryptedHand, player wallet)` pairs.
+pub fn close_encrypted_hands<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseEncryptedHands<'info>>,
+    _table_id: u64,
+) -> Result<()> {
+    let table = &ctx.accounts.table;
+    let hand_key = ctx.accounts.hand_data.key();
+
+    // --- Validation ---
+    require!(
+        table.game_state == GameState::HandComplete,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    require!(
+        ctx.remaining_accounts.len() % 2 == 0,
+        AcesUnknownErrorCode::InvalidEncryptedHandAccount
+    );
+
+    // --- Close Accounts ---
+    let mut closed = 0u8;
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let (encrypted_hand_info, player_info) = (&pair[0], &pair[1]);
+        let encrypted_hand: Account<'info, EncryptedHand> = Account::try_from(encrypted_hand_info)?;
+        require_keys_eq!(
+            encrypted_hand.hand_pubkey,
+            hand_key,
+            AcesUnknownErrorCode::InvalidEncryptedHandAccount
+        );
+        require_keys_eq!(
+            encrypted_hand.player_pubkey,
+            player_info.key(),
+            AcesUnknownErrorCode::InvalidEncryptedHandAccount
+        );
+        encrypted_hand.close(player_info.clone())?;
+        closed += 1;
+    }
+
+    emit!(EncryptedHandsClosed {
+        table_id: table.table_id,
+        hand_id: ctx.accounts.hand_data.hand_id,
+        closed,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct CloseEncryptedHands<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        seeds = [b"hand", table.key().as_ref(), table.hand_id_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub hand_data: Account<'info, HandData>,
+    pub payer: Signer<'info>,
+}
+
+#[event]
+pub struct EncryptedHandsClosed {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub closed: u8,
+}
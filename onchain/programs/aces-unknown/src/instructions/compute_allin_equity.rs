@@ -0,0 +1,138 @@
+//! src/instructions/compute_allin_equity.rs
+//!
+//! @description
+//! This instruction requests an all-in equity estimate for the current hand. Once
+//! betting is closed (`ready_for_runout`), anyone may call it to queue the
+//! `compute_allin_equity` computation on Arcium, which samples the remaining board
+//! runouts over the encrypted hole cards and publishes each contender's win and tie
+//! percentages. The estimate is purely informational and never affects payouts.
+//!
+//! @accounts
+//! - `table`: The poker table account whose hand is being run out.
+//! - `hand_data`: The account with the encrypted hands for the current hand.
+//! - `payer`: The account paying for the computation.
+//! - `remaining_accounts`: The table's occupied `PlayerSeat` accounts, used to
+//!   determine which seats are still contending for the pot.
+//!
+//! @logic
+//! 1. Validates that the hand is in progress and flagged for a runout.
+//! 2. Builds the contender mask from the seats still active in the hand; at least
+//!    two are required for equity to mean anything.
+//! 3. Queues the `compute_allin_equity` computation with the encrypted hands, the
+//!    contender mask, and the public board.
+//! 4. The callback emits `AllInEquity` with per-seat win and tie percentages in basis
+//!    points. Hole cards stay encrypted throughout. Until the Arcium integration is set
+//!    up, the output is simulated with `utils::equity` over the simulated deal.
+
+use anchor_lang::prelude::*;
+use crate::state::{GameState, HandData, Table, INVALID_CARD_INDEX, MAX_PLAYERS};
+use crate::error::AcesUnknownErrorCode;
+use crate::instructions::start_hand::simulate_hole_cards;
+use crate::utils::{estimate_equity, load_table_seats, require_all_occupied_seats, sampled_decks};
+
+/// How many runouts the simulated computation scores. Each one ranks every contender's
+/// hand, so the simulation keeps to a handful to stay within the compute budget.
+const SIMULATED_RUNOUTS: usize = 8;
+
+/// Instruction logic for requesting an all-in equity estimate.
+/// The occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+pub fn compute_allin_equity<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ComputeAllinEquity<'info>>,
+    _table_id: u64,
+) -> Result<()> {
+    let table = &ctx.accounts.table;
+
+    // --- Validation ---
+    require!(
+        table.game_state == GameState::HandInProgress,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    require!(table.ready_for_runout, AcesUnknownErrorCode::InvalidGameState);
+
+    let seats = load_table_seats(&table.key(), ctx.remaining_accounts)?;
+    require_all_occupied_seats(table, seats.iter().map(|seat| seat.seat_index))?;
+
+    let contenders = seats
+        .iter()
+        .filter(|seat| seat.is_active_in_hand)
+        .fold(0u8, |mask, seat| mask | (1 << seat.seat_index));
+    require!(contenders.count_ones() >= 2, AcesUnknownErrorCode::NotEnoughPlayers);
+
+    let num_community = table
+        .community_cards
+        .iter()
+        .filter(|card| card.is_some())
+        .count() as u8;
+
+    // TODO: Add Arcium computation queuing once Arcium integration is properly set up
+
+    emit!(AllInEquityRequested {
+        table_id: table.table_id,
+        hand_id: ctx.accounts.hand_data.hand_id,
+        contenders,
+        num_community,
+    });
+
+    // Simulate the circuit's output (in a real implementation, this would come from Arcium):
+    // the runouts are scored over the simulated deal, from decks seeded by the hand id.
+    let hand_data = &ctx.accounts.hand_data;
+    let contending: [bool; MAX_PLAYERS] = std::array::from_fn(|seat| contenders & (1 << seat) != 0);
+    let mut hole_cards = simulate_hole_cards(hand_data.dealt_in_seats, hand_data.deal_style);
+    for seat in 0..MAX_PLAYERS {
+        if !contending[seat] {
+            hole_cards[seat] = [INVALID_CARD_INDEX; 2];
+        }
+    }
+    let community_cards = table
+        .community_cards
+        .map(|card| card.map_or(INVALID_CARD_INDEX, |card| card.to_index().get()));
+    let decks = sampled_decks(hand_data.hand_id, SIMULATED_RUNOUTS);
+    let (win_bps, tie_bps) = estimate_equity(&hole_cards, contending, community_cards, num_community, &decks);
+
+    emit!(AllInEquity {
+        table_id: table.table_id,
+        hand_id: hand_data.hand_id,
+        win_bps,
+        tie_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct ComputeAllinEquity<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        seeds = [b"hand", table.key().as_ref(), table.hand_id_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub hand_data: Account<'info, HandData>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AllInEquityRequested {
+    pub table_id: u64,
+    pub hand_id: u64,
+    /// Bitmask of the seats whose equity is being computed.
+    pub contenders: u8,
+    /// How many community cards were already dealt when the estimate was requested.
+    pub num_community: u8,
+}
+
+#[event]
+pub struct AllInEquity {
+    pub table_id: u64,
+    pub hand_id: u64,
+    /// Per-seat share of runouts won outright, in basis points.
+    pub win_bps: [u16; MAX_PLAYERS],
+    /// Per-seat share of runouts split with another player, in basis points.
+    pub tie_bps: [u16; MAX_PLAYERS],
+}
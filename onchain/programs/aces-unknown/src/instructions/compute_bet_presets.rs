@@ -0,0 +1,113 @@
+//! src/instructions/compute_bet_presets.rs
+//!
+//! @description
+//! This read-only instruction computes the quick-bet sizes for the player whose
+//! turn it is, so clients can render 1/3 pot, 1/2 pot, 3/4 pot, pot and all-in
+//! buttons without re-implementing the sizing rules. Each preset is the `amount` to
+//! pass to `PlayerAction::Bet` (when nobody has bet) or `PlayerAction::Raise` (the
+//! player's total bet for the round), and is emitted in a `BetPresets` event.
+//!
+//! @accounts
+//! - `table`: The table whose hand is in progress.
+//! - `player_seat`: The seat at `turn_position`, i.e. the acting player.
+//! - `payer`: Any signer; computing presets is permissionless.
+//!
+//! @logic
+//! 1. Verifies the hand is in its betting phase.
+//! 2. Sizes each pot fraction as a pot-sized raise would be: the call is counted
+//!    into the pot first, and the fraction of that pot is added on top of the call.
+//! 3. Raises every preset to the minimum legal bet or raise, then caps it at the
+//!    player's stack; the all-in preset is the whole stack.
+//! 4. Emits `BetPresets`.
+
+use anchor_lang::prelude::*;
+use crate::state::{GameState, HandPhase, PlayerSeat, Table};
+use crate::error::AcesUnknownErrorCode;
+
+/// The pot fractions offered as presets, as `(numerator, denominator)`, in the order
+/// they are reported: 1/3, 1/2, 3/4 and full pot.
+const POT_FRACTIONS: [(u64, u64); 4] = [(1, 3), (1, 2), (3, 4), (1, 1)];
+
+/// Instruction logic for emitting the acting player's bet-sizing presets.
+pub fn compute_bet_presets(ctx: Context<ComputeBetPresets>, _table_id: u64) -> Result<()> {
+    let table = &ctx.accounts.table;
+    let seat = &ctx.accounts.player_seat;
+
+    // --- Validation ---
+    require!(
+        table.game_state == GameState::HandInProgress,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    table.require_phase(HandPhase::Betting)?;
+    require!(
+        seat.is_active_in_hand && !seat.is_all_in,
+        AcesUnknownErrorCode::InvalidAction
+    );
+
+    let presets = bet_presets(table, seat);
+
+    emit!(BetPresets {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        seat_index: seat.seat_index,
+        third_pot: presets[0],
+        half_pot: presets[1],
+        three_quarter_pot: presets[2],
+        pot: presets[3],
+        all_in: presets[4],
+    });
+
+    Ok(())
+}
+
+/// The 1/3, 1/2, 3/4, pot and all-in bet amounts for `seat`, as round totals,
+/// clamped to the minimum legal bet or raise and to the player's stack.
+pub(crate) fn bet_presets(table: &Table, seat: &PlayerSeat) -> [u64; 5] {
+    let to_call = table.current_bet.saturating_sub(seat.bet_this_round);
+    let pot_after_call = table.pot.saturating_add(to_call);
+    let min_total = if table.current_bet == 0 {
+        table.big_blind
+    } else {
+        table.current_bet + std::cmp::max(table.last_raise_size, table.big_blind)
+    };
+    let all_in = seat.stack + seat.bet_this_round;
+
+    let mut presets = [all_in; 5];
+    for (preset, (numerator, denominator)) in presets.iter_mut().zip(POT_FRACTIONS) {
+        let raise_by = (pot_after_call as u128 * numerator as u128 / denominator as u128) as u64;
+        let target = table.current_bet.saturating_add(raise_by);
+        *preset = std::cmp::min(std::cmp::max(target, min_total), all_in);
+    }
+    presets
+}
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct ComputeBetPresets<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+    /// The seat of the player whose turn it is.
+    #[account(
+        seeds = [b"player_seat", table.key().as_ref(), table.turn_position.to_le_bytes().as_ref()],
+        bump = player_seat.bump,
+    )]
+    pub player_seat: Account<'info, PlayerSeat>,
+    pub payer: Signer<'info>,
+}
+
+/// Quick-bet amounts for the acting player. Each is the `amount` for a `Bet` when
+/// there is no bet this round, otherwise for a `Raise` (the round total).
+#[event]
+pub struct BetPresets {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    pub third_pot: u64,
+    pub half_pot: u64,
+    pub three_quarter_pot: u64,
+    pub pot: u64,
+    pub all_in: u64,
+}
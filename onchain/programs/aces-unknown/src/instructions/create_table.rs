@@ -3,8 +3,8 @@
 //! @description
 //! This instruction allows a player to create a new poker table. It initializes a `Table`
 //! account with the specified parameters (blinds, token type) and a dedicated SPL token
-//! vault to hold all chips for that table. The creator is automatically seated and
-//! their initial buy-in is transferred to the vault.
+//! vault to hold all chips for that table. The creator is automatically seated at the
+//! seat of their choice and their initial buy-in is transferred to the vault.
 //!
 //! @accounts
 //! - `table`: The new `Table` account, initialized via PDA.
@@ -12,36 +12,87 @@
 //! - `token_mint`: The SPL token mint to be used for this table's currency.
 //! - `creator_token_account`: The creator's token account from which the buy-in is paid.
 //! - `table_vault`: A new token account (PDA) that will hold all player chips for this table.
+//! - `creator_seat`: The creator's new `PlayerSeat` account at `seat_index`.
+//! - `creator_rakeback`: The creator's `PlayerRakeback` account for the table's token.
+//! - `creator_limits`: The creator's `PlayerLimits` account, created if needed.
+//! - `creator_exclusion`: The creator's `PlayerExclusion` account, created if needed.
+//! - `creator_active_tables`: The creator's `PlayerActiveTables` account, created if needed.
 //!
 //! @logic
-//! 1. Validates that the big blind is greater than the small blind.
-//! 2. Validates that the initial buy-in meets a minimum requirement (e.g., 20 big blinds).
-//! 3. Initializes the `Table` account with game parameters.
+//! 1. Validates that the big blind is at least twice the small blind, that the chosen
+//!    seat exists, and, when the platform whitelists mints, that the table's token
+//!    mint is on the list.
+//! 2. Rejects a creator who has excluded themselves from play.
+//!    Validates that the initial buy-in meets a minimum requirement (e.g., 20 big blinds)
+//!    and records it against the creator's buy-in limit for the period. The creator's
+//!    seat counts against their cap on concurrent tables.
+//! 3. Initializes the `Table` account with game parameters, its optional name and
+//!    description hash, and the optional referrer who shares in its rake.
 //! 4. Initializes the `table_vault` token account, with the table PDA as its authority.
 //! 5. Transfers the `buy_in` amount from the creator's token account to the `table_vault`.
-//! 6. Creates a `PlayerInfo` struct for the creator and adds them to the first seat.
+//! 6. Creates a `PlayerSeat` account for the creator at the chosen seat. The dealer
+//!    button is drawn among the seated players when the first hand starts.
 //! 7. Sets the game state to `WaitingForPlayers`.
+//! 8. Verifies the seat bitmask and player count agree.
+//! 9. Counts the table in the platform's `PlatformStats`.
 
 use anchor_lang::prelude::*;
 use anchor_spl::{
     token::{self, Mint, Token, TokenAccount, Transfer},
 };
-use crate::state::{BettingRound, GameState, Table, PlatformConfig};
+use crate::state::{BettingRound, GameState, HandPhase, Table, TableSettings, PlatformConfig, PlayerSeat, PlayerRakeback, PlayerLimits, PlayerExclusion, PlayerActiveTables};
+use crate::state::constants::{MAX_PLAYERS, RECENT_HAND_NONCES};
 use crate::error::AcesUnknownErrorCode;
+use crate::utils::{record_table_created, require_seat_accounting_consistent};
 
 /// The instruction logic for creating a new poker table.
 pub fn create_table(
     ctx: Context<CreateTable>,
     table_id: u64,
+    seat_index: u8,
     small_blind: u64,
     big_blind: u64,
     buy_in: u64,
+    name: [u8; 32],
+    description_hash: [u8; 32],
+    referrer: Option<Pubkey>,
 ) -> Result<()> {
     // --- Validation ---
+    require!(
+        seat_index < MAX_PLAYERS as u8,
+        AcesUnknownErrorCode::InvalidSeatIndex
+    );
     require!(big_blind > small_blind, AcesUnknownErrorCode::InvalidStakes);
+    // Standard structures have a big blind of at least twice the small blind, which
+    // the min-raise logic (defaulting to one big blind) relies on.
+    require!(
+        small_blind
+            .checked_mul(2)
+            .map_or(false, |min_big_blind| big_blind >= min_big_blind),
+        AcesUnknownErrorCode::InvalidStakes
+    );
+    // The platform may restrict tables to approved currencies.
+    require!(
+        ctx.accounts.platform_config.is_mint_allowed(&ctx.accounts.token_mint.key()),
+        AcesUnknownErrorCode::InvalidTokenMint
+    );
     // A common rule is a minimum buy-in of 20 big blinds.
     require!(buy_in >= big_blind * 20, AcesUnknownErrorCode::InsufficientBuyIn);
 
+    let creator_key = ctx.accounts.creator.key();
+    let now = Clock::get()?.unix_timestamp;
+    let exclusion = &mut ctx.accounts.creator_exclusion;
+    exclusion.initialize_if_new(creator_key, ctx.bumps.creator_exclusion);
+    exclusion.require_not_excluded(now)?;
+
+    let limits = &mut ctx.accounts.creator_limits;
+    limits.initialize_if_new(creator_key, ctx.bumps.creator_limits);
+    limits.record_buy_in(buy_in, now)?;
+
+    let active_tables = &mut ctx.accounts.creator_active_tables;
+    active_tables.initialize_if_new(creator_key, ctx.bumps.creator_active_tables);
+    active_tables.record_join()?;
+
     // --- Token Transfer ---
     let cpi_accounts = Transfer {
         from: ctx.accounts.creator_token_account.to_account_info(),
@@ -59,25 +110,57 @@ pub fn create_table(
     table.admin = ctx.accounts.platform_config.admin;
     table.game_state = GameState::WaitingForPlayers;
     table.betting_round = BettingRound::PreFlop; // Default state
+    table.hand_phase = HandPhase::Complete;
     table.small_blind = small_blind;
     table.big_blind = big_blind;
     table.token_mint = ctx.accounts.token_mint.key();
     table.turn_duration_seconds = 30; // Default turn duration
+    table.settings = TableSettings::default();
+    table.last_activity_at = now;
+    table.name = name;
+    table.description_hash = description_hash;
+    table.referrer = referrer;
 
-    // Initialize empty seats
-    table.occupied_seats = 0; // No seats occupied initially
-    
-    table.player_count = 1;
-    table.dealer_position = 0; // Creator starts as the dealer
-    table.turn_position = 0;
+    // --- Seat the Creator ---
+    let creator_seat = &mut ctx.accounts.creator_seat;
+    creator_seat.table_pubkey = table.key();
+    creator_seat.seat_index = seat_index;
+    creator_seat.player_pubkey = ctx.accounts.creator.key();
+    creator_seat.stack = buy_in;
+    creator_seat.is_active_in_hand = false;
+    creator_seat.is_all_in = false;
+    creator_seat.bet_this_round = 0;
+    creator_seat.total_bet_this_hand = 0;
+    creator_seat.pending_action = None;
+    creator_seat.waiting_for_big_blind = false;
+    creator_seat.entry_blind_pending = false;
+    creator_seat.is_sitting_out = false;
+    creator_seat.sit_out_next_blind = false;
+    creator_seat.finishing_position = None;
+    creator_seat.shown_card = None;
+    creator_seat.recent_hand_nonces = [0; RECENT_HAND_NONCES];
+    creator_seat.showdown_revealed_at = 0;
+    creator_seat.bump = ctx.bumps.creator_seat;
+
+    ctx.accounts.creator_rakeback.initialize_if_new(
+        ctx.accounts.creator.key(),
+        ctx.accounts.token_mint.key(),
+        ctx.bumps.creator_rakeback,
+    );
 
-    msg!("Table #{} created by {}", table_id, table.creator);
+    table.occupy_seat(seat_index, creator_key);
+    // No button yet: `start_hand` draws it among the seated players on the first hand.
+    table.first_hand_dealt = false;
+    require_seat_accounting_consistent(table)?;
+    record_table_created(&mut ctx.accounts.platform_config);
+
+    msg!("Table #{} created by {} at seat {}", table_id, table.creator, seat_index);
     Ok(())
 }
 
 /// The context struct for the `create_table` instruction.
 #[derive(Accounts)]
-#[instruction(table_id: u64)]
+#[instruction(table_id: u64, seat_index: u8)]
 pub struct CreateTable<'info> {
     /// The new table account being created.
     /// It's a PDA seeded with "table" and the `table_id`.
@@ -94,7 +177,8 @@ pub struct CreateTable<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
-    /// The global platform configuration account.
+    /// The global platform configuration account, which counts the new table.
+    #[account(mut)]
     pub platform_config: Account<'info, PlatformConfig>,
     
     /// The SPL token mint for the table's currency.
@@ -119,6 +203,56 @@ pub struct CreateTable<'info> {
     )]
     pub table_vault: Account<'info, TokenAccount>,
 
+    /// The creator's seat account, created at the seat they chose.
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + PlayerSeat::INIT_SPACE,
+        seeds = [b"player_seat", table.key().as_ref(), seat_index.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub creator_seat: Account<'info, PlayerSeat>,
+
+    /// The creator's rake-back account for the table's token.
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + PlayerRakeback::INIT_SPACE,
+        seeds = [b"player_rakeback", creator.key().as_ref(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub creator_rakeback: Account<'info, PlayerRakeback>,
+
+    /// The creator's buy-in limits.
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + PlayerLimits::INIT_SPACE,
+        seeds = [b"player_limits", creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_limits: Account<'info, PlayerLimits>,
+
+    /// The creator's self-exclusion.
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + PlayerExclusion::INIT_SPACE,
+        seeds = [b"player_exclusion", creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_exclusion: Account<'info, PlayerExclusion>,
+
+    /// The creator's count of tables they are seated at.
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + PlayerActiveTables::INIT_SPACE,
+        seeds = [b"player_active_tables", creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_active_tables: Account<'info, PlayerActiveTables>,
+
     // System programs
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
@@ -9,68 +9,108 @@
 //! - `table`: The poker table account containing public game state.
 //! - `hand_data`: The account with the encrypted deck for the current hand.
 //! - `payer`: The player initiating the transaction. Any active player can do this.
+//! - `remaining_accounts`: The occupied `PlayerSeat` accounts, whose per-round
+//!   betting state (and any pending actions) is reset for the new street.
 //! - Arcium-related accounts for the `reveal_community_cards` computation.
 //!
 //! @logic
-//! 1. Validates the game state (`HandInProgress`).
-//! 2. Determines how many cards to reveal based on the current betting round.
+//! 1. Validates that at least two players are still in the hand (otherwise it is won
+//!    uncontested), the game state (`HandInProgress`), and that the street's betting is
+//!    closed (`HandPhase::AwaitingReveal`). A hand flagged for an all-in run-out is dealt
+//!    with `reveal_remaining_board` instead. A street is dealt once: a call while its
+//!    reveal is still pending (`HandData::pending_reveal`), or once its betting has
+//!    opened, fails with `InvalidBettingRoundTransition`.
+//! 2. Determines how many cards to reveal based on the current betting round, and
+//!    where they start: after the `cards_dealt` hole cards and any earlier streets.
 //! 3. Calculates the offset and length of the encrypted deck within the `HandData`
 //!    account to pass it to Arcium by reference (`Argument::Account`).
 //! 4. Queues the `reveal_community_cards` computation on Arcium.
 //! 5. The `deal_community_cards_callback` receives the now-public card indices and
-//!    the updated encrypted deck state. It updates both the `Table` (with public cards)
-//!    and `HandData` (with the new encrypted deck) accounts.
+//!    the updated encrypted deck state, and clears `pending_reveal`. It updates both the
+//!    `Table` (with public cards) and `HandData` (with the new encrypted deck) accounts. The circuit's count of real
+//!    cards must equal the number requested, and no revealed card may already be on the
+//!    board; otherwise the deck is out of sync and the hand is voided with every
+//!    contribution refunded (`HandVoided`). New cards are appended to the first empty board
+//!    slots via `append_community_cards`, and the new street's betting opens (`HandPhase::Betting`).
+//! 6. If the computation itself fails, the callback counts the failure in
+//!    `HandData::reveal_retry_count` and the reveal can be queued again, up to
+//!    `PlatformConfig::max_reveal_retries` times; the next failure voids the hand and
+//!    refunds it (`RevealRetryExhausted`, then `HandVoided`).
 
 use anchor_lang::prelude::*;
 use anchor_lang::Discriminator;
-use crate::state::{Table, HandData, GameState, BettingRound, Card};
+use crate::state::{revealed_to_cards, Table, HandData, GameState, HandPhase, BettingRound, Card, PlayerSeat, DECK_SIZE, INVALID_CARD_INDEX};
 use crate::error::AcesUnknownErrorCode;
+use crate::utils::{load_table_seats, next_actor_after, persist_seats, require_all_occupied_seats, require_contested_hand, void_hand_and_refund, VoidReason};
 
 
+/// The number of card slots in the `reveal_community_cards` output (a full flop).
+pub(crate) const MAX_REVEAL: usize = 3;
+
 /// Instruction logic for dealing community cards.
-pub fn deal_community_cards(ctx: Context<DealCommunityCards>, _table_id: u64) -> Result<()> {
+/// The occupied `PlayerSeat` accounts must be passed as writable `remaining_accounts`.
+pub fn deal_community_cards<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DealCommunityCards<'info>>,
+    _table_id: u64,
+) -> Result<()> {
     let table = &mut ctx.accounts.table;
+    let mut seats = load_table_seats(&table.key(), ctx.remaining_accounts)?;
+    require_all_occupied_seats(table, seats.iter().map(|seat| seat.seat_index))?;
 
     // --- Validation ---
+    require_contested_hand(&seats)?;
     require!(
         table.game_state == GameState::HandInProgress,
         AcesUnknownErrorCode::InvalidGameState
     );
-    // Check that the current betting round is actually complete
-    // This means all active players have either called, folded, or gone all-in
-    let betting_round_complete = true;
-    // Note: Player seat data is now stored in separate PlayerSeat accounts
-    // In a real implementation, we would need to check each PlayerSeat account
-    // to verify the betting round is complete
-    require!(betting_round_complete, AcesUnknownErrorCode::InvalidGameState);
-
-    let (num_cards_to_reveal, deck_top_card_idx) = match table.betting_round {
-        BettingRound::PreFlop => (3, 0), // Flop (3 cards), top card index is after hole cards
-        BettingRound::Flop => (1, 3),    // Turn (1 card)
-        BettingRound::River => (1, 4),   // River (1 card)
+    // An all-in run-out reveals every remaining street at once with `reveal_remaining_board`.
+    require!(!table.ready_for_runout, AcesUnknownErrorCode::InvalidGameState);
+    // A double-submitted call must not advance the board twice: the street's reveal may
+    // still be in flight, or it may have landed and opened the street's betting.
+    require!(
+        !ctx.accounts.hand_data.pending_reveal && !street_already_dealt(table),
+        AcesUnknownErrorCode::InvalidBettingRoundTransition
+    );
+    // The street's betting must be closed before the next cards are revealed.
+    table.require_phase(HandPhase::AwaitingReveal)?;
+
+    let board_cards = match table.betting_round {
+        BettingRound::PreFlop => 0, // Flop (3 cards)
+        BettingRound::Flop => 3,    // Turn (1 card)
+        BettingRound::Turn => 4,    // River (1 card)
         _ => return err!(AcesUnknownErrorCode::InvalidAction),
     };
+    // The board must hold exactly the earlier streets, so the turn and river land in
+    // their own slots (3 and 4) rather than wherever the first gap happens to be.
+    require!(
+        table.community_cards.iter().take_while(|card| card.is_some()).count() == board_cards
+            && table.community_cards[board_cards..].iter().all(|card| card.is_none()),
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    let (num_cards_to_reveal, deck_top_card_idx) =
+        next_street(ctx.accounts.hand_data.cards_dealt as usize, board_cards)
+            .ok_or(AcesUnknownErrorCode::InvalidAction)?;
 
     // TODO: Add Arcium computation queuing once Arcium integration is properly set up
-
-    // For now, simulate revealing community cards
-    let mut community_card_idx = 0;
-    while community_card_idx < 5 && table.community_cards[community_card_idx].is_some() {
-        community_card_idx += 1;
-    }
+    ctx.accounts.hand_data.pending_reveal = true;
 
     // Simulate revealing cards (in a real implementation, this would come from Arcium)
-    for i in 0..num_cards_to_reveal {
-        if community_card_idx < 5 {
-            // Use deterministic card generation for testing
-            let card_index = (deck_top_card_idx + i) as u8;
-            table.community_cards[community_card_idx] = Some(Card {
-                rank: card_index % 13,
-                suit: card_index / 13,
-            });
-            community_card_idx += 1;
+    // in the same shape as the circuit's output: padded indices plus a real-card count.
+    let (revealed_indices, revealed_count) = simulate_street_reveal(deck_top_card_idx, num_cards_to_reveal);
+    // The simulated reveal lands at once, as the callback would.
+    ctx.accounts.hand_data.pending_reveal = false;
+    let revealed = match reconcile_revealed_cards(
+        table,
+        &revealed_indices,
+        revealed_count,
+        num_cards_to_reveal as u8,
+    ) {
+        Ok(cards) => cards,
+        Err(reason) => {
+            return void_hand_and_refund(table, &mut seats, reason, Clock::get()?.unix_timestamp);
         }
-    }
+    };
+    append_community_cards(table, &revealed)?;
 
     // Advance betting round
     table.betting_round = match table.betting_round {
@@ -82,15 +122,21 @@ pub fn deal_community_cards(ctx: Context<DealCommunityCards>, _table_id: u64) ->
 
     // Reset round-based betting info and set turn to first active player after dealer
     table.current_bet = 0;
+    table.last_raise_size = table.big_blind;
+    table.acted_since_full_raise = 0;
+    for seat in seats.iter_mut() {
+        seat.reset_for_new_round();
+    }
+    persist_seats(&seats)?;
 
     // Set turn to first active player after dealer
-    let mut next_player_pos = (table.dealer_position + 1) % crate::state::constants::MAX_PLAYERS as u8;
-    while (table.occupied_seats & (1 << next_player_pos)) == 0 {
-        next_player_pos = (next_player_pos + 1) % crate::state::constants::MAX_PLAYERS as u8;
-    }
+    let seat_refs: Vec<&PlayerSeat> = seats.iter().map(|seat| &**seat).collect();
+    let next_player_pos = next_actor_after(table.dealer_position, &seat_refs)
+        .unwrap_or(table.dealer_position);
     table.turn_position = next_player_pos;
     table.last_aggressor_position = next_player_pos; // Initialize for new betting round
-    table.turn_started_at = Clock::get()?.unix_timestamp;
+    table.start_turn_timer(Clock::get()?.unix_timestamp);
+    table.hand_phase = HandPhase::Betting;
 
     emit!(CommunityCardsDealt {
         table_id: table.table_id,
@@ -101,9 +147,157 @@ pub fn deal_community_cards(ctx: Context<DealCommunityCards>, _table_id: u64) ->
     Ok(())
 }
 
+/// Whether the current street's cards are already on the board: its betting has opened
+/// after a reveal. Preflop betting opens with the deal, so it never counts.
+fn street_already_dealt(table: &Table) -> bool {
+    table.hand_phase == HandPhase::Betting && table.betting_round != BettingRound::PreFlop
+}
+
+/// Records a `reveal_community_cards` computation that failed, so the street's reveal
+/// can be queued again. Once more reveals have failed than `max_reveal_retries` allows,
+/// the hand is voided instead: every contribution is refunded and `RevealRetryExhausted`
+/// is emitted. `seat_accounts` are the table's occupied `PlayerSeat` accounts.
+/// Only the Arcium callback may report a failure, from its `ComputationOutputs`.
+#[allow(dead_code)] // Until the Arcium `reveal_community_cards_callback` is enabled in lib.rs.
+pub(crate) fn record_failed_reveal<'info>(
+    table: &mut Account<'info, Table>,
+    hand_data: &mut HandData,
+    max_reveal_retries: u8,
+    seat_accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    if !hand_data.record_failed_reveal(max_reveal_retries) {
+        msg!(
+            "Reveal failed for hand #{}: retry {} of {}",
+            hand_data.hand_id,
+            hand_data.reveal_retry_count,
+            max_reveal_retries
+        );
+        return Ok(());
+    }
+
+    let mut seats = load_table_seats(&table.key(), seat_accounts)?;
+    require_all_occupied_seats(table, seats.iter().map(|seat| seat.seat_index))?;
+    emit!(RevealRetryExhausted {
+        table_id: table.table_id,
+        hand_id: hand_data.hand_id,
+        failed_reveals: hand_data.reveal_retry_count,
+    });
+    void_hand_and_refund(table, &mut seats, VoidReason::RevealRetriesExhausted, Clock::get()?.unix_timestamp)
+}
+
+/// Locates the next street in the deck: `(cards to reveal, deck index of its burn card)`,
+/// given the `cards_dealt` hole cards and the `board_cards` already on the board.
+/// Each street burns one card and then reveals, so the deck cursor starts after the
+/// hole cards however many players were dealt in, whatever the `DealStyle`: both use
+/// exactly the first `cards_dealt` positions. `None` once the board is complete.
+pub(crate) fn next_street(cards_dealt: usize, board_cards: usize) -> Option<(usize, usize)> {
+    match board_cards {
+        0 => Some((3, cards_dealt)),     // Flop
+        3 => Some((1, cards_dealt + 4)), // Turn, after the flop's burn + 3
+        4 => Some((1, cards_dealt + 6)), // River, after the turn's burn + 1
+        _ => None,
+    }
+}
+
+/// Simulates the `reveal_community_cards` circuit's output until the Arcium integration
+/// is set up: the `num_cards` deck positions after the burn card at `deck_top_card_idx`,
+/// padded, plus a real-card count.
+pub(crate) fn simulate_street_reveal(deck_top_card_idx: usize, num_cards: usize) -> ([u8; MAX_REVEAL], u8) {
+    let mut revealed_indices = [INVALID_CARD_INDEX; MAX_REVEAL];
+    let mut revealed_count = 0u8;
+    for i in 0..num_cards.min(MAX_REVEAL) {
+        // Use deterministic card generation for testing, skipping the burn card
+        let deck_idx = deck_top_card_idx + 1 + i;
+        if deck_idx < DECK_SIZE as usize {
+            revealed_indices[i] = deck_idx as u8;
+            revealed_count += 1;
+        }
+    }
+    (revealed_indices, revealed_count)
+}
+
+/// Simulates the `rabbit_hunt` and `reveal_remaining_board` circuits' output until the
+/// Arcium integration is set up: the deck positions of the board cards still to come,
+/// in deal order from the burn card at `deck_top_card_idx`, padded, plus a real-card count.
+pub(crate) fn simulate_remaining_board(deck_top_card_idx: usize, num_community: usize) -> ([u8; 5], u8) {
+    let mut indices = [INVALID_CARD_INDEX; 5];
+    let mut count = 0u8;
+    let mut cursor = deck_top_card_idx;
+    for slot in num_community..indices.len() {
+        // A burn card precedes the flop, the turn, and the river.
+        if matches!(slot, 0 | 3 | 4) {
+            cursor += 1;
+        }
+        // Use deterministic card generation for testing
+        if cursor < DECK_SIZE as usize {
+            indices[count as usize] = cursor as u8;
+            count += 1;
+        }
+        cursor += 1;
+    }
+    (indices, count)
+}
+
+/// Converts the `reveal_community_cards` output into public cards. The circuit reports
+/// how many real cards it produced; fewer than `requested` means the deck cursor and
+/// the encrypted deck are out of sync, so the street is rejected instead of dealt short.
+/// Only the first `requested` slots are read: on the turn and river the output holds one
+/// real card followed by `INVALID_CARD_INDEX` padding, which never reaches the board.
+pub(crate) fn parse_revealed_cards(
+    revealed_indices: &[u8],
+    revealed_count: u8,
+    requested: u8,
+) -> Result<Vec<Card>> {
+    require!(
+        revealed_count == requested && requested as usize <= revealed_indices.len(),
+        AcesUnknownErrorCode::DeckIntegrityViolation
+    );
+    revealed_to_cards(&revealed_indices[..requested as usize])
+        .into_iter()
+        .map(|card| card.ok_or_else(|| AcesUnknownErrorCode::InvalidCardIndex.into()))
+        .collect()
+}
 
+/// Checks a reveal before it is dealt: the circuit output must parse into exactly the
+/// requested cards (`parse_revealed_cards`), none of which may already be on the board
+/// or appear twice. A failure means the deck can no longer be trusted, and the reason
+/// is returned so the hand can be voided rather than left stuck.
+pub(crate) fn reconcile_revealed_cards(
+    table: &Table,
+    revealed_indices: &[u8],
+    revealed_count: u8,
+    requested: u8,
+) -> std::result::Result<Vec<Card>, VoidReason> {
+    let revealed = parse_revealed_cards(revealed_indices, revealed_count, requested)
+        .map_err(|_| VoidReason::DeckOutOfSync)?;
+    let mut seen: Vec<Card> = table.community_cards.iter().flatten().copied().collect();
+    for card in revealed.iter() {
+        if seen.contains(card) {
+            return Err(VoidReason::DuplicateCard);
+        }
+        seen.push(*card);
+    }
+    Ok(revealed)
+}
 
+/// Writes `new_cards` into the next empty community card slots, in order.
+/// Revealed cards are never overwritten: fails if the board lacks room for all of them.
+pub(crate) fn append_community_cards(table: &mut Table, new_cards: &[Card]) -> Result<()> {
+    let first_empty = table
+        .community_cards
+        .iter()
+        .position(|card| card.is_none())
+        .unwrap_or(table.community_cards.len());
+    require!(
+        first_empty + new_cards.len() <= table.community_cards.len(),
+        AcesUnknownErrorCode::TooManyCommunityCards
+    );
 
+    for (slot, card) in table.community_cards[first_empty..].iter_mut().zip(new_cards) {
+        *slot = Some(*card);
+    }
+    Ok(())
+}
 
 #[derive(Accounts)]
 #[instruction(table_id: u64)]
@@ -134,7 +328,81 @@ pub struct CommunityCardsDealt {
     pub cards: [Option<Card>; 5],
 }
 
+/// A hand's community-card reveal failed more times than the platform allows retries;
+/// the hand is voided and refunded (`HandVoided`).
+#[event]
+pub struct RevealRetryExhausted {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub failed_reveals: u8,
+}
+
 #[event]
 pub struct HandShuffled {
     pub table_id: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::CardIndex;
+
+    fn card(index: u8) -> Card {
+        Card::from_index(CardIndex::new(index).unwrap())
+    }
+
+    fn table_with_board(board: &[u8]) -> Table {
+        let mut table = Table::deserialize(&mut &vec![0u8; Table::INIT_SPACE][..]).unwrap();
+        for (slot, index) in table.community_cards.iter_mut().zip(board) {
+            *slot = Some(card(*index));
+        }
+        table
+    }
+
+    #[test]
+    fn a_reveal_count_that_differs_from_the_request_breaks_deck_integrity() {
+        let res = parse_revealed_cards(&[5, 6, 7], 2, 3);
+        assert_eq!(res.unwrap_err(), AcesUnknownErrorCode::DeckIntegrityViolation.into());
+
+        let res = parse_revealed_cards(&[5, 6, 7], 3, 2);
+        assert_eq!(res.unwrap_err(), AcesUnknownErrorCode::DeckIntegrityViolation.into());
+
+        // A count past the output's card slots cannot be read either.
+        let res = parse_revealed_cards(&[5, 6, 7], 4, 4);
+        assert_eq!(res.unwrap_err(), AcesUnknownErrorCode::DeckIntegrityViolation.into());
+
+        assert_eq!(parse_revealed_cards(&[5, 6, 7], 3, 3).unwrap(), vec![card(5), card(6), card(7)]);
+    }
+
+    #[test]
+    fn a_clean_reveal_is_dealt_in_order() {
+        let table = table_with_board(&[5, 6, 7]);
+        let revealed = reconcile_revealed_cards(&table, &[9, INVALID_CARD_INDEX, INVALID_CARD_INDEX], 1, 1);
+        assert_eq!(revealed, Ok(vec![card(9)]));
+    }
+
+    #[test]
+    fn a_short_reveal_voids_the_hand_as_out_of_sync() {
+        let table = table_with_board(&[]);
+        let revealed = reconcile_revealed_cards(&table, &[1, 2, INVALID_CARD_INDEX], 2, 3);
+        assert_eq!(revealed, Err(VoidReason::DeckOutOfSync));
+
+        // A full count padded with the sentinel is just as short.
+        let revealed = reconcile_revealed_cards(&table, &[1, 2, INVALID_CARD_INDEX], 3, 3);
+        assert_eq!(revealed, Err(VoidReason::DeckOutOfSync));
+    }
+
+    #[test]
+    fn a_card_already_on_the_board_voids_the_hand_as_a_duplicate() {
+        let table = table_with_board(&[5, 6, 7]);
+        let revealed = reconcile_revealed_cards(&table, &[6, INVALID_CARD_INDEX, INVALID_CARD_INDEX], 1, 1);
+        assert_eq!(revealed, Err(VoidReason::DuplicateCard));
+    }
+
+    #[test]
+    fn a_card_revealed_twice_voids_the_hand_as_a_duplicate() {
+        let table = table_with_board(&[]);
+        let revealed = reconcile_revealed_cards(&table, &[8, 9, 8], 3, 3);
+        assert_eq!(revealed, Err(VoidReason::DuplicateCard));
+    }
+}
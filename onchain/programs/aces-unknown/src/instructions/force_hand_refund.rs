@@ -9,48 +9,73 @@
 //! @accounts
 //! - `table`: The table account that is stuck.
 //! - `payer`: The signer calling the instruction (can be any player at the table).
+//! - `platform_config`: Used to get the keeper reward.
+//! - `table_vault`: The table's token vault, from which the keeper reward is paid.
+//! - `keeper_token_account`: The caller's token account for the table's currency.
+//! - `remaining_accounts`: The writable `PlayerSeat` accounts for every occupied seat.
 //!
 //! @logic
-//! 1. Defines a `STUCK_HAND_TIMEOUT_SECONDS` constant.
+//! 1. Reads the table's stuck-hand timeout (`TableSettings::refund_timeout`), which
+//!    defaults to `DEFAULT_REFUND_TIMEOUT_SECONDS`.
 //! 2. Checks if the time since the last action (`turn_started_at`) exceeds this timeout.
-//! 3. If the hand is confirmed to be stuck, it iterates through all seated players.
+//! 3. If the hand is confirmed to be stuck, the keeper reward
+//!    (`PlatformConfig::keeper_reward_bps`) is taken out of the pot, each player paying
+//!    in proportion to their contribution, and paid to the caller (`KeeperRewarded`).
+//!    It then iterates through all seated players.
 //! 4. For each player, it adds their `total_bet_this_hand` back to their `stack`.
 //! 5. It resets the table's state to `HandComplete`, clearing pot info and resetting
 //!    player hand states, effectively voiding the hand.
 //! 6. This prevents player funds from being permanently locked in the pot.
+//! 7. Emits a `HandRefunded` event with the total amount returned.
 
 use anchor_lang::prelude::*;
-use crate::state::Table;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GameState, PlatformConfig, Table};
 use crate::error::AcesUnknownErrorCode;
-
-/// A long duration timeout to determine if a hand is unrecoverably stuck.
-const STUCK_HAND_TIMEOUT_SECONDS: i64 = 300; // 5 minutes
+use crate::utils::{charge_keeper_reward_pro_rata, keeper_reward, load_table_seats, pay_keeper_reward, persist_seats, refund_hand_contributions, require_all_occupied_seats};
 
 /// Instruction logic to refund a stuck hand.
-pub fn force_hand_refund(ctx: Context<ForceHandRefund>, _table_id: u64) -> Result<()> {
+pub fn force_hand_refund<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ForceHandRefund<'info>>,
+    _table_id: u64,
+) -> Result<()> {
     let table = &mut ctx.accounts.table;
 
     // --- Validation ---
+    require!(
+        table.game_state == GameState::HandInProgress,
+        AcesUnknownErrorCode::InvalidGameState
+    );
     let now = Clock::get()?.unix_timestamp;
     require!(
-        now <= table.turn_started_at + STUCK_HAND_TIMEOUT_SECONDS,
+        now > table.turn_started_at + table.settings.refund_timeout(),
         AcesUnknownErrorCode::HandNotStuck
     );
-    
-    // --- Refund Logic ---
-    // Note: Player data is now in separate PlayerSeat accounts
-    // In a real implementation, we would need to iterate through all PlayerSeat accounts
-    // and refund each player's bets for this hand
-    let total_refunded = 0; // Placeholder
 
-    // --- Reset Table State ---
-    require!(table.pot == total_refunded, AcesUnknownErrorCode::InvalidAction);
-    table.pot = 0;
-    table.current_bet = 0;
-    table.game_state = crate::state::GameState::HandComplete;
-    table.betting_round = crate::state::BettingRound::PreFlop; // Reset to default
+    let mut seats = load_table_seats(&table.key(), ctx.remaining_accounts)?;
+    require_all_occupied_seats(table, seats.iter().map(|seat| seat.seat_index))?;
     
-    msg!("Hand was stuck. Total pot of {} refunded to players.", total_refunded);
+    // --- Keeper Reward ---
+    let reward = keeper_reward(&ctx.accounts.platform_config, table.big_blind);
+    let reward = charge_keeper_reward_pro_rata(table, &mut seats, reward);
+    pay_keeper_reward(
+        table,
+        ctx.bumps.table,
+        &ctx.accounts.table_vault,
+        &ctx.accounts.keeper_token_account,
+        &ctx.accounts.token_program,
+        reward,
+    )?;
+
+    // --- Refund Logic & Reset Table State ---
+    let total_refunded = refund_hand_contributions(table, &mut seats, now)?;
+    persist_seats(&seats)?;
+
+    emit!(HandRefunded {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        total_refunded,
+    });
 
     Ok(())
 }
@@ -66,4 +91,27 @@ pub struct ForceHandRefund<'info> {
     pub table: Account<'info, Table>,
     #[account(mut)]
     pub payer: Signer<'info>,
-}
\ No newline at end of file
+    #[account(seeds = [b"platform_config"], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [b"vault", table.key().as_ref()],
+        bump,
+    )]
+    pub table_vault: Account<'info, TokenAccount>,
+    /// The keeper's token account, receiving the keeper reward.
+    #[account(
+        mut,
+        constraint = keeper_token_account.mint == table.token_mint @ AcesUnknownErrorCode::InvalidTokenMint,
+        constraint = keeper_token_account.owner == payer.key() @ AcesUnknownErrorCode::Unauthorized,
+    )]
+    pub keeper_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct HandRefunded {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub total_refunded: u64,
+}
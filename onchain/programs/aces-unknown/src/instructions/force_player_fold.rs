@@ -8,67 +8,114 @@
 //! @accounts
 //! - `table`: The table account where the player has timed out.
 //! - `payer`: The signer calling the instruction (can be anyone).
+//! - `player_seat`: The seat of the player whose turn it is.
+//! - `platform_config`: Used to get the rake parameters for a pot won uncontested, and
+//!   to count the hand once it is won.
+//! - `table_vault`: The table's token vault, from which rake is taken.
+//! - `treasury_vault`: The platform's treasury account to receive the rake.
+//! - `keeper_token_account`: The caller's token account for the table's currency, which
+//!   receives the keeper reward.
+//! - `remaining_accounts`: The table's other occupied `PlayerSeat` accounts, writable so
+//!   a player left alone in the hand can be paid.
 //!
 //! @logic
 //! 1. Fetches the current on-chain time using `Clock::get()`.
-//! 2. Compares the current time to the `turn_started_at` plus `turn_duration_seconds`
-//!    from the `Table` account.
+//! 2. Compares the current time to the `turn_deadline` from the `Table` account.
 //! 3. If the timer has expired, it marks the current player's hand as folded
-//!    (`is_active_in_hand = false`).
+//!    (`is_active_in_hand = false`). Their `total_bet_this_hand` stays in the pots,
+//!    less the keeper reward (`PlatformConfig::keeper_reward_bps`), which is paid out
+//!    of those forfeited chips to a caller other than the player, emitting
+//!    `KeeperRewarded`.
 //! 4. It then advances the turn to the next active, non-all-in player, ensuring the
-//!    game can continue.
-//! 5. If the timer has not expired, the instruction fails with a `TurnNotExpired` error.
+//!    game can continue, and emits a `PlayerFolded` event.
+//! 5. If the fold leaves a single player in the hand, that player is credited the pot
+//!    less rake (none before the flop), exactly as after a voluntary fold.
+//! 6. If the timer has not expired, the instruction fails with a `TurnNotExpired` error.
 
 use anchor_lang::prelude::*;
-use crate::state::Table;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GameState, HandPhase, PlatformConfig, PlayerAction, PlayerSeat, Table};
 use crate::error::AcesUnknownErrorCode;
-use crate::state::constants::MAX_PLAYERS;
+use crate::instructions::player_action::{apply_player_action, award_pot_to_last_player, FoldReason, PlayerFolded};
+use crate::utils::{charge_keeper_reward_to_seat, keeper_reward, load_table_seats, pay_keeper_reward, require_all_occupied_seats};
 
 /// The instruction logic for forcing a timed-out player to fold.
-pub fn force_player_fold(ctx: Context<ForcePlayerFold>, _table_id: u64) -> Result<()> {
+pub fn force_player_fold<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ForcePlayerFold<'info>>,
+    _table_id: u64,
+) -> Result<()> {
     let table = &mut ctx.accounts.table;
+    let player_seat = &mut ctx.accounts.player_seat;
     
     // --- Validation ---
+    require!(
+        table.game_state == GameState::HandInProgress,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    table.require_phase(HandPhase::Betting)?;
     let now = Clock::get()?.unix_timestamp;
     require!(
-        now <= table.turn_started_at + table.turn_duration_seconds as i64,
+        now > table.turn_deadline,
         AcesUnknownErrorCode::TurnNotExpired
     );
+    require!(
+        player_seat.seat_index == table.turn_position,
+        AcesUnknownErrorCode::NotPlayersTurn
+    );
+
+    let mut other_seats = load_table_seats(&table.key(), ctx.remaining_accounts)?;
+    require_all_occupied_seats(
+        table,
+        other_seats
+            .iter()
+            .map(|seat| seat.seat_index)
+            .chain(std::iter::once(player_seat.seat_index)),
+    )?;
     
-    // --- Action: Fold Player ---
-    let turn_pos = table.turn_position as usize;
-    // Note: Player data is now in separate PlayerSeat accounts
-    // In a real implementation, we would need to access the PlayerSeat account
-    // to update the player's status
-    msg!("Player at seat {} was folded due to timeout.", turn_pos);
-    
-    // --- Advance Turn ---
-    // This logic is duplicated from `player_action`. It could be refactored into a helper.
-    let mut next_turn_pos = (turn_pos + 1) % MAX_PLAYERS;
-    // Note: In a real implementation, we would need to check all PlayerSeat accounts
-    // to count active players. For now, we'll use a placeholder.
-    let active_players_count = 2; // Placeholder
-    
-    // If only one active player is left, the hand is over.
-    if active_players_count <= 1 {
-        table.game_state = crate::state::GameState::HandComplete;
-        return Ok(());
-    }
+    // --- Keeper Reward ---
+    // The timed-out player pays it out of the chips they are about to forfeit. A player
+    // forcing their own fold would only be refunded, so they earn nothing.
+    let reward = if ctx.accounts.payer.key() == player_seat.player_pubkey {
+        0
+    } else {
+        let reward = keeper_reward(&ctx.accounts.platform_config, table.big_blind);
+        charge_keeper_reward_to_seat(table, player_seat, reward)
+    };
+    pay_keeper_reward(
+        table,
+        ctx.bumps.table,
+        &ctx.accounts.table_vault,
+        &ctx.accounts.keeper_token_account,
+        &ctx.accounts.token_program,
+        reward,
+    )?;
+
+    // --- Action: Fold Player and Advance Turn ---
+    // Shares the fold and turn-advancement logic with `player_action`.
+    player_seat.pending_action = None;
+    apply_player_action(table, player_seat, &other_seats, PlayerAction::Fold, now)?;
+
+    emit!(PlayerFolded {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        seat_index: player_seat.seat_index,
+        reason: FoldReason::TimedOut,
+    });
 
-    // Find next player who can act.
-    loop {
-        if (table.occupied_seats & (1 << next_turn_pos)) != 0 {
-            // Note: In a real implementation, we would need to check the PlayerSeat account
-            // to verify the player is active in the hand
-            // For now, we'll assume the player is active
-            break;
-        }
-        next_turn_pos = (next_turn_pos + 1) % MAX_PLAYERS;
+    // --- Award an Uncontested Pot ---
+    if table.game_state == GameState::HandComplete {
+        award_pot_to_last_player(
+            table,
+            ctx.bumps.table,
+            player_seat,
+            &mut other_seats,
+            &mut ctx.accounts.platform_config,
+            &ctx.accounts.table_vault,
+            &ctx.accounts.treasury_vault,
+            &ctx.accounts.token_program,
+        )?;
     }
-    
-    table.turn_position = next_turn_pos as u8;
-    table.turn_started_at = now;
-    
+
     Ok(())
 }
 
@@ -84,4 +131,32 @@ pub struct ForcePlayerFold<'info> {
     /// The payer can be anyone, acting as a "keeper" to keep the game moving.
     #[account(mut)]
     pub payer: Signer<'info>,
-}
\ No newline at end of file
+    /// The seat of the player who timed out.
+    #[account(
+        mut,
+        seeds = [b"player_seat", table.key().as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
+        bump = player_seat.bump,
+    )]
+    pub player_seat: Account<'info, PlayerSeat>,
+    #[account(mut, seeds = [b"platform_config"], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [b"vault", table.key().as_ref()],
+        bump,
+    )]
+    pub table_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = platform_config.treasury_vault,
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+    /// The keeper's token account, receiving the keeper reward.
+    #[account(
+        mut,
+        constraint = keeper_token_account.mint == table.token_mint @ AcesUnknownErrorCode::InvalidTokenMint,
+        constraint = keeper_token_account.owner == payer.key() @ AcesUnknownErrorCode::Unauthorized,
+    )]
+    pub keeper_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
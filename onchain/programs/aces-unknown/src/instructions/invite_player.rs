@@ -0,0 +1,56 @@
+//! src/instructions/invite_player.rs
+//!
+//! @description
+//! This instruction allows the creator of a table to invite a wallet, adding it to
+//! the table's access list. While the table is private (`TableSettings::is_private`),
+//! only the creator and invited wallets may join. The list can be built up before
+//! the table is made private.
+//!
+//! @accounts
+//! - `table`: The `Table` account whose access list is updated.
+//! - `creator`: The table creator, who must sign.
+//!
+//! @logic
+//! 1. Verifies the signer is the table creator.
+//! 2. Rejects a wallet that is already invited, or a full access list.
+//! 3. Adds the wallet to the access list.
+
+use anchor_lang::prelude::*;
+use crate::state::Table;
+use crate::state::constants::MAX_TABLE_INVITES;
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for inviting a wallet to a table.
+pub fn invite_player(ctx: Context<InvitePlayer>, _table_id: u64, player: Pubkey) -> Result<()> {
+    let table = &mut ctx.accounts.table;
+    require!(
+        !table.access_list.contains(&player),
+        AcesUnknownErrorCode::InvalidAction
+    );
+    require!(
+        table.access_list.len() < MAX_TABLE_INVITES,
+        AcesUnknownErrorCode::InviteListFull
+    );
+
+    table.access_list.push(player);
+
+    msg!("Table #{} invited {}", table.table_id, player);
+    Ok(())
+}
+
+/// The context struct for the `invite_player` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct InvitePlayer<'info> {
+    /// The table account whose access list is being changed.
+    #[account(
+        mut,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = table.creator == creator.key() @ AcesUnknownErrorCode::NotTableCreator,
+    )]
+    pub table: Account<'info, Table>,
+
+    /// The creator of the table.
+    pub creator: Signer<'info>,
+}
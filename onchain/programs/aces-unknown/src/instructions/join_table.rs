@@ -10,19 +10,34 @@
 //! - `player`: The signer joining the table.
 //! - `player_token_account`: The player's token account from which the buy-in is paid.
 //! - `table_vault`: The table's token vault where the buy-in is transferred.
+//! - `player_rakeback`: The player's `PlayerRakeback` account for the table's token,
+//!   created if this is the first time they sit at a table using it.
+//! - `seat_reservation`: The seat's `SeatReservation` account, created if the seat
+//!   has never been reserved.
+//! - `player_limits`: The player's `PlayerLimits` account, created if needed.
+//! - `player_exclusion`: The player's `PlayerExclusion` account, created if needed.
+//! - `player_active_tables`: The player's `PlayerActiveTables` account, created if needed.
 //!
 //! @logic
 //! 1. Checks if the table is already full (`player_count >= MAX_PLAYERS`).
-//! 2. Checks if the player is already seated at the table to prevent duplicate entries.
+//! 2. Checks if the player is already seated at the table to prevent duplicate entries,
+//!    that a private table has invited them, and that they have not excluded
+//!    themselves from play.
+//!    If another player holds a live reservation on the seat, the join is rejected.
+//!    The buy-in is recorded against the player's buy-in limit for the period, and
+//!    the seat against their cap on concurrent tables.
 //! 3. Transfers the specified `buy_in` amount from the player to the table's vault.
 //! 4. Creates a new `PlayerSeat` account for the player.
 //! 5. Increments the `player_count` on the `Table` account.
+//! 6. Verifies the seat bitmask and player count still agree.
+//! 7. Clears any reservation on the seat.
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{Table, PlayerSeat};
+use crate::state::{Table, PlayerSeat, PlayerRakeback, PlayerLimits, PlayerExclusion, PlayerActiveTables, SeatReservation};
 use crate::error::AcesUnknownErrorCode;
-use crate::state::constants::MAX_PLAYERS;
+use crate::state::constants::{MAX_PLAYERS, RECENT_HAND_NONCES};
+use crate::utils::require_seat_accounting_consistent;
 
 /// The instruction logic for a player to join a table.
 pub fn join_table(ctx: Context<JoinTable>, table_id: u64, seat_index: u8, buy_in: u64) -> Result<()> {
@@ -47,6 +62,30 @@ pub fn join_table(ctx: Context<JoinTable>, table_id: u64, seat_index: u8, buy_in
     );
 
     let player_key = ctx.accounts.player.key();
+    // One wallet, one seat: a second seat would let a player collude with themselves.
+    require!(
+        table.seat_of(&player_key).is_none(),
+        AcesUnknownErrorCode::AlreadySeated
+    );
+    require!(table.admits(&player_key), AcesUnknownErrorCode::NotInvited);
+
+    let now = Clock::get()?.unix_timestamp;
+    let exclusion = &mut ctx.accounts.player_exclusion;
+    exclusion.initialize_if_new(player_key, ctx.bumps.player_exclusion);
+    exclusion.require_not_excluded(now)?;
+
+    let reservation = &mut ctx.accounts.seat_reservation;
+    if let Some(holder) = reservation.holder(now) {
+        require_keys_eq!(holder, player_key, AcesUnknownErrorCode::SeatReserved);
+    }
+
+    let limits = &mut ctx.accounts.player_limits;
+    limits.initialize_if_new(player_key, ctx.bumps.player_limits);
+    limits.record_buy_in(buy_in, now)?;
+
+    let active_tables = &mut ctx.accounts.player_active_tables;
+    active_tables.initialize_if_new(player_key, ctx.bumps.player_active_tables);
+    active_tables.record_join()?;
 
     // --- Token Transfer ---
     let cpi_accounts = Transfer {
@@ -68,11 +107,33 @@ pub fn join_table(ctx: Context<JoinTable>, table_id: u64, seat_index: u8, buy_in
     player_seat.is_all_in = false;
     player_seat.bet_this_round = 0;
     player_seat.total_bet_this_hand = 0;
+    player_seat.pending_action = None;
+    // Once hands are being dealt, a newcomer waits for the big blind (or posts in).
+    player_seat.waiting_for_big_blind = table.first_hand_dealt;
+    player_seat.entry_blind_pending = false;
+    player_seat.is_sitting_out = false;
+    player_seat.sit_out_next_blind = false;
+    player_seat.finishing_position = None;
+    player_seat.shown_card = None;
+    player_seat.recent_hand_nonces = [0; RECENT_HAND_NONCES];
+    player_seat.showdown_revealed_at = 0;
     player_seat.bump = ctx.bumps.player_seat;
 
+    ctx.accounts.player_rakeback.initialize_if_new(
+        player_key,
+        table.token_mint,
+        ctx.bumps.player_rakeback,
+    );
+
+    reservation.table_pubkey = table.key();
+    reservation.seat_index = seat_index;
+    reservation.reserved_by = None;
+    reservation.reserved_until = 0;
+    reservation.bump = ctx.bumps.seat_reservation;
+
     // --- Update Table ---
-    table.occupied_seats |= 1 << seat_index;
-    table.player_count += 1;
+    table.occupy_seat(seat_index, player_key);
+    require_seat_accounting_consistent(table)?;
 
     msg!("Player {} joined Table #{} at seat {}", player_key, table_id, seat_index);
     Ok(())
@@ -120,6 +181,56 @@ pub struct JoinTable<'info> {
     )]
     pub player_seat: Account<'info, PlayerSeat>,
 
+    /// The player's rake-back account for the table's token.
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerRakeback::INIT_SPACE,
+        seeds = [b"player_rakeback", player.key().as_ref(), table.token_mint.as_ref()],
+        bump,
+    )]
+    pub player_rakeback: Account<'info, PlayerRakeback>,
+
+    /// The seat's reservation account, checked and cleared on join.
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + SeatReservation::INIT_SPACE,
+        seeds = [b"seat_reservation", table.key().as_ref(), seat_index.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub seat_reservation: Account<'info, SeatReservation>,
+
+    /// The player's buy-in limits.
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerLimits::INIT_SPACE,
+        seeds = [b"player_limits", player.key().as_ref()],
+        bump,
+    )]
+    pub player_limits: Account<'info, PlayerLimits>,
+
+    /// The player's self-exclusion.
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerExclusion::INIT_SPACE,
+        seeds = [b"player_exclusion", player.key().as_ref()],
+        bump,
+    )]
+    pub player_exclusion: Account<'info, PlayerExclusion>,
+
+    /// The player's count of tables they are seated at.
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerActiveTables::INIT_SPACE,
+        seeds = [b"player_active_tables", player.key().as_ref()],
+        bump,
+    )]
+    pub player_active_tables: Account<'info, PlayerActiveTables>,
+
     // System programs
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
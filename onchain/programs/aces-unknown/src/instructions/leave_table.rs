@@ -11,6 +11,7 @@
 //! - `player_token_account`: The player's token account to receive the cashed-out chips.
 //! - `table_vault`: The table's token vault from which the chips are transferred.
 //! - `player_seat`: The player's seat account to be closed.
+//! - `player_active_tables`: The player's `PlayerActiveTables` account.
 //!
 //! @logic
 //! 1. Verifies that the game is not currently in progress (`GameState::HandInProgress`).
@@ -18,12 +19,15 @@
 //! 3. Signs with the table's PDA seeds to authorize a transfer from the `table_vault`.
 //! 4. Transfers the player's stack from the `table_vault` back to their `player_token_account`.
 //! 5. Closes the player's PlayerSeat account and refunds rent to the player.
-//! 6. Decrements the `player_count` on the `Table` account.
+//! 6. Decrements the `player_count` on the `Table` account and the player's count of
+//!    active tables, and emits `PlayerLeft`.
+//! 7. Verifies the seat bitmask and player count still agree.
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{GameState, Table, PlayerSeat};
+use crate::state::{GameState, Table, PlayerSeat, PlayerActiveTables};
 use crate::error::AcesUnknownErrorCode;
+use crate::utils::require_seat_accounting_consistent;
 
 /// The instruction logic for a player to leave a table.
 pub fn leave_table(ctx: Context<LeaveTable>, table_id: u64) -> Result<()> {
@@ -38,22 +42,25 @@ pub fn leave_table(ctx: Context<LeaveTable>, table_id: u64) -> Result<()> {
     let player_key = ctx.accounts.player.key();
     let player_seat = &ctx.accounts.player_seat;
     
-    // Verify the player seat belongs to the correct player and table
-    require!(
-        player_seat.player_pubkey == player_key,
-        AcesUnknownErrorCode::PlayerNotFound
+    // Verify the player seat belongs to the correct table and player
+    require_keys_eq!(
+        player_seat.table_pubkey,
+        table.key(),
+        AcesUnknownErrorCode::SeatTableMismatch
     );
-    require!(
-        player_seat.table_pubkey == table.key(),
-        AcesUnknownErrorCode::PlayerNotFound
+    require_keys_eq!(
+        player_seat.player_pubkey,
+        player_key,
+        AcesUnknownErrorCode::SeatOwnerMismatch
     );
     
     let cash_out_amount = player_seat.stack;
 
     if cash_out_amount > 0 {
         // --- Token Transfer ---
-        let table_key = table.key();
-        let seeds = &[&b"vault"[..], table_key.as_ref(), &[ctx.bumps.table_vault]];
+        // The vault's authority is the table PDA, so sign with the table's seeds.
+        let table_id_bytes = table_id.to_le_bytes();
+        let seeds = &[&b"table"[..], table_id_bytes.as_ref(), &[ctx.bumps.table]];
         let signer_seeds = &[&seeds[..]];
 
         let cpi_accounts = Transfer {
@@ -67,19 +74,21 @@ pub fn leave_table(ctx: Context<LeaveTable>, table_id: u64) -> Result<()> {
     }
 
     // --- State Update ---
-    table.occupied_seats &= !(1 << player_seat.seat_index);
-    table.player_count -= 1;
+    // A tournament seat is already freed when the player busts.
+    table.vacate_seat(player_seat.seat_index);
+    require_seat_accounting_consistent(table)?;
+    ctx.accounts.player_active_tables.record_leave();
 
     // TODO: Handle dealer button and turn adjustments if the leaving player affects them.
     // This logic can be complex and depends on house rules (e.g., dead button).
     // For now, we leave it simple.
 
-    msg!(
-        "Player {} left Table #{} with {} chips.",
-        player_key,
+    emit!(PlayerLeft {
         table_id,
-        cash_out_amount
-    );
+        seat_index: player_seat.seat_index,
+        player: player_key,
+        cash_out_amount,
+    });
     Ok(())
 }
 
@@ -115,15 +124,32 @@ pub struct LeaveTable<'info> {
     )]
     pub table_vault: Account<'info, TokenAccount>,
 
-    /// The player's seat account to be closed.
+    /// The player's seat account to be closed. Derived from the table it records so that
+    /// a seat from another table is reported as `SeatTableMismatch` by the handler.
     #[account(
         mut,
         close = player,
-        seeds = [b"player_seat", table.key().as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
+        seeds = [b"player_seat", player_seat.table_pubkey.as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
         bump = player_seat.bump,
     )]
     pub player_seat: Account<'info, PlayerSeat>,
 
+    /// The player's count of tables they are seated at.
+    #[account(
+        mut,
+        seeds = [b"player_active_tables", player.key().as_ref()],
+        bump = player_active_tables.bump,
+    )]
+    pub player_active_tables: Account<'info, PlayerActiveTables>,
+
     // System programs
     pub token_program: Program<'info, Token>,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct PlayerLeft {
+    pub table_id: u64,
+    pub seat_index: u8,
+    pub player: Pubkey,
+    pub cash_out_amount: u64,
+}
@@ -12,27 +12,103 @@
 // Platform and table management instructions
 pub mod create_table;
 pub mod join_table;
+pub mod reserve_seat;
 pub mod leave_table;
+pub mod stand_up_busted_player;
 pub mod update_rake_params;
+pub mod update_table_settings;
+pub mod reset_button;
+pub mod invite_player;
+pub mod revoke_invite;
+pub mod set_table_metadata;
+pub mod update_rakeback_config;
+pub mod update_keeper_reward;
+pub mod update_reveal_retry_limit;
+pub mod add_allowed_mint;
+pub mod remove_allowed_mint;
+pub mod set_treasury_vault;
+pub mod claim_rakeback;
+pub mod set_player_limits;
+pub mod self_exclude;
+pub mod set_max_concurrent_tables;
+pub mod admin_force_close_table;
+pub mod sweep_table_vault_dust;
 
 // Hand lifecycle instructions
 pub mod start_hand;
+pub mod start_bomb_pot;
 pub mod deal_community_cards;
+pub mod reveal_remaining_board;
 pub mod resolve_showdown;
+pub mod compute_allin_equity;
+pub mod rabbit_hunt;
+pub mod show_one_card;
+pub mod reveal_hole_cards;
+pub mod close_encrypted_hands;
+pub mod verify_shuffle_commitment;
+pub mod verify_shown_hands;
 
 // Player actions and safety mechanisms
 pub mod player_action;
+pub mod post_straddle;
+pub mod post_entry_blind;
+pub mod set_pending_action;
+pub mod set_sit_out_next_blind;
+pub mod apply_pending_action;
 pub mod force_player_fold;
 pub mod force_hand_refund;
+pub mod chop_blinds;
+pub mod compute_bet_presets;
+pub mod check_players_turn;
+pub mod snapshot_table;
+pub mod check_table_activity;
 
 // Re-export all public items from the submodules.
 pub use create_table::*;
 pub use join_table::*;
+pub use reserve_seat::*;
 pub use leave_table::*;
+pub use stand_up_busted_player::*;
 pub use update_rake_params::*;
+pub use update_table_settings::*;
+pub use reset_button::*;
+pub use invite_player::*;
+pub use revoke_invite::*;
+pub use set_table_metadata::*;
+pub use update_rakeback_config::*;
+pub use update_keeper_reward::*;
+pub use update_reveal_retry_limit::*;
+pub use add_allowed_mint::*;
+pub use remove_allowed_mint::*;
+pub use set_treasury_vault::*;
+pub use claim_rakeback::*;
+pub use set_player_limits::*;
+pub use self_exclude::*;
+pub use set_max_concurrent_tables::*;
+pub use admin_force_close_table::*;
+pub use sweep_table_vault_dust::*;
 pub use start_hand::*;
+pub use start_bomb_pot::*;
 pub use deal_community_cards::*;
+pub use reveal_remaining_board::*;
 pub use resolve_showdown::*;
+pub use compute_allin_equity::*;
+pub use rabbit_hunt::*;
+pub use show_one_card::*;
+pub use reveal_hole_cards::*;
+pub use close_encrypted_hands::*;
+pub use verify_shuffle_commitment::*;
+pub use verify_shown_hands::*;
 pub use player_action::*;
+pub use post_straddle::*;
+pub use post_entry_blind::*;
+pub use set_pending_action::*;
+pub use set_sit_out_next_blind::*;
+pub use apply_pending_action::*;
 pub use force_player_fold::*;
-pub use force_hand_refund::*;
\ No newline at end of file
+pub use force_hand_refund::*;
+pub use chop_blinds::*;
+pub use compute_bet_presets::*;
+pub use check_players_turn::*;
+pub use snapshot_table::*;
+pub use check_table_activity::*;
\ No newline at end of file
@@ -8,29 +8,62 @@
 //! @accounts
 //! - `table`: The poker table account where the action is taking place.
 //! - `player`: The signer performing the action.
+//! - `player_seat`: The acting player's seat.
+//! - `platform_config`: Used to get the rake parameters for a pot won uncontested, and
+//!   to count the hand once it is won.
+//! - `table_vault`: The table's token vault, from which rake is taken.
+//! - `treasury_vault`: The platform's treasury account to receive the rake.
+//! - `remaining_accounts`: The table's other occupied `PlayerSeat` accounts, writable so
+//!   a player left alone in the hand can be paid.
 //!
 //! @logic
-//! 1. Verifies that the game is in progress and it's the correct player's turn.
+//! 1. Verifies that the game is in progress, a betting round is open
+//!    (`HandPhase::Betting`), and it's the correct player's turn.
 //! 2. Checks the on-chain turn timer to prevent players from taking too long.
+//!    The action counts as table activity and resumes an idle-paused table.
 //! 3. Based on the `PlayerAction` enum provided, it validates and executes the move:
-//!    - **Fold**: Marks the player as inactive for the rest of the hand.
+//!    - **Fold**: Marks the player as inactive for the rest of the hand. Their
+//!      `total_bet_this_hand` is kept: the chips stay in the pots they contributed
+//!      to, though they can no longer win any of them.
 //!    - **Check**: Allowed only if there is no current bet to call.
-//!    - **Call**: Matches the `current_bet`.
-//!    - **Bet**: Makes the first bet in a round.
-//!    - **Raise**: Increases the `current_bet`.
-//! 4. Updates the player's stack, their bet amounts, and the table's pot.
-//! 5. Determines the next player to act and updates `turn_position`. If the betting
-//!    round is complete, this is handled by advancing to the next stage (e.g., dealing cards).
-//! 6. If the action concludes a betting round, prepares the table for the next action
-//!    (dealing community cards or resolving the showdown).
+//!    - **Call**: Matches the `current_bet`, or puts the player all-in for less. A
+//!      `Raise` to exactly the `current_bet` with the player's whole stack is the same
+//!      all-in call and is handled as one: it is not aggression and reopens nothing.
+//!    - **Bet**: Makes the first bet in a round, which on every street must be at least
+//!      the big blind.
+//!    - **Raise**: Increases the `current_bet` by at least the last full bet or raise
+//!      in the round (and never by less than the big blind). An all-in for less than a
+//!      full raise is allowed but does not reopen the betting for players who have
+//!      already acted.
+//! 4. Updates the player's stack, their bet amounts, and the table's pot, and emits
+//!    `PlayerActed` with the player's position and effective stack against the
+//!    deepest opponent.
+//! 5. Determines the next player to act (skipping folded and all-in players) and
+//!    updates `turn_position`. The betting round is complete once everyone who can
+//!    act has done so since the last full raise and matched the bet, so a limped big
+//!    blind still gets to check or raise. When it is, emits `BettingRoundComplete` so
+//!    clients advance to the next stage (e.g., dealing cards).
+//! 6. If the action concludes a betting round, moves the hand to the next phase:
+//!    `AwaitingReveal` for the next street, or `AwaitingShowdown` after the river.
+//! 7. If two or more players remain but none of them can act again (everyone else is
+//!    all-in), sets `ready_for_runout` so a keeper deals the remaining streets.
+//! 8. If a fold leaves a single player in the hand, the hand is over: that player is
+//!    credited the pot less rake (none before the flop), the rake goes to the treasury,
+//!    and `PotAwarded` is emitted.
 
 use anchor_lang::prelude::*;
-use crate::state::{Table, PlayerAction, GameState, PlayerSeat};
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{Table, PlayerAction, GameState, HandPhase, PlatformConfig, PlayerSeat, BettingRound, PositionLabel};
 use crate::error::AcesUnknownErrorCode;
-use crate::state::constants::MAX_PLAYERS;
+use crate::utils::{award_uncontested_pot, effective_stack, load_table_seats, next_actor_after, persist_seats, position_label, recompute_pots, record_hand_played, require_all_occupied_seats, transfer_rake};
 
 /// The instruction logic for a player taking an action during a betting round.
-pub fn player_action(ctx: Context<PlayerActionAccounts>, _table_id: u64, action: PlayerAction) -> Result<()> {
+/// The other occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+pub fn player_action<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PlayerActionAccounts<'info>>,
+    _table_id: u64,
+    action: PlayerAction,
+) -> Result<()> {
     let table = &mut ctx.accounts.table;
     let player_signer_key = ctx.accounts.player.key();
     let turn_pos = table.turn_position as usize;
@@ -41,15 +74,17 @@ pub fn player_action(ctx: Context<PlayerActionAccounts>, _table_id: u64, action:
         AcesUnknownErrorCode::InvalidGameState
     );
     
-    // Verify the player seat belongs to the correct player and table
+    // Verify the player seat belongs to the correct table and player
     let player_seat = &ctx.accounts.player_seat;
-    require!(
-        player_seat.player_pubkey == player_signer_key,
-        AcesUnknownErrorCode::NotPlayersTurn
+    require_keys_eq!(
+        player_seat.table_pubkey,
+        table.key(),
+        AcesUnknownErrorCode::SeatTableMismatch
     );
-    require!(
-        player_seat.table_pubkey == table.key(),
-        AcesUnknownErrorCode::PlayerNotFound
+    require_keys_eq!(
+        player_seat.player_pubkey,
+        player_signer_key,
+        AcesUnknownErrorCode::SeatOwnerMismatch
     );
     require!(
         player_seat.seat_index as usize == turn_pos,
@@ -59,28 +94,121 @@ pub fn player_action(ctx: Context<PlayerActionAccounts>, _table_id: u64, action:
     // Check turn timer - time should NOT be expired
     let now = Clock::get()?.unix_timestamp;
     require!(
-        now <= table.turn_started_at + table.turn_duration_seconds as i64,
+        now <= table.turn_deadline,
         AcesUnknownErrorCode::TurnTimerExpired
     );
-    
+
+    let mut other_seats = load_table_seats(&table.key(), ctx.remaining_accounts)?;
+    require_all_occupied_seats(
+        table,
+        other_seats
+            .iter()
+            .map(|seat| seat.seat_index)
+            .chain(std::iter::once(player_seat.seat_index)),
+    )?;
+
+    // Acting manually replaces any pre-selected action.
+    let current_player = &mut ctx.accounts.player_seat;
+    current_player.pending_action = None;
+
+    table.record_activity(now);
+    apply_player_action(table, current_player, &other_seats, action, now)?;
+
+    if action == PlayerAction::Fold {
+        emit!(PlayerFolded {
+            table_id: table.table_id,
+            hand_id: table.hand_id_counter,
+            seat_index: current_player.seat_index,
+            reason: FoldReason::Voluntary,
+        });
+    }
+
+    // --- Award an Uncontested Pot ---
+    // A fold that leaves one player in the hand completes it; they win the pot.
+    if table.game_state == GameState::HandComplete {
+        award_pot_to_last_player(
+            table,
+            ctx.bumps.table,
+            current_player,
+            &mut other_seats,
+            &mut ctx.accounts.platform_config,
+            &ctx.accounts.table_vault,
+            &ctx.accounts.treasury_vault,
+            &ctx.accounts.token_program,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Pays the pot, less rake, to the one player a fold has left in the hand, sends the
+/// rake to the treasury, and counts the hand played. Shared by `player_action` and
+/// `force_player_fold`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn award_pot_to_last_player<'info>(
+    table: &mut Account<'info, Table>,
+    table_bump: u8,
+    current_player: &mut PlayerSeat,
+    other_seats: &mut [Account<'info, PlayerSeat>],
+    platform_config: &mut Account<'info, PlatformConfig>,
+    table_vault: &Account<'info, TokenAccount>,
+    treasury_vault: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let rake = match other_seats.iter_mut().find(|seat| seat.is_active_in_hand) {
+        Some(winner) => award_uncontested_pot(table, winner, platform_config),
+        None => award_uncontested_pot(table, current_player, platform_config),
+    };
+    persist_seats(other_seats)?;
+    transfer_rake(table, table_bump, table_vault, treasury_vault, token_program, rake)?;
+    record_hand_played(platform_config);
+    Ok(())
+}
+
+/// Executes `action` for the player whose turn it is and advances the turn.
+/// Shared by `player_action` and `apply_pending_action`; callers are responsible
+/// for validating that `current_player` is the seat at `turn_position`.
+pub(crate) fn apply_player_action(
+    table: &mut Table,
+    current_player: &mut PlayerSeat,
+    other_seats: &[Account<PlayerSeat>],
+    action: PlayerAction,
+    now: i64,
+) -> Result<()> {
+    let turn_pos = current_player.seat_index;
+    require!(!table.ready_for_runout, AcesUnknownErrorCode::InvalidGameState);
+    table.require_phase(HandPhase::Betting)?;
+
     // Extract values we need before mutable borrow
     let current_bet = table.current_bet;
+    let last_raise_size = table.last_raise_size;
     let big_blind = table.big_blind;
     let pot = table.pot;
     
     // Extract table values first to avoid borrow conflicts
     let last_aggressor_position = table.last_aggressor_position;
-    
-    // Now we can borrow mutably
-    let current_player = &mut ctx.accounts.player_seat;
+
+    // Going all-in for exactly the current bet adds nothing to it: it is a call.
+    let action = match action {
+        PlayerAction::Raise { amount }
+            if amount == current_bet
+                && amount == current_player.stack + current_player.bet_this_round =>
+        {
+            PlayerAction::Call
+        }
+        action => action,
+    };
     
     // --- Action Handling ---
     let mut pot_delta = 0u64;
     let mut new_current_bet = current_bet;
+    let mut new_last_raise_size = last_raise_size;
     let mut new_last_aggressor = last_aggressor_position;
+    let mut full_raise = false;
     
     match action {
         PlayerAction::Fold => {
+            // The folded chips stay in the pot; only eligibility to win it is lost.
             current_player.is_active_in_hand = false;
         }
         PlayerAction::Check => {
@@ -94,7 +222,7 @@ pub fn player_action(ctx: Context<PlayerActionAccounts>, _table_id: u64, action:
             require!(call_amount > 0, AcesUnknownErrorCode::InvalidAction);
             
             let actual_call = std::cmp::min(call_amount, current_player.stack);
-            current_player.stack -= actual_call;
+            current_player.debit(actual_call)?;
             current_player.bet_this_round += actual_call;
             current_player.total_bet_this_hand += actual_call;
             pot_delta = actual_call;
@@ -108,30 +236,53 @@ pub fn player_action(ctx: Context<PlayerActionAccounts>, _table_id: u64, action:
             require!(amount >= big_blind, AcesUnknownErrorCode::BetTooSmall);
             require!(amount <= current_player.stack, AcesUnknownErrorCode::InsufficientFunds);
             
-            current_player.stack -= amount;
+            current_player.debit(amount)?;
             current_player.bet_this_round += amount;
             current_player.total_bet_this_hand += amount;
             pot_delta = amount;
             new_current_bet = amount;
-            new_last_aggressor = turn_pos as u8;
+            new_last_raise_size = amount;
+            new_last_aggressor = turn_pos;
+            full_raise = true;
 
             if current_player.stack == 0 {
                 current_player.is_all_in = true;
             }
         }
         PlayerAction::Raise { amount } => {
-            let min_raise = current_bet * 2;
+            // `amount` is the player's total bet for the round, so it must add chips.
+            // A full raise must increase the current bet (not the player's own bet) by
+            // at least the size of the last full raise. A player may also raise all-in
+            // for less; that does not reopen the betting for anyone who has already
+            // acted since the last full raise.
+            let min_raise = current_bet + std::cmp::max(last_raise_size, big_blind);
+            let all_in_amount = current_player.stack + current_player.bet_this_round;
             require!(current_bet > 0, AcesUnknownErrorCode::InvalidAction);
-            require!(amount >= min_raise, AcesUnknownErrorCode::BetTooSmall);
-            require!(amount <= current_player.stack + current_player.bet_this_round, AcesUnknownErrorCode::InsufficientFunds);
+            require!(
+                amount > current_player.bet_this_round,
+                AcesUnknownErrorCode::InvalidBetAmount
+            );
+            require!(amount <= all_in_amount, AcesUnknownErrorCode::InsufficientFunds);
+            require!(
+                table.acted_since_full_raise & (1 << turn_pos) == 0,
+                AcesUnknownErrorCode::RaiseNotReopened
+            );
+            full_raise = amount >= min_raise;
+            require!(
+                full_raise || (amount == all_in_amount && amount > current_bet),
+                AcesUnknownErrorCode::BetTooSmall
+            );
 
             let amount_to_add = amount - current_player.bet_this_round;
-            current_player.stack -= amount_to_add;
+            current_player.debit(amount_to_add)?;
             current_player.bet_this_round = amount;
             current_player.total_bet_this_hand += amount_to_add;
             pot_delta = amount_to_add;
             new_current_bet = amount;
-            new_last_aggressor = turn_pos as u8;
+            if full_raise {
+                new_last_raise_size = amount - current_bet;
+            }
+            new_last_aggressor = turn_pos;
 
             if current_player.stack == 0 {
                 current_player.is_all_in = true;
@@ -140,49 +291,122 @@ pub fn player_action(ctx: Context<PlayerActionAccounts>, _table_id: u64, action:
     }
     
     // Update table fields after releasing the borrow
+    table.straddle_window_open = false;
     table.pot = pot + pot_delta;
     table.current_bet = new_current_bet;
+    table.last_raise_size = new_last_raise_size;
     table.last_aggressor_position = new_last_aggressor;
+    // Only a full bet or raise reopens the betting for players who already acted.
+    if full_raise {
+        table.acted_since_full_raise = 0;
+    }
+    table.acted_since_full_raise |= 1 << turn_pos;
     
     // --- Advance Turn or End Round ---
-    // Check for end-of-hand conditions (e.g., only one player left)
-    // Note: In a real implementation, we would need to check all PlayerSeat accounts
-    // to count active players. For now, we'll use a placeholder.
-    let active_players_count = 2; // Placeholder - should be calculated from PlayerSeat accounts
+    let mut seats: Vec<&PlayerSeat> = other_seats.iter().map(|seat| &**seat).collect();
+    seats.push(&*current_player);
+
+    // Once anyone is all-in, keep the side pot breakdown current.
+    if current_player.is_all_in || !table.pots.is_empty() {
+        recompute_pots(table, &seats);
+    }
+
+    emit!(PlayerActed {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        seat_index: turn_pos,
+        position: position_label(table, turn_pos, table.player_count as usize),
+        action,
+        pot: table.pot,
+        effective_stack: effective_stack(current_player, &seats),
+    });
+
+    // Check for end-of-hand conditions (only one player left)
+    let active_players_count = seats.iter().filter(|seat| seat.is_active_in_hand).count();
     if active_players_count <= 1 {
-        // Hand is over, proceeds to showdown/payout
-        // The frontend will call `resolve_showdown`
-        table.game_state = GameState::HandComplete; // Or a specific pre-resolve state
+        // Everyone else has folded, so the hand is over without a showdown: the
+        // caller pays the pot to the last player (`award_pot_to_last_player`).
+        table.complete_hand(now);
         return Ok(());
     }
 
-    // Find the next player
-    let mut next_turn_pos = (turn_pos + 1) % MAX_PLAYERS;
-    loop {
-        if (table.occupied_seats & (1 << next_turn_pos)) != 0 {
-            // Note: In a real implementation, we would need to check the PlayerSeat account
-            // to verify the player is active in the hand
-            // For now, we'll assume the player is active
-            break;
-        }
-        next_turn_pos = (next_turn_pos + 1) % MAX_PLAYERS;
+    // With no betting left to do, the board is simply run out.
+    if betting_is_closed(&seats, table.current_bet) {
+        table.ready_for_runout = true;
+        table.close_betting_round();
+        emit!(ReadyForRunout {
+            table_id: table.table_id,
+            hand_id: table.hand_id_counter,
+        });
+        return Ok(());
     }
+
+    // Find the next player who can still act
+    let next_turn_pos = match next_actor_after(turn_pos, &seats) {
+        Some(position) => position,
+        None => {
+            table.close_betting_round();
+            emit_betting_round_complete(table);
+            return Ok(());
+        }
+    };
     
     // Check if the betting round is over
-    if next_turn_pos as u8 == table.last_aggressor_position {
-        // Round is over. The next step will be triggered by a `deal_community_cards` call.
-        // We can signal this by setting a specific state or just let the client logic handle it.
-        // For now, we'll just stop advancing the turn. The client will see the state
-        // and know to call the next instruction.
-        msg!("Betting round is complete.");
+    if round_is_over(table, &seats) {
+        // Round is over. The next step will be triggered by a `deal_community_cards` call
+        // (or `resolve_showdown` after the river); clients watch for `BettingRoundComplete`.
+        // The turn is not advanced.
+        table.close_betting_round();
+        emit_betting_round_complete(table);
     } else {
-        table.turn_position = next_turn_pos as u8;
-        table.turn_started_at = now;
+        table.turn_position = next_turn_pos;
+        table.start_turn_timer(now);
     }
 
     Ok(())
 }
 
+/// Signals that the current betting round is over, so clients know to call
+/// `deal_community_cards` or `resolve_showdown` next.
+fn emit_betting_round_complete(table: &Table) {
+    msg!("Betting round is complete.");
+    emit!(BettingRoundComplete {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        betting_round: table.betting_round,
+        pot: table.pot,
+    });
+}
+
+/// Whether the betting round is over: every player who can still act has acted since
+/// the last full bet or raise and matched the current bet. With no bet on the street,
+/// that means everyone has checked (or folded). Preflop, the blinds only count as
+/// acting once they make a decision, so the big blind keeps the option to check or
+/// raise when everyone limps.
+fn round_is_over(table: &Table, seats: &[&PlayerSeat]) -> bool {
+    seats
+        .iter()
+        .filter(|seat| seat.is_active_in_hand && !seat.is_all_in)
+        .all(|seat| {
+            table.acted_since_full_raise & (1 << seat.seat_index) != 0
+                && seat.bet_this_round >= table.current_bet
+        })
+}
+
+/// True when no remaining player can make another betting decision this hand:
+/// everyone still in is all-in, except at most one player who has already
+/// matched the current bet.
+pub(crate) fn betting_is_closed(seats: &[&PlayerSeat], current_bet: u64) -> bool {
+    let mut can_act = seats
+        .iter()
+        .filter(|seat| seat.is_active_in_hand && !seat.is_all_in);
+    match (can_act.next(), can_act.next()) {
+        (None, _) => true,
+        (Some(seat), None) => seat.bet_this_round >= current_bet,
+        _ => false,
+    }
+}
+
 #[derive(Accounts)]
 #[instruction(table_id: u64)]
 pub struct PlayerActionAccounts<'info> {
@@ -195,11 +419,74 @@ pub struct PlayerActionAccounts<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
     
-    /// The player's seat account.
+    /// The player's seat account. Derived from the table it records so that a seat
+    /// from another table is reported as `SeatTableMismatch` by the handler.
     #[account(
         mut,
-        seeds = [b"player_seat", table.key().as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
+        seeds = [b"player_seat", player_seat.table_pubkey.as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
         bump = player_seat.bump,
     )]
     pub player_seat: Account<'info, PlayerSeat>,
-}
\ No newline at end of file
+
+    #[account(mut, seeds = [b"platform_config"], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [b"vault", table.key().as_ref()],
+        bump,
+    )]
+    pub table_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = platform_config.treasury_vault,
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Why a player was folded, as reported in `PlayerFolded`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FoldReason {
+    /// The player folded with `player_action`.
+    Voluntary,
+    /// The player's pre-selected action resolved to a fold.
+    PendingAction,
+    /// The player's turn timer expired and a keeper folded them.
+    TimedOut,
+}
+
+#[event]
+pub struct PlayerActed {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    /// The actor's position relative to the button.
+    pub position: PositionLabel,
+    pub action: PlayerAction,
+    pub pot: u64,
+    /// The smaller of the actor's remaining stack and the largest remaining stack
+    /// among the other players still in the hand.
+    pub effective_stack: u64,
+}
+
+#[event]
+pub struct PlayerFolded {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    pub reason: FoldReason,
+}
+
+#[event]
+pub struct ReadyForRunout {
+    pub table_id: u64,
+    pub hand_id: u64,
+}
+
+#[event]
+pub struct BettingRoundComplete {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub betting_round: BettingRound,
+    pub pot: u64,
+}
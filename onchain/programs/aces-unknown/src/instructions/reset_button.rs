@@ -0,0 +1,85 @@
+//! src/instructions/reset_button.rs
+//!
+//! @description
+//! A recovery tool for the table creator: if the dealer button has ended up in an
+//! inconsistent place (for example after irregular leaves), it can be moved by hand
+//! to any occupied seat between hands. This is not part of the normal flow; the
+//! button otherwise only moves when a hand starts.
+//!
+//! @accounts
+//! - `table`: The `Table` account whose button is moved.
+//! - `creator`: The table creator, who must sign.
+//!
+//! @logic
+//! 1. Verifies the signer is the table creator.
+//! 2. Verifies no hand is in progress and the table has dealt its first hand (before
+//!    then, the first hand draws the button at random).
+//! 3. Verifies the chosen seat is occupied.
+//! 4. Sets `dealer_position` to the seat. As after any hand, the next hand moves the
+//!    button on to the next seat in the rotation.
+//! 5. Emits `ButtonReset`.
+
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Table};
+use crate::state::constants::MAX_PLAYERS;
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for moving the dealer button to a chosen seat.
+pub fn reset_button(ctx: Context<ResetButton>, _table_id: u64, seat_index: u8) -> Result<()> {
+    let table = &mut ctx.accounts.table;
+
+    // --- Validation ---
+    require!(
+        table.game_state != GameState::HandInProgress && table.first_hand_dealt,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    require!(
+        seat_index < MAX_PLAYERS as u8,
+        AcesUnknownErrorCode::InvalidSeatIndex
+    );
+    require!(
+        table.occupied_seats & (1 << seat_index) != 0,
+        AcesUnknownErrorCode::PlayerNotFound
+    );
+
+    let previous_position = table.dealer_position;
+    table.dealer_position = seat_index;
+
+    emit!(ButtonReset {
+        table_id: table.table_id,
+        previous_position,
+        dealer_position: seat_index,
+    });
+
+    msg!(
+        "Table #{} button reset from seat {} to seat {}",
+        table.table_id,
+        previous_position,
+        seat_index
+    );
+    Ok(())
+}
+
+/// The context struct for the `reset_button` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct ResetButton<'info> {
+    /// The table account whose button is being moved.
+    #[account(
+        mut,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = table.creator == creator.key() @ AcesUnknownErrorCode::NotTableCreator,
+    )]
+    pub table: Account<'info, Table>,
+
+    /// The creator of the table.
+    pub creator: Signer<'info>,
+}
+
+#[event]
+pub struct ButtonReset {
+    pub table_id: u64,
+    pub previous_position: u8,
+    pub dealer_position: u8,
+}
@@ -10,35 +10,83 @@
 //! @accounts
 //! - `table`: The table account with the final state of the hand.
 //! - `hand_data`: The account holding the encrypted player hands.
-//! - `platform_config`: Used to get the rake parameters.
+//! - `platform_config`: Used to get the rake parameters, and counts the hand played.
 //! - `table_vault`: The table's token vault from which payouts and rake are made.
 //! - `treasury_vault`: The platform's treasury account to receive the rake.
+//! - `referrer_token_account`: The table referrer's token account for the table's
+//!   currency, to receive their share of the rake. Required only when the table has a
+//!   referrer and the platform pays referrers a share.
+//! - `remaining_accounts`: The occupied `PlayerSeat` accounts, then the `EncryptedHand`
+//!   account of each player still in the hand (in the same order as their seats; the
+//!   PDA address even if it was never created), then
+//!   (when rake-back is enabled) every seated player's `PlayerRakeback` account in seat order.
 //!
 //! @logic
-//! 1. Validates the game state and betting round.
+//! 1. Validates that at least two players are still in the hand (otherwise it is won
+//!    uncontested), the game state, the betting round, and that the hand is awaiting
+//!    its showdown (`HandPhase::AwaitingShowdown`) with none already being evaluated
+//!    (`HandData::showdown_in_progress`), so the pot cannot be paid out twice.
 //! 2. Gathers all necessary inputs for the Arcium circuit: encrypted player hands,
-//!    public community cards, total player bets, etc.
-//! 3. Queues the `evaluate_hands_and_payout` computation.
-//! 4. The `resolve_showdown_callback` receives the public `WinnerInfo` results.
-//! 5. It calculates the total pot and the rake amount based on `PlatformConfig`.
-//! 6. Transfers the rake from the `table_vault` to the `treasury_vault`.
-//! 7. Distributes the remaining pot to the winner(s) by updating their stacks in the `Table` account.
-//! 8. Updates the `Table` state to `HandComplete`, resets hand-specific data, and closes the
-//!    `HandData` account to refund the rent.
+//!    public community cards, total player bets, etc. Before spending compute on the
+//!    queue, it checks that every player still in the hand has their `EncryptedHand`
+//!    and that the players' bets add up to the pot. A player who was never dealt in
+//!    cannot have one; rather than trap the pot, they are treated as folded (emitting
+//!    `MissingEncryptedHand`) and any chips nobody else matched are returned to them.
+//!    A dealt-in player's missing hand still aborts the showdown.
+//!    At a table with a showdown reveal timeout, once any player has revealed with
+//!    `reveal_hole_cards`, the showdown waits until everyone still in the hand has
+//!    revealed or the timeout has passed (`ShowdownRevealPending`). Those who have not
+//!    are then mucked (emitting `HandMucked`), forfeiting their chips in the pot just
+//!    like a fold.
+//! 3. Queues the `evaluate_hands_and_payout` computation, which holds
+//!    `showdown_in_progress` until its callback lands.
+//! 4. The `resolve_showdown_callback` receives the public per-seat `ShowdownDetail` results.
+//! 5. It calculates the rake based on `PlatformConfig`: on the whole pot, or on each
+//!    side pot separately under `RakeScope::PerPot`, each pot capped on its own.
+//! 6. Transfers the rake from the `table_vault` to the `treasury_vault`, less the
+//!    referrer's `referrer_rake_bps` share, which goes to the referrer.
+//! 7. Accrues rake-back for each player in proportion to their share of the pot.
+//! 8. Distributes the remaining pot to the winner(s) by crediting their stacks, emitting
+//!    `ShowdownWinnings` for each, then `ShowdownDetails` with every seat's evaluated
+//!    hand and winnings. Each pot is paid less its rake (a whole-pot rake comes
+//!    out of the main pot first); odd chips from a split pot go to the tied winners
+//!    nearest the button's left.
+//! 9. Stands up players left with no chips: at a cash table they sit out, and at a
+//!    tournament table their seat is freed and their finishing position recorded.
+//! 10. Updates the `Table` state to `HandComplete`. The `HandData` account stays open:
+//!     `rabbit_hunt`, `verify_shown_hands` and `close_encrypted_hands` still read the
+//!     finished hand.
+//! 11. Counts the hand in the platform's `PlatformStats`.
 
 use anchor_lang::prelude::*;
 use anchor_lang::Discriminator;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{Table, HandData, GameState, BettingRound, PlatformConfig};
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{Table, HandData, GameState, HandPhase, BettingRound, PlatformConfig, PlayerRakeback, PlayerSeat};
 use crate::error::AcesUnknownErrorCode;
+use crate::state::constants::MAX_PLAYERS;
+use crate::instructions::show_one_card::dealt_deck_position;
+use crate::utils::{best_hand_key, calculate_payouts, HandKey, showdown_pots, compute_showdown_rakes, record_hand_played, referrer_rake_share, transfer_rake, is_encrypted_hand, require_encrypted_hand, load_table_seats, persist_seats, require_all_occupied_seats, require_contested_hand, require_seat_accounting_consistent};
 
 
-pub fn resolve_showdown(ctx: Context<ResolveShowdown>, _table_id: u64) -> Result<()> {
+pub fn resolve_showdown<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ResolveShowdown<'info>>,
+    _table_id: u64,
+) -> Result<()> {
     let table = &mut ctx.accounts.table;
-    let hand_data = &ctx.accounts.hand_data;
+    let hand_data = &mut ctx.accounts.hand_data;
     let platform_config = &ctx.accounts.platform_config;
 
     // --- Validation ---
+    let seat_count = table.occupied_seats.count_ones() as usize;
+    require!(
+        ctx.remaining_accounts.len() >= seat_count,
+        AcesUnknownErrorCode::InvalidSeatAccounts
+    );
+    let (seat_accounts, circuit_accounts) = ctx.remaining_accounts.split_at(seat_count);
+    let mut seats = load_table_seats(&table.key(), seat_accounts)?;
+    require_all_occupied_seats(table, seats.iter().map(|seat| seat.seat_index))?;
+    require_contested_hand(&seats)?;
+
     require!(
         table.game_state == GameState::HandInProgress,
         AcesUnknownErrorCode::InvalidGameState
@@ -48,53 +96,159 @@ pub fn resolve_showdown(ctx: Context<ResolveShowdown>, _table_id: u64) -> Result
         table.betting_round == BettingRound::River,
         AcesUnknownErrorCode::InvalidGameState
     );
-    // Check that the current betting round is actually complete
-    // This means all active players have either called, folded, or gone all-in
-    let betting_round_complete = true;
-    // Note: Player seat data is now stored in separate PlayerSeat accounts
-    // In a real implementation, we would need to check each PlayerSeat account
-    // to verify the betting round is complete
-    require!(betting_round_complete, AcesUnknownErrorCode::InvalidGameState);
+    // Check that the river betting is closed (or the board was run out).
+    table.require_phase(HandPhase::AwaitingShowdown)?;
 
+    // --- Validate Circuit Inputs ---
+    let hand_key = hand_data.key();
+    let in_hand: Vec<usize> = (0..seats.len()).filter(|&i| seats[i].is_active_in_hand).collect();
+    require!(
+        circuit_accounts.len() >= in_hand.len(),
+        AcesUnknownErrorCode::MissingEncryptedHand
+    );
+    let (encrypted_hand_accounts, rakeback_accounts) = circuit_accounts.split_at(in_hand.len());
+    let mut dropped = Vec::new();
+    for (&i, encrypted_hand_info) in in_hand.iter().zip(encrypted_hand_accounts.iter()) {
+        let seat = &mut seats[i];
+        let dealt_in = hand_data.dealt_in_seats & (1 << seat.seat_index) != 0;
+        if !dealt_in && !is_encrypted_hand(encrypted_hand_info)? {
+            // Never dealt cards, so there is no hand to evaluate: play on without them.
+            seat.is_active_in_hand = false;
+            dropped.push(i);
+            emit!(MissingEncryptedHand {
+                table_id: table.table_id,
+                hand_id: hand_data.hand_id,
+                seat_index: seat.seat_index,
+                player: seat.player_pubkey,
+            });
+            continue;
+        }
+        require_encrypted_hand(encrypted_hand_info, &hand_key, &seat.player_pubkey)?;
+    }
+    dropped.extend(muck_unrevealed_hands(table, &mut seats, hand_data.hand_id)?);
+    return_unmatched_chips(table, &mut seats, &dropped);
+    let (player_bets, active_players) = showdown_circuit_inputs(&seats, table.pot)?;
+
+    // A showdown already queued will pay the pot when its callback lands, so this one
+    // holds `showdown_in_progress` until its own callback does.
+    hand_data.begin_showdown()?;
     // TODO: Add Arcium computation queuing once Arcium integration is properly set up
 
-    // For now, simulate showdown resolution
-    let total_pot = table.pot;
-    let rake_bps = platform_config.rake_bps as u64;
-    let mut rake_amount = (total_pot * rake_bps) / 10000;
-    if platform_config.rake_max_cap > 0 && platform_config.rake_max_cap < rake_amount {
-        rake_amount = platform_config.rake_max_cap;
+    // For now, simulate showdown resolution: the simulated deck is in order, so each
+    // hole card is its deck position (in a real implementation, the hands would be
+    // evaluated by Arcium).
+    let board: Vec<u8> = table.community_cards.iter().flatten().map(|card| card.to_index().get()).collect();
+    let mut hand_keys = [None; MAX_PLAYERS];
+    for (i, key) in hand_keys.iter_mut().enumerate().filter(|(i, _)| active_players[*i]) {
+        let mut cards = board.clone();
+        for card_index in 0..2 {
+            cards.push(
+                dealt_deck_position(hand_data.dealt_in_seats, hand_data.deal_style, i as u8, card_index)
+                    .ok_or(AcesUnknownErrorCode::MissingEncryptedHand)?,
+            );
+        }
+        *key = Some(best_hand_key(&cards));
     }
+    let total_pot = table.pot;
+    let pot_rakes = compute_showdown_rakes(&showdown_pots(&player_bets, &hand_keys), platform_config);
+    let rake_amount: u64 = pot_rakes.iter().sum();
 
     // --- Transfer Rake ---
-    if rake_amount > 0 {
-        let table_key = table.key();
-        let seeds = &[&b"vault"[..], table_key.as_ref()];
-        let signer_seeds = &[&seeds[..]];
-
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.table_vault.to_account_info(),
-            to: ctx.accounts.treasury_vault.to_account_info(),
-            authority: table.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        token::transfer(cpi_ctx, rake_amount)?;
+    let referrer_rake = if table.referrer.is_some() {
+        referrer_rake_share(rake_amount, platform_config)
+    } else {
+        0
+    };
+    if referrer_rake > 0 {
+        let referrer_token_account = ctx
+            .accounts
+            .referrer_token_account
+            .as_ref()
+            .ok_or(AcesUnknownErrorCode::InvalidReferrerAccount)?;
+        require!(
+            Some(referrer_token_account.owner) == table.referrer
+                && referrer_token_account.mint == table.token_mint,
+            AcesUnknownErrorCode::InvalidReferrerAccount
+        );
+        transfer_rake(
+            table,
+            ctx.bumps.table,
+            &ctx.accounts.table_vault,
+            referrer_token_account,
+            &ctx.accounts.token_program,
+            referrer_rake,
+        )?;
+        emit!(ReferrerRakePaid {
+            table_id: table.table_id,
+            hand_id: hand_data.hand_id,
+            referrer: referrer_token_account.owner,
+            amount: referrer_rake,
+        });
+    }
+    transfer_rake(
+        table,
+        ctx.bumps.table,
+        &ctx.accounts.table_vault,
+        &ctx.accounts.treasury_vault,
+        &ctx.accounts.token_program,
+        rake_amount - referrer_rake,
+    )?;
+
+    // --- Accrue Rake-back ---
+    let rakeback_bps = platform_config.rakeback.rakeback_bps;
+    if rake_amount > 0 && rakeback_bps > 0 {
+        require!(
+            rakeback_accounts.len() == seats.len(),
+            AcesUnknownErrorCode::InvalidRakebackAccount
+        );
+        for (seat, rakeback_info) in seats.iter().zip(rakeback_accounts.iter()) {
+            accrue_rakeback(
+                seat,
+                rakeback_info,
+                &table.token_mint,
+                total_pot,
+                rake_amount,
+                rakeback_bps,
+            )?;
+        }
     }
 
     // --- Distribute Winnings ---
-    // For now, simulate a simple winner distribution
-    // In a real implementation, this would come from Arcium computation results
-    let remaining_pot = total_pot - rake_amount;
-    if remaining_pot > 0 {
-        // Simulate distributing to a winner (in practice, this would be determined by Arcium)
-        // We can't update player.stack because it's not stored in PlayerSeatInfo
-        // In a real implementation, we would need to access this information
-        // from a separate account or use a different approach
+    // Simulate the circuit's payouts (in a real implementation, these would come from
+    // Arcium).
+    let payouts = calculate_payouts(&player_bets, &hand_keys, table.dealer_position, &pot_rakes);
+    require!(
+        payouts.iter().sum::<u64>() == total_pot - rake_amount,
+        AcesUnknownErrorCode::PotMismatch
+    );
+    for seat in seats.iter_mut() {
+        let amount = payouts[seat.seat_index as usize];
+        if amount > 0 {
+            seat.stack = seat.stack.checked_add(amount).ok_or(AcesUnknownErrorCode::PotMismatch)?;
+            emit!(ShowdownWinnings {
+                table_id: table.table_id,
+                hand_id: hand_data.hand_id,
+                seat_index: seat.seat_index,
+                amount,
+            });
+        }
     }
+    emit!(ShowdownDetails {
+        table_id: table.table_id,
+        hand_id: hand_data.hand_id,
+        details: showdown_details(&hand_keys, &payouts),
+    });
+
+    // The simulated callback has paid the pot.
+    hand_data.showdown_in_progress = false;
+
+    // --- Stand Up Busted Players ---
+    retire_busted_players(table, &mut seats, hand_data.hand_id)?;
+    persist_seats(&seats)?;
 
     // --- Reset Table State ---
-    table.game_state = GameState::HandComplete;
+    table.complete_hand(Clock::get()?.unix_timestamp);
+    record_hand_played(&mut ctx.accounts.platform_config);
 
     emit!(HandResolved {
         table_id: table.table_id,
@@ -106,9 +260,171 @@ pub fn resolve_showdown(ctx: Context<ResolveShowdown>, _table_id: u64) -> Result
     Ok(())
 }
 
+/// Packs each seat's evaluated hand and winnings the way the circuit's
+/// `ShowdownDetail` output does. A seat not in the showdown has no hand, so its
+/// category is zero.
+fn showdown_details(
+    hand_keys: &[Option<HandKey>; MAX_PLAYERS],
+    payouts: &[u64; MAX_PLAYERS],
+) -> [ShowdownDetail; MAX_PLAYERS] {
+    std::array::from_fn(|i| {
+        let key = hand_keys[i].unwrap_or_default();
+        ShowdownDetail {
+            hand_category: key[0],
+            hand_ranks: [key[1], key[2], key[3], key[4], key[5]],
+            amount_won: payouts[i],
+        }
+    })
+}
 
+/// At a table with a showdown reveal timeout, mucks every player still in the hand who
+/// has not revealed their hole cards once the timeout has passed since the first reveal,
+/// returning their indices. Fails with `ShowdownRevealPending` while it has not. If
+/// nobody has revealed, the reveal window never opened and every hand is evaluated.
+fn muck_unrevealed_hands(table: &Table, seats: &mut [Account<PlayerSeat>], hand_id: u64) -> Result<Vec<usize>> {
+    let timeout = table.settings.showdown_reveal_timeout_seconds as i64;
+    if timeout == 0 {
+        return Ok(Vec::new());
+    }
+    let in_hand = || seats.iter().filter(|seat| seat.is_active_in_hand);
+    let Some(first_reveal) = in_hand().map(|seat| seat.showdown_revealed_at).filter(|&at| at > 0).min() else {
+        return Ok(Vec::new());
+    };
+    if in_hand().all(|seat| seat.showdown_revealed_at > 0) {
+        return Ok(Vec::new());
+    }
+    require!(
+        Clock::get()?.unix_timestamp >= first_reveal + timeout,
+        AcesUnknownErrorCode::ShowdownRevealPending
+    );
 
+    let mut mucked = Vec::new();
+    for (i, seat) in seats.iter_mut().enumerate() {
+        if seat.is_active_in_hand && seat.showdown_revealed_at == 0 {
+            seat.is_active_in_hand = false;
+            mucked.push(i);
+            emit!(HandMucked {
+                table_id: table.table_id,
+                hand_id,
+                seat_index: seat.seat_index,
+                player: seat.player_pubkey,
+            });
+        }
+    }
+    Ok(mucked)
+}
 
+/// Returns to each dropped player whatever part of their contribution exceeds every
+/// remaining player's: no pot that anyone left in the hand is eligible for covers it.
+fn return_unmatched_chips(table: &mut Table, seats: &mut [Account<PlayerSeat>], dropped: &[usize]) {
+    let top_bet = seats
+        .iter()
+        .filter(|seat| seat.is_active_in_hand)
+        .map(|seat| seat.total_bet_this_hand)
+        .max()
+        .unwrap_or(0);
+    for &i in dropped {
+        let seat = &mut seats[i];
+        let unmatched = seat.total_bet_this_hand.saturating_sub(top_bet);
+        seat.total_bet_this_hand -= unmatched;
+        seat.stack += unmatched;
+        table.pot -= unmatched;
+    }
+}
+
+/// Assembles the `evaluate_hands_and_payout` inputs, indexed by seat: every seat's
+/// contribution to the hand (folded players' chips still count towards the pots) and
+/// whether it is still in the hand. The contributions must account for the whole pot,
+/// or the circuit would pay out chips that are not there.
+fn showdown_circuit_inputs(
+    seats: &[Account<PlayerSeat>],
+    pot: u64,
+) -> Result<([u64; MAX_PLAYERS], [bool; MAX_PLAYERS])> {
+    let mut player_bets = [0u64; MAX_PLAYERS];
+    let mut active_players = [false; MAX_PLAYERS];
+    let mut total_bets = 0u64;
+    for seat in seats.iter() {
+        let index = seat.seat_index as usize;
+        player_bets[index] = seat.total_bet_this_hand;
+        active_players[index] = seat.is_active_in_hand;
+        total_bets = total_bets
+            .checked_add(seat.total_bet_this_hand)
+            .ok_or(AcesUnknownErrorCode::PotMismatch)?;
+    }
+    require!(total_bets == pot, AcesUnknownErrorCode::PotMismatch);
+    Ok((player_bets, active_players))
+}
+
+/// Handles every player who lost their whole stack this hand. At a tournament table
+/// their seat is freed and they are given a finishing position, with players who bust
+/// in the same hand ranked by the stack they started it with. At a cash table they
+/// sit out until their seat is freed with `leave_table` or `stand_up_busted_player`.
+fn retire_busted_players(table: &mut Table, seats: &mut [Account<PlayerSeat>], hand_id: u64) -> Result<()> {
+    let mut busted: Vec<usize> = (0..seats.len())
+        .filter(|&i| seats[i].stack == 0 && seats[i].total_bet_this_hand > 0)
+        .collect();
+    // Everyone busting lost their whole stack, so the bigger contribution finishes higher.
+    busted.sort_by(|&a, &b| seats[b].total_bet_this_hand.cmp(&seats[a].total_bet_this_hand));
+    let first_position = table.player_count + 1 - busted.len() as u8;
+
+    for (rank, &i) in busted.iter().enumerate() {
+        let seat = &mut seats[i];
+        let finishing_position = if table.settings.is_tournament {
+            let position = first_position + rank as u8;
+            seat.finishing_position = Some(position);
+            table.vacate_seat(seat.seat_index);
+            Some(position)
+        } else {
+            seat.is_sitting_out = true;
+            None
+        };
+        emit!(PlayerBusted {
+            table_id: table.table_id,
+            hand_id,
+            seat_index: seat.seat_index,
+            player: seat.player_pubkey,
+            finishing_position,
+        });
+    }
+    require_seat_accounting_consistent(table)
+}
+
+/// Credits a player's `PlayerRakeback` account with their share of the rake:
+/// the rake attributable to their pot contribution, scaled by `rakeback_bps`.
+fn accrue_rakeback<'info>(
+    seat: &PlayerSeat,
+    rakeback_info: &'info AccountInfo<'info>,
+    token_mint: &Pubkey,
+    total_pot: u64,
+    rake_amount: u64,
+    rakeback_bps: u16,
+) -> Result<()> {
+    let mut rakeback: Account<'info, PlayerRakeback> = Account::try_from(rakeback_info)?;
+    require_keys_eq!(rakeback.player, seat.player_pubkey, AcesUnknownErrorCode::InvalidRakebackAccount);
+    require_keys_eq!(rakeback.token_mint, *token_mint, AcesUnknownErrorCode::InvalidRakebackAccount);
+    let expected_key = Pubkey::create_program_address(
+        &[
+            b"player_rakeback",
+            rakeback.player.as_ref(),
+            rakeback.token_mint.as_ref(),
+            &[rakeback.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| error!(AcesUnknownErrorCode::InvalidRakebackAccount))?;
+    require_keys_eq!(expected_key, rakeback_info.key(), AcesUnknownErrorCode::InvalidRakebackAccount);
+
+    if total_pot == 0 || seat.total_bet_this_hand == 0 {
+        return Ok(());
+    }
+    let rake_contributed =
+        rake_amount as u128 * seat.total_bet_this_hand as u128 / total_pot as u128;
+    let accrual = (rake_contributed * rakeback_bps as u128 / 10000) as u64;
+    rakeback.accrued = rakeback.accrued.checked_add(accrual).ok_or(AcesUnknownErrorCode::PotMismatch)?;
+    rakeback.exit(&crate::ID)?;
+
+    Ok(())
+}
 
 #[derive(Accounts)]
 #[instruction(table_id: u64)]
@@ -128,12 +444,24 @@ pub struct ResolveShowdown<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(mut, seeds = [b"platform_config"], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     // Token accounts
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"vault", table.key().as_ref()],
+        bump,
+    )]
     pub table_vault: Account<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        address = platform_config.treasury_vault,
+        constraint = treasury_vault.mint == table.token_mint @ AcesUnknownErrorCode::InvalidTokenMint,
+    )]
     pub treasury_vault: Account<'info, TokenAccount>,
-    pub platform_config: Account<'info, PlatformConfig>,
+    #[account(mut)]
+    pub referrer_token_account: Option<Account<'info, TokenAccount>>,
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -147,3 +475,71 @@ pub struct HandResolved {
     pub pot: u64,
     pub rake: u64,
 }
+
+#[event]
+pub struct PlayerBusted {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    pub player: Pubkey,
+    /// The player's finishing place at a tournament table; `None` at a cash table.
+    pub finishing_position: Option<u8>,
+}
+
+/// A warning that a player still in the hand had no `EncryptedHand` and, having
+/// never been dealt in, was left out of the showdown.
+#[event]
+pub struct MissingEncryptedHand {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    pub player: Pubkey,
+}
+
+/// A player still in the hand who had not revealed their hole cards when the showdown
+/// reveal timeout ran out, and forfeited the pot.
+#[event]
+pub struct HandMucked {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    pub player: Pubkey,
+}
+
+#[event]
+pub struct ReferrerRakePaid {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub referrer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ShowdownWinnings {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    pub amount: u64,
+}
+
+/// One seat's showdown result, mirroring the `evaluate_hands_and_payout` circuit's
+/// `ShowdownDetail` output.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShowdownDetail {
+    /// The best hand's category, from 9 for a straight flush down to 1 for high card;
+    /// 0 for a seat that was not in the showdown.
+    pub hand_category: u8,
+    /// The ranks that break ties within the category, most significant first (0 for a
+    /// deuce up to 12 for an ace), zero-padded.
+    pub hand_ranks: [u8; 5],
+    /// The chips the seat won, after rake.
+    pub amount_won: u64,
+}
+
+/// Every seat's evaluated hand and winnings, indexed by seat, for clients and HUDs.
+#[event]
+pub struct ShowdownDetails {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub details: [ShowdownDetail; MAX_PLAYERS],
+}
@@ -0,0 +1,113 @@
+//! src/instructions/reveal_hole_cards.rs
+//!
+//! @description
+//! This instruction lets a player still in the hand reveal their hole cards at the
+//! showdown, at tables that set `TableSettings::showdown_reveal_timeout_seconds`. The
+//! first reveal starts the clock: once the timeout has passed, `resolve_showdown` mucks
+//! every player who has not revealed, and they forfeit the pot to those who did.
+//!
+//! @accounts
+//! - `table`: The table whose hand is awaiting its showdown.
+//! - `hand_data`: The current hand's account with the encrypted deck.
+//! - `player_seat`: The seat of the player revealing their cards.
+//! - `player`: The seated player, who must sign.
+//!
+//! @logic
+//! 1. Verifies the table uses showdown reveals, the hand is awaiting a showdown that has
+//!    not been queued yet, and the seat belongs to the signer, is still in the hand, was
+//!    dealt in, and has not revealed already.
+//! 2. Records the time of the reveal in `showdown_revealed_at`.
+//! 3. Reveals both hole cards and emits `HoleCardsRevealed`.
+
+use anchor_lang::prelude::*;
+use crate::state::{Card, CardIndex, GameState, HandData, HandPhase, PlayerSeat, Table};
+use crate::error::AcesUnknownErrorCode;
+use crate::instructions::show_one_card::dealt_deck_position;
+
+/// Instruction logic for revealing a player's hole cards at the showdown.
+pub fn reveal_hole_cards(ctx: Context<RevealHoleCards>, _table_id: u64) -> Result<()> {
+    let table = &ctx.accounts.table;
+    let hand_data = &ctx.accounts.hand_data;
+    let player_seat = &mut ctx.accounts.player_seat;
+
+    // --- Validation ---
+    require!(
+        table.settings.showdown_reveal_timeout_seconds > 0,
+        AcesUnknownErrorCode::InvalidAction
+    );
+    require!(
+        table.game_state == GameState::HandInProgress,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    table.require_phase(HandPhase::AwaitingShowdown)?;
+    require!(!hand_data.showdown_in_progress, AcesUnknownErrorCode::InvalidGameState);
+    require_keys_eq!(
+        player_seat.table_pubkey,
+        table.key(),
+        AcesUnknownErrorCode::SeatTableMismatch
+    );
+    require_keys_eq!(
+        player_seat.player_pubkey,
+        ctx.accounts.player.key(),
+        AcesUnknownErrorCode::SeatOwnerMismatch
+    );
+    require!(
+        player_seat.is_active_in_hand && player_seat.showdown_revealed_at == 0,
+        AcesUnknownErrorCode::InvalidAction
+    );
+
+    // TODO: Add Arcium computation queuing once Arcium integration is properly set up
+
+    // Simulate the circuit's output (in a real implementation, this would come from Arcium):
+    // the simulated deck is in order, so the card at a position is that position's index.
+    let seat_index = player_seat.seat_index;
+    let hole_card = |card_index: u8| -> Result<Card> {
+        let deck_position =
+            dealt_deck_position(hand_data.dealt_in_seats, hand_data.deal_style, seat_index, card_index)
+                .ok_or(AcesUnknownErrorCode::InvalidAction)?;
+        Ok(Card::from_index(CardIndex::new(deck_position)?))
+    };
+    let cards = [hole_card(0)?, hole_card(1)?];
+    player_seat.showdown_revealed_at = Clock::get()?.unix_timestamp;
+
+    emit!(HoleCardsRevealed {
+        table_id: table.table_id,
+        hand_id: hand_data.hand_id,
+        seat_index: player_seat.seat_index,
+        player: player_seat.player_pubkey,
+        cards,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct RevealHoleCards<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        seeds = [b"hand", table.key().as_ref(), table.hand_id_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub hand_data: Account<'info, HandData>,
+    #[account(
+        mut,
+        seeds = [b"player_seat", player_seat.table_pubkey.as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
+        bump = player_seat.bump,
+    )]
+    pub player_seat: Account<'info, PlayerSeat>,
+    pub player: Signer<'info>,
+}
+
+#[event]
+pub struct HoleCardsRevealed {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    pub player: Pubkey,
+    pub cards: [Card; 2],
+}
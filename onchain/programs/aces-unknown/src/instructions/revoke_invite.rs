@@ -0,0 +1,50 @@
+//! src/instructions/revoke_invite.rs
+//!
+//! @description
+//! This instruction allows the creator of a table to remove a wallet from the
+//! table's access list. A player who is already seated keeps their seat; the
+//! revocation only stops them joining again while the table is private.
+//!
+//! @accounts
+//! - `table`: The `Table` account whose access list is updated.
+//! - `creator`: The table creator, who must sign.
+//!
+//! @logic
+//! 1. Verifies the signer is the table creator.
+//! 2. Removes the wallet from the access list, failing if it was not invited.
+
+use anchor_lang::prelude::*;
+use crate::state::Table;
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for revoking a wallet's invitation to a table.
+pub fn revoke_invite(ctx: Context<RevokeInvite>, _table_id: u64, player: Pubkey) -> Result<()> {
+    let table = &mut ctx.accounts.table;
+    let index = table
+        .access_list
+        .iter()
+        .position(|invited| *invited == player)
+        .ok_or(AcesUnknownErrorCode::NotInvited)?;
+
+    table.access_list.remove(index);
+
+    msg!("Table #{} revoked the invitation of {}", table.table_id, player);
+    Ok(())
+}
+
+/// The context struct for the `revoke_invite` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct RevokeInvite<'info> {
+    /// The table account whose access list is being changed.
+    #[account(
+        mut,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = table.creator == creator.key() @ AcesUnknownErrorCode::NotTableCreator,
+    )]
+    pub table: Account<'info, Table>,
+
+    /// The creator of the table.
+    pub creator: Signer<'info>,
+}
@@ -0,0 +1,58 @@
+//! src/instructions/self_exclude.rs
+//!
+//! @description
+//! This instruction lets a player exclude themselves from play: until the chosen
+//! time they can neither create nor join a table. Tables they are already seated at
+//! are unaffected, so they can still leave and cash out.
+//!
+//! @accounts
+//! - `player`: The signer excluding themselves. Pays for the account on first use.
+//! - `player_exclusion`: The player's `PlayerExclusion` account, created if needed.
+//!
+//! @logic
+//! 1. Creates the `PlayerExclusion` account if the player has never had one.
+//! 2. Sets the exclusion to end at `until`, which must be in the future. A running
+//!    exclusion can be extended but not shortened.
+
+use anchor_lang::prelude::*;
+use crate::state::PlayerExclusion;
+
+/// The instruction logic for a player excluding themselves until `until`.
+pub fn self_exclude(ctx: Context<SelfExclude>, until: i64) -> Result<()> {
+    let player_key = ctx.accounts.player.key();
+    let exclusion = &mut ctx.accounts.player_exclusion;
+    exclusion.initialize_if_new(player_key, ctx.bumps.player_exclusion);
+    exclusion.exclude_until(until, Clock::get()?.unix_timestamp)?;
+
+    emit!(PlayerSelfExcluded {
+        player: player_key,
+        excluded_until: until,
+    });
+
+    Ok(())
+}
+
+/// The context struct for the `self_exclude` instruction.
+#[derive(Accounts)]
+pub struct SelfExclude<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The player's exclusion account.
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerExclusion::INIT_SPACE,
+        seeds = [b"player_exclusion", player.key().as_ref()],
+        bump,
+    )]
+    pub player_exclusion: Account<'info, PlayerExclusion>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PlayerSelfExcluded {
+    pub player: Pubkey,
+    pub excluded_until: i64,
+}
@@ -0,0 +1,56 @@
+//! src/instructions/set_max_concurrent_tables.rs
+//!
+//! @description
+//! This instruction lets a player cap how many tables they can be seated at at
+//! once. The cap is checked whenever they create or join a table; seats they
+//! already hold are unaffected.
+//!
+//! @accounts
+//! - `player`: The signer setting their cap. Pays for the account on first use.
+//! - `player_active_tables`: The player's `PlayerActiveTables` account, created if needed.
+//!
+//! @logic
+//! 1. Creates the `PlayerActiveTables` account if the player has never had one.
+//! 2. Replaces the cap; `NO_CONCURRENT_TABLE_LIMIT` removes it.
+
+use anchor_lang::prelude::*;
+use crate::state::PlayerActiveTables;
+
+/// The instruction logic for a player setting their concurrent table cap.
+pub fn set_max_concurrent_tables(
+    ctx: Context<SetMaxConcurrentTables>,
+    max_concurrent_tables: u16,
+) -> Result<()> {
+    let player_key = ctx.accounts.player.key();
+    let active_tables = &mut ctx.accounts.player_active_tables;
+    active_tables.initialize_if_new(player_key, ctx.bumps.player_active_tables);
+    active_tables.max_concurrent_tables = max_concurrent_tables;
+
+    msg!(
+        "Player {} concurrent table cap set to {} ({} active)",
+        player_key,
+        max_concurrent_tables,
+        active_tables.active_tables
+    );
+
+    Ok(())
+}
+
+/// The context struct for the `set_max_concurrent_tables` instruction.
+#[derive(Accounts)]
+pub struct SetMaxConcurrentTables<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The player's active table count and cap.
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerActiveTables::INIT_SPACE,
+        seeds = [b"player_active_tables", player.key().as_ref()],
+        bump,
+    )]
+    pub player_active_tables: Account<'info, PlayerActiveTables>,
+
+    pub system_program: Program<'info, System>,
+}
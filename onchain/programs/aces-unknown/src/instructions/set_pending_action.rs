@@ -0,0 +1,77 @@
+//! src/instructions/set_pending_action.rs
+//!
+//! @description
+//! This instruction lets a player pre-select an action ("check/fold", "call any",
+//! or "fold") before their turn arrives. The choice is stored on the player's seat
+//! and applied automatically by `apply_pending_action` once the turn reaches them,
+//! so the player does not have to be online at that moment.
+//!
+//! @accounts
+//! - `table`: The poker table account where the hand is in progress.
+//! - `player`: The signer setting the pending action; must own `player_seat`.
+//! - `player_seat`: The player's seat account, where the pending action is stored.
+//!
+//! @logic
+//! 1. Verifies that a hand is in progress and that the signer owns the seat.
+//! 2. When setting an action, verifies the player can still act this hand.
+//! 3. Stores the pending action, or clears it when `None` is passed.
+//!
+//! Pending actions only apply to the current betting round; they are cleared when
+//! a new street is dealt or a new hand starts.
+
+use anchor_lang::prelude::*;
+use crate::state::{GameState, HandPhase, PendingAction, PlayerSeat, Table};
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for setting or clearing a player's pending action.
+pub fn set_pending_action(
+    ctx: Context<SetPendingAction>,
+    _table_id: u64,
+    pending_action: Option<PendingAction>,
+) -> Result<()> {
+    let table = &ctx.accounts.table;
+    let player_seat = &mut ctx.accounts.player_seat;
+
+    // --- Validation ---
+    require!(
+        table.game_state == GameState::HandInProgress,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    table.require_phase(HandPhase::Betting)?;
+    require_keys_eq!(
+        player_seat.player_pubkey,
+        ctx.accounts.player.key(),
+        AcesUnknownErrorCode::PlayerNotFound
+    );
+    if pending_action.is_some() {
+        require!(
+            player_seat.is_active_in_hand && !player_seat.is_all_in,
+            AcesUnknownErrorCode::InvalidAction
+        );
+    }
+
+    player_seat.pending_action = pending_action;
+
+    Ok(())
+}
+
+/// The context struct for the `set_pending_action` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct SetPendingAction<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+
+    pub player: Signer<'info>,
+
+    /// The player's seat account.
+    #[account(
+        mut,
+        seeds = [b"player_seat", table.key().as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
+        bump = player_seat.bump,
+    )]
+    pub player_seat: Account<'info, PlayerSeat>,
+}
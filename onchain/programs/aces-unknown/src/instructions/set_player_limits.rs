@@ -0,0 +1,63 @@
+//! src/instructions/set_player_limits.rs
+//!
+//! @description
+//! This instruction lets a player set their own responsible-gaming limit: the most
+//! they may buy in for, across all tables, within `BUY_IN_LIMIT_PERIOD_SECONDS`.
+//!
+//! @accounts
+//! - `player`: The signer setting their limit. Pays for the account on first use.
+//! - `player_limits`: The player's `PlayerLimits` account, created if needed.
+//!
+//! @logic
+//! 1. Creates the `PlayerLimits` account if the player has never had one.
+//! 2. A lower limit applies immediately. A higher limit (including `NO_BUY_IN_LIMIT`)
+//!    is queued and only applies once `LIMIT_INCREASE_COOLDOWN_SECONDS` have passed.
+
+use anchor_lang::prelude::*;
+use crate::state::PlayerLimits;
+
+/// The instruction logic for a player setting their buy-in limit.
+pub fn set_player_limits(ctx: Context<SetPlayerLimits>, max_buy_in_per_period: u64) -> Result<()> {
+    let player_key = ctx.accounts.player.key();
+    let limits = &mut ctx.accounts.player_limits;
+    limits.initialize_if_new(player_key, ctx.bumps.player_limits);
+
+    let now = Clock::get()?.unix_timestamp;
+    limits.set_limit(max_buy_in_per_period, now);
+
+    emit!(PlayerLimitsUpdated {
+        player: player_key,
+        max_buy_in_per_period: limits.max_buy_in_per_period,
+        pending_max_buy_in: limits.pending_max_buy_in,
+        pending_effective_at: limits.pending_effective_at,
+    });
+
+    Ok(())
+}
+
+/// The context struct for the `set_player_limits` instruction.
+#[derive(Accounts)]
+pub struct SetPlayerLimits<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// The player's limits account.
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerLimits::INIT_SPACE,
+        seeds = [b"player_limits", player.key().as_ref()],
+        bump,
+    )]
+    pub player_limits: Account<'info, PlayerLimits>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PlayerLimitsUpdated {
+    pub player: Pubkey,
+    pub max_buy_in_per_period: u64,
+    pub pending_max_buy_in: Option<u64>,
+    pub pending_effective_at: i64,
+}
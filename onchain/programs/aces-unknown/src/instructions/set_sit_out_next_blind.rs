@@ -0,0 +1,62 @@
+//! src/instructions/set_sit_out_next_blind.rs
+//!
+//! @description
+//! This instruction lets a player skip the next hand in which they would post a
+//! blind, without sitting out for good. `start_hand` sits the player out of that
+//! one hand (a dead small blind, or the big blind passes to the next seat), clears
+//! the flag, and deals them back in from the following hand.
+//!
+//! @accounts
+//! - `table`: The poker table account the player is seated at.
+//! - `player`: The signer; must own `player_seat`.
+//! - `player_seat`: The player's seat account, where the flag is stored.
+//!
+//! @logic
+//! 1. Verifies that the signer owns the seat.
+//! 2. Sets or clears `sit_out_next_blind`. It can be changed at any time, including
+//!    mid-hand; it takes effect the next time a hand starts.
+
+use anchor_lang::prelude::*;
+use crate::state::{PlayerSeat, Table};
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for setting or clearing a player's `sit_out_next_blind` flag.
+pub fn set_sit_out_next_blind(
+    ctx: Context<SetSitOutNextBlind>,
+    _table_id: u64,
+    sit_out: bool,
+) -> Result<()> {
+    let player_seat = &mut ctx.accounts.player_seat;
+
+    // --- Validation ---
+    require_keys_eq!(
+        player_seat.player_pubkey,
+        ctx.accounts.player.key(),
+        AcesUnknownErrorCode::PlayerNotFound
+    );
+
+    player_seat.sit_out_next_blind = sit_out;
+
+    Ok(())
+}
+
+/// The context struct for the `set_sit_out_next_blind` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct SetSitOutNextBlind<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+
+    pub player: Signer<'info>,
+
+    /// The player's seat account.
+    #[account(
+        mut,
+        seeds = [b"player_seat", table.key().as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
+        bump = player_seat.bump,
+    )]
+    pub player_seat: Account<'info, PlayerSeat>,
+}
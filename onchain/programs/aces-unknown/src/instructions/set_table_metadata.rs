@@ -0,0 +1,63 @@
+//! src/instructions/set_table_metadata.rs
+//!
+//! @description
+//! This instruction allows the creator of a table to change its discoverability
+//! metadata: a fixed-size name and the hash of an off-chain description. Neither
+//! affects play, so the metadata can be changed at any time, even mid-hand.
+//!
+//! @accounts
+//! - `table`: The `Table` account whose metadata is updated.
+//! - `creator`: The table creator, who must sign.
+//!
+//! @logic
+//! 1. Verifies the signer is the table creator.
+//! 2. Replaces the table's name and description hash.
+//! 3. Emits `TableMetadataUpdated` for indexers.
+
+use anchor_lang::prelude::*;
+use crate::state::Table;
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for updating a table's name and description hash.
+pub fn set_table_metadata(
+    ctx: Context<SetTableMetadata>,
+    _table_id: u64,
+    name: [u8; 32],
+    description_hash: [u8; 32],
+) -> Result<()> {
+    let table = &mut ctx.accounts.table;
+    table.name = name;
+    table.description_hash = description_hash;
+
+    emit!(TableMetadataUpdated {
+        table_id: table.table_id,
+        name,
+        description_hash,
+    });
+
+    Ok(())
+}
+
+/// The context struct for the `set_table_metadata` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct SetTableMetadata<'info> {
+    /// The table account whose metadata is being changed.
+    #[account(
+        mut,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = table.creator == creator.key() @ AcesUnknownErrorCode::NotTableCreator,
+    )]
+    pub table: Account<'info, Table>,
+
+    /// The creator of the table.
+    pub creator: Signer<'info>,
+}
+
+#[event]
+pub struct TableMetadataUpdated {
+    pub table_id: u64,
+    pub name: [u8; 32],
+    pub description_hash: [u8; 32],
+}
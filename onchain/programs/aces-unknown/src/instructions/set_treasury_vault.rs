@@ -0,0 +1,95 @@
+//! src/instructions/set_treasury_vault.rs
+//!
+//! @description
+//! This instruction allows the platform administrator to move the treasury, the token
+//! account every table's rake is paid into, to a new vault (for example to rotate the
+//! key that controls it). Since every rake transfer and rake-back claim follows
+//! `platform_config.treasury_vault`, the new vault is checked before it is accepted.
+//!
+//! @accounts
+//! - `platform_config`: The global platform configuration whose treasury is replaced.
+//! - `admin`: The platform administrator.
+//! - `new_treasury_vault`: The token account that will collect rake from now on.
+//! - `new_treasury_authority`: The new vault's owner, who must co-sign to show the
+//!   vault can be paid out of (rake-back claims are signed by the treasury's owner).
+//!
+//! @logic
+//! 1. Verifies the new vault is a token account other than the current treasury, with
+//!    no delegate or close authority that could move or close it behind its owner.
+//! 2. If `required_mint` is given, verifies the new vault holds that mint.
+//! 3. Points `platform_config.treasury_vault` at the new vault and emits
+//!    `TreasuryVaultChanged`.
+//!
+//! @security
+//! This instruction is secured by an `address` constraint on the `admin` account,
+//! ensuring that only the wallet public key stored in `platform_config.admin` can
+//! sign and successfully execute this transaction.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::PlatformConfig;
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for moving the platform treasury to a new vault.
+///
+/// # Arguments
+/// * `ctx` - The context containing the required accounts.
+/// * `required_mint` - If set, the mint the new vault must hold.
+pub fn set_treasury_vault(ctx: Context<SetTreasuryVault>, required_mint: Option<Pubkey>) -> Result<()> {
+    let new_vault = &ctx.accounts.new_treasury_vault;
+
+    // --- Validation ---
+    require_keys_neq!(
+        new_vault.key(),
+        ctx.accounts.platform_config.treasury_vault,
+        AcesUnknownErrorCode::InvalidAction
+    );
+    // Nobody but the vault's owner may be able to move or close the collected rake.
+    require!(
+        new_vault.delegate.is_none() && new_vault.close_authority.is_none(),
+        AcesUnknownErrorCode::InvalidAction
+    );
+    if let Some(mint) = required_mint {
+        require_keys_eq!(new_vault.mint, mint, AcesUnknownErrorCode::InvalidTokenMint);
+    }
+
+    let platform_config = &mut ctx.accounts.platform_config;
+    let previous_vault = platform_config.treasury_vault;
+    platform_config.treasury_vault = new_vault.key();
+
+    emit!(TreasuryVaultChanged {
+        previous_vault,
+        new_vault: new_vault.key(),
+        authority: new_vault.owner,
+    });
+
+    msg!("Treasury vault changed from {} to {}", previous_vault, new_vault.key());
+
+    Ok(())
+}
+
+/// The context struct for the `set_treasury_vault` instruction.
+#[derive(Accounts)]
+pub struct SetTreasuryVault<'info> {
+    /// The platform configuration account to be modified.
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// The administrator's signer account.
+    #[account(address = platform_config.admin)]
+    pub admin: Signer<'info>,
+
+    /// The token account that becomes the treasury.
+    pub new_treasury_vault: Account<'info, TokenAccount>,
+
+    /// The owner of the new treasury vault.
+    #[account(address = new_treasury_vault.owner @ AcesUnknownErrorCode::Unauthorized)]
+    pub new_treasury_authority: Signer<'info>,
+}
+
+#[event]
+pub struct TreasuryVaultChanged {
+    pub previous_vault: Pubkey,
+    pub new_vault: Pubkey,
+    pub authority: Pubkey,
+}
@@ -0,0 +1,95 @@
+//! src/instructions/snapshot_table.rs
+//!
+//! @description
+//! This read-only instruction emits the public state of a table and every seat at it
+//! in a single `TableSnapshot` event, so light clients and block explorers can render
+//! the table from one transaction instead of fetching the `Table` and each
+//! `PlayerSeat` account separately.
+//!
+//! @accounts
+//! - `table`: The table to snapshot.
+//! - `payer`: Any signer; taking a snapshot is permissionless.
+//! - `remaining_accounts`: The table's occupied `PlayerSeat` accounts.
+//!
+//! @logic
+//! 1. Loads and validates the occupied seats, so the snapshot covers every one of them.
+//! 2. Emits `TableSnapshot` with the hand's progress (phase, round, turn and its
+//!    deadline), the pot and its side pots, the board, and each seat's stack and bets.
+
+use anchor_lang::prelude::*;
+use crate::state::{BettingRound, Card, GameState, HandPhase, PlayerSeatInfo, PotInfo, Table};
+use crate::state::constants::MAX_PLAYERS;
+use crate::utils::{load_table_seats, require_all_occupied_seats};
+
+/// Instruction logic for emitting a snapshot of the table.
+/// The occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+pub fn snapshot_table<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SnapshotTable<'info>>,
+    _table_id: u64,
+) -> Result<()> {
+    let table = &ctx.accounts.table;
+    let seats = load_table_seats(&table.key(), ctx.remaining_accounts)?;
+    require_all_occupied_seats(table, seats.iter().map(|seat| seat.seat_index))?;
+
+    let mut seat_infos = [None; MAX_PLAYERS];
+    for seat in seats.iter() {
+        seat_infos[seat.seat_index as usize] = Some(PlayerSeatInfo {
+            pubkey: seat.player_pubkey,
+            stack: seat.stack,
+            is_active_in_hand: seat.is_active_in_hand,
+            is_all_in: seat.is_all_in,
+            bet_this_round: seat.bet_this_round,
+            total_bet_this_hand: seat.total_bet_this_hand,
+        });
+    }
+
+    emit!(TableSnapshot {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        game_state: table.game_state,
+        hand_phase: table.hand_phase,
+        betting_round: table.betting_round,
+        dealer_position: table.dealer_position,
+        turn_position: table.turn_position,
+        turn_deadline: table.turn_deadline,
+        pot: table.pot,
+        pots: table.pots.clone(),
+        current_bet: table.current_bet,
+        community_cards: table.community_cards,
+        seats: seat_infos,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct SnapshotTable<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+    pub payer: Signer<'info>,
+}
+
+/// The public state of a table and its seats at the time of the snapshot.
+#[event]
+pub struct TableSnapshot {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub game_state: GameState,
+    pub hand_phase: HandPhase,
+    pub betting_round: BettingRound,
+    pub dealer_position: u8,
+    pub turn_position: u8,
+    pub turn_deadline: i64,
+    pub pot: u64,
+    /// The main pot and side pots, empty while every player in the hand contests the
+    /// whole pot (see `Table::pots`).
+    pub pots: Vec<PotInfo>,
+    pub current_bet: u64,
+    pub community_cards: [Option<Card>; 5],
+    /// Each seat's player, stack and bets, indexed by seat; `None` for an empty seat.
+    pub seats: [Option<PlayerSeatInfo>; MAX_PLAYERS],
+}
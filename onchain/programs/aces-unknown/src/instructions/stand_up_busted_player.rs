@@ -0,0 +1,99 @@
+//! src/instructions/stand_up_busted_player.rs
+//!
+//! @description
+//! This instruction frees the seat of a player who has busted, so the seat can be
+//! taken by someone else. A busted player has nothing left to cash out, so anyone
+//! may stand them up between hands. The seat account is closed and its rent is
+//! refunded to the player.
+//!
+//! @accounts
+//! - `table`: The `Table` account the busted player is seated at.
+//! - `player_seat`: The busted player's seat account, closed by this instruction.
+//! - `player`: The busted player's wallet, which receives the seat's rent.
+//! - `player_active_tables`: The busted player's `PlayerActiveTables` account.
+//! - `payer`: Any signer.
+//!
+//! @logic
+//! 1. Verifies no hand is in progress and the seat belongs to the table and player.
+//! 2. Verifies the player is busted: no chips left and sitting out (cash) or
+//!    finished (tournament).
+//! 3. Frees the seat if it still counts as occupied (a tournament seat is freed at showdown).
+//! 4. Closes the `PlayerSeat` account, decrements the player's count of active tables,
+//!    and emits `PlayerLeft`.
+
+use anchor_lang::prelude::*;
+use crate::state::{GameState, PlayerActiveTables, PlayerSeat, Table};
+use crate::error::AcesUnknownErrorCode;
+use crate::instructions::leave_table::PlayerLeft;
+use crate::utils::require_seat_accounting_consistent;
+
+/// The instruction logic for standing up a busted player.
+pub fn stand_up_busted_player(ctx: Context<StandUpBustedPlayer>, table_id: u64) -> Result<()> {
+    let table = &mut ctx.accounts.table;
+    let player_seat = &ctx.accounts.player_seat;
+
+    // --- Validation ---
+    require!(
+        table.game_state != GameState::HandInProgress,
+        AcesUnknownErrorCode::CannotLeaveMidHand
+    );
+    require_keys_eq!(
+        player_seat.table_pubkey,
+        table.key(),
+        AcesUnknownErrorCode::SeatTableMismatch
+    );
+    require_keys_eq!(
+        player_seat.player_pubkey,
+        ctx.accounts.player.key(),
+        AcesUnknownErrorCode::SeatOwnerMismatch
+    );
+    require!(player_seat.is_busted(), AcesUnknownErrorCode::PlayerNotBusted);
+
+    // --- State Update ---
+    table.vacate_seat(player_seat.seat_index);
+    require_seat_accounting_consistent(table)?;
+    ctx.accounts.player_active_tables.record_leave();
+
+    emit!(PlayerLeft {
+        table_id,
+        seat_index: player_seat.seat_index,
+        player: player_seat.player_pubkey,
+        cash_out_amount: 0,
+    });
+    Ok(())
+}
+
+/// The context struct for the `stand_up_busted_player` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct StandUpBustedPlayer<'info> {
+    #[account(
+        mut,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub table: Account<'info, Table>,
+
+    /// The busted player's seat account, closed to the player.
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"player_seat", player_seat.table_pubkey.as_ref(), player_seat.seat_index.to_le_bytes().as_ref()],
+        bump = player_seat.bump,
+    )]
+    pub player_seat: Account<'info, PlayerSeat>,
+
+    /// CHECK: Only receives the seat's rent; checked against `player_seat.player_pubkey`.
+    #[account(mut)]
+    pub player: UncheckedAccount<'info>,
+
+    /// The busted player's count of tables they are seated at.
+    #[account(
+        mut,
+        seeds = [b"player_active_tables", player.key().as_ref()],
+        bump = player_active_tables.bump,
+    )]
+    pub player_active_tables: Account<'info, PlayerActiveTables>,
+
+    pub payer: Signer<'info>,
+}
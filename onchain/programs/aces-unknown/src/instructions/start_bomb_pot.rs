@@ -0,0 +1,43 @@
+//! src/instructions/start_bomb_pot.rs
+//!
+//! @description
+//! This instruction starts a "bomb pot": a hand with no blinds and no preflop betting.
+//! Every player dealt in posts the table's `bomb_pot_ante`, hole cards and the flop are
+//! dealt together, and the first betting round is on the flop. It runs the same
+//! shuffle/deal and reveal steps as `start_hand` followed by `deal_community_cards`,
+//! just back to back. Tables opt in by setting `TableSettings::bomb_pot_ante`.
+//!
+//! @accounts
+//! - Same as `start_hand`. `payer` must be the table creator, who decides when a
+//!   bomb pot is played.
+//!
+//! @logic
+//! 1. Verifies the payer is the table creator and the table has a bomb-pot ante.
+//! 2. Validates, moves the button, and creates the `HandData` and `EncryptedHand`
+//!    accounts exactly as `start_hand` does.
+//! 3. Collects the ante from every player dealt in (all-in if they are short), emitting
+//!    `AntePosted` for each and `BombPotStarted` with the pot.
+//! 4. Deals the flop and opens betting on it with the first player left of the button.
+//!    If the antes left fewer than two players able to bet, the board is run out instead.
+
+use anchor_lang::prelude::*;
+use crate::error::AcesUnknownErrorCode;
+use crate::instructions::start_hand::{deal_new_hand, StartHand};
+
+/// Instruction logic for starting a bomb pot hand.
+pub fn start_bomb_pot<'info>(
+    ctx: Context<'_, '_, 'info, 'info, StartHand<'info>>,
+    _table_id: u64,
+) -> Result<()> {
+    let table = &ctx.accounts.table;
+
+    // --- Validation ---
+    require_keys_eq!(
+        ctx.accounts.payer.key(),
+        table.creator,
+        AcesUnknownErrorCode::NotTableCreator
+    );
+    require!(table.settings.bomb_pot_ante > 0, AcesUnknownErrorCode::BombPotsDisabled);
+
+    deal_new_hand(ctx, _table_id, true)
+}
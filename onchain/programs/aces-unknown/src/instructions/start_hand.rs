@@ -11,28 +11,84 @@
 //! @accounts
 //! - `table`: The poker table account where the hand is being started.
 //! - `payer`: The player initiating the transaction. Any player can start a hand.
-//! - `hand_data`: A new account initialized to store encrypted hand details.
+//! - `hand_data`: A new account initialized to store encrypted hand details, derived
+//!   from the new hand's id (`hand_id_counter + 1`) and recording that id.
+//! - `remaining_accounts`: The writable `PlayerSeat` accounts for every occupied seat,
+//!   followed by the writable `EncryptedHand` PDA of each of those players, in the
+//!   same order. Only the PDAs of players dealt in are created.
 //! - Arcium-related accounts for queuing the `shuffle_and_deal` computation.
 //!
 //! @logic
 //! 1. Validates game state (`WaitingForPlayers` or `HandComplete`) and player count (>= 2).
-//! 2. Rotates the dealer button to the next active player.
-//! 3. Identifies the small blind (SB) and big blind (BB) positions based on standard poker rules.
+//!    After a completed hand, the table's `min_seconds_between_hands` must have elapsed.
+//! 2. On the table's first hand, draws the dealer button among the seated players from
+//!    the `shuffle_and_deal` circuit's public RNG draw (simulated until the Arcium integration
+//!    is set up); on later hands, rotates it to the next occupied seat. Busted players and
+//!    players sitting out are skipped: the button moves on to the next seat that can
+//!    play rather than staying dead on an absent player's seat.
+//! 3. Identifies the small blind (SB) and big blind (BB) positions based on standard poker
+//!    rules, over the same set of seats.
 //! 4. Deducts blind amounts from the SB and BB players' stacks and adds them to the pot.
-//! 5. Prepares inputs for the Arcium `shuffle_and_deal` circuit, including player public keys.
-//! 6. Calls `queue_computation` to start the confidential shuffle and deal process.
-//! 7. The `start_hand_callback` receives the encrypted results, populates the `HandData`
+//!    A player who cannot cover their blind posts all-in for their remaining stack; the
+//!    amount to call is still the full big blind and a side pot forms at showdown.
+//!    With `ShortBlindPolicy::SitOut`, a short blind sits the hand out instead: the big
+//!    blind passes to the next seat that can cover it, and a short small blind is dead.
+//!    A player who set `sit_out_next_blind` sits out the same way for one hand.
+//!    A newcomer waiting for the big blind is dealt in when it reaches their seat, and
+//!    players who used `post_entry_blind` post a dead and a live big blind.
+//!    With `AnteMode::BigBlindAnte`, the button first posts a single ante for the table
+//!    (one big blind unless `ante_amount` says otherwise), all-in if they are short.
+//! 5. Creates an `EncryptedHand` account for every player dealt in, to hold their hole cards.
+//!    Each records the nonce the player's cards are encrypted with, which must differ
+//!    from the player's recent hands' nonces (`NonceReused`); it is kept on their seat.
+//! 6. Prepares inputs for the Arcium `shuffle_and_deal` circuit, including player public keys.
+//! 7. Calls `queue_computation` to start the confidential shuffle and deal process.
+//! 8. The `start_hand_callback` receives the encrypted results, populates the `HandData`
 //!    account, sets the game state to `HandInProgress`, and sets the turn to the first player to act.
+//!    The hand is in `HandPhase::Dealing` while the computation is queued and moves to
+//!    `HandPhase::Betting` once the callback has dealt the cards.
+//!    The circuit's `cards_dealt` must equal two cards per player dealt in; it is stored in
+//!    `HandData` so community cards are drawn from after the hole cards. The table's
+//!    `DealStyle` is recorded alongside it: a round-robin or sequential deal uses the
+//!    same number of cards, but puts each seat's hole cards at different positions.
+//!    If the circuit reports that a dealt hole card was invalid or dealt twice, the
+//!    hand is a misdeal: the blinds and antes are refunded, `Misdeal` is emitted, and
+//!    the table is left ready for a fresh `start_hand`.
+//!
+//! `start_bomb_pot` shares this logic (`deal_new_hand`), collecting an ante from every
+//! player dealt in instead of the blinds and dealing the flop along with the hole cards.
 
 use anchor_lang::prelude::*;
 use anchor_lang::Discriminator;
-use crate::state::{Table, HandData, GameState};
+use anchor_lang::solana_program::hash::hashv;
+use crate::state::{
+    AnteMode, BettingRound, DealStyle, Table, HandData, GameState, HandPhase, PlayerSeat, ShortBlindPolicy,
+    DECK_SIZE, INVALID_CARD_INDEX,
+};
 use crate::error::AcesUnknownErrorCode;
 use crate::state::constants::MAX_PLAYERS;
+use crate::instructions::deal_community_cards::{
+    append_community_cards, next_street, parse_revealed_cards, simulate_street_reveal, CommunityCardsDealt,
+};
+use crate::instructions::player_action::betting_is_closed;
+use crate::instructions::show_one_card::dealt_deck_position;
+use crate::utils::{create_encrypted_hand, declare_misdeal, load_table_seats, next_actor_after, persist_seats, recompute_pots, require_all_occupied_seats, seat_at_mut, shuffle_commitment};
 
 
 /// Instruction logic for starting a new hand.
-pub fn start_hand(ctx: Context<StartHand>, _table_id: u64) -> Result<()> {
+pub fn start_hand<'info>(
+    ctx: Context<'_, '_, 'info, 'info, StartHand<'info>>,
+    _table_id: u64,
+) -> Result<()> {
+    deal_new_hand(ctx, _table_id, false)
+}
+
+/// Starts a hand for `start_hand`, or for `start_bomb_pot` when `bomb_pot` is set.
+pub(crate) fn deal_new_hand<'info>(
+    ctx: Context<'_, '_, 'info, 'info, StartHand<'info>>,
+    _table_id: u64,
+    bomb_pot: bool,
+) -> Result<()> {
     let table = &mut ctx.accounts.table;
 
     // --- Validation ---
@@ -41,49 +97,165 @@ pub fn start_hand(ctx: Context<StartHand>, _table_id: u64) -> Result<()> {
         table.game_state == GameState::WaitingForPlayers || table.game_state == GameState::HandComplete,
         AcesUnknownErrorCode::InvalidGameState
     );
+    table.require_phase(HandPhase::Complete)?;
     require!(
         table.player_count >= 2,
         AcesUnknownErrorCode::NotEnoughPlayers
     );
+    if table.game_state == GameState::HandComplete {
+        let ready_at = table.last_hand_completed_at + table.settings.min_seconds_between_hands as i64;
+        require!(
+            Clock::get()?.unix_timestamp >= ready_at,
+            AcesUnknownErrorCode::HandStartTooSoon
+        );
+    }
+
+    let seat_count = table.occupied_seats.count_ones() as usize;
+    require!(
+        ctx.remaining_accounts.len() == seat_count * 2,
+        AcesUnknownErrorCode::InvalidSeatAccounts
+    );
+    let (seat_accounts, encrypted_hand_accounts) = ctx.remaining_accounts.split_at(seat_count);
+    let mut seats = load_table_seats(&table.key(), seat_accounts)?;
+    require_all_occupied_seats(table, seats.iter().map(|seat| seat.seat_index))?;
 
     // --- Reset Table for New Hand ---
     table.pot = 0;
     table.current_bet = 0;
+    table.last_raise_size = 0;
     table.community_cards = [None; 5];
-    table.hand_id_counter = table.hand_id_counter.checked_add(1).unwrap();
+    table.hand_id_counter = table.hand_id_counter.checked_add(1).ok_or(AcesUnknownErrorCode::InvalidGameState)?;
     table.last_aggressor_position = 0; // Reset for new hand
+    table.acted_since_full_raise = 0;
+    table.ready_for_runout = false;
+    table.pots.clear();
 
-    // Note: Player seat data is now stored in separate PlayerSeat accounts
-    // The individual PlayerSeat accounts will be updated in a separate instruction
-    // or through a callback that has access to all the PlayerSeat accounts
-
-    // --- Rotate Dealer Button ---
-    let mut next_dealer_pos = (table.dealer_position + 1) % MAX_PLAYERS as u8;
-    while (table.occupied_seats & (1 << next_dealer_pos)) == 0 {
-        next_dealer_pos = (next_dealer_pos + 1) % MAX_PLAYERS as u8;
+    for seat in seats.iter_mut() {
+        seat.reset_for_new_hand();
     }
-    table.dealer_position = next_dealer_pos;
-    msg!("start_hand: dealer rotated to {}", table.dealer_position);
 
-    // --- Identify Blinds ---
-    let (sb_pos, bb_pos, first_to_act_pos) = find_blinds_and_first_actor(table)?;
-    msg!("start_hand: blinds: SB={}, BB={}, First={}", sb_pos, bb_pos, first_to_act_pos);
+    // Busted and sitting-out players keep their seats but take no part in the
+    // rotation: the button and blinds move past them.
+    let in_rotation = rotation_seats(&seats);
+    require!(in_rotation.count_ones() >= 2, AcesUnknownErrorCode::NotEnoughPlayers);
 
-    // --- Collect Blinds ---
-    // Note: We can't directly modify the player's stack and bet information
-    // because they're stored in the compact PlayerSeatInfo struct
-    // In a real implementation, we would need to update the full player data
-    // in a separate account or use a different approach
+    // --- Position Dealer Button ---
+    if table.first_hand_dealt {
+        let mut next_dealer_pos = (table.dealer_position + 1) % MAX_PLAYERS as u8;
+        while (in_rotation & (1 << next_dealer_pos)) == 0 {
+            next_dealer_pos = (next_dealer_pos + 1) % MAX_PLAYERS as u8;
+        }
+        table.dealer_position = next_dealer_pos;
+    } else {
+        // The creator always sits down first, so a fixed starting seat would hand them
+        // a predictable position. Draw the first button among the seated players instead,
+        // with the public random draw the `shuffle_and_deal` circuit makes alongside the
+        // shuffle, which nobody at the table can predict or time.
+        // TODO: Once the shuffle is queued, place the button and post the blinds in its callback.
+        let button_draw = simulate_button_draw(&ctx.accounts.hand_data.key());
+        table.dealer_position = draw_first_button(in_rotation, button_draw);
+        table.first_hand_dealt = true;
+    }
+    msg!("start_hand: dealer button at {}", table.dealer_position);
 
-    table.current_bet = table.big_blind;
+    // --- Collect Forced Bets ---
+    // A bomb pot has no blinds and no preflop round: everyone dealt in antes instead.
+    let first_to_act_pos = if bomb_pot {
+        post_bomb_pot_antes(table, &mut seats)?;
+        None
+    } else {
+        Some(post_blinds(table, &mut seats, in_rotation)?)
+    };
+    persist_seats(&seats)?;
+    if seats.iter().any(|seat| seat.is_all_in) {
+        let seat_refs: Vec<&PlayerSeat> = seats.iter().map(|seat| &**seat).collect();
+        recompute_pots(table, &seat_refs);
+    }
     msg!("start_hand: blinds collected, pot={}", table.pot);
 
     // TODO: Add Arcium computation queuing once Arcium integration is properly set up
+    table.hand_phase = HandPhase::Dealing;
+
+    let dealt_in = seats.iter().filter(|seat| seat.is_active_in_hand).count();
 
-    // For now, just set the table state and turn
-    table.turn_position = first_to_act_pos;
-    table.turn_started_at = Clock::get()?.unix_timestamp;
+    // --- Create Encrypted Hand Accounts ---
+    // The `shuffle_and_deal` callback writes each player's hole-card ciphertext here.
+    // Every hand encrypts under a fresh nonce, checked against the player's recent ones.
+    let hand_key = ctx.accounts.hand_data.key();
+    for (seat, encrypted_hand_info) in seats.iter_mut().zip(encrypted_hand_accounts.iter()) {
+        if seat.is_active_in_hand {
+            let nonce = simulate_hand_nonce(&hand_key, &seat.player_pubkey);
+            seat.record_hand_nonce(nonce)?;
+            create_encrypted_hand(
+                &ctx.accounts.payer,
+                &ctx.accounts.system_program,
+                encrypted_hand_info,
+                &hand_key,
+                &seat.player_pubkey,
+                nonce,
+            )?;
+        }
+    }
+    persist_seats(&seats)?;
+
+    // Simulate the circuit's count of cards consumed by the deal (two per player dealt in).
+    let cards_dealt = (dealt_in * 2) as u8;
+    let hand_data = &mut ctx.accounts.hand_data;
+    hand_data.table_pubkey = table.key();
+    hand_data.hand_id = table.hand_id_counter;
+    hand_data.cards_dealt = parse_cards_dealt(cards_dealt, dealt_in)?;
+    hand_data.dealt_in_seats = seats
+        .iter()
+        .filter(|seat| seat.is_active_in_hand)
+        .fold(0u8, |mask, seat| mask | 1 << seat.seat_index);
+    hand_data.deal_style = table.settings.deal_style;
+    // Simulate the circuit's shuffle commitment: the simulated deck is in order, committed
+    // to with an all-zero salt so the hand can be verified once it is over.
+    let simulated_deck: [u8; 52] = std::array::from_fn(|i| i as u8);
+    hand_data.shuffle_commitment = shuffle_commitment(&simulated_deck, &[0; 32]);
+
+    // The circuit checks that every hole card is a real card dealt once.
+    // A malformed deck cannot be played; the hand is voided before anyone acts.
+    let hole_cards = simulate_hole_cards(hand_data.dealt_in_seats, hand_data.deal_style);
+    if !deal_is_valid(&hole_cards, hand_data.dealt_in_seats) {
+        declare_misdeal(table, &mut seats, Clock::get()?.unix_timestamp)?;
+        return persist_seats(&seats);
+    }
+
+    match first_to_act_pos {
+        Some(first_to_act_pos) => {
+            // UTG may optionally straddle before anyone acts (3+ players only).
+            table.straddle_window_open = table.settings.allow_straddle && dealt_in >= 3;
+
+            // For now, just set the table state and turn. Seats still sitting out, or already
+            // all-in from the ante, are skipped.
+            let seat_refs: Vec<&PlayerSeat> = seats.iter().map(|seat| &**seat).collect();
+            let first_seat_cannot_act = seat_refs.iter().any(|seat| {
+                seat.seat_index == first_to_act_pos && (!seat.is_active_in_hand || seat.is_all_in)
+            });
+            table.turn_position = if first_seat_cannot_act {
+                next_actor_after(first_to_act_pos, &seat_refs).unwrap_or(first_to_act_pos)
+            } else {
+                first_to_act_pos
+            };
+        }
+        None => {
+            table.straddle_window_open = false;
+            if !deal_bomb_pot_flop(table, &seats, cards_dealt)? {
+                // Everyone but one player is all-in from the ante; nobody can bet.
+                table.ready_for_runout = true;
+            }
+        }
+    }
+    let now = Clock::get()?.unix_timestamp;
+    table.start_turn_timer(now);
+    table.record_activity(now);
     table.game_state = GameState::HandInProgress;
+    // The shuffle callback opens preflop betting (flop betting in a bomb pot).
+    table.hand_phase = HandPhase::Betting;
+    if table.ready_for_runout {
+        table.close_betting_round();
+    }
 
     // Emit event for clients
     emit!(HandStarted {
@@ -94,20 +266,254 @@ pub fn start_hand(ctx: Context<StartHand>, _table_id: u64) -> Result<()> {
     Ok(())
 }
 
-/// Helper function to find blind and first actor positions.
-fn find_blinds_and_first_actor(table: &Account<Table>) -> Result<(u8, u8, u8)> {
+/// Simulates the nonce the `shuffle_and_deal` callback reports for a player's hole-card
+/// encryption until the Arcium integration is set up: derived from the hand's account
+/// and the player, so it differs from hand to hand.
+fn simulate_hand_nonce(hand_key: &Pubkey, player: &Pubkey) -> u128 {
+    let hash = hashv(&[b"hand_nonce", hand_key.as_ref(), player.as_ref()]).to_bytes();
+    u128::from_le_bytes(hash[..16].try_into().unwrap())
+}
+
+/// Simulates the `shuffle_and_deal` circuit's public `button_draw` until the Arcium
+/// integration is set up: derived from the hand's account, like the simulated nonces.
+fn simulate_button_draw(hand_key: &Pubkey) -> u64 {
+    let hash = hashv(&[b"button_draw", hand_key.as_ref()]).to_bytes();
+    u64::from_le_bytes(hash[..8].try_into().unwrap())
+}
+
+/// Simulates the hole cards `shuffle_and_deal` deals until the Arcium integration is set
+/// up: the simulated deck is in order, so each card is the index of the deck position it
+/// was dealt from. Seats not dealt in hold `INVALID_CARD_INDEX`.
+pub(crate) fn simulate_hole_cards(dealt_in_seats: u8, deal_style: DealStyle) -> [[u8; 2]; MAX_PLAYERS] {
+    std::array::from_fn(|seat| {
+        [0, 1].map(|card_index| {
+            dealt_deck_position(dealt_in_seats, deal_style, seat as u8, card_index).unwrap_or(INVALID_CARD_INDEX)
+        })
+    })
+}
+
+/// The circuit's check of a deal: every hole card of a seat dealt in is a real card, and
+/// no card was dealt twice. Anything else is a misdeal.
+pub(crate) fn deal_is_valid(hole_cards: &[[u8; 2]; MAX_PLAYERS], dealt_in_seats: u8) -> bool {
+    let dealt: Vec<u8> = (0..MAX_PLAYERS)
+        .filter(|seat| dealt_in_seats & (1 << seat) != 0)
+        .flat_map(|seat| hole_cards[seat])
+        .collect();
+    dealt
+        .iter()
+        .enumerate()
+        .all(|(i, card)| *card < DECK_SIZE && !dealt[..i].contains(card))
+}
+
+/// Identifies the blinds and collects them, along with any ante and entry blinds.
+/// Returns the seat first to act preflop.
+fn post_blinds(table: &mut Table, seats: &mut [Account<PlayerSeat>], in_rotation: u8) -> Result<u8> {
+    // --- Identify Blinds ---
+    let (sb_pos, mut bb_pos, mut first_to_act_pos) = find_blinds_and_first_actor(table, in_rotation)?;
+    msg!("start_hand: blinds: SB={}, BB={}, First={}", sb_pos, bb_pos, first_to_act_pos);
+
+    // A newcomer waiting for the big blind is dealt in once it reaches them.
+    let bb_seat = seat_at_mut(seats, bb_pos)?;
+    if bb_seat.waiting_for_big_blind || bb_seat.entry_blind_pending {
+        bb_seat.waiting_for_big_blind = false;
+        bb_seat.entry_blind_pending = false;
+        bb_seat.is_active_in_hand = bb_seat.stack > 0;
+    }
+
+    // A blind who cannot cover their blind posts all-in below, unless the table
+    // prefers to sit them out for the hand. A player who asked to skip their next
+    // blind sits out whichever blind reaches them, and is dealt in again afterward.
+    let short_blind_sits_out = table.settings.short_blind_policy == ShortBlindPolicy::SitOut;
+    let sits_out = |seat: &PlayerSeat, blind: u64| {
+        seat.is_active_in_hand
+            && (seat.sit_out_next_blind || (short_blind_sits_out && seat.stack < blind))
+    };
+    let mut anyone_sat_out = false;
+    let sb_seat = seat_at_mut(seats, sb_pos)?;
+    if sits_out(&**sb_seat, table.small_blind) {
+        sb_seat.is_active_in_hand = false;
+        sb_seat.sit_out_next_blind = false;
+        anyone_sat_out = true;
+        emit!(PlayerSatOut {
+            table_id: table.table_id,
+            hand_id: table.hand_id_counter,
+            seat_index: sb_pos,
+        });
+    }
+
+    // The big blind passes along to the next seat that posts it.
+    let mut bb_moved = false;
+    for _ in 0..MAX_PLAYERS {
+        let bb_seat = seat_at_mut(seats, bb_pos)?;
+        if !sits_out(&**bb_seat, table.big_blind) {
+            break;
+        }
+        bb_seat.is_active_in_hand = false;
+        bb_seat.sit_out_next_blind = false;
+        emit!(PlayerSatOut {
+            table_id: table.table_id,
+            hand_id: table.hand_id_counter,
+            seat_index: bb_pos,
+        });
+        let seat_refs: Vec<&PlayerSeat> = seats.iter().map(|seat| &**seat).collect();
+        bb_pos = next_actor_after(bb_pos, &seat_refs).ok_or(AcesUnknownErrorCode::NotEnoughPlayers)?;
+        bb_moved = true;
+        anyone_sat_out = true;
+    }
+    if bb_moved {
+        let seat_refs: Vec<&PlayerSeat> = seats.iter().map(|seat| &**seat).collect();
+        first_to_act_pos = next_actor_after(bb_pos, &seat_refs).unwrap_or(bb_pos);
+    }
+    if anyone_sat_out {
+        require!(
+            seats.iter().filter(|seat| seat.is_active_in_hand).count() >= 2,
+            AcesUnknownErrorCode::NotEnoughPlayers
+        );
+    }
+
+    // --- Collect Ante ---
+    // Under a big blind ante, the button posts one ante for the whole table.
+    let mut ante_posted = 0;
+    if table.settings.ante_mode == AnteMode::BigBlindAnte {
+        let ante = table.settings.big_blind_ante(table.big_blind);
+        let button_seat = seat_at_mut(seats, table.dealer_position)?;
+        if button_seat.is_active_in_hand {
+            ante_posted = button_seat.post_ante(ante)?;
+            emit!(AntePosted {
+                table_id: table.table_id,
+                hand_id: table.hand_id_counter,
+                seat_index: table.dealer_position,
+                amount: ante_posted,
+            });
+        }
+    }
+
+    // --- Collect Blinds ---
+    // A short-stacked blind posts all-in for whatever they have left. A small blind
+    // seat that is still sitting out posts nothing (a dead small blind).
+    let sb_seat = seat_at_mut(seats, sb_pos)?;
+    let sb_posted = if sb_seat.is_active_in_hand {
+        sb_seat.post_forced_bet(table.small_blind)?
+    } else {
+        0
+    };
+    let bb_posted = seat_at_mut(seats, bb_pos)?.post_forced_bet(table.big_blind)?;
+
+    // Players who paid to enter early post a dead big blind plus a live one.
+    let mut entry_posted = 0;
+    for seat in seats.iter_mut().filter(|seat| seat.entry_blind_pending) {
+        entry_posted += seat.post_entry_blind(table.big_blind)?;
+    }
+    table.pot = ante_posted + sb_posted + bb_posted + entry_posted;
+
+    // The amount to call is always the full big blind, even if the BB posted less.
+    // The difference is settled as a side pot by the payout calculation.
+    table.current_bet = table.big_blind;
+    table.last_raise_size = table.big_blind;
+    table.last_aggressor_position = bb_pos;
+
+    Ok(first_to_act_pos)
+}
+
+/// Collects the table's `bomb_pot_ante` from every player dealt in. Like any ante it is
+/// dead money, so nothing is owed to call when betting opens on the flop.
+fn post_bomb_pot_antes(table: &mut Table, seats: &mut [Account<PlayerSeat>]) -> Result<()> {
+    require!(
+        seats.iter().filter(|seat| seat.is_active_in_hand).count() >= 2,
+        AcesUnknownErrorCode::NotEnoughPlayers
+    );
+    let ante = table.settings.bomb_pot_ante;
+    let mut pot = 0;
+    for seat in seats.iter_mut().filter(|seat| seat.is_active_in_hand) {
+        let posted = seat.post_ante(ante)?;
+        pot += posted;
+        emit!(AntePosted {
+            table_id: table.table_id,
+            hand_id: table.hand_id_counter,
+            seat_index: seat.seat_index,
+            amount: posted,
+        });
+    }
+    table.pot = pot;
+    table.current_bet = 0;
+    table.last_raise_size = table.big_blind;
+    emit!(BombPotStarted {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        ante,
+        pot,
+    });
+    Ok(())
+}
+
+/// Deals a bomb pot's flop straight after the hole cards and opens betting on it,
+/// first to act left of the button. Returns `false` if fewer than two players can
+/// bet, in which case the board is simply run out.
+fn deal_bomb_pot_flop(table: &mut Table, seats: &[Account<PlayerSeat>], cards_dealt: u8) -> Result<bool> {
+    let (num_cards, deck_top_card_idx) =
+        next_street(cards_dealt as usize, 0).ok_or(AcesUnknownErrorCode::InvalidAction)?;
+    // TODO: Queue `reveal_community_cards` after the deal once Arcium integration is set up.
+    let (revealed_indices, revealed_count) = simulate_street_reveal(deck_top_card_idx, num_cards);
+    let flop = parse_revealed_cards(&revealed_indices, revealed_count, num_cards as u8)?;
+    append_community_cards(table, &flop)?;
+    table.betting_round = BettingRound::Flop;
+
+    let seat_refs: Vec<&PlayerSeat> = seats.iter().map(|seat| &**seat).collect();
+    let first_to_act = next_actor_after(table.dealer_position, &seat_refs)
+        .unwrap_or(table.dealer_position);
+    table.turn_position = first_to_act;
+    table.last_aggressor_position = first_to_act;
+
+    emit!(CommunityCardsDealt {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        cards: table.community_cards,
+    });
+    Ok(!betting_is_closed(&seat_refs, 0))
+}
+
+/// Validates the `shuffle_and_deal` count of cards consumed by the deal. Anything other
+/// than two cards per player dealt in means the deck and the board offset would disagree.
+pub(crate) fn parse_cards_dealt(cards_dealt: u8, dealt_in: usize) -> Result<u8> {
+    require!(
+        cards_dealt as usize == dealt_in * 2,
+        AcesUnknownErrorCode::DeckIntegrityViolation
+    );
+    Ok(cards_dealt)
+}
+
+/// The seats taking part in the button and blind rotation: every occupied seat
+/// except busted players and players sitting out. A newcomer waiting for the big
+/// blind stays in the rotation so the big blind can reach them.
+fn rotation_seats(seats: &[Account<PlayerSeat>]) -> u8 {
+    seats
+        .iter()
+        .filter(|seat| seat.stack > 0 && !seat.is_sitting_out)
+        .fold(0u8, |mask, seat| mask | 1 << seat.seat_index)
+}
+
+/// Helper function to find blind and first actor positions among the seats in
+/// `in_rotation`. If the dealer button sits on a seat that has left the rotation, the
+/// blinds are placed as if it were on the next seat in the rotation.
+fn find_blinds_and_first_actor(table: &Table, in_rotation: u8) -> Result<(u8, u8, u8)> {
     let mut active_indices = [0u8; MAX_PLAYERS];
     let mut num_active = 0;
     for i in 0..MAX_PLAYERS {
-        if (table.occupied_seats & (1 << i)) != 0 {
+        if (in_rotation & (1 << i)) != 0 {
             active_indices[num_active] = i as u8;
             num_active += 1;
         }
     }
-    let dealer_idx_in_active = active_indices[..num_active].iter().position(|&p| p == table.dealer_position).unwrap();
-    
+    require!(num_active >= 2, AcesUnknownErrorCode::NotEnoughPlayers);
+    // The seats are in order, so the first at or after the button is the button itself
+    // or the next seat in the rotation, wrapping around past the last seat.
+    let dealer_idx_in_active = active_indices[..num_active]
+        .iter()
+        .position(|&p| p >= table.dealer_position)
+        .unwrap_or(0);
+
     if num_active == 2 { // Heads-up case
-        let sb_pos = table.dealer_position;
+        let sb_pos = active_indices[dealer_idx_in_active];
         let bb_pos = active_indices[(dealer_idx_in_active + 1) % num_active];
         Ok((sb_pos, bb_pos, sb_pos)) // Dealer (SB) acts first pre-flop
     } else { // 3+ players
@@ -118,10 +524,15 @@ fn find_blinds_and_first_actor(table: &Account<Table>) -> Result<(u8, u8, u8)> {
     }
 }
 
-
-
-
-
+/// Picks the first dealer button from the circuit's random `button_draw`, among the
+/// seats in the rotation.
+fn draw_first_button(in_rotation: u8, button_draw: u64) -> u8 {
+    let occupied: Vec<u8> = (0..MAX_PLAYERS as u8)
+        .filter(|&i| in_rotation & (1 << i) != 0)
+        .collect();
+    let pick = button_draw % occupied.len() as u64;
+    occupied[pick as usize]
+}
 
 #[derive(Accounts)]
 #[instruction(table_id: u64)]
@@ -138,7 +549,10 @@ pub struct StartHand<'info> {
         init,
         payer = payer,
         space = 8 + HandData::INIT_SPACE,
-        seeds = [b"hand", table.key().as_ref(), table.hand_id_counter.to_le_bytes().as_ref()],
+        // The handler increments `hand_id_counter`, so the new hand's account is derived
+        // from the incremented id, as every later instruction of the hand derives it.
+        // The handler also rejects a counter that would overflow.
+        seeds = [b"hand", table.key().as_ref(), table.hand_id_counter.wrapping_add(1).to_le_bytes().as_ref()],
         bump
     )]
     pub hand_data: Account<'info, HandData>,
@@ -152,3 +566,109 @@ pub struct HandStarted {
     pub table_id: u64,
     pub hand_id: u64,
 }
+
+#[event]
+pub struct PlayerSatOut {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+}
+
+#[event]
+pub struct AntePosted {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BombPotStarted {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub ante: u64,
+    pub pot: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_first_button_is_drawn_among_the_seats_in_rotation() {
+        // Seats 1, 3 and 4: the draw picks among them in seat order.
+        assert_eq!(draw_first_button(0b011010, 0), 1);
+        assert_eq!(draw_first_button(0b011010, 1), 3);
+        assert_eq!(draw_first_button(0b011010, 2), 4);
+        assert_eq!(draw_first_button(0b011010, u64::MAX), 1);
+        // A lone seat in rotation always gets the button.
+        assert_eq!(draw_first_button(0b100000, 7), 5);
+    }
+
+    fn table_with_button(dealer_position: u8) -> Table {
+        let mut table = Table::deserialize(&mut &vec![0u8; Table::INIT_SPACE][..]).unwrap();
+        table.dealer_position = dealer_position;
+        table
+    }
+
+    #[test]
+    fn the_blinds_follow_the_button_around_the_rotation() {
+        // Seats 0, 1, 3 and 4 with the button on seat 1.
+        assert_eq!(find_blinds_and_first_actor(&table_with_button(1), 0b011011).unwrap(), (3, 4, 0));
+        // Heads-up, the button is the small blind and acts first preflop.
+        assert_eq!(find_blinds_and_first_actor(&table_with_button(3), 0b001010).unwrap(), (3, 1, 3));
+    }
+
+    #[test]
+    fn a_button_on_a_seat_out_of_the_rotation_plays_from_the_next_seat() {
+        // Seat 2 has left the rotation: the blinds are placed as if seat 3 had the button.
+        assert_eq!(find_blinds_and_first_actor(&table_with_button(2), 0b011011).unwrap(), (4, 0, 1));
+        // Past the last seat in the rotation, the button wraps around to the first.
+        assert_eq!(find_blinds_and_first_actor(&table_with_button(5), 0b000111).unwrap(), (1, 2, 0));
+        // Heads-up, the next seat is the small blind.
+        assert_eq!(find_blinds_and_first_actor(&table_with_button(1), 0b000101).unwrap(), (2, 0, 2));
+    }
+
+    #[test]
+    fn blinds_need_two_seats_in_the_rotation() {
+        for in_rotation in [0, 0b000100] {
+            let res = find_blinds_and_first_actor(&table_with_button(2), in_rotation);
+            assert_eq!(res.unwrap_err(), AcesUnknownErrorCode::NotEnoughPlayers.into());
+        }
+    }
+
+    #[test]
+    fn the_simulated_deal_is_valid() {
+        for deal_style in [DealStyle::RoundRobin, DealStyle::Sequential] {
+            let hole_cards = simulate_hole_cards(0b101101, deal_style);
+            assert!(deal_is_valid(&hole_cards, 0b101101));
+            assert_eq!(hole_cards[1], [INVALID_CARD_INDEX; 2]);
+        }
+    }
+
+    #[test]
+    fn a_card_dealt_twice_is_a_misdeal() {
+        let mut hole_cards = simulate_hole_cards(0b000111, DealStyle::RoundRobin);
+        hole_cards[2][1] = hole_cards[0][0];
+        assert!(!deal_is_valid(&hole_cards, 0b000111));
+
+        // Both of one player's cards count too.
+        let mut hole_cards = simulate_hole_cards(0b000111, DealStyle::RoundRobin);
+        hole_cards[1][1] = hole_cards[1][0];
+        assert!(!deal_is_valid(&hole_cards, 0b000111));
+    }
+
+    #[test]
+    fn a_card_outside_the_deck_is_a_misdeal() {
+        let mut hole_cards = simulate_hole_cards(0b000011, DealStyle::Sequential);
+        hole_cards[1][0] = DECK_SIZE;
+        assert!(!deal_is_valid(&hole_cards, 0b000011));
+    }
+
+    #[test]
+    fn seats_not_dealt_in_are_not_checked() {
+        let mut hole_cards = simulate_hole_cards(0b000011, DealStyle::RoundRobin);
+        hole_cards[4] = hole_cards[0];
+        assert!(deal_is_valid(&hole_cards, 0b000011));
+    }
+}
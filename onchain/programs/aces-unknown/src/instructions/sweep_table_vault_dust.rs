@@ -0,0 +1,105 @@
+//! src/instructions/sweep_table_vault_dust.rs
+//!
+//! @description
+//! Once every player has left a table, its vault should be empty, but a few units can
+//! be left behind (rounding, or tokens sent to the vault directly). This lets the
+//! platform administrator move that dust to the treasury. To make sure no real chips
+//! are swept, the table must be empty and the balance must be less than one big blind.
+//!
+//! @accounts
+//! - `platform_config`: The global platform configuration, used to authorize the admin
+//!   and locate the treasury.
+//! - `admin`: The platform administrator.
+//! - `table`: The emptied table whose vault is swept.
+//! - `table_vault`: The table's token vault.
+//! - `treasury_vault`: The platform treasury, receiving the dust.
+//!
+//! @logic
+//! 1. Verifies no hand is in progress and no seat is occupied.
+//! 2. Verifies the vault holds something, and less than the table's big blind.
+//! 3. Transfers the whole balance to the treasury and emits `VaultDustSwept`.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{GameState, PlatformConfig, Table};
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for sweeping an empty table's vault dust to the treasury.
+pub fn sweep_table_vault_dust(ctx: Context<SweepTableVaultDust>, table_id: u64) -> Result<()> {
+    let table = &ctx.accounts.table;
+
+    // --- Validation ---
+    require!(
+        table.game_state != GameState::HandInProgress
+            && table.occupied_seats == 0
+            && table.player_count == 0,
+        AcesUnknownErrorCode::TableNotEmpty
+    );
+    let dust = ctx.accounts.table_vault.amount;
+    require!(dust > 0, AcesUnknownErrorCode::InvalidAction);
+    require!(dust < table.big_blind, AcesUnknownErrorCode::NotVaultDust);
+
+    // --- Sweep ---
+    let table_id_bytes = table_id.to_le_bytes();
+    let seeds = &[&b"table"[..], table_id_bytes.as_ref(), &[ctx.bumps.table]];
+    let signer_seeds = &[&seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.table_vault.to_account_info(),
+        to: ctx.accounts.treasury_vault.to_account_info(),
+        authority: table.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, dust)?;
+
+    emit!(VaultDustSwept {
+        table_id,
+        amount: dust,
+    });
+
+    msg!("Swept {} dust from table #{}'s vault", dust, table_id);
+    Ok(())
+}
+
+/// The context struct for the `sweep_table_vault_dust` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct SweepTableVaultDust<'info> {
+    /// The global platform configuration account.
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// The administrator's signer account.
+    #[account(address = platform_config.admin)]
+    pub admin: Signer<'info>,
+
+    /// The emptied table.
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub table: Account<'info, Table>,
+
+    /// The table's token vault.
+    #[account(
+        mut,
+        seeds = [b"vault", table.key().as_ref()],
+        bump,
+    )]
+    pub table_vault: Account<'info, TokenAccount>,
+
+    /// The platform treasury, receiving the dust.
+    #[account(
+        mut,
+        address = platform_config.treasury_vault,
+        constraint = treasury_vault.mint == table.token_mint @ AcesUnknownErrorCode::InvalidTokenMint,
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct VaultDustSwept {
+    pub table_id: u64,
+    pub amount: u64,
+}
@@ -0,0 +1,48 @@
+//! src/instructions/update_keeper_reward.rs
+//!
+//! @description
+//! This instruction allows the platform administrator to set the reward paid to
+//! keepers who call `force_player_fold` or `force_hand_refund`, as a share of the
+//! table's big blind. The reward is capped at `MAX_KEEPER_REWARD_BPS` so keeping
+//! a table moving never costs its players much.
+//!
+//! @security
+//! This instruction is secured by an `address` constraint on the `admin` account,
+//! ensuring that only the wallet public key stored in `platform_config.admin` can
+//! sign and successfully execute this transaction.
+
+use anchor_lang::prelude::*;
+use crate::state::PlatformConfig;
+use crate::state::constants::MAX_KEEPER_REWARD_BPS;
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for updating the keeper reward.
+///
+/// # Arguments
+/// * `ctx` - The context containing the required accounts.
+/// * `keeper_reward_bps` - The reward per keeper call, in basis points of the big blind.
+///   0 disables keeper rewards.
+pub fn update_keeper_reward(ctx: Context<UpdateKeeperReward>, keeper_reward_bps: u16) -> Result<()> {
+    require!(
+        keeper_reward_bps <= MAX_KEEPER_REWARD_BPS,
+        AcesUnknownErrorCode::InvalidAction
+    );
+
+    ctx.accounts.platform_config.keeper_reward_bps = keeper_reward_bps;
+
+    msg!("Keeper reward updated: keeper_reward_bps = {}", keeper_reward_bps);
+
+    Ok(())
+}
+
+/// The context struct for the `update_keeper_reward` instruction.
+#[derive(Accounts)]
+pub struct UpdateKeeperReward<'info> {
+    /// The platform configuration account to be modified.
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// The administrator's signer account.
+    #[account(address = platform_config.admin)]
+    pub admin: Signer<'info>,
+}
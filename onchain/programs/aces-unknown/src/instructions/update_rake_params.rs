@@ -12,34 +12,49 @@
 //! sign and successfully execute this transaction.
 
 use anchor_lang::prelude::*;
-use crate::state::PlatformConfig;
+use crate::state::{PlatformConfig, RakeScope};
 use crate::error::AcesUnknownErrorCode;
 
 /// The instruction logic for updating platform rake parameters.
 ///
-/// It validates the input and updates the `rake_bps` and `rake_max_cap` fields
-/// in the `PlatformConfig` account.
+/// It validates the input and updates the `rake_bps`, `rake_max_cap`,
+/// `rake_on_showdown_only`, `referrer_rake_bps`, and `rake_scope` fields in the
+/// `PlatformConfig` account.
 ///
 /// # Arguments
 /// * `ctx` - The context containing the required accounts.
 /// * `new_rake_bps` - The new rake percentage in basis points (e.g., 500 for 5%).
 /// * `new_rake_max_cap` - The new maximum rake amount in the smallest token denomination.
+/// * `rake_on_showdown_only` - Whether pots won without a showdown go unraked.
+/// * `referrer_rake_bps` - The share of the rake paid to a table's referrer, in basis points.
+/// * `rake_scope` - Whether showdown rake is taken from the whole pot or from each side pot.
 pub fn update_rake_params(
     ctx: Context<UpdateRakeParams>,
     new_rake_bps: u16,
     new_rake_max_cap: u64,
+    rake_on_showdown_only: bool,
+    referrer_rake_bps: u16,
+    rake_scope: RakeScope,
 ) -> Result<()> {
     // Input validation: A rake of 100% (10000 bps) or more is nonsensical.
     require!(new_rake_bps <= 10000, AcesUnknownErrorCode::InvalidAction);
+    // The referrer's share cannot exceed the whole rake.
+    require!(referrer_rake_bps <= 10000, AcesUnknownErrorCode::InvalidAction);
 
     let platform_config = &mut ctx.accounts.platform_config;
     platform_config.rake_bps = new_rake_bps;
     platform_config.rake_max_cap = new_rake_max_cap;
+    platform_config.rake_on_showdown_only = rake_on_showdown_only;
+    platform_config.referrer_rake_bps = referrer_rake_bps;
+    platform_config.rake_scope = rake_scope;
 
     msg!(
-        "Rake parameters updated: new_rake_bps = {}, new_rake_max_cap = {}",
+        "Rake parameters updated: new_rake_bps = {}, new_rake_max_cap = {}, rake_on_showdown_only = {}, referrer_rake_bps = {}, rake_scope = {:?}",
         new_rake_bps,
-        new_rake_max_cap
+        new_rake_max_cap,
+        rake_on_showdown_only,
+        referrer_rake_bps,
+        rake_scope
     );
 
     Ok(())
@@ -0,0 +1,47 @@
+//! src/instructions/update_rakeback_config.rs
+//!
+//! @description
+//! This instruction allows the platform administrator to update the rake-back
+//! settings stored in the `PlatformConfig` singleton account. Rake-back returns a
+//! share of the rake each player paid, accrued per hand and claimed later with
+//! `claim_rakeback`.
+//!
+//! @security
+//! This instruction is secured by an `address` constraint on the `admin` account,
+//! ensuring that only the wallet public key stored in `platform_config.admin` can
+//! sign and successfully execute this transaction.
+
+use anchor_lang::prelude::*;
+use crate::state::{PlatformConfig, RakebackConfig};
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for updating the platform rake-back settings.
+///
+/// # Arguments
+/// * `ctx` - The context containing the required accounts.
+/// * `rakeback` - The new rake-back settings.
+pub fn update_rakeback_config(
+    ctx: Context<UpdateRakebackConfig>,
+    rakeback: RakebackConfig,
+) -> Result<()> {
+    // Input validation: more than 100% of the rake cannot be returned.
+    require!(rakeback.rakeback_bps <= 10000, AcesUnknownErrorCode::InvalidAction);
+
+    ctx.accounts.platform_config.rakeback = rakeback;
+
+    msg!("Rake-back updated: rakeback_bps = {}", rakeback.rakeback_bps);
+
+    Ok(())
+}
+
+/// The context struct for the `update_rakeback_config` instruction.
+#[derive(Accounts)]
+pub struct UpdateRakebackConfig<'info> {
+    /// The platform configuration account to be modified.
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// The administrator's signer account.
+    #[account(address = platform_config.admin)]
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,48 @@
+//! src/instructions/update_reveal_retry_limit.rs
+//!
+//! @description
+//! This instruction allows the platform administrator to set how many times a hand
+//! may re-queue a `reveal_community_cards` computation that failed before the hand is
+//! voided and every contribution refunded. The limit is capped at `MAX_REVEAL_RETRIES`
+//! so a hand always has room to queue the computations it needs to finish.
+//!
+//! @security
+//! This instruction is secured by an `address` constraint on the `admin` account,
+//! ensuring that only the wallet public key stored in `platform_config.admin` can
+//! sign and successfully execute this transaction.
+
+use anchor_lang::prelude::*;
+use crate::state::PlatformConfig;
+use crate::state::constants::MAX_REVEAL_RETRIES;
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for updating the reveal retry limit.
+///
+/// # Arguments
+/// * `ctx` - The context containing the required accounts.
+/// * `max_reveal_retries` - How many failed reveals a hand may retry. 0 voids a hand
+///   on its first failed reveal.
+pub fn update_reveal_retry_limit(ctx: Context<UpdateRevealRetryLimit>, max_reveal_retries: u8) -> Result<()> {
+    require!(
+        max_reveal_retries <= MAX_REVEAL_RETRIES,
+        AcesUnknownErrorCode::InvalidAction
+    );
+
+    ctx.accounts.platform_config.max_reveal_retries = max_reveal_retries;
+
+    msg!("Reveal retry limit updated: max_reveal_retries = {}", max_reveal_retries);
+
+    Ok(())
+}
+
+/// The context struct for the `update_reveal_retry_limit` instruction.
+#[derive(Accounts)]
+pub struct UpdateRevealRetryLimit<'info> {
+    /// The platform configuration account to be modified.
+    #[account(mut)]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// The administrator's signer account.
+    #[account(address = platform_config.admin)]
+    pub admin: Signer<'info>,
+}
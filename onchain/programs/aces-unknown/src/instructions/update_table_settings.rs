@@ -0,0 +1,70 @@
+//! src/instructions/update_table_settings.rs
+//!
+//! @description
+//! This instruction allows the creator of a table to change its optional house
+//! rules (the `TableSettings` struct). Settings can only change between hands so
+//! that a hand is always played under the rules it started with.
+//!
+//! @accounts
+//! - `table`: The `Table` account whose settings are updated.
+//! - `creator`: The table creator, who must sign.
+//!
+//! @logic
+//! 1. Verifies the signer is the table creator.
+//! 2. Verifies no hand is currently in progress.
+//! 3. Verifies a configured `refund_timeout_seconds` lies within the platform's bounds,
+//!    and that a showdown reveal timeout runs out before it.
+//! 4. Replaces the table's settings with the provided ones.
+
+use anchor_lang::prelude::*;
+use crate::state::{GameState, Table, TableSettings};
+use crate::state::constants::{MAX_REFUND_TIMEOUT_SECONDS, MIN_REFUND_TIMEOUT_SECONDS};
+use crate::error::AcesUnknownErrorCode;
+
+/// The instruction logic for updating a table's optional house rules.
+pub fn update_table_settings(
+    ctx: Context<UpdateTableSettings>,
+    _table_id: u64,
+    settings: TableSettings,
+) -> Result<()> {
+    let table = &mut ctx.accounts.table;
+
+    // --- Validation ---
+    require!(
+        table.game_state != GameState::HandInProgress,
+        AcesUnknownErrorCode::InvalidGameState
+    );
+    require!(
+        settings.refund_timeout_seconds == 0
+            || (MIN_REFUND_TIMEOUT_SECONDS..=MAX_REFUND_TIMEOUT_SECONDS)
+                .contains(&settings.refund_timeout_seconds),
+        AcesUnknownErrorCode::InvalidRefundTimeout
+    );
+    // A stalled reveal must be mucked before the whole hand could be refunded instead.
+    require!(
+        (settings.showdown_reveal_timeout_seconds as i64) < settings.refund_timeout(),
+        AcesUnknownErrorCode::InvalidShowdownRevealTimeout
+    );
+
+    table.settings = settings;
+
+    msg!("Table #{} settings updated: {:?}", table.table_id, settings);
+    Ok(())
+}
+
+/// The context struct for the `update_table_settings` instruction.
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct UpdateTableSettings<'info> {
+    /// The table account whose settings are being changed.
+    #[account(
+        mut,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = table.creator == creator.key() @ AcesUnknownErrorCode::NotTableCreator,
+    )]
+    pub table: Account<'info, Table>,
+
+    /// The creator of the table.
+    pub creator: Signer<'info>,
+}
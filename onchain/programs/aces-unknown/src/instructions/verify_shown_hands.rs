@@ -0,0 +1,168 @@
+//! src/instructions/verify_shown_hands.rs
+//!
+//! @description
+//! This instruction closes the fairness loop for a hand whose deck ordering and salt
+//! have been revealed: it checks that every hand shown at the showdown is the pair of
+//! cards dealt to that seat from the committed deck. A mismatch means the hand was
+//! altered after the shuffle. It changes no state; the result is emitted as a
+//! `ShownHandsVerified` event so the check is recorded in the transaction. The shown
+//! cards are supplied by the caller, so a failed check is evidence to inspect against
+//! the hand's `HoleCardsRevealed` events rather than a flag anyone could set.
+//!
+//! @accounts
+//! - `table`: The table the hand was played at.
+//! - `hand_data`: The hand's account with its shuffle commitment and deal order.
+//! - `payer`: Any signer; verifying a hand is permissionless.
+//!
+//! @logic
+//! 1. Requires the deck ordering and salt to open the hand's `shuffle_commitment`.
+//! 2. Recomputes each shown seat's deck positions from the hand's `dealt_in_seats` and
+//!    `DealStyle`, and compares the shown cards with the ordering at those positions.
+//! 3. Emits `ShownHandsVerified` with a pass/fail flag per seat. Seats that did not
+//!    show always pass; a seat that was not dealt in has no cards to show and fails.
+
+use anchor_lang::prelude::*;
+use crate::state::{DealStyle, HandData, Table};
+use crate::error::AcesUnknownErrorCode;
+use crate::state::constants::MAX_PLAYERS;
+use crate::instructions::show_one_card::dealt_deck_position;
+use crate::utils;
+
+/// Instruction logic for verifying shown hands against a revealed deck ordering.
+/// `shown_seats` is a bitmask of the seats that showed (bit `i` for seat `i`), and
+/// `shown_cards` holds each one's card indices in deal order.
+pub fn verify_shown_hands(
+    ctx: Context<VerifyShownHands>,
+    _table_id: u64,
+    _hand_id: u64,
+    deck_ordering: [u8; 52],
+    salt: [u8; 32],
+    shown_seats: u8,
+    shown_cards: [[u8; 2]; MAX_PLAYERS],
+) -> Result<()> {
+    let hand_data = &ctx.accounts.hand_data;
+
+    // --- Validation ---
+    require!(
+        utils::verify_shuffle_commitment(deck_ordering, salt, hand_data.shuffle_commitment),
+        AcesUnknownErrorCode::ShuffleCommitmentMismatch
+    );
+
+    let verified = check_shown_hole_cards(
+        &deck_ordering,
+        hand_data.dealt_in_seats,
+        hand_data.deal_style,
+        shown_seats,
+        &shown_cards,
+    );
+
+    emit!(ShownHandsVerified {
+        table_id: ctx.accounts.table.table_id,
+        hand_id: hand_data.hand_id,
+        verified,
+    });
+
+    Ok(())
+}
+
+/// Returns, per seat, whether the cards it showed are the ones dealt to it from
+/// `deck_ordering`. Seats not in `shown_seats` pass.
+pub(crate) fn check_shown_hole_cards(
+    deck_ordering: &[u8; 52],
+    dealt_in_seats: u8,
+    deal_style: DealStyle,
+    shown_seats: u8,
+    shown_cards: &[[u8; 2]; MAX_PLAYERS],
+) -> [bool; MAX_PLAYERS] {
+    std::array::from_fn(|seat| {
+        if shown_seats & (1 << seat) == 0 {
+            return true;
+        }
+        (0..2u8).all(|card_index| {
+            dealt_deck_position(dealt_in_seats, deal_style, seat as u8, card_index)
+                .is_some_and(|position| {
+                    deck_ordering[position as usize] == shown_cards[seat][card_index as usize]
+                })
+        })
+    })
+}
+
+#[derive(Accounts)]
+#[instruction(table_id: u64, hand_id: u64)]
+pub struct VerifyShownHands<'info> {
+    #[account(
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+    #[account(
+        seeds = [b"hand", table.key().as_ref(), hand_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub hand_data: Account<'info, HandData>,
+    pub payer: Signer<'info>,
+}
+
+#[event]
+pub struct ShownHandsVerified {
+    pub table_id: u64,
+    pub hand_id: u64,
+    /// Whether each seat's shown cards match the deck, indexed by seat; `false` marks a
+    /// hand that differs from the one dealt. Seats that did not show pass.
+    pub verified: [bool; MAX_PLAYERS],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A shuffled ordering, so no card sits at the position of its own index.
+    fn ordering() -> [u8; 52] {
+        std::array::from_fn(|i| ((i * 19 + 7) % 52) as u8)
+    }
+
+    #[test]
+    fn shown_hands_match_their_round_robin_positions() {
+        // Seats 0, 2 and 5 dealt in: seat 2 ranks second, so it holds positions 1 and 4.
+        let deck = ordering();
+        let mut shown_cards = [[0; 2]; MAX_PLAYERS];
+        shown_cards[0] = [deck[0], deck[3]];
+        shown_cards[2] = [deck[1], deck[4]];
+        shown_cards[5] = [deck[2], deck[5]];
+        let verified = check_shown_hole_cards(&deck, 0b100101, DealStyle::RoundRobin, 0b100101, &shown_cards);
+        assert_eq!(verified, [true; MAX_PLAYERS]);
+    }
+
+    #[test]
+    fn shown_hands_match_their_sequential_positions() {
+        let deck = ordering();
+        let mut shown_cards = [[0; 2]; MAX_PLAYERS];
+        shown_cards[2] = [deck[2], deck[3]];
+        let verified = check_shown_hole_cards(&deck, 0b100101, DealStyle::Sequential, 0b000100, &shown_cards);
+        assert_eq!(verified, [true; MAX_PLAYERS]);
+    }
+
+    #[test]
+    fn a_tampered_shown_card_fails_verification() {
+        let deck = ordering();
+        let mut shown_cards = [[0; 2]; MAX_PLAYERS];
+        shown_cards[0] = [deck[0], deck[3]];
+        // Seat 2 shows its first card with a different second card.
+        shown_cards[2] = [deck[1], deck[6]];
+        let verified = check_shown_hole_cards(&deck, 0b100101, DealStyle::RoundRobin, 0b000101, &shown_cards);
+        assert_eq!(verified, [true, true, false, true, true, true]);
+
+        // The right cards in the wrong order fail too.
+        shown_cards[2] = [deck[4], deck[1]];
+        let verified = check_shown_hole_cards(&deck, 0b100101, DealStyle::RoundRobin, 0b000100, &shown_cards);
+        assert!(!verified[2]);
+    }
+
+    #[test]
+    fn a_seat_not_dealt_in_cannot_show_a_hand() {
+        let deck = ordering();
+        let shown_cards = [[deck[0], deck[3]]; MAX_PLAYERS];
+        let verified = check_shown_hole_cards(&deck, 0b100101, DealStyle::RoundRobin, 0b000010, &shown_cards);
+        assert!(!verified[1]);
+    }
+}
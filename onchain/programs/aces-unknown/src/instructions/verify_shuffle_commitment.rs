@@ -0,0 +1,42 @@
+//! src/instructions/verify_shuffle_commitment.rs
+//!
+//! @description
+//! This instruction lets anyone check a revealed deck ordering and salt against a
+//! hand's shuffle commitment on-chain. It changes no state; the result is emitted as
+//! a `ShuffleCommitmentVerified` event so the check is recorded in the transaction.
+//!
+//! @accounts
+//! - `payer`: Any signer.
+//!
+//! @logic
+//! 1. Recomputes the commitment from the ordering and salt with
+//!    `utils::verify_shuffle_commitment`, the same construction the circuit uses.
+//! 2. Emits `ShuffleCommitmentVerified` with whether it matched.
+
+use anchor_lang::prelude::*;
+use crate::utils;
+
+/// Instruction logic for verifying a deck ordering against a shuffle commitment.
+pub fn verify_shuffle_commitment(
+    _ctx: Context<VerifyShuffleCommitment>,
+    deck_ordering: [u8; 52],
+    salt: [u8; 32],
+    commitment: [u8; 32],
+) -> Result<()> {
+    let valid = utils::verify_shuffle_commitment(deck_ordering, salt, commitment);
+
+    emit!(ShuffleCommitmentVerified { commitment, valid });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VerifyShuffleCommitment<'info> {
+    pub payer: Signer<'info>,
+}
+
+#[event]
+pub struct ShuffleCommitmentVerified {
+    pub commitment: [u8; 32],
+    pub valid: bool,
+}
@@ -18,6 +18,9 @@ use arcium_anchor::prelude::*;
 pub mod state;
 pub mod error;
 pub mod instructions;
+pub mod utils;
+#[cfg(test)]
+mod scripted_hands;
 
 // Make their contents available for the program.
 use state::*;
@@ -32,6 +35,11 @@ use instructions::*;
 const COMP_DEF_OFFSET_SHUFFLE_AND_DEAL: u32 = comp_def_offset("shuffle_and_deal");
 const COMP_DEF_OFFSET_REVEAL_COMMUNITY_CARDS: u32 = comp_def_offset("reveal_community_cards");
 const COMP_DEF_OFFSET_EVALUATE_HANDS_AND_PAYOUT: u32 = comp_def_offset("evaluate_hands_and_payout");
+const COMP_DEF_OFFSET_COMPUTE_ALLIN_EQUITY: u32 = comp_def_offset("compute_allin_equity");
+const COMP_DEF_OFFSET_VERIFY_SHOWN_HOLE_CARDS: u32 = comp_def_offset("verify_shown_hole_cards");
+const COMP_DEF_OFFSET_RABBIT_HUNT: u32 = comp_def_offset("rabbit_hunt");
+const COMP_DEF_OFFSET_REVEAL_REMAINING_BOARD: u32 = comp_def_offset("reveal_remaining_board");
+const COMP_DEF_OFFSET_SHOW_ONE_CARD: u32 = comp_def_offset("show_one_card");
 
 // Program ID
 declare_id!("4ir9eYNjfVJggq19Su6DzAD4e24Yi4THesJjpBbAonVV");
@@ -68,6 +76,46 @@ pub mod aces_unknown {
         Ok(())
     }
 
+    /// Initialize the compute_allin_equity computation definition
+    pub fn init_compute_allin_equity_comp_def(ctx: Context<InitComputeAllinEquityCompDef>) -> Result<()> {
+        // TODO: Replace with proper Arcium init when tools are working correctly
+        // init_comp_def(ctx.accounts, true, 0, None, None)?;
+        // For now, just return success
+        Ok(())
+    }
+
+    /// Initialize the verify_shown_hole_cards computation definition
+    pub fn init_verify_shown_hole_cards_comp_def(ctx: Context<InitVerifyShownHoleCardsCompDef>) -> Result<()> {
+        // TODO: Replace with proper Arcium init when tools are working correctly
+        // init_comp_def(ctx.accounts, true, 0, None, None)?;
+        // For now, just return success
+        Ok(())
+    }
+
+    /// Initialize the rabbit_hunt computation definition
+    pub fn init_rabbit_hunt_comp_def(ctx: Context<InitRabbitHuntCompDef>) -> Result<()> {
+        // TODO: Replace with proper Arcium init when tools are working correctly
+        // init_comp_def(ctx.accounts, true, 0, None, None)?;
+        // For now, just return success
+        Ok(())
+    }
+
+    /// Initialize the reveal_remaining_board computation definition
+    pub fn init_reveal_remaining_board_comp_def(ctx: Context<InitRevealRemainingBoardCompDef>) -> Result<()> {
+        // TODO: Replace with proper Arcium init when tools are working correctly
+        // init_comp_def(ctx.accounts, true, 0, None, None)?;
+        // For now, just return success
+        Ok(())
+    }
+
+    /// Initialize the show_one_card computation definition
+    pub fn init_show_one_card_comp_def(ctx: Context<InitShowOneCardCompDef>) -> Result<()> {
+        // TODO: Replace with proper Arcium init when tools are working correctly
+        // init_comp_def(ctx.accounts, true, 0, None, None)?;
+        // For now, just return success
+        Ok(())
+    }
+
     // ========================================
     // Arcium Computation Invocations
     // ========================================
@@ -79,8 +127,8 @@ pub mod aces_unknown {
     ) -> Result<()> {
         // Simplified computation queuing for compilation
         // TODO: Replace with proper Arcium computation queuing
-        // For now, just return success
-        Ok(())
+        // For now, just record the offset the callback must match
+        ctx.accounts.hand_data.record_queued_computation(computation_offset)
     }
 
     /// Queue a reveal community cards computation (called by deal_community_cards)
@@ -89,9 +137,18 @@ pub mod aces_unknown {
         computation_offset: u64,
         num_cards: u8,
     ) -> Result<()> {
+        // Only the current hand's next street, once its betting has closed.
+        ctx.accounts.table.require_phase(HandPhase::AwaitingReveal)?;
+        // A street is revealed once: not again while its reveal is still in flight.
+        require!(
+            !ctx.accounts.hand_data.pending_reveal,
+            error::AcesUnknownErrorCode::InvalidBettingRoundTransition
+        );
         // Simplified computation queuing for compilation
         // TODO: Replace with proper Arcium computation queuing
-        // For now, just return success
+        // For now, just record the offset the callback must match
+        ctx.accounts.hand_data.record_queued_computation(computation_offset)?;
+        ctx.accounts.hand_data.pending_reveal = true;
         Ok(())
     }
 
@@ -100,10 +157,14 @@ pub mod aces_unknown {
         ctx: Context<EvaluateHandsAndPayout>,
         computation_offset: u64,
     ) -> Result<()> {
+        // Only the current hand's showdown, once the river betting has closed.
+        ctx.accounts.table.require_phase(HandPhase::AwaitingShowdown)?;
         // Simplified computation queuing for compilation
         // TODO: Replace with proper Arcium computation queuing
-        // For now, just return success
-        Ok(())
+        // For now, just record the offset the callback must match
+        ctx.accounts.hand_data.record_queued_computation(computation_offset)?;
+        // The pot is paid out once: no second showdown while the first is in flight.
+        ctx.accounts.hand_data.begin_showdown()
     }
 
     // ========================================
@@ -126,7 +187,8 @@ pub mod aces_unknown {
     // }
 
     /// Simplified callback for shuffle_and_deal computation result
-    pub fn shuffle_and_deal_callback(ctx: Context<ShuffleAndDealCallback>) -> Result<()> {
+    pub fn shuffle_and_deal_callback(ctx: Context<ShuffleAndDealCallback>, _computation_offset: u64) -> Result<()> {
+        ctx.accounts.hand_data.pending_computation_offset = None;
         emit!(HandShuffled { table_id: ctx.accounts.table.table_id });
         Ok(())
     }
@@ -140,7 +202,14 @@ pub mod aces_unknown {
     // ) -> Result<()> {
     //     let _result = match output {
     //         ComputationOutputs::Success(data) => data,
-    //         _ => return Err(error::AcesUnknownErrorCode::AbortedComputation.into()),
+    //         _ => {
+    //             return record_failed_reveal(
+    //                 &mut ctx.accounts.table,
+    //                 &mut ctx.accounts.hand_data,
+    //                 ctx.accounts.platform_config.max_reveal_retries,
+    //                 ctx.remaining_accounts,
+    //             )
+    //         }
     //     };
     //     emit!(CommunityCardsDealt {
     //         table_id: ctx.accounts.table.table_id,
@@ -150,8 +219,15 @@ pub mod aces_unknown {
     //     Ok(())
     // }
 
-    /// Simplified callback for reveal_community_cards computation result
-    pub fn reveal_community_cards_callback(ctx: Context<RevealCommunityCardsCallback>) -> Result<()> {
+    /// Simplified callback for reveal_community_cards computation result. It only lands a
+    /// successful reveal: a failure is reported by the Arcium callback's
+    /// `ComputationOutputs`, never by the caller.
+    pub fn reveal_community_cards_callback(
+        ctx: Context<RevealCommunityCardsCallback>,
+        _computation_offset: u64,
+    ) -> Result<()> {
+        ctx.accounts.hand_data.pending_computation_offset = None;
+        ctx.accounts.hand_data.pending_reveal = false;
         emit!(CommunityCardsDealt {
             table_id: ctx.accounts.table.table_id,
             hand_id: ctx.accounts.hand_data.hand_id,
@@ -167,10 +243,16 @@ pub mod aces_unknown {
     //     ctx: Context<EvaluateHandsAndPayoutCallback>,
     //     output: ComputationOutputs<EvaluateHandsAndPayoutOutput>,
     // ) -> Result<()> {
-    //     let _result = match output {
+    //     // One `ShowdownDetail` per seat: hand category, tie-break ranks, amount won.
+    //     let details: [ShowdownDetail; MAX_PLAYERS] = match output {
     //         ComputationOutputs::Success(data) => data,
     //         _ => return Err(error::AcesUnknownErrorCode::AbortedComputation.into()),
     //     };
+    //     emit!(ShowdownDetails {
+    //         table_id: ctx.accounts.table.table_id,
+    //         hand_id: ctx.accounts.hand_data.hand_id,
+    //         details,
+    //     });
     //     emit!(HandResolved {
     //         table_id: ctx.accounts.table.table_id,
     //         hand_id: ctx.accounts.hand_data.hand_id,
@@ -181,7 +263,9 @@ pub mod aces_unknown {
     // }
 
     /// Simplified callback for evaluate_hands_and_payout computation result
-    pub fn evaluate_hands_and_payout_callback(ctx: Context<EvaluateHandsAndPayoutCallback>) -> Result<()> {
+    pub fn evaluate_hands_and_payout_callback(ctx: Context<EvaluateHandsAndPayoutCallback>, _computation_offset: u64) -> Result<()> {
+        ctx.accounts.hand_data.pending_computation_offset = None;
+        ctx.accounts.hand_data.showdown_in_progress = false;
         emit!(HandResolved {
             table_id: ctx.accounts.table.table_id,
             hand_id: ctx.accounts.hand_data.hand_id,
@@ -191,6 +275,45 @@ pub mod aces_unknown {
         Ok(())
     }
 
+    /// Callback for compute_allin_equity computation result
+    // TODO: Uncomment when Arcium macros are working correctly
+    // #[arcium_callback(encrypted_ix = "compute_allin_equity")]
+    // pub fn compute_allin_equity_callback(
+    //     ctx: Context<ComputeAllinEquityCallback>,
+    //     output: ComputationOutputs<ComputeAllinEquityOutput>,
+    // ) -> Result<()> {
+    //     let (win_bps, tie_bps) = match output {
+    //         ComputationOutputs::Success(data) => data,
+    //         _ => return Err(error::AcesUnknownErrorCode::AbortedComputation.into()),
+    //     };
+    //     emit!(AllInEquity {
+    //         table_id: ctx.accounts.table.table_id,
+    //         hand_id: ctx.accounts.hand_data.hand_id,
+    //         win_bps,
+    //         tie_bps,
+    //     });
+    //     Ok(())
+    // }
+
+    /// Callback for verify_shown_hole_cards computation result
+    // TODO: Uncomment when Arcium macros are working correctly
+    // #[arcium_callback(encrypted_ix = "verify_shown_hole_cards")]
+    // pub fn verify_shown_hole_cards_callback(
+    //     ctx: Context<VerifyShownHoleCardsCallback>,
+    //     output: ComputationOutputs<VerifyShownHoleCardsOutput>,
+    // ) -> Result<()> {
+    //     let verified: [bool; MAX_PLAYERS] = match output {
+    //         ComputationOutputs::Success(data) => data,
+    //         _ => return Err(error::AcesUnknownErrorCode::AbortedComputation.into()),
+    //     };
+    //     emit!(ShownHandsVerified {
+    //         table_id: ctx.accounts.table.table_id,
+    //         hand_id: ctx.accounts.hand_data.hand_id,
+    //         verified,
+    //     });
+    //     Ok(())
+    // }
+
     // ========================================
     // Admin & Table Management Instructions
     // ========================================
@@ -202,6 +325,14 @@ pub mod aces_unknown {
         ctx.accounts.platform_config.rake_bps = 500; // Default 5.00%
         ctx.accounts.platform_config.rake_max_cap = 0; // Default no cap
         ctx.accounts.platform_config.treasury_vault = ctx.accounts.treasury_vault.key();
+        ctx.accounts.platform_config.rakeback = crate::state::RakebackConfig::default();
+        ctx.accounts.platform_config.rake_on_showdown_only = false;
+        ctx.accounts.platform_config.referrer_rake_bps = 0;
+        ctx.accounts.platform_config.rake_scope = crate::state::RakeScope::TotalPot;
+        ctx.accounts.platform_config.keeper_reward_bps = 0;
+        ctx.accounts.platform_config.max_reveal_retries = crate::state::DEFAULT_MAX_REVEAL_RETRIES;
+        ctx.accounts.platform_config.allowed_mints = Vec::new();
+        ctx.accounts.platform_config.stats = crate::state::PlatformStats::default();
         Ok(())
     }
 
@@ -210,19 +341,154 @@ pub mod aces_unknown {
         ctx: Context<UpdateRakeParams>,
         new_rake_bps: u16,
         new_rake_max_cap: u64,
+        rake_on_showdown_only: bool,
+        referrer_rake_bps: u16,
+        rake_scope: crate::state::RakeScope,
     ) -> Result<()> {
-        instructions::update_rake_params::update_rake_params(ctx, new_rake_bps, new_rake_max_cap)
+        instructions::update_rake_params::update_rake_params(
+            ctx,
+            new_rake_bps,
+            new_rake_max_cap,
+            rake_on_showdown_only,
+            referrer_rake_bps,
+            rake_scope,
+        )
+    }
+
+    /// Instruction for the platform admin to update rake-back settings.
+    pub fn update_rakeback_config(
+        ctx: Context<UpdateRakebackConfig>,
+        rakeback: crate::state::RakebackConfig,
+    ) -> Result<()> {
+        instructions::update_rakeback_config::update_rakeback_config(ctx, rakeback)
+    }
+
+    /// Instruction for the platform admin to set the reward paid to keepers who force
+    /// a fold or refund a stuck hand.
+    pub fn update_keeper_reward(ctx: Context<UpdateKeeperReward>, keeper_reward_bps: u16) -> Result<()> {
+        instructions::update_keeper_reward::update_keeper_reward(ctx, keeper_reward_bps)
+    }
+
+    /// Instruction for the platform admin to set how many failed community-card reveals
+    /// a hand may retry before it is voided and refunded.
+    pub fn update_reveal_retry_limit(ctx: Context<UpdateRevealRetryLimit>, max_reveal_retries: u8) -> Result<()> {
+        instructions::update_reveal_retry_limit::update_reveal_retry_limit(ctx, max_reveal_retries)
+    }
+
+    /// Instruction for the platform admin to add a token mint to the table currency whitelist.
+    pub fn add_allowed_mint(ctx: Context<AddAllowedMint>, mint: Pubkey) -> Result<()> {
+        instructions::add_allowed_mint::add_allowed_mint(ctx, mint)
+    }
+
+    /// Instruction for the platform admin to remove a token mint from the table currency whitelist.
+    pub fn remove_allowed_mint(ctx: Context<RemoveAllowedMint>, mint: Pubkey) -> Result<()> {
+        instructions::remove_allowed_mint::remove_allowed_mint(ctx, mint)
+    }
+
+    /// Instruction for the platform admin to move the treasury that collects rake to a
+    /// new token account, co-signed by that account's owner.
+    pub fn set_treasury_vault(ctx: Context<SetTreasuryVault>, required_mint: Option<Pubkey>) -> Result<()> {
+        instructions::set_treasury_vault::set_treasury_vault(ctx, required_mint)
+    }
+
+    /// Instruction for a player to claim their accrued rake-back from the treasury.
+    pub fn claim_rakeback(ctx: Context<ClaimRakeback>) -> Result<()> {
+        instructions::claim_rakeback::claim_rakeback(ctx)
+    }
+
+    /// Instruction for a player to set a self-imposed limit on their total buy-ins per period.
+    pub fn set_player_limits(ctx: Context<SetPlayerLimits>, max_buy_in_per_period: u64) -> Result<()> {
+        instructions::set_player_limits::set_player_limits(ctx, max_buy_in_per_period)
+    }
+
+    /// Instruction for a player to exclude themselves from creating or joining tables until `until`.
+    pub fn self_exclude(ctx: Context<SelfExclude>, until: i64) -> Result<()> {
+        instructions::self_exclude::self_exclude(ctx, until)
+    }
+
+    /// Instruction for a player to cap how many tables they can be seated at at once.
+    pub fn set_max_concurrent_tables(
+        ctx: Context<SetMaxConcurrentTables>,
+        max_concurrent_tables: u16,
+    ) -> Result<()> {
+        instructions::set_max_concurrent_tables::set_max_concurrent_tables(ctx, max_concurrent_tables)
+    }
+
+    /// Admin-only instruction to cash out every player and close a table.
+    /// For each occupied seat, pass its `PlayerSeat`, the player's token account, the
+    /// player's wallet, and their `PlayerActiveTables` as `remaining_accounts`.
+    pub fn admin_force_close_table<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AdminForceCloseTable<'info>>,
+        table_id: u64,
+        refund_hand: bool,
+    ) -> Result<()> {
+        instructions::admin_force_close_table::admin_force_close_table(ctx, table_id, refund_hand)
+    }
+
+    /// Moderation tool for the platform admin: moves the dust left in an empty table's
+    /// vault (less than one big blind) to the treasury.
+    pub fn sweep_table_vault_dust(ctx: Context<SweepTableVaultDust>, table_id: u64) -> Result<()> {
+        instructions::sweep_table_vault_dust::sweep_table_vault_dust(ctx, table_id)
     }
 
     /// Instruction for a player to create a new poker table.
     pub fn create_table(
         ctx: Context<CreateTable>,
         table_id: u64,
+        seat_index: u8,
         small_blind: u64,
         big_blind: u64,
         buy_in: u64,
+        name: [u8; 32],
+        description_hash: [u8; 32],
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_table::create_table(
+            ctx,
+            table_id,
+            seat_index,
+            small_blind,
+            big_blind,
+            buy_in,
+            name,
+            description_hash,
+            referrer,
+        )
+    }
+
+    /// Instruction for the table creator to change the table's name and description hash.
+    pub fn set_table_metadata(
+        ctx: Context<SetTableMetadata>,
+        table_id: u64,
+        name: [u8; 32],
+        description_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::set_table_metadata::set_table_metadata(ctx, table_id, name, description_hash)
+    }
+
+    /// Instruction for the table creator to change the table's optional house rules.
+    pub fn update_table_settings(
+        ctx: Context<UpdateTableSettings>,
+        table_id: u64,
+        settings: TableSettings,
     ) -> Result<()> {
-        instructions::create_table::create_table(ctx, table_id, small_blind, big_blind, buy_in)
+        instructions::update_table_settings::update_table_settings(ctx, table_id, settings)
+    }
+
+    /// Recovery instruction for the table creator to move the dealer button to an
+    /// occupied seat between hands.
+    pub fn reset_button(ctx: Context<ResetButton>, table_id: u64, seat_index: u8) -> Result<()> {
+        instructions::reset_button::reset_button(ctx, table_id, seat_index)
+    }
+
+    /// Instruction for the table creator to add a wallet to the table's access list.
+    pub fn invite_player(ctx: Context<InvitePlayer>, table_id: u64, player: Pubkey) -> Result<()> {
+        instructions::invite_player::invite_player(ctx, table_id, player)
+    }
+
+    /// Instruction for the table creator to remove a wallet from the table's access list.
+    pub fn revoke_invite(ctx: Context<RevokeInvite>, table_id: u64, player: Pubkey) -> Result<()> {
+        instructions::revoke_invite::revoke_invite(ctx, table_id, player)
     }
 
     /// Instruction for a player to join an existing table.
@@ -230,49 +496,247 @@ pub mod aces_unknown {
         instructions::join_table::join_table(ctx, table_id, seat_index, buy_in)
     }
 
+    /// Instruction for a player to hold an empty seat briefly while they fund the buy-in.
+    pub fn reserve_seat(ctx: Context<ReserveSeat>, table_id: u64, seat_index: u8) -> Result<()> {
+        instructions::reserve_seat::reserve_seat(ctx, table_id, seat_index)
+    }
+
     /// Instruction for a player to leave a table and cash out their chips.
     pub fn leave_table(ctx: Context<LeaveTable>, table_id: u64) -> Result<()> {
         instructions::leave_table::leave_table(ctx, table_id)
     }
 
+    /// Frees the seat of a player with no chips left. Permissionless; rent goes to the player.
+    pub fn stand_up_busted_player(ctx: Context<StandUpBustedPlayer>, table_id: u64) -> Result<()> {
+        instructions::stand_up_busted_player::stand_up_busted_player(ctx, table_id)
+    }
+
     // ========================================
     // Hand Lifecycle Instructions
     // ========================================
 
     /// Starts a new hand, collects blinds, and queues the shuffle/deal computation.
-    pub fn start_hand(ctx: Context<StartHand>, table_id: u64) -> Result<()> {
+    /// The occupied `PlayerSeat` accounts must be passed as writable `remaining_accounts`.
+    pub fn start_hand<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StartHand<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
         instructions::start_hand::start_hand(ctx, table_id)
     }
 
+    /// Starts a bomb pot: every player antes `bomb_pot_ante`, and betting opens on the flop.
+    /// Only the table creator may start one. Takes the same accounts as `start_hand`.
+    pub fn start_bomb_pot<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StartHand<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
+        instructions::start_bomb_pot::start_bomb_pot(ctx, table_id)
+    }
+
     /// Reveals the next community cards (flop, turn, or river).
-    pub fn deal_community_cards(ctx: Context<DealCommunityCards>, table_id: u64) -> Result<()> {
+    /// The occupied `PlayerSeat` accounts must be passed as writable `remaining_accounts`.
+    pub fn deal_community_cards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DealCommunityCards<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
         instructions::deal_community_cards::deal_community_cards(ctx, table_id)
     }
 
+    /// Reveals every remaining community card at once when the hand is run out all-in.
+    /// The occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+    pub fn reveal_remaining_board<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RevealRemainingBoard<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
+        instructions::reveal_remaining_board::reveal_remaining_board(ctx, table_id)
+    }
+
     /// Resolves the showdown, determines the winner, and handles payouts.
-    pub fn resolve_showdown(ctx: Context<ResolveShowdown>, table_id: u64) -> Result<()> {
+    /// The occupied `PlayerSeat` accounts must be passed as `remaining_accounts`, followed
+    /// by the players' `PlayerRakeback` accounts when rake-back is enabled.
+    pub fn resolve_showdown<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveShowdown<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
         instructions::resolve_showdown::resolve_showdown(ctx, table_id)
     }
+
+    /// Requests an informational win/tie estimate once every contender is all-in.
+    /// The occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+    pub fn compute_allin_equity<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ComputeAllinEquity<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
+        instructions::compute_allin_equity::compute_allin_equity(ctx, table_id)
+    }
+
+    /// Reveals the community cards that would have come after a hand ended early.
+    /// Informational only; requires `TableSettings::allow_rabbit_hunt`.
+    pub fn rabbit_hunt(ctx: Context<RabbitHuntAccounts>, table_id: u64) -> Result<()> {
+        instructions::rabbit_hunt::rabbit_hunt(ctx, table_id)
+    }
+
+    /// Shows one of the player's hole cards after the hand is over, keeping the other private.
+    pub fn show_one_card(ctx: Context<ShowOneCard>, table_id: u64, card_index: u8) -> Result<()> {
+        instructions::show_one_card::show_one_card(ctx, table_id, card_index)
+    }
+
+    /// Reveals the player's hole cards at the showdown, at tables with a showdown reveal
+    /// timeout. Players who have not revealed by the time it runs out are mucked.
+    pub fn reveal_hole_cards(ctx: Context<RevealHoleCards>, table_id: u64) -> Result<()> {
+        instructions::reveal_hole_cards::reveal_hole_cards(ctx, table_id)
+    }
+
+    /// Closes a completed hand's `EncryptedHand` accounts, refunding rent to each player.
+    /// Pass `(EncryptedHand, player wallet)` pairs as `remaining_accounts`.
+    pub fn close_encrypted_hands<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseEncryptedHands<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
+        instructions::close_encrypted_hands::close_encrypted_hands(ctx, table_id)
+    }
+
+    /// Checks a revealed deck ordering and salt against a hand's shuffle commitment,
+    /// emitting the result as a `ShuffleCommitmentVerified` event.
+    pub fn verify_shuffle_commitment(
+        ctx: Context<VerifyShuffleCommitment>,
+        deck_ordering: [u8; 52],
+        salt: [u8; 32],
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::verify_shuffle_commitment::verify_shuffle_commitment(ctx, deck_ordering, salt, commitment)
+    }
+
+    /// Checks the hands shown at a hand's showdown against its revealed deck ordering,
+    /// emitting a pass/fail flag per seat as a `ShownHandsVerified` event.
+    pub fn verify_shown_hands(
+        ctx: Context<VerifyShownHands>,
+        table_id: u64,
+        hand_id: u64,
+        deck_ordering: [u8; 52],
+        salt: [u8; 32],
+        shown_seats: u8,
+        shown_cards: [[u8; 2]; MAX_PLAYERS],
+    ) -> Result<()> {
+        instructions::verify_shown_hands::verify_shown_hands(
+            ctx, table_id, hand_id, deck_ordering, salt, shown_seats, shown_cards,
+        )
+    }
     
     // ========================================
     // Player Action & Timeout Instructions
     // ========================================
     
     /// The main instruction for a player to take an action (fold, check, call, bet, raise).
-    pub fn player_action(ctx: Context<PlayerActionAccounts>, table_id: u64, action: crate::state::PlayerAction) -> Result<()> {
+    /// The other occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+    pub fn player_action<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PlayerActionAccounts<'info>>,
+        table_id: u64,
+        action: crate::state::PlayerAction,
+    ) -> Result<()> {
         instructions::player_action::player_action(ctx, table_id, action)
     }
 
+    /// Instruction for a player to pre-select (or clear) an action for their next turn.
+    pub fn set_pending_action(
+        ctx: Context<SetPendingAction>,
+        table_id: u64,
+        pending_action: Option<crate::state::PendingAction>,
+    ) -> Result<()> {
+        instructions::set_pending_action::set_pending_action(ctx, table_id, pending_action)
+    }
+
+    /// Instruction for a player to skip (or stop skipping) the next hand in which they would post a blind.
+    pub fn set_sit_out_next_blind(
+        ctx: Context<SetSitOutNextBlind>,
+        table_id: u64,
+        sit_out: bool,
+    ) -> Result<()> {
+        instructions::set_sit_out_next_blind::set_sit_out_next_blind(ctx, table_id, sit_out)
+    }
+
+    /// Instruction for anyone to apply the pending action of the player whose turn it is.
+    /// The other occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+    pub fn apply_pending_action<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApplyPendingAction<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
+        instructions::apply_pending_action::apply_pending_action(ctx, table_id)
+    }
+
+    /// Instruction for the UTG player to post an optional straddle before the first action.
+    /// The other occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+    pub fn post_straddle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PostStraddle<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
+        instructions::post_straddle::post_straddle(ctx, table_id)
+    }
+
+    /// Instruction for a player waiting for the big blind to post a dead and a live
+    /// big blind and be dealt into the next hand.
+    pub fn post_entry_blind(ctx: Context<PostEntryBlind>, table_id: u64) -> Result<()> {
+        instructions::post_entry_blind::post_entry_blind(ctx, table_id)
+    }
+
     /// Instruction for anyone to fold a player whose turn timer has expired.
-    pub fn force_player_fold(ctx: Context<ForcePlayerFold>, table_id: u64) -> Result<()> {
+    /// The other occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+    pub fn force_player_fold<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ForcePlayerFold<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
         instructions::force_player_fold::force_player_fold(ctx, table_id)
     }
 
     /// Safety instruction to refund all bets if a hand becomes unrecoverably stuck.
-    pub fn force_hand_refund(ctx: Context<ForceHandRefund>, table_id: u64) -> Result<()> {
+    /// The occupied `PlayerSeat` accounts must be passed as writable `remaining_accounts`.
+    pub fn force_hand_refund<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ForceHandRefund<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
         instructions::force_hand_refund::force_hand_refund(ctx, table_id)
     }
 
+    /// Lets both heads-up players agree to take back their blinds and void the hand
+    /// before anyone acts. The occupied `PlayerSeat` accounts must be passed as writable
+    /// `remaining_accounts`.
+    pub fn chop_blinds<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ChopBlinds<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
+        instructions::chop_blinds::chop_blinds(ctx, table_id)
+    }
+
+    /// Emits the acting player's 1/3, 1/2, 3/4, pot and all-in bet sizes as `BetPresets`,
+    /// clamped to the minimum raise and the player's stack.
+    pub fn compute_bet_presets(ctx: Context<ComputeBetPresets>, table_id: u64) -> Result<()> {
+        instructions::compute_bet_presets::compute_bet_presets(ctx, table_id)
+    }
+
+    /// Emits whether it is `player`'s turn to act as `PlayersTurnChecked`.
+    /// The occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+    pub fn check_players_turn<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CheckPlayersTurn<'info>>,
+        table_id: u64,
+        player: Pubkey,
+    ) -> Result<()> {
+        instructions::check_players_turn::check_players_turn(ctx, table_id, player)
+    }
+
+    /// Emits the table's public state and every seat's stack and bets as `TableSnapshot`.
+    /// The occupied `PlayerSeat` accounts must be passed as `remaining_accounts`.
+    pub fn snapshot_table<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SnapshotTable<'info>>,
+        table_id: u64,
+    ) -> Result<()> {
+        instructions::snapshot_table::snapshot_table(ctx, table_id)
+    }
+
+    /// Keeper instruction to pause a table that has been idle longer than its configured window.
+    pub fn check_table_activity(ctx: Context<CheckTableActivity>, table_id: u64) -> Result<()> {
+        instructions::check_table_activity::check_table_activity(ctx, table_id)
+    }
+
     // ========================================
     // Arcium Callbacks (placeholders - to be implemented when Arcium integration is properly set up)
     // ========================================
@@ -333,6 +797,56 @@ pub struct InitEvaluateHandsAndPayoutCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct InitComputeAllinEquityCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Computation definition account initialized by Arcium program
+    #[account(mut)]
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitVerifyShownHoleCardsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Computation definition account initialized by Arcium program
+    #[account(mut)]
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitRabbitHuntCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Computation definition account initialized by Arcium program
+    #[account(mut)]
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitRevealRemainingBoardCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Computation definition account initialized by Arcium program
+    #[account(mut)]
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitShowOneCardCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Computation definition account initialized by Arcium program
+    #[account(mut)]
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
 // ========================================
 // Arcium Computation Contexts (Simplified for Compatibility)
 // TODO: Replace with proper Arcium macros when tools are working correctly
@@ -361,8 +875,18 @@ pub struct RevealCommunityCards<'info> {
     #[account(mut)]
     pub computation_account: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
-    // Hand-specific accounts
-    #[account(mut)]
+    // The table whose next street is revealed
+    #[account(
+        seeds = [b"table", table.table_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub table: Account<'info, Table>,
+    // Hand-specific accounts: the table's current hand
+    #[account(
+        mut,
+        seeds = [b"hand", table.key().as_ref(), table.hand_id_counter.to_le_bytes().as_ref()],
+        bump,
+    )]
     pub hand_data: Account<'info, HandData>,
 }
 
@@ -375,43 +899,93 @@ pub struct EvaluateHandsAndPayout<'info> {
     #[account(mut)]
     pub computation_account: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
-    // Hand-specific accounts
-    #[account(mut)]
+    // The table whose showdown is evaluated
+    #[account(
+        seeds = [b"table", table.table_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub table: Account<'info, Table>,
+    // Hand-specific accounts: the table's current hand
+    #[account(
+        mut,
+        seeds = [b"hand", table.key().as_ref(), table.hand_id_counter.to_le_bytes().as_ref()],
+        bump,
+    )]
     pub hand_data: Account<'info, HandData>,
 }
 
 // ========================================
 // Arcium Callback Contexts (Simplified for Compatibility)
 // TODO: Replace with proper Arcium macros when tools are working correctly
+//
+// Each callback is tied to the computation that queued it: the table must be the
+// table PDA, the hand data must be that table's current hand, and the computation
+// offset must match the one recorded on the hand when it was queued.
 // ========================================
 
 #[derive(Accounts)]
+#[instruction(computation_offset: u64)]
 pub struct ShuffleAndDealCallback<'info> {
     // Hand data account to update
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = hand_data.table_pubkey == table.key() @ error::AcesUnknownErrorCode::ComputationMismatch,
+        constraint = hand_data.hand_id == table.hand_id_counter @ error::AcesUnknownErrorCode::ComputationMismatch,
+        constraint = hand_data.pending_computation_offset == Some(computation_offset)
+            @ error::AcesUnknownErrorCode::ComputationMismatch,
+    )]
     pub hand_data: Account<'info, HandData>,
     // Table account for reference
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"table", table.table_id.to_le_bytes().as_ref()],
+        bump,
+    )]
     pub table: Account<'info, Table>,
 }
 
 #[derive(Accounts)]
+#[instruction(computation_offset: u64)]
 pub struct RevealCommunityCardsCallback<'info> {
     // Hand data account to update
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = hand_data.table_pubkey == table.key() @ error::AcesUnknownErrorCode::ComputationMismatch,
+        constraint = hand_data.hand_id == table.hand_id_counter @ error::AcesUnknownErrorCode::ComputationMismatch,
+        constraint = hand_data.pending_computation_offset == Some(computation_offset)
+            @ error::AcesUnknownErrorCode::ComputationMismatch,
+    )]
     pub hand_data: Account<'info, HandData>,
     // Table account to update with community cards
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"table", table.table_id.to_le_bytes().as_ref()],
+        bump,
+    )]
     pub table: Account<'info, Table>,
+    // Platform config for the reveal retry limit
+    #[account(seeds = [b"platform_config"], bump)]
+    pub platform_config: Account<'info, PlatformConfig>,
 }
 
 #[derive(Accounts)]
+#[instruction(computation_offset: u64)]
 pub struct EvaluateHandsAndPayoutCallback<'info> {
     // Hand data account
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = hand_data.table_pubkey == table.key() @ error::AcesUnknownErrorCode::ComputationMismatch,
+        constraint = hand_data.hand_id == table.hand_id_counter @ error::AcesUnknownErrorCode::ComputationMismatch,
+        constraint = hand_data.pending_computation_offset == Some(computation_offset)
+            @ error::AcesUnknownErrorCode::ComputationMismatch,
+    )]
     pub hand_data: Account<'info, HandData>,
     // Table account
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"table", table.table_id.to_le_bytes().as_ref()],
+        bump,
+    )]
     pub table: Account<'info, Table>,
     // Platform config for rake
     #[account(mut)]
@@ -0,0 +1,652 @@
+//! src/scripted_hands.rs
+//!
+//! @description
+//! An in-memory harness that plays scripted hands through the program's entrypoint,
+//! from `initialize_platform_config` and `create_table` to the hand's payout, and
+//! checks the final stacks, pot and rake.
+//!
+//! Every Arcium computation a hand needs is queued and its callback landed under the
+//! same computation offset, as the MPC network would. The circuit outputs themselves
+//! are the canned ones the instructions simulate until the Arcium integration is set
+//! up: the deck is in index order, so a script knows every hole card and board card.
+//!
+//! Cross-program invocations do nothing off-chain. Accounts an instruction creates are
+//! therefore set up beforehand in the state the System or Token program would leave
+//! them in, and token balances never move: the rake is read from the events instead.
+
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::{ProgramResult, SUCCESS};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::{Discriminator, Event, InstructionData, Space};
+use anchor_spl::token::spl_token;
+use crate::error::AcesUnknownErrorCode;
+use crate::instructions::HandResolved;
+use crate::utils::PotAwarded;
+use crate::state::{
+    EncryptedHand, HandData, HandPhase, GameState, PlatformConfig, PlayerAction, PlayerActiveTables, PlayerExclusion,
+    PlayerLimits, PlayerRakeback, PlayerSeat, SeatReservation, Table,
+};
+
+/// The largest account the harness can hold, including room to grow.
+const MAX_ACCOUNT_DATA: usize = 16 * 1024;
+const SMALL_BLIND: u64 = 10;
+const BIG_BLIND: u64 = 20;
+const BUY_IN: u64 = 400;
+const TABLE_ID: u64 = 7;
+
+thread_local! {
+    static UNIX_TIMESTAMP: Cell<i64> = const { Cell::new(1_700_000_000) };
+    static EVENTS: RefCell<Vec<Vec<u8>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Serves the clock and rent sysvars and records every emitted event.
+struct HarnessSyscalls;
+
+impl SyscallStubs for HarnessSyscalls {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let unix_timestamp = UNIX_TIMESTAMP.with(Cell::get);
+        let clock = Clock {
+            slot: unix_timestamp as u64,
+            unix_timestamp,
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        EVENTS.with(|events| events.borrow_mut().extend(fields.iter().map(|field| field.to_vec())));
+    }
+}
+
+/// An account as the runtime lays it out for a program: the original data length
+/// just before the key, and the current data length just before the data, which is
+/// where `AccountInfo::realloc` and `assign` expect them.
+#[repr(C)]
+struct SerializedAccount {
+    original_data_len: u32,
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data_len: u64,
+    data: [u8; MAX_ACCOUNT_DATA],
+}
+
+#[derive(Clone, Default)]
+struct StoredAccount {
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+    executable: bool,
+}
+
+/// One table, its players and every account the program touches.
+struct Harness {
+    accounts: BTreeMap<Pubkey, StoredAccount>,
+    mint: Pubkey,
+    treasury_vault: Pubkey,
+    table: Pubkey,
+    vault: Pubkey,
+    /// The wallet sitting at each seat.
+    players: BTreeMap<u8, Pubkey>,
+    next_computation_offset: u64,
+}
+
+impl Harness {
+    /// A platform and a table with `seats` bought in for `BUY_IN` each.
+    fn new(seats: &[u8]) -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(HarnessSyscalls));
+        });
+        EVENTS.with(|events| events.borrow_mut().clear());
+
+        let table = pda(&[b"table", &TABLE_ID.to_le_bytes()]);
+        let mut harness = Harness {
+            accounts: BTreeMap::new(),
+            mint: Pubkey::new_unique(),
+            treasury_vault: Pubkey::new_unique(),
+            table,
+            vault: pda(&[b"vault", table.as_ref()]),
+            players: BTreeMap::new(),
+            next_computation_offset: 1,
+        };
+        for program in [System::id(), spl_token::ID, crate::ID] {
+            harness.accounts.insert(program, StoredAccount { executable: true, ..Default::default() });
+        }
+        let mut rent = Vec::new();
+        rent.extend_from_slice(&Rent::default().lamports_per_byte_year.to_le_bytes());
+        rent.extend_from_slice(&Rent::default().exemption_threshold.to_le_bytes());
+        rent.push(Rent::default().burn_percent);
+        harness.accounts.insert(sysvar::rent::ID, StoredAccount { data: rent, ..Default::default() });
+        harness.store_packed(
+            harness.mint,
+            spl_token::state::Mint { supply: u64::MAX, is_initialized: true, ..Default::default() },
+        );
+
+        let admin = harness.wallet();
+        harness.token_account(harness.treasury_vault, admin, 0);
+        harness.reserve::<PlatformConfig>(platform_config());
+        harness
+            .process(
+                crate::accounts::InitializePlatformConfig {
+                    platform_config: platform_config(),
+                    admin,
+                    treasury_vault: harness.treasury_vault,
+                    system_program: System::id(),
+                },
+                crate::instruction::InitializePlatformConfig {},
+                Vec::new(),
+            )
+            .unwrap();
+
+        for &seat_index in seats {
+            harness.sit_down(seat_index);
+        }
+        harness
+    }
+
+    /// A funded wallet.
+    fn wallet(&mut self) -> Pubkey {
+        let wallet = Pubkey::new_unique();
+        self.accounts.insert(wallet, StoredAccount { lamports: 10_000_000_000, ..Default::default() });
+        wallet
+    }
+
+    fn token_account(&mut self, key: Pubkey, owner: Pubkey, amount: u64) {
+        self.store_packed(
+            key,
+            spl_token::state::Account {
+                mint: self.mint,
+                owner,
+                amount,
+                state: spl_token::state::AccountState::Initialized,
+                delegate: COption::None,
+                ..Default::default()
+            },
+        );
+    }
+
+    fn store_packed<T: Pack>(&mut self, key: Pubkey, state: T) {
+        let mut data = vec![0; T::LEN];
+        state.pack_into_slice(&mut data);
+        let lamports = Rent::default().minimum_balance(data.len());
+        self.accounts.insert(key, StoredAccount { lamports, data, owner: spl_token::ID, executable: false });
+    }
+
+    /// Sets up the empty `T` account an `init` or `init_if_needed` constraint creates,
+    /// as the System program would leave it once Anchor has written the discriminator.
+    fn reserve<T: Discriminator + Space>(&mut self, key: Pubkey) {
+        let mut data = vec![0; 8 + T::INIT_SPACE];
+        data[..8].copy_from_slice(T::DISCRIMINATOR);
+        let lamports = Rent::default().minimum_balance(data.len());
+        self.accounts.insert(key, StoredAccount { lamports, data, owner: crate::ID, executable: false });
+    }
+
+    fn sit_down(&mut self, seat_index: u8) {
+        let player = self.wallet();
+        let player_token_account = Pubkey::new_unique();
+        self.token_account(player_token_account, player, BUY_IN);
+        let seat = self.seat(seat_index);
+        let rakeback = self.rakeback(&player);
+        let limits = pda(&[b"player_limits", player.as_ref()]);
+        let exclusion = pda(&[b"player_exclusion", player.as_ref()]);
+        let active_tables = pda(&[b"player_active_tables", player.as_ref()]);
+        self.reserve::<PlayerSeat>(seat);
+        self.reserve::<PlayerRakeback>(rakeback);
+        self.reserve::<PlayerLimits>(limits);
+        self.reserve::<PlayerExclusion>(exclusion);
+        self.reserve::<PlayerActiveTables>(active_tables);
+
+        if self.players.is_empty() {
+            self.reserve::<Table>(self.table);
+            self.token_account(self.vault, self.table, 0);
+            self.process(
+                crate::accounts::CreateTable {
+                    table: self.table,
+                    creator: player,
+                    platform_config: platform_config(),
+                    token_mint: self.mint,
+                    creator_token_account: player_token_account,
+                    table_vault: self.vault,
+                    creator_seat: seat,
+                    creator_rakeback: rakeback,
+                    creator_limits: limits,
+                    creator_exclusion: exclusion,
+                    creator_active_tables: active_tables,
+                    system_program: System::id(),
+                    token_program: spl_token::ID,
+                    rent: sysvar::rent::ID,
+                },
+                crate::instruction::CreateTable {
+                    table_id: TABLE_ID,
+                    seat_index,
+                    small_blind: SMALL_BLIND,
+                    big_blind: BIG_BLIND,
+                    buy_in: BUY_IN,
+                    name: [0; 32],
+                    description_hash: [0; 32],
+                    referrer: None,
+                },
+                Vec::new(),
+            )
+            .unwrap();
+        } else {
+            let seat_reservation = pda(&[b"seat_reservation", self.table.as_ref(), &[seat_index]]);
+            self.reserve::<SeatReservation>(seat_reservation);
+            self.process(
+                crate::accounts::JoinTable {
+                    table: self.table,
+                    player,
+                    player_token_account,
+                    table_vault: self.vault,
+                    player_seat: seat,
+                    player_rakeback: rakeback,
+                    seat_reservation,
+                    player_limits: limits,
+                    player_exclusion: exclusion,
+                    player_active_tables: active_tables,
+                    token_program: spl_token::ID,
+                    system_program: System::id(),
+                },
+                crate::instruction::JoinTable { table_id: TABLE_ID, seat_index, buy_in: BUY_IN },
+                Vec::new(),
+            )
+            .unwrap();
+        }
+        self.players.insert(seat_index, player);
+    }
+
+    fn seat(&self, seat_index: u8) -> Pubkey {
+        pda(&[b"player_seat", self.table.as_ref(), &[seat_index]])
+    }
+
+    fn rakeback(&self, player: &Pubkey) -> Pubkey {
+        pda(&[b"player_rakeback", player.as_ref(), self.mint.as_ref()])
+    }
+
+    fn hand_data(&self, hand_id: u64) -> Pubkey {
+        pda(&[b"hand", self.table.as_ref(), &hand_id.to_le_bytes()])
+    }
+
+    fn encrypted_hand(&self, hand_data: &Pubkey, seat_index: u8) -> Pubkey {
+        pda(&[b"encrypted_hand", hand_data.as_ref(), self.players[&seat_index].as_ref()])
+    }
+
+    fn table_state(&self) -> Table {
+        self.load(&self.table)
+    }
+
+    fn seat_state(&self, seat_index: u8) -> PlayerSeat {
+        self.load(&self.seat(seat_index))
+    }
+
+    fn stacks(&self) -> Vec<u64> {
+        self.players.keys().map(|&seat_index| self.seat_state(seat_index).stack).collect()
+    }
+
+    fn load<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        T::try_deserialize(&mut &self.accounts[key].data[..]).unwrap()
+    }
+
+    fn store<T: AccountSerialize>(&mut self, key: &Pubkey, state: &T) {
+        let data = &mut self.accounts.get_mut(key).unwrap().data;
+        data.clear();
+        state.try_serialize(data).unwrap();
+    }
+
+    fn seat_metas(&self) -> Vec<AccountMeta> {
+        self.players.keys().map(|&seat_index| AccountMeta::new(self.seat(seat_index), false)).collect()
+    }
+
+    fn current_hand_data(&self) -> Pubkey {
+        self.hand_data(self.table_state().hand_id_counter)
+    }
+
+    /// Starts the next hand, then queues the shuffle and lands its callback.
+    fn start_hand(&mut self) {
+        self.send_start_hand().unwrap();
+        let hand_data = self.current_hand_data();
+        let payer = self.players.values().next().copied().unwrap();
+        let computation_offset = self.next_computation_offset();
+        self.process(
+            crate::accounts::ShuffleAndDeal {
+                payer,
+                computation_account: Pubkey::new_unique(),
+                system_program: System::id(),
+                hand_data,
+            },
+            crate::instruction::ShuffleAndDeal { computation_offset },
+            Vec::new(),
+        )
+        .unwrap();
+        self.process(
+            crate::accounts::ShuffleAndDealCallback { hand_data, table: self.table },
+            crate::instruction::ShuffleAndDealCallback { _computation_offset: computation_offset },
+            Vec::new(),
+        )
+        .unwrap();
+    }
+
+    /// Sends `start_hand` alone, with the accounts it creates set up beforehand.
+    fn send_start_hand(&mut self) -> ProgramResult {
+        let hand_data = self.hand_data(self.table_state().hand_id_counter.wrapping_add(1));
+        self.reserve::<HandData>(hand_data);
+        let seat_indices: Vec<u8> = self.players.keys().copied().collect();
+        let mut remaining_accounts = self.seat_metas();
+        for seat_index in seat_indices {
+            let encrypted_hand = self.encrypted_hand(&hand_data, seat_index);
+            self.reserve::<EncryptedHand>(encrypted_hand);
+            remaining_accounts.push(AccountMeta::new(encrypted_hand, false));
+        }
+        let payer = self.players.values().next().copied().unwrap();
+        self.process(
+            crate::accounts::StartHand { table: self.table, payer, hand_data, system_program: System::id() },
+            crate::instruction::StartHand { table_id: TABLE_ID },
+            remaining_accounts,
+        )
+    }
+
+    /// Takes `action` for whichever player's turn it is.
+    fn act(&mut self, action: PlayerAction) {
+        let seat_index = self.table_state().turn_position;
+        let others = self
+            .players
+            .keys()
+            .filter(|&&other| other != seat_index)
+            .map(|&other| AccountMeta::new(self.seat(other), false))
+            .collect();
+        self.process(
+            crate::accounts::PlayerActionAccounts {
+                table: self.table,
+                player: self.players[&seat_index],
+                player_seat: self.seat(seat_index),
+                platform_config: platform_config(),
+                table_vault: self.vault,
+                treasury_vault: self.treasury_vault,
+                token_program: spl_token::ID,
+            },
+            crate::instruction::PlayerAction { table_id: TABLE_ID, action },
+            others,
+        )
+        .unwrap();
+    }
+
+    /// Queues the next street's reveal and lands its callback, then deals the street.
+    fn deal_street(&mut self) {
+        let hand_data = self.current_hand_data();
+        let payer = self.players.values().next().copied().unwrap();
+        let num_cards = if self.table_state().community_cards[0].is_none() { 3 } else { 1 };
+        let computation_offset = self.next_computation_offset();
+        self.process(
+            crate::accounts::RevealCommunityCards {
+                payer,
+                computation_account: Pubkey::new_unique(),
+                system_program: System::id(),
+                table: self.table,
+                hand_data,
+            },
+            crate::instruction::RevealCommunityCards { computation_offset, num_cards },
+            Vec::new(),
+        )
+        .unwrap();
+        self.process(
+            crate::accounts::RevealCommunityCardsCallback {
+                hand_data,
+                table: self.table,
+                platform_config: platform_config(),
+            },
+            crate::instruction::RevealCommunityCardsCallback { _computation_offset: computation_offset },
+            Vec::new(),
+        )
+        .unwrap();
+        self.process(
+            crate::accounts::DealCommunityCards {
+                table: self.table,
+                hand_data,
+                payer,
+                system_program: System::id(),
+            },
+            crate::instruction::DealCommunityCards { table_id: TABLE_ID },
+            self.seat_metas(),
+        )
+        .unwrap();
+    }
+
+    fn resolve_showdown(&mut self) {
+        let hand_data = self.current_hand_data();
+        let payer = self.players.values().next().copied().unwrap();
+        let mut remaining_accounts = self.seat_metas();
+        for &seat_index in self.players.keys() {
+            if self.seat_state(seat_index).is_active_in_hand {
+                remaining_accounts.push(AccountMeta::new(self.encrypted_hand(&hand_data, seat_index), false));
+            }
+        }
+        for player in self.players.values() {
+            remaining_accounts.push(AccountMeta::new(self.rakeback(player), false));
+        }
+        self.process(
+            crate::accounts::ResolveShowdown {
+                table: self.table,
+                hand_data,
+                payer,
+                platform_config: platform_config(),
+                table_vault: self.vault,
+                treasury_vault: self.treasury_vault,
+                referrer_token_account: None,
+                token_program: spl_token::ID,
+                system_program: System::id(),
+            },
+            crate::instruction::ResolveShowdown { table_id: TABLE_ID },
+            remaining_accounts,
+        )
+        .unwrap();
+    }
+
+    /// Plays one scripted street per entry, in turn order, dealing the board between
+    /// them, and resolves the showdown if the river betting closes.
+    fn play_hand(&mut self, streets: &[&[PlayerAction]]) {
+        self.start_hand();
+        for (street, actions) in streets.iter().enumerate() {
+            if street > 0 {
+                self.deal_street();
+            }
+            for &action in actions.iter() {
+                self.act(action);
+            }
+        }
+        if self.table_state().hand_phase == HandPhase::AwaitingShowdown {
+            self.resolve_showdown();
+        }
+    }
+
+    fn next_computation_offset(&mut self) -> u64 {
+        self.next_computation_offset += 1;
+        self.next_computation_offset
+    }
+
+    /// Runs one instruction against the stored accounts, keeping its writes if it succeeds.
+    fn process(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        data: impl InstructionData,
+        remaining_accounts: Vec<AccountMeta>,
+    ) -> ProgramResult {
+        let mut metas = accounts.to_account_metas(None);
+        metas.extend(remaining_accounts);
+        let instruction = Instruction { program_id: crate::ID, accounts: metas, data: data.data() };
+        UNIX_TIMESTAMP.with(|now| now.set(now.get() + 1));
+
+        // One serialized account per distinct key, flagged as the most permissive of its metas.
+        let mut keys: Vec<(Pubkey, bool, bool)> = Vec::new();
+        for meta in instruction.accounts.iter() {
+            match keys.iter_mut().find(|(key, _, _)| *key == meta.pubkey) {
+                Some((_, is_signer, is_writable)) => {
+                    *is_signer |= meta.is_signer;
+                    *is_writable |= meta.is_writable;
+                }
+                None => keys.push((meta.pubkey, meta.is_signer, meta.is_writable)),
+            }
+        }
+        let mut serialized: Vec<Box<SerializedAccount>> = keys
+            .iter()
+            .map(|(key, _, _)| {
+                let stored = self.accounts.get(key).cloned().unwrap_or_default();
+                let mut account = Box::new(SerializedAccount {
+                    original_data_len: stored.data.len() as u32,
+                    key: *key,
+                    owner: stored.owner,
+                    lamports: stored.lamports,
+                    data_len: stored.data.len() as u64,
+                    data: [0; MAX_ACCOUNT_DATA],
+                });
+                account.data[..stored.data.len()].copy_from_slice(&stored.data);
+                account
+            })
+            .collect();
+
+        let result = {
+            let infos: Vec<AccountInfo> = serialized
+                .iter_mut()
+                .zip(keys.iter())
+                .map(|(account, &(key, is_signer, is_writable))| {
+                    let SerializedAccount { key: account_key, owner, lamports, data_len, data, .. } = &mut **account;
+                    let executable = self.accounts.get(&key).is_some_and(|stored| stored.executable);
+                    let data = &mut data[..*data_len as usize];
+                    AccountInfo::new(account_key, is_signer, is_writable, lamports, data, owner, executable, 0)
+                })
+                .collect();
+            let infos: Vec<AccountInfo> = instruction
+                .accounts
+                .iter()
+                .map(|meta| infos[keys.iter().position(|(key, _, _)| *key == meta.pubkey).unwrap()].clone())
+                .collect();
+            crate::entry(&instruction.program_id, &infos, &instruction.data)
+        };
+
+        if result.is_ok() {
+            for account in serialized.iter() {
+                let executable = self.accounts.get(&account.key).is_some_and(|stored| stored.executable);
+                if account.lamports == 0 && !executable {
+                    self.accounts.remove(&account.key);
+                    continue;
+                }
+                self.accounts.insert(
+                    account.key,
+                    StoredAccount {
+                        lamports: account.lamports,
+                        data: account.data[..account.data_len as usize].to_vec(),
+                        owner: account.owner,
+                        executable,
+                    },
+                );
+            }
+        }
+        result
+    }
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &crate::ID).0
+}
+
+fn platform_config() -> Pubkey {
+    pda(&[b"platform_config"])
+}
+
+/// Every `T` event emitted so far by this test.
+fn events<T: Event + Discriminator>() -> Vec<T> {
+    EVENTS.with(|events| {
+        events
+            .borrow()
+            .iter()
+            .filter(|data| data.starts_with(T::DISCRIMINATOR))
+            .map(|data| T::try_from_slice(&data[T::DISCRIMINATOR.len()..]).unwrap())
+            .collect()
+    })
+}
+
+#[test]
+fn a_scripted_hand_reaches_showdown_and_pays_the_best_hand_less_the_rake() {
+    let mut harness = Harness::new(&[0, 1, 2]);
+
+    // Everyone limps and checks the big blind's option, the flop is bet and called
+    // twice, and the turn and river are checked down.
+    harness.play_hand(&[
+        &[PlayerAction::Call, PlayerAction::Call, PlayerAction::Check],
+        &[PlayerAction::Bet { amount: 40 }, PlayerAction::Call, PlayerAction::Call],
+        &[PlayerAction::Check; 3],
+        &[PlayerAction::Check; 3],
+    ]);
+
+    // The simulated deck is in index order: six hole cards, then burn-and-deal, so the
+    // board is 9c Tc Jc Kc 2d. Seat 2 holds the 7c under either deal style, making the
+    // best flush.
+    let table = harness.table_state();
+    let board: Vec<u8> = table.community_cards.iter().flatten().map(|card| card.to_index().get()).collect();
+    assert_eq!(board, vec![7, 8, 9, 11, 13]);
+    assert_eq!(table.game_state, GameState::HandComplete);
+    assert_eq!(table.hand_phase, HandPhase::Complete);
+
+    // A 5% rake on the 180-chip pot; the rest goes to seat 2.
+    let resolved = events::<HandResolved>();
+    assert_eq!(resolved.len(), 1);
+    assert_eq!((resolved[0].pot, resolved[0].rake), (180, 9));
+    assert_eq!(harness.stacks(), vec![340, 340, 511]);
+    assert_eq!(harness.stacks().iter().sum::<u64>() + 9, 3 * BUY_IN);
+    assert!(events::<PotAwarded>().is_empty());
+}
+
+#[test]
+fn a_scripted_hand_folded_preflop_pays_the_big_blind_unraked() {
+    let mut harness = Harness::new(&[0, 1, 2]);
+
+    // Three-handed, the button acts first preflop: the button and small blind fold.
+    harness.play_hand(&[&[PlayerAction::Fold, PlayerAction::Fold]]);
+
+    let table = harness.table_state();
+    assert_eq!(table.game_state, GameState::HandComplete);
+    assert_eq!(table.pot, 0);
+    assert!(table.community_cards.iter().all(|card| card.is_none()));
+
+    let button = table.dealer_position;
+    let (small_blind, big_blind) = ((button + 1) % 3, (button + 2) % 3);
+    assert_eq!(harness.seat_state(button).stack, BUY_IN);
+    assert_eq!(harness.seat_state(small_blind).stack, BUY_IN - SMALL_BLIND);
+    assert_eq!(harness.seat_state(big_blind).stack, BUY_IN + SMALL_BLIND);
+
+    // Both blinds go to the big blind; a pot won before the flop is not raked.
+    let awarded = events::<PotAwarded>();
+    assert_eq!(awarded.len(), 1);
+    assert_eq!((awarded[0].seat_index, awarded[0].amount, awarded[0].rake), (big_blind, 30, 0));
+    assert!(events::<HandResolved>().is_empty());
+}
+
+#[test]
+fn a_table_out_of_hand_ids_cannot_start_another_hand() {
+    let mut harness = Harness::new(&[0, 1]);
+    let mut table = harness.table_state();
+    table.hand_id_counter = u64::MAX;
+    let table_key = harness.table;
+    harness.store(&table_key, &table);
+
+    let res = harness.send_start_hand();
+    assert_eq!(res.unwrap_err(), Error::from(AcesUnknownErrorCode::InvalidGameState).into());
+    assert_eq!(harness.table_state().hand_id_counter, u64::MAX);
+    assert_eq!(harness.stacks(), vec![BUY_IN, BUY_IN]);
+}
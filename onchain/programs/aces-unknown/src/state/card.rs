@@ -8,8 +8,27 @@
 //! Key features:
 //! - Represents a card with a `rank` and a `suit`.
 //! - Derives necessary traits for on-chain storage and client-side deserialization.
+//! - `CardIndex` wraps the raw 0-51 deck index that Arcium circuits return, with a
+//!   single sentinel (`INVALID_CARD_INDEX`) shared with the circuits for padding.
+//! - `revealed_to_cards` decodes a circuit's revealed indices into public cards.
 
 use anchor_lang::prelude::*;
+use crate::error::AcesUnknownErrorCode;
+
+/// The number of cards in a standard deck; valid card indices are `0..DECK_SIZE`.
+pub const DECK_SIZE: u8 = 52;
+
+/// The sentinel index used for dummy hole cards, burned cards, and reveal padding.
+/// Must match `INVALID_CARD_INDEX` in the encrypted instructions crate. It is the
+/// first value past the deck so it still fits the circuits' 6-bit card packing.
+pub const INVALID_CARD_INDEX: u8 = DECK_SIZE;
+
+/// Decodes the card indices revealed by a circuit into public cards, one per index:
+/// real cards (0-51) become `Some(Card)`, and the `INVALID_CARD_INDEX` padding (or
+/// any other out-of-range value) becomes `None`.
+pub fn revealed_to_cards(indices: &[u8]) -> Vec<Option<Card>> {
+    indices.iter().map(|&index| CardIndex(index).card()).collect()
+}
 
 /// Represents a single playing card with its rank and suit.
 /// This struct is intended for storing public card information on-chain,
@@ -25,4 +44,99 @@ pub struct Card {
     /// The suit of the card, from 0 to 3.
     /// 0 = Clubs, 1 = Diamonds, 2 = Hearts, 3 = Spades.
     pub suit: u8,
-}
\ No newline at end of file
+}
+
+impl Card {
+    /// Creates a card from its deck index. Matches `Card::from_index` in the circuits:
+    /// `suit = index / 13`, `rank = index % 13`.
+    pub fn from_index(index: CardIndex) -> Self {
+        Card {
+            rank: index.get() % 13,
+            suit: index.get() / 13,
+        }
+    }
+
+    /// Returns the card's deck index, `suit * 13 + rank`.
+    pub fn to_index(&self) -> CardIndex {
+        CardIndex(self.suit * 13 + self.rank)
+    }
+}
+
+/// A card's position in a standard deck (`suit * 13 + rank`), as produced by the
+/// Arcium circuits. Construct with `CardIndex::new` to reject out-of-range values.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CardIndex(u8);
+
+impl CardIndex {
+    /// The padding value circuits use for "no card".
+    pub const INVALID: CardIndex = CardIndex(INVALID_CARD_INDEX);
+
+    /// Validates a raw index from a circuit. Only real cards (0-51) are accepted.
+    pub fn new(index: u8) -> Result<Self> {
+        require!(index < DECK_SIZE, AcesUnknownErrorCode::InvalidCardIndex);
+        Ok(CardIndex(index))
+    }
+
+    /// Returns the raw index.
+    pub fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `false` for the `INVALID` sentinel.
+    pub fn is_valid(self) -> bool {
+        self.0 < DECK_SIZE
+    }
+
+    /// The public card this index refers to, or `None` for the sentinel.
+    pub fn card(self) -> Option<Card> {
+        if !self.is_valid() {
+            return None;
+        }
+        Some(Card::from_index(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card_indices_past_the_deck_are_rejected() {
+        assert_eq!(CardIndex::new(0).unwrap().get(), 0);
+        assert_eq!(CardIndex::new(51).unwrap().get(), 51);
+        for index in [DECK_SIZE, INVALID_CARD_INDEX, 63, u8::MAX] {
+            assert_eq!(CardIndex::new(index).unwrap_err(), AcesUnknownErrorCode::InvalidCardIndex.into());
+        }
+    }
+
+    #[test]
+    fn every_card_round_trips_through_its_index() {
+        for index in 0..DECK_SIZE {
+            let card = Card::from_index(CardIndex::new(index).unwrap());
+            assert!(card.rank < 13 && card.suit < 4);
+            assert_eq!(card.to_index().get(), index);
+            assert_eq!(CardIndex::new(index).unwrap().card(), Some(card));
+        }
+        // Every card is distinct.
+        let mut cards: Vec<(u8, u8)> = (0..DECK_SIZE)
+            .map(|index| Card::from_index(CardIndex::new(index).unwrap()))
+            .map(|card| (card.suit, card.rank))
+            .collect();
+        cards.sort();
+        cards.dedup();
+        assert_eq!(cards.len(), DECK_SIZE as usize);
+    }
+
+    #[test]
+    fn the_sentinel_is_the_one_invalid_index_everywhere() {
+        assert_eq!(CardIndex::INVALID.get(), INVALID_CARD_INDEX);
+        assert_eq!(INVALID_CARD_INDEX, DECK_SIZE);
+        assert!(!CardIndex::INVALID.is_valid());
+        assert_eq!(CardIndex::INVALID.card(), None);
+        // It still fits the circuits' 6-bit card packing.
+        assert!(INVALID_CARD_INDEX < 1 << 6);
+
+        let cards = revealed_to_cards(&[7, INVALID_CARD_INDEX, 51]);
+        assert_eq!(cards, vec![Some(Card { rank: 7, suit: 0 }), None, Some(Card { rank: 12, suit: 3 })]);
+    }
+}
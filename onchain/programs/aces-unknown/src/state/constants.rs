@@ -7,6 +7,50 @@
 //! Key Constants:
 //! - MAX_PLAYERS: The maximum number of players allowed at a single poker table.
 //!                This is set to 6 for "6-max" No-Limit Texas Hold'em games.
+//! - MAX_ALLOWED_MINTS: The maximum number of token mints on the platform's whitelist.
+//! - MAX_TABLE_INVITES: The maximum number of wallets on a private table's access list.
+//! - MAX_COMPUTATIONS_PER_HAND: How many Arcium computations one hand may queue.
+//! - DEFAULT/MAX_REVEAL_RETRIES: How many failed community-card reveals a hand survives
+//!   before it is voided, and the most the platform may allow.
+//! - DEFAULT/MIN/MAX_REFUND_TIMEOUT_SECONDS: How long a hand must be stuck before
+//!   `force_hand_refund` can void it, and the range a table may configure.
 
 // The maximum number of players allowed at a poker table.
-pub const MAX_PLAYERS: usize = 6;
\ No newline at end of file
+pub const MAX_PLAYERS: usize = 6;
+
+// The maximum number of token mints the platform can whitelist for new tables.
+pub const MAX_ALLOWED_MINTS: usize = 16;
+
+// The maximum number of wallets a private table can invite.
+pub const MAX_TABLE_INVITES: usize = 32;
+
+// The most Arcium computations a hand may queue: the shuffle, one reveal per street,
+// the showdown and every reveal retry the platform may allow, with room to spare.
+pub const MAX_COMPUTATIONS_PER_HAND: usize = 12;
+
+// How many failed community-card reveals a hand may retry before it is voided, until
+// the admin configures otherwise.
+pub const DEFAULT_MAX_REVEAL_RETRIES: u8 = 2;
+
+// The most reveal retries the admin may allow. Each retry queues another computation,
+// so the limit must leave the hand room in `MAX_COMPUTATIONS_PER_HAND` to finish.
+pub const MAX_REVEAL_RETRIES: u8 = 4;
+
+// How many of a seated player's most recent hole-card encryption nonces are kept to
+// catch a nonce being reused with their key.
+pub const RECENT_HAND_NONCES: usize = 8;
+
+// The largest keeper reward the admin may set, in basis points of the big blind (half a
+// big blind).
+pub const MAX_KEEPER_REWARD_BPS: u16 = 5_000;
+
+// The stuck-hand timeout for tables that do not configure their own (5 minutes).
+pub const DEFAULT_REFUND_TIMEOUT_SECONDS: u32 = 300;
+
+// The shortest stuck-hand timeout a table may set, so hands that are merely slow
+// cannot be voided.
+pub const MIN_REFUND_TIMEOUT_SECONDS: u32 = 60;
+
+// The longest stuck-hand timeout a table may set (30 days). A very long timeout all but
+// disables refunds on a trusted cluster, but a stuck hand can never lock funds forever.
+pub const MAX_REFUND_TIMEOUT_SECONDS: u32 = 30 * 24 * 60 * 60;
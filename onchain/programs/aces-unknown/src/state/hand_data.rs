@@ -8,9 +8,18 @@
 //! Key features:
 //! - Stores the encrypted deck and player hands, ensuring no party can see hidden cards.
 //! - Holds a cryptographic commitment to the shuffle for later verification.
+//! - Records whether any hand shown at showdown failed verification against the deck.
+//! - Records whether a street's community cards are being revealed, or the showdown
+//!   is being evaluated, so neither can be requested twice, and how many reveals have
+//!   failed, so a hand cannot retry them forever.
+//! - Records the offset of the computation queued for the hand, so only its callback
+//!   can write back to it, and every offset the hand has used, so none is reused.
 //! - Uses fixed-size arrays for predictable on-chain sizing.
 
 use anchor_lang::prelude::*;
+use crate::error::AcesUnknownErrorCode;
+use crate::state::constants::MAX_COMPUTATIONS_PER_HAND;
+use crate::state::DealStyle;
 
 /// An account to store the encrypted data for a single hand of poker.
 /// This data is generated by Arcium and used as input for subsequent Arcium computations.
@@ -33,6 +42,81 @@ pub struct HandData {
 
     /// The nonce used for encrypting and decrypting the deck.
     pub encrypted_deck_nonce: u128,
+
+    /// How many cards from the top of the deck were dealt as hole cards (two per
+    /// player dealt in), as reported by `shuffle_and_deal`. The board is drawn from
+    /// this position onward.
+    pub cards_dealt: u8,
+
+    /// Bitmask of the seats dealt into this hand (bit `i` set for seat `i`), which
+    /// fixes the deck positions each seat's hole cards were dealt from.
+    pub dealt_in_seats: u8,
+
+    /// The table's deal style when the hand was dealt, which together with
+    /// `dealt_in_seats` fixes the deck position of every hole card.
+    pub deal_style: DealStyle,
+
+    /// Set while a `reveal_community_cards` computation is in flight, and cleared by
+    /// its callback. No other street can be requested until the reveal lands.
+    pub pending_reveal: bool,
+
+    /// Set while the `evaluate_hands_and_payout` computation is in flight, and cleared
+    /// by its callback. A second showdown cannot be queued, so the pot is paid once.
+    pub showdown_in_progress: bool,
+
+    /// How many `reveal_community_cards` computations have failed this hand, and so had
+    /// to be queued again. Past `PlatformConfig::max_reveal_retries` the hand is voided.
+    pub reveal_retry_count: u8,
+
+    /// The offset of the Arcium computation queued for this hand and not yet called
+    /// back. A callback must present the same offset, and clears it when it lands.
+    pub pending_computation_offset: Option<u64>,
+
+    /// The offsets of every computation queued for this hand, in order. The first
+    /// `computations_queued` entries are in use.
+    pub used_computation_offsets: [u64; MAX_COMPUTATIONS_PER_HAND],
+
+    /// How many computations have been queued for this hand.
+    pub computations_queued: u8,
+}
+
+impl HandData {
+    /// Records a newly queued computation as the one the next callback must match.
+    /// An offset this hand has already used is rejected, since Arcium keys its
+    /// computation accounts by offset, as is queuing while another is still pending.
+    pub fn record_queued_computation(&mut self, computation_offset: u64) -> Result<()> {
+        let queued = self.computations_queued as usize;
+        require!(
+            !self.used_computation_offsets[..queued].contains(&computation_offset),
+            AcesUnknownErrorCode::ComputationOffsetReused
+        );
+        require!(
+            self.pending_computation_offset.is_none() && queued < MAX_COMPUTATIONS_PER_HAND,
+            AcesUnknownErrorCode::InvalidGameState
+        );
+
+        self.used_computation_offsets[queued] = computation_offset;
+        self.computations_queued += 1;
+        self.pending_computation_offset = Some(computation_offset);
+        Ok(())
+    }
+
+    /// Marks the `evaluate_hands_and_payout` computation as queued, so the pot is paid
+    /// out only by its callback. Fails if a showdown is already being evaluated.
+    pub fn begin_showdown(&mut self) -> Result<()> {
+        require!(!self.showdown_in_progress, AcesUnknownErrorCode::InvalidGameState);
+        self.showdown_in_progress = true;
+        Ok(())
+    }
+
+    /// Counts a failed `reveal_community_cards` computation, so the street's reveal can
+    /// be queued again. Returns whether more reveals have now failed than
+    /// `max_reveal_retries` allows, and the hand must be voided instead.
+    pub fn record_failed_reveal(&mut self, max_reveal_retries: u8) -> bool {
+        self.pending_reveal = false;
+        self.reveal_retry_count = self.reveal_retry_count.saturating_add(1);
+        self.reveal_retry_count > max_reveal_retries
+    }
 }
 
 /// A struct to hold the encrypted information for a single player's hand.
@@ -46,4 +130,56 @@ pub struct EncryptedHandInfo {
     pub nonce: u128,
     /// The player's x25519 public key used for the key exchange.
     pub encryption_key: [u8; 32],
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_hand() -> HandData {
+        HandData::deserialize(&mut &vec![0u8; HandData::INIT_SPACE][..]).unwrap()
+    }
+
+    #[test]
+    fn failed_reveals_are_retried_up_to_the_limit() {
+        let mut hand = empty_hand();
+        hand.pending_reveal = true;
+        assert!(!hand.record_failed_reveal(2));
+        assert!(!hand.pending_reveal);
+        assert!(!hand.record_failed_reveal(2));
+        assert_eq!(hand.reveal_retry_count, 2);
+        assert!(hand.record_failed_reveal(2));
+        assert_eq!(hand.reveal_retry_count, 3);
+    }
+
+    #[test]
+    fn no_retries_voids_on_the_first_failure() {
+        let mut hand = empty_hand();
+        assert!(hand.record_failed_reveal(0));
+    }
+
+    #[test]
+    fn a_showdown_is_evaluated_once_at_a_time() {
+        let mut hand = empty_hand();
+        hand.begin_showdown().unwrap();
+        assert!(hand.showdown_in_progress);
+        assert_eq!(
+            hand.begin_showdown().unwrap_err(),
+            AcesUnknownErrorCode::InvalidGameState.into()
+        );
+    }
+
+    #[test]
+    fn a_used_computation_offset_is_rejected() {
+        let mut hand = empty_hand();
+        hand.record_queued_computation(7).unwrap();
+        assert!(hand.record_queued_computation(8).is_err());
+        hand.pending_computation_offset = None;
+        assert_eq!(
+            hand.record_queued_computation(7).unwrap_err(),
+            AcesUnknownErrorCode::ComputationOffsetReused.into()
+        );
+        hand.record_queued_computation(8).unwrap();
+        assert_eq!(hand.computations_queued, 2);
+    }
+}
@@ -15,6 +15,11 @@ pub mod card;
 pub mod constants;
 pub mod player_seat;
 pub mod encrypted_hand;
+pub mod player_rakeback;
+pub mod seat_reservation;
+pub mod player_limits;
+pub mod player_exclusion;
+pub mod player_active_tables;
 
 // Re-export the contents of each submodule for easy access from other parts of the program.
 pub use platform_config::*;
@@ -23,4 +28,9 @@ pub use hand_data::*;
 pub use card::*;
 pub use constants::*;
 pub use player_seat::*;
-pub use encrypted_hand::*;
\ No newline at end of file
+pub use encrypted_hand::*;
+pub use player_rakeback::*;
+pub use seat_reservation::*;
+pub use player_limits::*;
+pub use player_exclusion::*;
+pub use player_active_tables::*;
\ No newline at end of file
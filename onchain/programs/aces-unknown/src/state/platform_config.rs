@@ -7,9 +7,17 @@
 //!
 //! Key features:
 //! - Stores the administrative authority wallet.
-//! - Defines configurable rake parameters (basis points and max cap).
+//! - Defines configurable rake parameters (basis points, max cap, whether only
+//!   showdown pots are raked, and whether a showdown's side pots are raked separately).
+//! - Defines the share of rake returned to players as rake-back, and the share paid
+//!   to a table's referrer.
+//! - Optionally restricts new tables to a whitelist of token mints.
+//! - Defines the reward paid to keepers who force a fold or a stuck-hand refund.
+//! - Bounds how many failed community-card reveals a hand may retry.
+//! - Keeps platform-wide table and hand counters (`PlatformStats`) for indexers.
 
 use anchor_lang::prelude::*;
+use crate::state::constants::MAX_ALLOWED_MINTS;
 
 /// A singleton account that stores global platform settings.
 /// This account is controlled by an administrative key.
@@ -31,4 +39,72 @@ pub struct PlatformConfig {
     
     /// The treasury vault account where platform rake is collected.
     pub treasury_vault: Pubkey,
-}
\ No newline at end of file
+
+    /// Rake-back settings for rewarding player volume.
+    pub rakeback: RakebackConfig,
+
+    /// When set, only pots that reach a showdown are raked; a pot won because
+    /// everyone else folded is paid out in full.
+    pub rake_on_showdown_only: bool,
+
+    /// The share of a showdown pot's rake paid to the table's referrer, if it has one,
+    /// in basis points. The treasury keeps the rest.
+    pub referrer_rake_bps: u16,
+
+    /// Whether a showdown's rake is computed and capped on the whole pot or on each
+    /// side pot separately.
+    pub rake_scope: RakeScope,
+
+    /// The reward paid to a keeper for each `force_player_fold` or `force_hand_refund`,
+    /// in basis points of the table's big blind, taken out of the pot. 0 disables it.
+    pub keeper_reward_bps: u16,
+
+    /// How many times a hand may re-queue a community-card reveal whose computation
+    /// failed. One more failure voids the hand and refunds it.
+    pub max_reveal_retries: u8,
+
+    /// The token mints tables may be created with. Empty allows any mint.
+    #[max_len(MAX_ALLOWED_MINTS)]
+    pub allowed_mints: Vec<Pubkey>,
+
+    /// Platform-wide counters, maintained as tables open and close and hands finish.
+    pub stats: PlatformStats,
+}
+
+impl PlatformConfig {
+    /// Whether a new table may use `mint` as its currency.
+    pub fn is_mint_allowed(&self, mint: &Pubkey) -> bool {
+        self.allowed_mints.is_empty() || self.allowed_mints.contains(mint)
+    }
+}
+
+/// Which pot a showdown's rake is computed and capped on.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum RakeScope {
+    /// One rake on the whole pot, capped once, taken out of the main pot first.
+    #[default]
+    TotalPot,
+    /// Each side pot is raked and capped on its own, as if it were a separate pot.
+    PerPot,
+}
+
+/// Configuration for returning part of the collected rake to the players who paid it.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct RakebackConfig {
+    /// The share of each player's rake contribution returned to them, in basis points.
+    /// 0 disables rake-back.
+    pub rakeback_bps: u16,
+}
+
+/// Platform-wide counters for indexers and analytics. Every change is announced with a
+/// `PlatformStatsUpdated` event.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct PlatformStats {
+    /// Every table ever created, including tables since closed.
+    pub total_tables_created: u64,
+    /// Tables created and not yet closed.
+    pub active_tables: u64,
+    /// Hands played to completion, whether won at showdown or because everyone else
+    /// folded. Voided and refunded hands are not counted.
+    pub total_hands_played: u64,
+}
@@ -0,0 +1,61 @@
+//! src/state/player_active_tables.rs
+//!
+//! @description
+//! This module defines the `PlayerActiveTables` account, which counts the tables a
+//! player is currently seated at. The count goes up when they create or join a table
+//! and down when their seat is closed, so a player can cap how many tables they play
+//! at once.
+//!
+//! Key features:
+//! - One account per player, created the first time they take a seat or set a cap
+//! - `max_concurrent_tables` is self-imposed; a new seat beyond it is rejected
+//! - Lowering the cap below the current count only blocks new seats
+
+use anchor_lang::prelude::*;
+use crate::error::AcesUnknownErrorCode;
+
+/// The cap value meaning "no limit".
+pub const NO_CONCURRENT_TABLE_LIMIT: u16 = u16::MAX;
+
+/// Tracks how many tables a player is seated at, and their cap.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerActiveTables {
+    /// The player's wallet public key
+    pub player: Pubkey,
+
+    /// The number of tables the player currently holds a seat at
+    pub active_tables: u16,
+
+    /// The most tables the player may sit at at once (`NO_CONCURRENT_TABLE_LIMIT` if unset)
+    pub max_concurrent_tables: u16,
+
+    /// Bump seed for the PDA
+    pub bump: u8,
+}
+
+impl PlayerActiveTables {
+    /// Fills in the account's identity the first time it is created, with no cap set.
+    pub fn initialize_if_new(&mut self, player: Pubkey, bump: u8) {
+        if self.player == Pubkey::default() {
+            self.player = player;
+            self.max_concurrent_tables = NO_CONCURRENT_TABLE_LIMIT;
+            self.bump = bump;
+        }
+    }
+
+    /// Records a new seat, failing if it would exceed the player's cap.
+    pub fn record_join(&mut self) -> Result<()> {
+        require!(
+            self.active_tables < self.max_concurrent_tables,
+            AcesUnknownErrorCode::TooManyConcurrentTables
+        );
+        self.active_tables += 1;
+        Ok(())
+    }
+
+    /// Records that one of the player's seats was closed.
+    pub fn record_leave(&mut self) {
+        self.active_tables = self.active_tables.saturating_sub(1);
+    }
+}
@@ -0,0 +1,55 @@
+//! src/state/player_exclusion.rs
+//!
+//! @description
+//! This module defines the `PlayerExclusion` account, which records a player's
+//! self-exclusion: a responsible-gaming block on creating or joining any table until
+//! a chosen time. `create_table` and `join_table` reject an excluded player.
+//!
+//! Key features:
+//! - One account per player, created the first time they sit down or exclude themselves
+//! - An exclusion can be extended but never shortened, so it cannot be undone on impulse
+
+use anchor_lang::prelude::*;
+use crate::error::AcesUnknownErrorCode;
+
+/// Tracks how long a player has excluded themselves from play.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerExclusion {
+    /// The player's wallet public key
+    pub player: Pubkey,
+
+    /// Unix timestamp until which the player may not create or join tables (0 if never excluded)
+    pub excluded_until: i64,
+
+    /// Bump seed for the PDA
+    pub bump: u8,
+}
+
+impl PlayerExclusion {
+    /// Fills in the account's identity the first time it is created, with no exclusion.
+    pub fn initialize_if_new(&mut self, player: Pubkey, bump: u8) {
+        if self.player == Pubkey::default() {
+            self.player = player;
+            self.bump = bump;
+        }
+    }
+
+    /// Excludes the player until `until`, which must lie in the future and may not end
+    /// a current exclusion any earlier.
+    pub fn exclude_until(&mut self, until: i64, now: i64) -> Result<()> {
+        require!(until > now, AcesUnknownErrorCode::InvalidAction);
+        require!(
+            until >= self.excluded_until,
+            AcesUnknownErrorCode::ExclusionCannotBeShortened
+        );
+        self.excluded_until = until;
+        Ok(())
+    }
+
+    /// Fails with `SelfExcluded` while the player's exclusion is running.
+    pub fn require_not_excluded(&self, now: i64) -> Result<()> {
+        require!(now >= self.excluded_until, AcesUnknownErrorCode::SelfExcluded);
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+//! src/state/player_limits.rs
+//!
+//! @description
+//! This module defines the `PlayerLimits` account, which holds a player's
+//! self-imposed responsible-gaming limits. The player caps the total amount they
+//! can buy in for across all tables within a rolling period; `create_table` and
+//! `join_table` record every buy-in against the cap.
+//!
+//! Key features:
+//! - One account per player, created the first time they buy in or set a limit
+//! - Lowering the limit takes effect immediately
+//! - Raising (or removing) the limit only takes effect after a cooldown, so it
+//!   cannot be undone in the heat of a session
+
+use anchor_lang::prelude::*;
+use crate::error::AcesUnknownErrorCode;
+
+/// The length of the period over which buy-ins are totalled, in seconds.
+pub const BUY_IN_LIMIT_PERIOD_SECONDS: i64 = 24 * 60 * 60;
+
+/// How long a requested limit increase waits before it applies, in seconds.
+pub const LIMIT_INCREASE_COOLDOWN_SECONDS: i64 = 24 * 60 * 60;
+
+/// The limit value meaning "no limit".
+pub const NO_BUY_IN_LIMIT: u64 = u64::MAX;
+
+/// Tracks a player's buy-in limit and their buy-ins in the current period.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerLimits {
+    /// The player's wallet public key
+    pub player: Pubkey,
+    
+    /// Maximum total buy-ins per period (`NO_BUY_IN_LIMIT` if unset)
+    pub max_buy_in_per_period: u64,
+    
+    /// A requested increase waiting out the cooldown
+    pub pending_max_buy_in: Option<u64>,
+    
+    /// Unix timestamp at which `pending_max_buy_in` applies
+    pub pending_effective_at: i64,
+    
+    /// Unix timestamp at which the current period started
+    pub period_started_at: i64,
+    
+    /// Total bought in during the current period
+    pub bought_in_this_period: u64,
+    
+    /// Bump seed for the PDA
+    pub bump: u8,
+}
+
+impl PlayerLimits {
+    /// Fills in the account's identity the first time it is created, with no limit set.
+    pub fn initialize_if_new(&mut self, player: Pubkey, bump: u8) {
+        if self.player == Pubkey::default() {
+            self.player = player;
+            self.max_buy_in_per_period = NO_BUY_IN_LIMIT;
+            self.bump = bump;
+        }
+    }
+
+    /// Changes the limit. Decreases apply at once and cancel any pending increase;
+    /// increases are queued until `LIMIT_INCREASE_COOLDOWN_SECONDS` have passed.
+    pub fn set_limit(&mut self, new_limit: u64, now: i64) {
+        self.apply_pending_increase(now);
+        if new_limit <= self.max_buy_in_per_period {
+            self.max_buy_in_per_period = new_limit;
+            self.pending_max_buy_in = None;
+        } else {
+            self.pending_max_buy_in = Some(new_limit);
+            self.pending_effective_at = now + LIMIT_INCREASE_COOLDOWN_SECONDS;
+        }
+    }
+
+    /// Records a buy-in of `amount`, failing if it would exceed the limit for the period.
+    pub fn record_buy_in(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.apply_pending_increase(now);
+        if now >= self.period_started_at + BUY_IN_LIMIT_PERIOD_SECONDS {
+            self.period_started_at = now;
+            self.bought_in_this_period = 0;
+        }
+
+        let total = self
+            .bought_in_this_period
+            .checked_add(amount)
+            .ok_or(AcesUnknownErrorCode::PlayerLimitExceeded)?;
+        require!(
+            total <= self.max_buy_in_per_period,
+            AcesUnknownErrorCode::PlayerLimitExceeded
+        );
+        self.bought_in_this_period = total;
+        Ok(())
+    }
+
+    fn apply_pending_increase(&mut self, now: i64) {
+        if let Some(pending) = self.pending_max_buy_in {
+            if now >= self.pending_effective_at {
+                self.max_buy_in_per_period = pending;
+                self.pending_max_buy_in = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! src/state/player_rakeback.rs
+//!
+//! @description
+//! This module defines the `PlayerRakeback` account, which tracks the rake-back a
+//! player has earned on a given token. Each time a raked hand is resolved, every
+//! player who contributed to the pot accrues a share of the rake proportional to
+//! their contribution, scaled by `PlatformConfig::rakeback.rakeback_bps`.
+//!
+//! Key features:
+//! - One account per (player, token mint) pair, since rake is taken per currency
+//! - Uses a PDA with the player and token mint as seeds
+//! - Accrued rake-back is paid out of the treasury via `claim_rakeback`
+
+use anchor_lang::prelude::*;
+
+/// Tracks the rake-back earned and claimed by a single player for one token mint.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerRakeback {
+    /// The player's wallet public key
+    pub player: Pubkey,
+    
+    /// The token mint the rake-back is denominated in
+    pub token_mint: Pubkey,
+    
+    /// Rake-back accrued and not yet claimed
+    pub accrued: u64,
+    
+    /// Total rake-back claimed over the account's lifetime
+    pub total_claimed: u64,
+    
+    /// Bump seed for the PDA
+    pub bump: u8,
+}
+
+impl PlayerRakeback {
+    /// Fills in the account's identity the first time it is created.
+    /// The account is created lazily (`init_if_needed`) when a player first sits
+    /// at a table using `token_mint`, so later calls leave it untouched.
+    pub fn initialize_if_new(&mut self, player: Pubkey, token_mint: Pubkey, bump: u8) {
+        if self.player == Pubkey::default() {
+            self.player = player;
+            self.token_mint = token_mint;
+            self.bump = bump;
+        }
+    }
+}
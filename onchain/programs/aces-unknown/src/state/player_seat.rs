@@ -11,6 +11,9 @@
 //! - Can be efficiently accessed by instructions that need player data
 
 use anchor_lang::prelude::*;
+use crate::state::{Card, PlayerAction};
+use crate::state::constants::RECENT_HAND_NONCES;
+use crate::error::AcesUnknownErrorCode;
 
 /// Contains the state for a single player seated at a table.
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
@@ -57,6 +60,157 @@ pub struct PlayerSeat {
     /// The total amount the player has committed to the pot in the entire hand
     pub total_bet_this_hand: u64,
     
+    /// An action the player pre-selected to be applied automatically when the turn
+    /// reaches them. Only valid for the betting round in which it was set.
+    pub pending_action: Option<PendingAction>,
+    
+    /// Set for a player who joined after play began. They sit out until the big
+    /// blind reaches their seat or they pay to enter early with `post_entry_blind`.
+    pub waiting_for_big_blind: bool,
+    
+    /// Set by `post_entry_blind`: at the next `start_hand` the player posts a dead big
+    /// blind into the pot plus a live big blind, and is dealt in.
+    pub entry_blind_pending: bool,
+    
+    /// Set when the player busts at a cash table. They are dealt out of every hand
+    /// until the seat is freed with `leave_table` or `stand_up_busted_player`.
+    pub is_sitting_out: bool,
+    
+    /// Set with `set_sit_out_next_blind`: the player sits out the next hand in which
+    /// they would post a blind, then the flag clears and they are dealt back in.
+    pub sit_out_next_blind: bool,
+    
+    /// At a tournament table, the place the player finished in once they bust
+    /// (1 is the winner). Their seat no longer counts as occupied.
+    pub finishing_position: Option<u8>,
+    
+    /// The one hole card the player chose to show with `show_one_card` after the
+    /// last hand ended. Cleared when the next hand starts.
+    pub shown_card: Option<Card>,
+    
+    /// The nonces the player's last `RECENT_HAND_NONCES` hands of hole cards were
+    /// encrypted with, newest first (zero for unused slots). Encrypting twice with the
+    /// same key and nonce would leak the cards, so a repeat is rejected.
+    pub recent_hand_nonces: [u128; RECENT_HAND_NONCES],
+    
+    /// The Unix timestamp at which the player revealed their hole cards at this hand's
+    /// showdown with `reveal_hole_cards`, or zero if they have not. Cleared when the
+    /// next hand starts.
+    pub showdown_revealed_at: i64,
+    
     /// Bump seed for the PDA
     pub bump: u8,
-}
\ No newline at end of file
+}
+
+impl PlayerSeat {
+    /// Clears all per-hand betting state so the seat can be dealt into a new hand.
+    pub fn reset_for_new_hand(&mut self) {
+        self.is_active_in_hand = self.stack > 0 && !self.waiting_for_big_blind && !self.is_sitting_out;
+        self.is_all_in = false;
+        self.bet_this_round = 0;
+        self.total_bet_this_hand = 0;
+        self.pending_action = None;
+        self.shown_card = None;
+        self.showdown_revealed_at = 0;
+    }
+
+    /// Records the nonce this hand's hole cards were encrypted with for the player.
+    /// Fails with `NonceReused` if it is zero or one of their recent hands used it.
+    pub fn record_hand_nonce(&mut self, nonce: u128) -> Result<()> {
+        require!(
+            nonce != 0 && !self.recent_hand_nonces.contains(&nonce),
+            AcesUnknownErrorCode::NonceReused
+        );
+        self.recent_hand_nonces.rotate_right(1);
+        self.recent_hand_nonces[0] = nonce;
+        Ok(())
+    }
+
+    /// Clears per-round betting state when a new betting round (street) begins.
+    pub fn reset_for_new_round(&mut self) {
+        self.bet_this_round = 0;
+        self.pending_action = None;
+    }
+
+    /// Takes `amount` chips out of the player's stack. Every stack decrement goes
+    /// through here, so a bug that would take more than the player has fails with
+    /// `InsufficientFunds` instead of wrapping the stack around.
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        self.stack = self
+            .stack
+            .checked_sub(amount)
+            .ok_or(AcesUnknownErrorCode::InsufficientFunds)?;
+        Ok(())
+    }
+
+    /// Posts a forced bet (blind or ante), capped at the player's stack.
+    /// A player who cannot cover the full amount posts all-in for what they have.
+    /// Returns the amount actually posted.
+    pub fn post_forced_bet(&mut self, amount: u64) -> Result<u64> {
+        let posted = std::cmp::min(amount, self.stack);
+        self.debit(posted)?;
+        self.bet_this_round += posted;
+        self.total_bet_this_hand += posted;
+        if self.stack == 0 {
+            self.is_all_in = true;
+        }
+        Ok(posted)
+    }
+
+    /// Whether the player has lost their whole stack and can no longer be dealt in.
+    pub fn is_busted(&self) -> bool {
+        self.stack == 0 && (self.is_sitting_out || self.finishing_position.is_some())
+    }
+
+    /// Posts an ante: dead money that goes into the pot without counting toward a
+    /// call, capped at the player's stack. Returns the amount actually posted.
+    pub fn post_ante(&mut self, amount: u64) -> Result<u64> {
+        let posted = std::cmp::min(amount, self.stack);
+        self.debit(posted)?;
+        self.total_bet_this_hand += posted;
+        if self.stack == 0 {
+            self.is_all_in = true;
+        }
+        Ok(posted)
+    }
+
+    /// Posts the entry blind paid for with `post_entry_blind`: a dead big blind that
+    /// goes straight into the pot without counting toward a call, plus a live big
+    /// blind topped up from whatever the seat has already posted this round.
+    /// Returns the total amount added to the pot.
+    pub fn post_entry_blind(&mut self, big_blind: u64) -> Result<u64> {
+        let dead = std::cmp::min(big_blind, self.stack);
+        self.debit(dead)?;
+        self.total_bet_this_hand += dead;
+        let live = self.post_forced_bet(big_blind.saturating_sub(self.bet_this_round))?;
+        self.entry_blind_pending = false;
+        Ok(dead + live)
+    }
+}
+
+/// An action a player can pre-select before their turn, applied automatically by
+/// `apply_pending_action` once the turn reaches them.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PendingAction {
+    /// Check if there is nothing to call, otherwise fold.
+    CheckFold,
+    /// Call whatever bet the player faces, or check if there is none.
+    CallAny,
+    /// Fold regardless of the betting.
+    Fold,
+}
+
+impl PendingAction {
+    /// Resolves the pre-selected action into a concrete `PlayerAction` given the
+    /// bet the player currently faces.
+    pub fn resolve(self, bet_this_round: u64, current_bet: u64) -> PlayerAction {
+        let facing_bet = bet_this_round < current_bet;
+        match self {
+            PendingAction::CheckFold if facing_bet => PlayerAction::Fold,
+            PendingAction::CheckFold => PlayerAction::Check,
+            PendingAction::CallAny if facing_bet => PlayerAction::Call,
+            PendingAction::CallAny => PlayerAction::Check,
+            PendingAction::Fold => PlayerAction::Fold,
+        }
+    }
+}
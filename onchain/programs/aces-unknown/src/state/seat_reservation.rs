@@ -0,0 +1,44 @@
+//! src/state/seat_reservation.rs
+//!
+//! @description
+//! This module defines the `SeatReservation` account, which lets a player hold an
+//! empty seat for a short window while they fund their wallet. While a reservation
+//! is live, only the reserving player may `join_table` into that seat. Reservations
+//! expire on their own; there is nothing to clean up when a hold lapses.
+//!
+//! Key features:
+//! - One account per (table, seat index) pair, reused by every reservation of that seat
+//! - Uses a PDA with the table and seat index as seeds
+//! - Cleared when a player sits down in the seat
+
+use anchor_lang::prelude::*;
+
+/// How long a seat reservation holds the seat, in seconds.
+pub const SEAT_RESERVATION_SECONDS: i64 = 60;
+
+/// Tracks who, if anyone, is currently holding an empty seat.
+#[account]
+#[derive(InitSpace)]
+pub struct SeatReservation {
+    /// The table the reserved seat belongs to
+    pub table_pubkey: Pubkey,
+    
+    /// The reserved seat index (0-5)
+    pub seat_index: u8,
+    
+    /// The player holding the seat, if any
+    pub reserved_by: Option<Pubkey>,
+    
+    /// Unix timestamp at which the reservation lapses
+    pub reserved_until: i64,
+    
+    /// Bump seed for the PDA
+    pub bump: u8,
+}
+
+impl SeatReservation {
+    /// Returns the player holding the seat at `now`, ignoring expired reservations.
+    pub fn holder(&self, now: i64) -> Option<Pubkey> {
+        self.reserved_by.filter(|_| now < self.reserved_until)
+    }
+}
@@ -13,6 +13,8 @@
 
 use anchor_lang::prelude::*;
 use crate::state::card::Card;
+use crate::state::constants::{DEFAULT_REFUND_TIMEOUT_SECONDS, MAX_PLAYERS, MAX_TABLE_INVITES};
+use crate::error::AcesUnknownErrorCode;
 
 /// A compact representation of a player seat for quick lookup
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
@@ -51,6 +53,9 @@ pub struct Table {
     pub game_state: GameState,
     /// The current betting round (e.g., PreFlop, Flop, Turn, River).
     pub betting_round: BettingRound,
+    /// Which step of the current street the hand is waiting on: the deal, player
+    /// actions, a board reveal, or the showdown. `Complete` between hands.
+    pub hand_phase: HandPhase,
     /// The small blind amount.
     pub small_blind: u64,
     /// The big blind amount.
@@ -59,22 +64,259 @@ pub struct Table {
     pub token_mint: Pubkey,
     /// The total amount of chips in the main pot for the current hand.
     pub pot: u64,
+    /// The breakdown of `pot` into a main pot and side pots, once anyone is all-in.
+    /// Empty while the whole pot is contested by every player in the hand.
+    #[max_len(MAX_PLAYERS)]
+    pub pots: Vec<PotInfo>,
     /// The current amount a player must call to stay in the hand.
     pub current_bet: u64,
+    /// The size of the last full bet or raise in the current round.
+    /// A new raise must increase `current_bet` by at least this amount.
+    pub last_raise_size: u64,
     /// The five community cards. `None` if not yet dealt.
     pub community_cards: [Option<Card>; 5],
     /// The Unix timestamp when the current player's turn started. Used for the turn timer.
     pub turn_started_at: i64,
     /// The duration of a player's turn in seconds.
     pub turn_duration_seconds: u32,
+    /// The Unix timestamp at which the current turn expires (`turn_started_at + turn_duration_seconds`).
+    /// Stored so clients can read an absolute deadline instead of recomputing it.
+    pub turn_deadline: i64,
     /// A counter for the number of hands played at this table, used to create unique hand IDs.
     pub hand_id_counter: u64,
     /// The index of the player who made the last aggressive action (bet or raise) in the current round.
-    /// This is used to determine when a betting round is complete.
     pub last_aggressor_position: u8,
+    /// Bitmask of the seats that have acted since the last full bet or raise in the
+    /// current round. The round is over once every player who can act is in it and has
+    /// matched the bet. An all-in for less than a full raise does not clear it, so those
+    /// players may only call or fold when the action comes back to them.
+    pub acted_since_full_raise: u8,
     /// A bitmask representing which seats are occupied (1 = occupied, 0 = empty).
     /// This allows us to track seat occupancy without storing large arrays.
     pub occupied_seats: u8,
+    /// The wallet seated at each seat, `None` for an empty one. Kept in step with
+    /// `occupied_seats`, so a player's seat can be found without loading every
+    /// `PlayerSeat` account.
+    pub seat_pubkeys: [Option<Pubkey>; MAX_PLAYERS],
+    /// Optional house rules chosen by the table creator.
+    pub settings: TableSettings,
+    /// True between blind collection and the first preflop action, while UTG may straddle.
+    pub straddle_window_open: bool,
+    /// Whether the table has dealt its first hand, whose button is drawn at random.
+    pub first_hand_dealt: bool,
+    /// True once every player still in the hand is all-in (or only one can act and has
+    /// matched the bet), so the remaining streets are dealt with no further betting.
+    pub ready_for_runout: bool,
+    /// The Unix timestamp of the last `start_hand` or player action at this table.
+    pub last_activity_at: i64,
+    /// Set by `check_table_activity` once the table has been idle for longer than
+    /// `TableSettings::idle_pause_seconds`. Cleared by the next hand or action.
+    pub is_paused: bool,
+    /// The Unix timestamp at which the last hand completed, whether by showdown, by
+    /// everyone else folding, or by a refund.
+    pub last_hand_completed_at: i64,
+    /// A human-readable table name for indexers and frontends, UTF-8 padded with
+    /// zero bytes. All zeros when unnamed.
+    pub name: [u8; 32],
+    /// A hash of an off-chain table description. All zeros when there is none.
+    pub description_hash: [u8; 32],
+    /// The wallet that referred this table to the platform, set at creation. It is
+    /// paid `PlatformConfig::referrer_rake_bps` of the rake from showdown pots.
+    pub referrer: Option<Pubkey>,
+    /// The wallets the creator has invited, who alone may join while the table is
+    /// private (`TableSettings::is_private`). Managed with `invite_player` and
+    /// `revoke_invite`.
+    #[max_len(MAX_TABLE_INVITES)]
+    pub access_list: Vec<Pubkey>,
+}
+
+impl Table {
+    /// Starts the current player's turn timer at `now`.
+    pub fn start_turn_timer(&mut self, now: i64) {
+        self.turn_started_at = now;
+        self.turn_deadline = now + self.turn_duration_seconds as i64;
+    }
+
+    /// Marks the current hand complete at `now`, starting the wait before the next one.
+    pub fn complete_hand(&mut self, now: i64) {
+        self.game_state = GameState::HandComplete;
+        self.hand_phase = HandPhase::Complete;
+        self.last_hand_completed_at = now;
+    }
+
+    /// Fails with `InvalidGameState` unless the hand is in `phase`.
+    pub fn require_phase(&self, phase: HandPhase) -> Result<()> {
+        require!(self.hand_phase == phase, AcesUnknownErrorCode::InvalidGameState);
+        Ok(())
+    }
+
+    /// Closes the current street's betting. After the river only the showdown is left;
+    /// on earlier streets the next cards are revealed.
+    pub fn close_betting_round(&mut self) {
+        self.hand_phase = if self.betting_round == BettingRound::River {
+            HandPhase::AwaitingShowdown
+        } else {
+            HandPhase::AwaitingReveal
+        };
+    }
+
+    /// Seats `player` at the empty seat `seat_index`.
+    pub fn occupy_seat(&mut self, seat_index: u8, player: Pubkey) {
+        self.occupied_seats |= 1 << seat_index;
+        self.seat_pubkeys[seat_index as usize] = Some(player);
+        self.player_count += 1;
+    }
+
+    /// Frees `seat_index`, if it is still occupied.
+    pub fn vacate_seat(&mut self, seat_index: u8) {
+        let seat_bit = 1 << seat_index;
+        if self.occupied_seats & seat_bit != 0 {
+            self.occupied_seats &= !seat_bit;
+            self.seat_pubkeys[seat_index as usize] = None;
+            self.player_count -= 1;
+        }
+    }
+
+    /// The seat `player` occupies at this table, if any.
+    pub fn seat_of(&self, player: &Pubkey) -> Option<u8> {
+        self.seat_pubkeys
+            .iter()
+            .position(|seated| seated.as_ref() == Some(player))
+            .map(|seat_index| seat_index as u8)
+    }
+
+    /// Whether `player` may take a seat: anyone at a public table, and only the creator
+    /// and invited wallets at a private one.
+    pub fn admits(&self, player: &Pubkey) -> bool {
+        !self.settings.is_private || *player == self.creator || self.access_list.contains(player)
+    }
+
+    /// Records player activity at `now`, resuming the table if it was idle-paused.
+    pub fn record_activity(&mut self, now: i64) {
+        self.last_activity_at = now;
+        self.is_paused = false;
+    }
+}
+
+/// One pot within the hand, as split by all-in amounts.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PotInfo {
+    /// The chips in this pot.
+    pub amount: u64,
+    /// Bitmask of the seats that can win this pot (bit `i` set for seat `i`).
+    pub eligible_seats: u8,
+}
+
+/// Optional house rules for a table, managed by the creator between hands.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct TableSettings {
+    /// Whether the UTG player may post a voluntary straddle of two big blinds.
+    pub allow_straddle: bool,
+    /// How long the table may go without a hand or action before a keeper can pause it.
+    /// Zero disables idle pausing.
+    pub idle_pause_seconds: u32,
+    /// Whether players may reveal the undealt board after a hand ends early (`rabbit_hunt`).
+    pub allow_rabbit_hunt: bool,
+    /// What happens to a blind whose stack cannot cover their blind.
+    pub short_blind_policy: ShortBlindPolicy,
+    /// Whether an ante is collected at the start of each hand, and from whom.
+    pub ante_mode: AnteMode,
+    /// The ante collected under `AnteMode::BigBlindAnte`. Zero means one big blind.
+    pub ante_amount: u64,
+    /// Tournament tables free a busted player's seat at showdown and record their
+    /// finishing position; cash tables sit the busted player out instead.
+    pub is_tournament: bool,
+    /// How long after a hand completes the next one may start, giving players time to
+    /// leave or rebuy. Zero lets the next hand start immediately.
+    pub min_seconds_between_hands: u32,
+    /// Whether heads-up players may agree to take back their blinds and void a hand
+    /// before anyone acts (`chop_blinds`).
+    pub allow_chop_blinds: bool,
+    /// How long a hand must go without progress before anyone can void it and refund
+    /// the bets with `force_hand_refund`. Zero means `DEFAULT_REFUND_TIMEOUT_SECONDS`;
+    /// otherwise it must lie between `MIN_REFUND_TIMEOUT_SECONDS` and
+    /// `MAX_REFUND_TIMEOUT_SECONDS`.
+    pub refund_timeout_seconds: u32,
+    /// The ante every player dealt in posts for a `start_bomb_pot` hand. Zero disables
+    /// bomb pots.
+    pub bomb_pot_ante: u64,
+    /// Invite-only: when set, only the creator and wallets on the table's access list
+    /// may join.
+    pub is_private: bool,
+    /// The order `shuffle_and_deal` deals the hole cards in, which fixes the deck
+    /// positions each seat's cards come from.
+    pub deal_style: DealStyle,
+    /// How long the players at a showdown have to reveal their hole cards once the
+    /// first of them has (`reveal_hole_cards`). After that, anyone still holding back is
+    /// mucked and the showdown goes ahead without them. Zero disables reveals: every
+    /// hand is evaluated. Must be shorter than the stuck-hand refund timeout.
+    pub showdown_reveal_timeout_seconds: u32,
+}
+
+impl TableSettings {
+    /// The single ante collected per hand under `AnteMode::BigBlindAnte`.
+    pub fn big_blind_ante(&self, big_blind: u64) -> u64 {
+        if self.ante_amount == 0 {
+            big_blind
+        } else {
+            self.ante_amount
+        }
+    }
+
+    /// The stuck-hand timeout `force_hand_refund` enforces, in seconds.
+    pub fn refund_timeout(&self) -> i64 {
+        if self.refund_timeout_seconds == 0 {
+            DEFAULT_REFUND_TIMEOUT_SECONDS as i64
+        } else {
+            self.refund_timeout_seconds as i64
+        }
+    }
+}
+
+/// How `start_hand` treats a small or big blind whose stack is below their blind.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ShortBlindPolicy {
+    /// Post all-in for whatever is left; the shortfall is settled as a side pot.
+    #[default]
+    PostAllIn,
+    /// Sit the player out for the hand. A short big blind passes to the next seat
+    /// in line, and a short small blind is simply not posted (a dead small blind).
+    SitOut,
+}
+
+/// How antes are collected at the start of a hand.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AnteMode {
+    /// No ante.
+    #[default]
+    NoAnte,
+    /// A single ante for the whole table, posted by the button player. A button
+    /// who cannot cover it posts all-in for what they have.
+    BigBlindAnte,
+}
+
+/// The order hole cards are dealt from the top of the shuffled deck. Either way the
+/// deal consumes two cards per player dealt in, so the board starts at the same
+/// position (`HandData::cards_dealt`) and never overlaps a hole card.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum DealStyle {
+    /// One card to each player dealt in, in seat order, then a second round, as in
+    /// a live deal.
+    #[default]
+    RoundRobin,
+    /// Both cards to one player before moving on to the next.
+    Sequential,
+}
+
+impl DealStyle {
+    /// The deck position of hole card `card_index` (0 or 1) for the player ranked
+    /// `rank` in seat order among the `dealt_in` players dealt in.
+    pub fn deck_position(self, dealt_in: u8, rank: u8, card_index: u8) -> u8 {
+        match self {
+            DealStyle::RoundRobin => card_index * dealt_in + rank,
+            DealStyle::Sequential => rank * 2 + card_index,
+        }
+    }
 }
 
 /// Enum representing the possible states of a poker game.
@@ -85,6 +327,25 @@ pub enum GameState {
     HandComplete,
 }
 
+/// Where a hand in progress stands within the current street. `GameState` only says
+/// whether a hand is being played; the phase says which instruction or Arcium
+/// callback it is waiting on, so every instruction can check it is called in turn.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum HandPhase {
+    /// The `shuffle_and_deal` computation is queued; nobody can act until it returns.
+    Dealing,
+    /// A betting round is open and the player at `turn_position` is to act.
+    Betting,
+    /// Betting is closed for the street and the next cards are to be revealed, by
+    /// `deal_community_cards` or, for an all-in run-out, `reveal_remaining_board`.
+    AwaitingReveal,
+    /// The board is complete and betting is over; `resolve_showdown` is next.
+    AwaitingShowdown,
+    /// No hand is being played: the last one is over, or none has started yet.
+    #[default]
+    Complete,
+}
+
 /// Enum representing the different betting rounds in a hand of Texas Hold'em.
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BettingRound {
@@ -95,6 +356,17 @@ pub enum BettingRound {
     Showdown,
 }
 
+/// A seat's position relative to the dealer button, as reported in `PlayerActed`.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PositionLabel {
+    SmallBlind,
+    BigBlind,
+    UnderTheGun,
+    MiddlePosition,
+    Cutoff,
+    Button,
+}
+
 /// Enum representing the possible actions a player can take.
 /// This enum is passed as an argument to the `player_action` instruction.
 #[derive(InitSpace, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
@@ -0,0 +1,100 @@
+//! src/utils/encrypted_hands.rs
+//!
+//! @description
+//! Each player's hole cards live in their own `EncryptedHand` PDA, derived from the
+//! hand's `HandData` account and the player's wallet. `start_hand` creates one for
+//! every player dealt in, and the showdown reads them back as circuit inputs. This
+//! module holds the shared creation and validation logic for those accounts, which
+//! instructions receive through `remaining_accounts`.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_lang::Discriminator;
+use crate::state::EncryptedHand;
+use crate::error::AcesUnknownErrorCode;
+
+/// Creates the `EncryptedHand` PDA for `player` in the hand `hand_key`, paid for by
+/// `payer`, recording the `nonce` the player's hole cards are encrypted with. The
+/// ciphertext is left empty until the `shuffle_and_deal` callback fills it.
+pub fn create_encrypted_hand<'info>(
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    encrypted_hand_info: &AccountInfo<'info>,
+    hand_key: &Pubkey,
+    player: &Pubkey,
+    nonce: u128,
+) -> Result<()> {
+    let (expected_key, bump) = Pubkey::find_program_address(
+        &[b"encrypted_hand", hand_key.as_ref(), player.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        expected_key,
+        encrypted_hand_info.key(),
+        AcesUnknownErrorCode::InvalidEncryptedHandAccount
+    );
+
+    let space = 8 + EncryptedHand::INIT_SPACE;
+    let signer_seeds: &[&[u8]] = &[b"encrypted_hand", hand_key.as_ref(), player.as_ref(), &[bump]];
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            CreateAccount {
+                from: payer.to_account_info(),
+                to: encrypted_hand_info.clone(),
+            },
+            &[signer_seeds],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+
+    let encrypted_hand = EncryptedHand {
+        hand_pubkey: *hand_key,
+        player_pubkey: *player,
+        ciphertext: [0u8; 32],
+        nonce,
+        encryption_key: [0u8; 32],
+        bump,
+    };
+    let mut data = encrypted_hand_info.try_borrow_mut_data()?;
+    encrypted_hand.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
+
+/// Whether `encrypted_hand_info` holds an `EncryptedHand` at all, as opposed to an
+/// account that was never created.
+pub fn is_encrypted_hand(encrypted_hand_info: &AccountInfo) -> Result<bool> {
+    Ok(encrypted_hand_info.owner == &crate::ID
+        && encrypted_hand_info
+            .try_borrow_data()?
+            .starts_with(EncryptedHand::DISCRIMINATOR))
+}
+
+/// Ensures `encrypted_hand_info` is `player`'s `EncryptedHand` for the hand `hand_key`.
+/// An account that was never created (or is not an `EncryptedHand` at all) is reported
+/// as missing rather than as a deserialization failure.
+pub fn require_encrypted_hand<'info>(
+    encrypted_hand_info: &'info AccountInfo<'info>,
+    hand_key: &Pubkey,
+    player: &Pubkey,
+) -> Result<()> {
+    require!(
+        is_encrypted_hand(encrypted_hand_info)?,
+        AcesUnknownErrorCode::MissingEncryptedHand
+    );
+
+    let encrypted_hand: Account<'info, EncryptedHand> = Account::try_from(encrypted_hand_info)?;
+    require_keys_eq!(
+        encrypted_hand.hand_pubkey,
+        *hand_key,
+        AcesUnknownErrorCode::InvalidEncryptedHandAccount
+    );
+    require_keys_eq!(
+        encrypted_hand.player_pubkey,
+        *player,
+        AcesUnknownErrorCode::InvalidEncryptedHandAccount
+    );
+    Ok(())
+}
@@ -0,0 +1,172 @@
+//! src/utils/equity.rs
+//!
+//! @description
+//! Mirrors the runout scoring of the `compute_allin_equity` circuit until the Arcium
+//! integration is set up: each sampled deck order completes the board, and the
+//! contenders' best hands on it, ranked with `best_hand_key` exactly as at the
+//! showdown, count a win for a sole winner or a tie for each of several. The scoring is
+//! deterministic given the deck order, so `sampled_decks` stands in for the circuit's
+//! RNG, both in the simulated computation and when checking a known matchup.
+
+use crate::state::constants::MAX_PLAYERS;
+use crate::state::DECK_SIZE;
+use crate::utils::best_hand_key;
+
+/// Equities are reported in basis points (1/100th of a percent).
+pub const BASIS_POINTS: u64 = 10_000;
+
+/// Fills the board slots past `num_community` from `shuffled_deck` in order, skipping
+/// every known card: the contenders' hole cards and the board already dealt.
+/// `hole_cards` entries for non-contenders must be the `INVALID_CARD_INDEX` sentinel.
+pub fn complete_board(
+    hole_cards: &[[u8; 2]; MAX_PLAYERS],
+    community_cards: [u8; 5],
+    num_community: u8,
+    shuffled_deck: &[u8; DECK_SIZE as usize],
+) -> [u8; 5] {
+    let dealt = &community_cards[..num_community as usize];
+    let mut board = community_cards;
+    let undealt = shuffled_deck
+        .iter()
+        .copied()
+        .filter(|card| !dealt.contains(card) && !hole_cards.iter().any(|hole| hole.contains(card)));
+    for (slot, card) in board[num_community as usize..].iter_mut().zip(undealt) {
+        *slot = card;
+    }
+    board
+}
+
+/// Scores one runout of `shuffled_deck` and adds it to the running win and tie counts,
+/// indexed by seat.
+pub fn tally_runout(
+    hole_cards: &[[u8; 2]; MAX_PLAYERS],
+    contenders: [bool; MAX_PLAYERS],
+    community_cards: [u8; 5],
+    num_community: u8,
+    shuffled_deck: &[u8; DECK_SIZE as usize],
+    wins: &mut [u64; MAX_PLAYERS],
+    ties: &mut [u64; MAX_PLAYERS],
+) {
+    let board = complete_board(hole_cards, community_cards, num_community, shuffled_deck);
+    let hand_keys: [_; MAX_PLAYERS] = std::array::from_fn(|i| {
+        contenders[i].then(|| best_hand_key(&[hole_cards[i].as_slice(), &board].concat()))
+    });
+    let best = hand_keys.iter().flatten().max();
+    let winners: Vec<usize> = (0..MAX_PLAYERS)
+        .filter(|&i| best.is_some() && hand_keys[i].as_ref() == best)
+        .collect();
+
+    let counts = if winners.len() == 1 { wins } else { ties };
+    for i in winners {
+        counts[i] += 1;
+    }
+}
+
+/// Converts runout counts into basis points of `samples`. No runouts give no equity.
+pub fn to_basis_points(counts: [u64; MAX_PLAYERS], samples: u64) -> [u16; MAX_PLAYERS] {
+    if samples == 0 {
+        return [0; MAX_PLAYERS];
+    }
+    counts.map(|count| (count * BASIS_POINTS / samples) as u16)
+}
+
+/// Deterministic deck orders from a fixed seed, standing in for the circuit's RNG.
+pub fn sampled_decks(mut seed: u64, samples: usize) -> Vec<[u8; DECK_SIZE as usize]> {
+    // xorshift never leaves zero, so a zero seed is nudged off it.
+    seed |= 1;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+    (0..samples)
+        .map(|_| {
+            let mut deck: [u8; DECK_SIZE as usize] = std::array::from_fn(|i| i as u8);
+            for i in (1..deck.len()).rev() {
+                deck.swap(i, (next() % (i as u64 + 1)) as usize);
+            }
+            deck
+        })
+        .collect()
+}
+
+/// Scores a runout per deck in `decks` and returns each seat's share of the runouts won
+/// outright and split, in basis points: the `compute_allin_equity` circuit's output.
+pub fn estimate_equity(
+    hole_cards: &[[u8; 2]; MAX_PLAYERS],
+    contenders: [bool; MAX_PLAYERS],
+    community_cards: [u8; 5],
+    num_community: u8,
+    decks: &[[u8; DECK_SIZE as usize]],
+) -> ([u16; MAX_PLAYERS], [u16; MAX_PLAYERS]) {
+    let mut wins = [0; MAX_PLAYERS];
+    let mut ties = [0; MAX_PLAYERS];
+    for deck in decks {
+        tally_runout(hole_cards, contenders, community_cards, num_community, deck, &mut wins, &mut ties);
+    }
+    let samples = decks.len() as u64;
+    (to_basis_points(wins, samples), to_basis_points(ties, samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::INVALID_CARD_INDEX;
+
+    const NO_HOLE: [u8; 2] = [INVALID_CARD_INDEX; 2];
+
+    #[test]
+    fn aces_are_about_four_to_one_against_kings_preflop() {
+        // A♣ A♦ against K♥ K♠.
+        let hole_cards = [[12, 25], [37, 50], NO_HOLE, NO_HOLE, NO_HOLE, NO_HOLE];
+        let contenders = hole_cards.map(|hole| hole != NO_HOLE);
+        let decks = sampled_decks(0x5eed, 2_000);
+        let (win_bps, tie_bps) = estimate_equity(&hole_cards, contenders, [INVALID_CARD_INDEX; 5], 0, &decks);
+        assert!((7_800..=8_500).contains(&win_bps[0]), "aces won {} bps", win_bps[0]);
+        assert!((1_500..=2_200).contains(&win_bps[1]), "kings won {} bps", win_bps[1]);
+        assert!(tie_bps[0] < 200);
+        assert_eq!(win_bps[2..], [0; 4]);
+    }
+
+    #[test]
+    fn a_completed_board_skips_every_known_card() {
+        let hole_cards = [[0, 1], [2, 3], NO_HOLE, NO_HOLE, NO_HOLE, NO_HOLE];
+        let deck: [u8; DECK_SIZE as usize] = std::array::from_fn(|i| i as u8);
+        let flop = [4, 5, 6, INVALID_CARD_INDEX, INVALID_CARD_INDEX];
+        let board = complete_board(&hole_cards, flop, 3, &deck);
+        assert_eq!(board, [4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn a_runout_is_scored_from_the_deck_it_is_given() {
+        // A♣ A♦ against K♥ K♠ on a K♣ 7♦ 2♥ flop: the kings have a set.
+        let hole_cards = [[12, 25], [37, 50], NO_HOLE, NO_HOLE, NO_HOLE, NO_HOLE];
+        let flop = [11, 18, 26, INVALID_CARD_INDEX, INVALID_CARD_INDEX];
+        let contenders = hole_cards.map(|hole| hole != NO_HOLE);
+        let mut wins = [0; MAX_PLAYERS];
+        let mut ties = [0; MAX_PLAYERS];
+
+        // A♥ and then A♠ come first: the aces make quads.
+        let mut deck: [u8; DECK_SIZE as usize] = std::array::from_fn(|i| i as u8);
+        deck.swap(0, 38);
+        deck.swap(1, 51);
+        tally_runout(&hole_cards, contenders, flop, 3, &deck, &mut wins, &mut ties);
+        assert_eq!(wins[..2], [1, 0]);
+
+        // Otherwise the set holds up.
+        let deck: [u8; DECK_SIZE as usize] = std::array::from_fn(|i| i as u8);
+        tally_runout(&hole_cards, contenders, flop, 3, &deck, &mut wins, &mut ties);
+        assert_eq!(wins[..2], [1, 1]);
+        assert_eq!(ties, [0; MAX_PLAYERS]);
+    }
+
+    #[test]
+    fn no_runouts_give_no_equity() {
+        assert_eq!(to_basis_points([3, 0, 0, 0, 0, 0], 0), [0; MAX_PLAYERS]);
+        let hole_cards = [[12, 25], [37, 50], NO_HOLE, NO_HOLE, NO_HOLE, NO_HOLE];
+        let contenders = hole_cards.map(|hole| hole != NO_HOLE);
+        let (win_bps, tie_bps) = estimate_equity(&hole_cards, contenders, [INVALID_CARD_INDEX; 5], 0, &[]);
+        assert_eq!((win_bps, tie_bps), ([0; MAX_PLAYERS], [0; MAX_PLAYERS]));
+    }
+}
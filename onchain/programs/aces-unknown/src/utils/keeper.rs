@@ -0,0 +1,83 @@
+//! src/utils/keeper.rs
+//!
+//! @description
+//! Anyone may act as a keeper and call `force_player_fold` or `force_hand_refund` to
+//! keep a table moving. To cover their fees, the platform can pay them a small reward
+//! (`PlatformConfig::keeper_reward_bps`, a share of the table's big blind) out of the
+//! chips in the pot. The reward is charged to the players' contributions, so the pot
+//! always adds up to them, and never exceeds what those players have in the pot:
+//! - a timed-out player pays it out of the chips they forfeit by folding;
+//! - when a stuck hand is refunded, every player pays a share of it in proportion to
+//!   their contribution, rounded down.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{PlatformConfig, PlayerSeat, Table};
+use crate::utils::transfer_rake;
+
+/// The keeper reward configured for a table with the given big blind.
+pub fn keeper_reward(config: &PlatformConfig, big_blind: u64) -> u64 {
+    (big_blind as u128 * config.keeper_reward_bps as u128 / 10_000) as u64
+}
+
+/// Takes up to `reward` out of `seat`'s contribution to the pot. Returns the amount
+/// taken, which is all the seat had in the pot if that is less.
+pub fn charge_keeper_reward_to_seat(table: &mut Table, seat: &mut PlayerSeat, reward: u64) -> u64 {
+    let charged = reward.min(seat.total_bet_this_hand);
+    seat.total_bet_this_hand -= charged;
+    table.pot -= charged;
+    charged
+}
+
+/// Takes up to `reward` out of the pot, each seat paying in proportion to its
+/// contribution, rounded down. Returns the amount taken.
+pub fn charge_keeper_reward_pro_rata(
+    table: &mut Table,
+    seats: &mut [Account<PlayerSeat>],
+    reward: u64,
+) -> u64 {
+    let pot = table.pot;
+    if pot == 0 {
+        return 0;
+    }
+    let reward = reward.min(pot);
+    let mut charged = 0u64;
+    for seat in seats.iter_mut() {
+        let share = (reward as u128 * seat.total_bet_this_hand as u128 / pot as u128) as u64;
+        seat.total_bet_this_hand -= share;
+        charged += share;
+    }
+    table.pot -= charged;
+    charged
+}
+
+/// Pays a keeper reward already taken out of the pot from the table's vault to the
+/// keeper's token account, and emits `KeeperRewarded`. Nothing happens for zero.
+pub fn pay_keeper_reward<'info>(
+    table: &Account<'info, Table>,
+    table_bump: u8,
+    table_vault: &Account<'info, TokenAccount>,
+    keeper_token_account: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    transfer_rake(table, table_bump, table_vault, keeper_token_account, token_program, amount)?;
+    emit!(KeeperRewarded {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        keeper: keeper_token_account.owner,
+        amount,
+    });
+    Ok(())
+}
+
+#[event]
+pub struct KeeperRewarded {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub keeper: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,44 @@
+//! src/utils/mod.rs
+//!
+//! @description
+//! This module groups helper logic that is shared between several instructions
+//! but does not belong to any single one of them. Keeping it here avoids
+//! duplicating account-handling code across the instruction modules.
+//!
+//! @modules
+//! - `seats`: Loading, validating, and persisting the `PlayerSeat` accounts that
+//!   instructions receive through `remaining_accounts`.
+//! - `pots`: Breaking the pot into a main pot and side pots as players go all-in.
+//! - `positions`: Naming each seat's position relative to the dealer button.
+//! - `rake`: Computing the platform rake taken from a pot.
+//! - `shuffle`: Recomputing and checking a hand's shuffle commitment.
+//! - `encrypted_hands`: Creating and validating players' `EncryptedHand` accounts.
+//! - `refunds`: Voiding a hand and returning every player's contribution.
+//! - `stats`: Updating the platform-wide `PlatformStats` counters.
+//! - `showdown`: Ranking hands and splitting the pots at showdown.
+//! - `keeper`: Charging and paying the reward for keepers who unstick a table.
+//! - `equity`: Scoring all-in runouts the way the equity circuit does.
+
+pub mod seats;
+pub mod pots;
+pub mod positions;
+pub mod rake;
+pub mod shuffle;
+pub mod encrypted_hands;
+pub mod refunds;
+pub mod stats;
+pub mod showdown;
+pub mod keeper;
+pub mod equity;
+
+pub use seats::*;
+pub use pots::*;
+pub use positions::*;
+pub use rake::*;
+pub use shuffle::*;
+pub use encrypted_hands::*;
+pub use refunds::*;
+pub use stats::*;
+pub use showdown::*;
+pub use keeper::*;
+pub use equity::*;
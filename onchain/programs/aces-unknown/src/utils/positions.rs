@@ -0,0 +1,42 @@
+//! src/utils/positions.rs
+//!
+//! @description
+//! Names each seat's position relative to the dealer button (SB, BB, UTG, ...) for
+//! UI and HUD tools. Positions are counted clockwise from the button over the
+//! occupied seats, so empty seats are skipped and the labels adapt to the number of
+//! players at the table.
+
+use crate::state::{PositionLabel, Table};
+use crate::state::constants::MAX_PLAYERS;
+
+/// Returns the position of `seat_index` relative to the button, given `num_active`
+/// seated players. Heads-up, the button is the small blind and the other player the
+/// big blind. With three or more players, the seats after the big blind are UTG, then
+/// middle position, with the cutoff directly before the button.
+pub fn position_label(table: &Table, seat_index: u8, num_active: usize) -> PositionLabel {
+    // Count occupied seats from the button (offset 0) clockwise to `seat_index`.
+    let mut offset = 0usize;
+    let mut pos = table.dealer_position;
+    while pos != seat_index && offset < MAX_PLAYERS {
+        pos = (pos + 1) % MAX_PLAYERS as u8;
+        if table.occupied_seats & (1 << pos) != 0 {
+            offset += 1;
+        }
+    }
+
+    if num_active <= 2 {
+        return if offset == 0 {
+            PositionLabel::SmallBlind
+        } else {
+            PositionLabel::BigBlind
+        };
+    }
+    match offset {
+        0 => PositionLabel::Button,
+        1 => PositionLabel::SmallBlind,
+        2 => PositionLabel::BigBlind,
+        3 => PositionLabel::UnderTheGun,
+        n if n + 1 == num_active => PositionLabel::Cutoff,
+        _ => PositionLabel::MiddlePosition,
+    }
+}
@@ -0,0 +1,49 @@
+//! src/utils/pots.rs
+//!
+//! @description
+//! Once a player is all-in, the chips in the middle no longer form a single pot:
+//! each all-in amount caps a pot that only the players who matched it can win.
+//! This module derives that breakdown from the seats' hand contributions so it can
+//! be published in `Table::pots` before showdown. It mirrors the levels the
+//! `calculate_payouts` circuit derives when it distributes the pots.
+
+use crate::state::{PlayerSeat, PotInfo, Table};
+
+/// Rebuilds `table.pots` from every seat's `total_bet_this_hand`.
+///
+/// Each distinct all-in amount among the players still in the hand closes a pot,
+/// eligible to the players in the hand whose contribution reaches it (or who can
+/// still bet to reach it). Chips above the highest all-in form the last pot,
+/// eligible to the players who can still bet.
+/// Folded players' chips count towards the pots but make them ineligible.
+pub fn recompute_pots(table: &mut Table, seats: &[&PlayerSeat]) {
+    let mut levels: Vec<u64> = seats
+        .iter()
+        .filter(|seat| seat.is_active_in_hand && seat.is_all_in)
+        .map(|seat| seat.total_bet_this_hand)
+        .collect();
+    levels.sort_unstable();
+    levels.dedup();
+    levels.push(u64::MAX);
+
+    let mut pots = Vec::with_capacity(levels.len());
+    let mut previous_level = 0u64;
+    for level in levels {
+        let mut amount = 0u64;
+        let mut eligible_seats = 0u8;
+        for seat in seats.iter() {
+            let bet = seat.total_bet_this_hand;
+            amount += bet.min(level) - bet.min(previous_level);
+            // Players who can still bet may yet match any level; all-in players
+            // only reach the levels their contribution covers.
+            if seat.is_active_in_hand && (!seat.is_all_in || bet >= level) {
+                eligible_seats |= 1 << seat.seat_index;
+            }
+        }
+        if amount > 0 {
+            pots.push(PotInfo { amount, eligible_seats });
+        }
+        previous_level = level;
+    }
+    table.pots = pots;
+}
@@ -0,0 +1,127 @@
+//! src/utils/rake.rs
+//!
+//! @description
+//! The platform takes its rake from each pot as it is paid out. This module holds the
+//! one place the rake is computed from the `PlatformConfig` parameters, so every path
+//! that pays out a pot (a showdown, or a pot won because everyone else folded)
+//! charges the same rake. Pots won before the flop are never raked ("no flop, no drop").
+//! At a table with a referrer, part of a showdown pot's rake goes to the referrer.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{BettingRound, PlatformConfig, PlayerSeat, PotInfo, RakeScope, Table};
+
+/// Returns the rake to take from a pot of `pot` chips: `rake_bps` of the pot, capped
+/// at `rake_max_cap` when a cap is set. A pot that did not go to showdown is not
+/// raked when the platform only rakes showdown pots.
+pub fn compute_rake(pot: u64, config: &PlatformConfig, went_to_showdown: bool) -> u64 {
+    if config.rake_on_showdown_only && !went_to_showdown {
+        return 0;
+    }
+    // Multiply in u128: a large-supply token's pot times `rake_bps` can exceed u64.
+    // The quotient is at most `pot`, so narrowing it back is lossless.
+    let rake = (pot as u128 * config.rake_bps as u128 / 10000) as u64;
+    if config.rake_max_cap > 0 {
+        rake.min(config.rake_max_cap)
+    } else {
+        rake
+    }
+}
+
+/// Returns the rake to take from each of a showdown's pots, main pot first. Under
+/// `RakeScope::TotalPot` the whole pot is raked and capped once, the rake coming out of
+/// the main pot first. Under `RakeScope::PerPot` each pot is raked and capped on its
+/// own, and a pot only one player is eligible for is an uncalled bet going back to
+/// them, so it is not raked.
+pub fn compute_showdown_rakes(pots: &[PotInfo], config: &PlatformConfig) -> Vec<u64> {
+    match config.rake_scope {
+        RakeScope::TotalPot => {
+            let mut rake_left = compute_rake(pots.iter().map(|pot| pot.amount).sum(), config, true);
+            pots.iter()
+                .map(|pot| {
+                    let taken = rake_left.min(pot.amount);
+                    rake_left -= taken;
+                    taken
+                })
+                .collect()
+        }
+        RakeScope::PerPot => pots
+            .iter()
+            .map(|pot| {
+                if pot.eligible_seats.count_ones() > 1 {
+                    compute_rake(pot.amount, config, true)
+                } else {
+                    0
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Returns the referrer's share of `rake`: `referrer_rake_bps` of it, rounded down so
+/// any remainder stays with the treasury.
+pub fn referrer_rake_share(rake: u64, config: &PlatformConfig) -> u64 {
+    (rake as u128 * config.referrer_rake_bps as u128 / 10000) as u64
+}
+
+/// Credits the pot of a hand everyone else folded to `winner`, the last player left in
+/// it, less the rake. A pot won preflop is not raked. Clears the pot and emits
+/// `PotAwarded`; returns the rake, which the caller transfers to the treasury.
+pub fn award_uncontested_pot(table: &mut Table, winner: &mut PlayerSeat, config: &PlatformConfig) -> u64 {
+    let pot = table.pot;
+    let rake = if table.betting_round == BettingRound::PreFlop {
+        0
+    } else {
+        compute_rake(pot, config, false)
+    };
+    let amount = pot - rake;
+    winner.stack += amount;
+    table.pot = 0;
+    table.pots.clear();
+
+    emit!(PotAwarded {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        seat_index: winner.seat_index,
+        amount,
+        rake,
+    });
+    rake
+}
+
+/// Moves `amount` of rake from a table's vault to `recipient`: the platform treasury,
+/// or a referrer's token account. The vault's authority is the table PDA, so the
+/// transfer is signed with the table's seeds.
+pub fn transfer_rake<'info>(
+    table: &Account<'info, Table>,
+    table_bump: u8,
+    table_vault: &Account<'info, TokenAccount>,
+    recipient: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let table_id_bytes = table.table_id.to_le_bytes();
+    let seeds = &[&b"table"[..], table_id_bytes.as_ref(), &[table_bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: table_vault.to_account_info(),
+        to: recipient.to_account_info(),
+        authority: table.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, amount)
+}
+
+#[event]
+pub struct PotAwarded {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub seat_index: u8,
+    /// The chips credited to the winner, after rake.
+    pub amount: u64,
+    pub rake: u64,
+}
@@ -0,0 +1,159 @@
+//! src/utils/refunds.rs
+//!
+//! @description
+//! Voiding a hand returns every player's contribution to their stack and closes the
+//! hand without a winner. A stuck hand is voided by `force_hand_refund` after a
+//! timeout; a hand whose deck fails an integrity check when cards are revealed is
+//! voided straight away, since no later street or showdown could be trusted, as is a
+//! hand whose reveal keeps failing after the platform's allowed retries. A deal
+//! whose hole cards fail the same checks is a misdeal, and only the forced bets are
+//! refunded.
+
+use std::ops::DerefMut;
+use anchor_lang::prelude::*;
+use crate::state::{BettingRound, PlayerSeat, Table};
+use crate::error::AcesUnknownErrorCode;
+use crate::utils::persist_seats;
+
+/// Why a hand was voided, as reported in `HandVoided`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoidReason {
+    /// A reveal returned fewer cards than requested, or an invalid card index.
+    DeckOutOfSync,
+    /// A reveal returned a card that is already on the board, or the same card twice.
+    DuplicateCard,
+    /// The community-card reveal failed more times than the platform allows retries.
+    RevealRetriesExhausted,
+}
+
+/// Returns each seat's `total_bet_this_hand` to its stack, clears the pot, and marks
+/// the hand complete. The refunds must account for the whole pot. Returns the total
+/// refunded; the seats are persisted by the caller.
+pub fn refund_hand_contributions<S: DerefMut<Target = PlayerSeat>>(
+    table: &mut Table,
+    seats: &mut [S],
+    now: i64,
+) -> Result<u64> {
+    let mut total_refunded = 0u64;
+    for seat in seats.iter_mut() {
+        let refund = seat.total_bet_this_hand;
+        seat.stack += refund;
+        total_refunded += refund;
+        // Clear the voided hand's betting state; nobody is in a hand until the next one starts.
+        seat.reset_for_new_hand();
+        seat.is_active_in_hand = false;
+    }
+
+    require!(table.pot == total_refunded, AcesUnknownErrorCode::InvalidAction);
+    table.pot = 0;
+    table.current_bet = 0;
+    table.ready_for_runout = false;
+    table.pots.clear();
+    table.complete_hand(now);
+    table.betting_round = BettingRound::PreFlop; // Reset to default
+    Ok(total_refunded)
+}
+
+/// Voids the current hand after an integrity failure: refunds every contribution,
+/// persists the seats, and emits `HandVoided`.
+pub fn void_hand_and_refund(
+    table: &mut Table,
+    seats: &mut [Account<PlayerSeat>],
+    reason: VoidReason,
+    now: i64,
+) -> Result<()> {
+    let total_refunded = refund_hand_contributions(table, seats, now)?;
+    persist_seats(seats)?;
+
+    emit!(HandVoided {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        reason,
+        total_refunded,
+    });
+    Ok(())
+}
+
+/// Declares a misdeal after `shuffle_and_deal` reports an invalid deal: refunds the
+/// blinds and antes already posted and emits `Misdeal`; the seats are persisted by the
+/// caller. The hand is complete, so the next `start_hand` deals from a fresh shuffle.
+pub fn declare_misdeal<S: DerefMut<Target = PlayerSeat>>(
+    table: &mut Table,
+    seats: &mut [S],
+    now: i64,
+) -> Result<()> {
+    let total_refunded = refund_hand_contributions(table, seats, now)?;
+
+    emit!(Misdeal {
+        table_id: table.table_id,
+        hand_id: table.hand_id_counter,
+        total_refunded,
+    });
+    Ok(())
+}
+
+#[event]
+pub struct Misdeal {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub total_refunded: u64,
+}
+
+#[event]
+pub struct HandVoided {
+    pub table_id: u64,
+    pub hand_id: u64,
+    pub reason: VoidReason,
+    pub total_refunded: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{GameState, HandPhase};
+
+    fn zeroed_table() -> Table {
+        Table::deserialize(&mut &vec![0u8; Table::INIT_SPACE][..]).unwrap()
+    }
+
+    fn seat_with_bets(stack: u64, total_bet_this_hand: u64) -> PlayerSeat {
+        let mut seat = PlayerSeat::deserialize(&mut &vec![0u8; PlayerSeat::INIT_SPACE][..]).unwrap();
+        seat.stack = stack;
+        seat.total_bet_this_hand = total_bet_this_hand;
+        seat.bet_this_round = total_bet_this_hand;
+        seat.is_active_in_hand = true;
+        seat
+    }
+
+    #[test]
+    fn a_misdeal_refunds_the_forced_bets_and_completes_the_hand() {
+        let mut table = zeroed_table();
+        table.game_state = GameState::HandInProgress;
+        table.hand_phase = HandPhase::Dealing;
+        table.pot = 30;
+        table.current_bet = 20;
+        let mut small_blind = seat_with_bets(390, 10);
+        let mut big_blind = seat_with_bets(380, 20);
+        let mut seats = [&mut small_blind, &mut big_blind];
+
+        declare_misdeal(&mut table, &mut seats, 1_000).unwrap();
+
+        assert_eq!((small_blind.stack, big_blind.stack), (400, 400));
+        assert_eq!((small_blind.total_bet_this_hand, big_blind.bet_this_round), (0, 0));
+        assert!(!small_blind.is_active_in_hand && !big_blind.is_active_in_hand);
+        assert_eq!((table.pot, table.current_bet), (0, 0));
+        assert_eq!(table.game_state, GameState::HandComplete);
+        assert_eq!(table.hand_phase, HandPhase::Complete);
+        assert_eq!(table.last_hand_completed_at, 1_000);
+    }
+
+    #[test]
+    fn a_misdeal_whose_refunds_miss_part_of_the_pot_fails() {
+        let mut table = zeroed_table();
+        table.pot = 35;
+        let mut seats = [seat_with_bets(390, 10), seat_with_bets(380, 20)].map(Box::new);
+
+        let res = declare_misdeal(&mut table, &mut seats, 1_000);
+        assert_eq!(res.unwrap_err(), AcesUnknownErrorCode::InvalidAction.into());
+    }
+}
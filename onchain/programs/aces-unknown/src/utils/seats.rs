@@ -0,0 +1,193 @@
+//! src/utils/seats.rs
+//!
+//! @description
+//! Player state lives in one `PlayerSeat` PDA per seat rather than in the `Table`
+//! account, so instructions that need to read or modify several players at once
+//! receive those seat accounts through `ctx.remaining_accounts`. This module
+//! provides the shared logic for turning those raw accounts into typed, validated
+//! `PlayerSeat` accounts and for writing any modifications back.
+//!
+//! @security
+//! Every account is checked to be a program-owned `PlayerSeat`, to belong to the
+//! expected table, and to live at the canonical seat PDA, so a caller cannot
+//! substitute a seat from another table or a forged account.
+
+use anchor_lang::prelude::*;
+use crate::state::{PlayerSeat, Table};
+use crate::state::constants::MAX_PLAYERS;
+use crate::error::AcesUnknownErrorCode;
+
+/// Deserializes and validates the `PlayerSeat` accounts passed in `remaining_accounts`.
+pub fn load_table_seats<'info>(
+    table_key: &Pubkey,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<Account<'info, PlayerSeat>>> {
+    let mut seats = Vec::with_capacity(accounts.len());
+    for account_info in accounts.iter() {
+        let seat: Account<'info, PlayerSeat> = Account::try_from(account_info)?;
+        require_keys_eq!(
+            seat.table_pubkey,
+            *table_key,
+            AcesUnknownErrorCode::InvalidSeatAccounts
+        );
+
+        let expected_key = Pubkey::create_program_address(
+            &[
+                b"player_seat",
+                table_key.as_ref(),
+                seat.seat_index.to_le_bytes().as_ref(),
+                &[seat.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| error!(AcesUnknownErrorCode::InvalidSeatAccounts))?;
+        require_keys_eq!(
+            expected_key,
+            account_info.key(),
+            AcesUnknownErrorCode::InvalidSeatAccounts
+        );
+
+        seats.push(seat);
+    }
+    Ok(seats)
+}
+
+/// Ensures the given seat indices are exactly the table's occupied seats, with no
+/// duplicates and none missing.
+pub fn require_all_occupied_seats(
+    table: &Table,
+    seat_indices: impl IntoIterator<Item = u8>,
+) -> Result<()> {
+    let mut mask = 0u8;
+    for seat_index in seat_indices {
+        let bit = 1u8 << seat_index;
+        require!(mask & bit == 0, AcesUnknownErrorCode::InvalidSeatAccounts);
+        mask |= bit;
+    }
+    require!(
+        mask == table.occupied_seats,
+        AcesUnknownErrorCode::InvalidSeatAccounts
+    );
+    Ok(())
+}
+
+/// Ensures at least two players are still in the hand. Once everyone else has
+/// folded, the hand goes to the last player uncontested, with no further board
+/// cards or showdown.
+pub fn require_contested_hand(seats: &[Account<PlayerSeat>]) -> Result<()> {
+    require!(
+        seats.iter().filter(|seat| seat.is_active_in_hand).count() >= 2,
+        AcesUnknownErrorCode::HandUncontested
+    );
+    Ok(())
+}
+
+/// Checks that the `occupied_seats` bitmask, `player_count` and `seat_pubkeys` agree.
+/// They are stored separately, so this runs at the end of every seat-mutating
+/// instruction to catch any instruction that updates one without the others.
+pub fn require_seat_accounting_consistent(table: &Table) -> Result<()> {
+    require!(
+        table.occupied_seats.count_ones() == table.player_count as u32,
+        AcesUnknownErrorCode::SeatAccountingMismatch
+    );
+    for (seat_index, seated) in table.seat_pubkeys.iter().enumerate() {
+        require!(
+            seated.is_some() == (table.occupied_seats & (1 << seat_index) != 0),
+            AcesUnknownErrorCode::SeatAccountingMismatch
+        );
+    }
+    Ok(())
+}
+
+/// Returns a mutable reference to the seat at `seat_index`.
+pub fn seat_at_mut<'a, 'info>(
+    seats: &'a mut [Account<'info, PlayerSeat>],
+    seat_index: u8,
+) -> Result<&'a mut Account<'info, PlayerSeat>> {
+    seats
+        .iter_mut()
+        .find(|seat| seat.seat_index == seat_index)
+        .ok_or_else(|| error!(AcesUnknownErrorCode::PlayerNotFound))
+}
+
+/// Writes modified seat accounts back to their account data.
+/// Accounts loaded from `remaining_accounts` are not serialized automatically by Anchor.
+pub fn persist_seats(seats: &[Account<PlayerSeat>]) -> Result<()> {
+    for seat in seats.iter() {
+        seat.exit(&crate::ID)?;
+    }
+    Ok(())
+}
+
+/// Finds the next seat clockwise from `from` whose player can still act this hand,
+/// i.e. one that is dealt in and not all-in. Returns `None` if nobody can act.
+pub fn next_actor_after(from: u8, seats: &[&PlayerSeat]) -> Option<u8> {
+    for offset in 1..=MAX_PLAYERS {
+        let position = ((from as usize + offset) % MAX_PLAYERS) as u8;
+        if let Some(seat) = seats.iter().find(|seat| seat.seat_index == position) {
+            if seat.is_active_in_hand && !seat.is_all_in {
+                return Some(position);
+            }
+        }
+    }
+    None
+}
+
+/// Returns `actor`'s effective stack: the most they can win or lose against the
+/// deepest other player still in the hand, i.e. the smaller of the two stacks.
+/// Returns 0 if no one else is still in the hand.
+pub fn effective_stack(actor: &PlayerSeat, seats: &[&PlayerSeat]) -> u64 {
+    let deepest_opponent = seats
+        .iter()
+        .filter(|seat| seat.seat_index != actor.seat_index && seat.is_active_in_hand)
+        .map(|seat| seat.stack)
+        .max()
+        .unwrap_or(0);
+    std::cmp::min(actor.stack, deepest_opponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table_with_seats(seat_indices: &[u8]) -> Table {
+        let mut table = Table::deserialize(&mut &vec![0u8; Table::INIT_SPACE][..]).unwrap();
+        for &seat_index in seat_indices {
+            table.occupy_seat(seat_index, Pubkey::new_unique());
+        }
+        table
+    }
+
+    #[test]
+    fn seats_taken_and_freed_stay_in_step() {
+        let mut table = table_with_seats(&[0, 2, 5]);
+        require_seat_accounting_consistent(&table).unwrap();
+        table.vacate_seat(2);
+        table.vacate_seat(2);
+        require_seat_accounting_consistent(&table).unwrap();
+        assert_eq!((table.occupied_seats, table.player_count), (0b100001, 2));
+    }
+
+    #[test]
+    fn a_bitmask_out_of_step_with_the_player_count_is_a_mismatch() {
+        let mut table = table_with_seats(&[0, 2]);
+        table.player_count = 3;
+        let res = require_seat_accounting_consistent(&table);
+        assert_eq!(res.unwrap_err(), AcesUnknownErrorCode::SeatAccountingMismatch.into());
+
+        // A seat cleared from the bitmask alone is caught too.
+        let mut table = table_with_seats(&[0, 2]);
+        table.occupied_seats &= !(1 << 2);
+        let res = require_seat_accounting_consistent(&table);
+        assert_eq!(res.unwrap_err(), AcesUnknownErrorCode::SeatAccountingMismatch.into());
+    }
+
+    #[test]
+    fn a_seated_player_missing_from_the_bitmask_is_a_mismatch() {
+        // The count matches, but the bitmask names the wrong seat.
+        let mut table = table_with_seats(&[0, 2]);
+        table.occupied_seats = 0b000011;
+        let res = require_seat_accounting_consistent(&table);
+        assert_eq!(res.unwrap_err(), AcesUnknownErrorCode::SeatAccountingMismatch.into());
+    }
+}
@@ -0,0 +1,431 @@
+//! src/utils/showdown.rs
+//!
+//! @description
+//! Stands in for the `evaluate_hands_and_payout` circuit until the Arcium integration
+//! is set up. Hands are ranked the way the circuit's `evaluate_7_cards` ranks them, and
+//! the pots are split the way its `calculate_payouts` splits them: one pot per all-in
+//! level, each paid to the best hand among the players who reached it, less that pot's
+//! rake, with odd chips going to the winners nearest the button's left.
+
+use crate::state::constants::MAX_PLAYERS;
+use crate::state::PotInfo;
+
+/// A hand's strength as the circuit's `HandRank` packs it: the category
+/// (9 for a straight flush down to 1 for high card) followed by its tie-break ranks,
+/// most significant first. Keys compare correctly as arrays.
+pub type HandKey = [u8; 6];
+
+/// The number of ranks in a suit; a card index is `suit * 13 + rank`.
+const NUM_RANKS: u8 = 13;
+/// The rank of an ace, the high card of a broadway straight and the low card of a wheel.
+const ACE_RANK: u8 = 12;
+
+/// Ranks the best five-card hand that can be made from `cards` (card indices 0-51).
+/// Fewer than five cards make no hand and rank below every real one.
+pub fn best_hand_key(cards: &[u8]) -> HandKey {
+    let mut best = [0u8; 6];
+    for subset in 0u32..(1 << cards.len()) {
+        if subset.count_ones() != 5 {
+            continue;
+        }
+        let mut hand = [0u8; 5];
+        let chosen = (0..cards.len()).filter(|&i| subset & (1 << i) != 0);
+        for (slot, i) in hand.iter_mut().zip(chosen) {
+            *slot = cards[i];
+        }
+        best = best.max(five_card_key(hand));
+    }
+    best
+}
+
+/// Ranks exactly five cards.
+fn five_card_key(cards: [u8; 5]) -> HandKey {
+    let mut ranks = cards.map(|card| card % NUM_RANKS);
+    ranks.sort_unstable_by(|a, b| b.cmp(a));
+    let flush = cards.iter().all(|&card| card / NUM_RANKS == cards[0] / NUM_RANKS);
+    let distinct = ranks.windows(2).all(|pair| pair[0] != pair[1]);
+    let straight_high = if !distinct {
+        None
+    } else if ranks[0] - ranks[4] == 4 {
+        Some(ranks[0])
+    } else if ranks == [ACE_RANK, 3, 2, 1, 0] {
+        // A-2-3-4-5 plays as a five-high straight.
+        Some(3)
+    } else {
+        None
+    };
+
+    // (count, rank) per distinct rank: bigger groups first, then higher ranks.
+    let mut groups: Vec<(u8, u8)> = Vec::with_capacity(5);
+    for &rank in ranks.iter() {
+        match groups.iter_mut().find(|group| group.1 == rank) {
+            Some(group) => group.0 += 1,
+            None => groups.push((1, rank)),
+        }
+    }
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+    let rank_of = |i: usize| groups.get(i).map_or(0, |group| group.1);
+    let second_count = groups.get(1).map_or(0, |group| group.0);
+
+    match (straight_high, flush, groups[0].0, second_count) {
+        (Some(high), true, _, _) => [9, high, 0, 0, 0, 0],
+        (_, _, 4, _) => [8, rank_of(0), rank_of(1), 0, 0, 0],
+        (_, _, 3, 2) => [7, rank_of(0), rank_of(1), 0, 0, 0],
+        (_, true, _, _) => [6, ranks[0], ranks[1], ranks[2], ranks[3], ranks[4]],
+        (Some(high), _, _, _) => [5, high, 0, 0, 0, 0],
+        (_, _, 3, _) => [4, rank_of(0), rank_of(1), rank_of(2), 0, 0],
+        (_, _, 2, 2) => [3, rank_of(0), rank_of(1), rank_of(2), 0, 0],
+        (_, _, 2, _) => [2, rank_of(0), rank_of(1), rank_of(2), rank_of(3), 0],
+        _ => [1, ranks[0], ranks[1], ranks[2], ranks[3], ranks[4]],
+    }
+}
+
+/// The contribution levels that cap the hand's pots, lowest (the main pot) first: each
+/// distinct contribution of a player still in the hand.
+fn pot_levels(player_bets: &[u64; MAX_PLAYERS], hand_keys: &[Option<HandKey>; MAX_PLAYERS]) -> Vec<u64> {
+    let mut levels: Vec<u64> = (0..MAX_PLAYERS)
+        .filter(|&i| hand_keys[i].is_some())
+        .map(|i| player_bets[i])
+        .collect();
+    levels.sort_unstable();
+    levels.dedup();
+    levels
+}
+
+/// Returns the pots the hand's chips are split into, the main pot first and then each
+/// side pot, in the order `calculate_payouts` pays them. Each is eligible to the
+/// players still in the hand whose contribution reaches its level.
+pub fn showdown_pots(
+    player_bets: &[u64; MAX_PLAYERS],
+    hand_keys: &[Option<HandKey>; MAX_PLAYERS],
+) -> Vec<PotInfo> {
+    let mut previous_level = 0u64;
+    pot_levels(player_bets, hand_keys)
+        .into_iter()
+        .map(|level| {
+            let amount = player_bets
+                .iter()
+                .map(|&bet| bet.min(level) - bet.min(previous_level))
+                .sum();
+            let eligible_seats = (0..MAX_PLAYERS)
+                .filter(|&i| hand_keys[i].is_some() && player_bets[i] >= level)
+                .fold(0u8, |seats, i| seats | (1 << i));
+            previous_level = level;
+            PotInfo { amount, eligible_seats }
+        })
+        .collect()
+}
+
+/// Splits the hand's chips among the players still in it, indexed by seat.
+///
+/// `player_bets` holds every seat's contribution to the hand, folded players included;
+/// `hand_keys` is `Some` for each player still in the hand. Each distinct contribution
+/// of a player still in the hand caps a pot, won by the best hand among the players
+/// whose contribution reaches it. `pot_rakes` holds the rake taken out of each pot
+/// before it is paid, in `showdown_pots` order. Tied winners split a pot evenly, and
+/// any odd chips go one at a time to the tied winners in order from the seat left of
+/// the button. The payouts add up to every contribution less the rake.
+pub fn calculate_payouts(
+    player_bets: &[u64; MAX_PLAYERS],
+    hand_keys: &[Option<HandKey>; MAX_PLAYERS],
+    dealer_position: u8,
+    pot_rakes: &[u64],
+) -> [u64; MAX_PLAYERS] {
+    // Seats in the order odd chips are handed out: starting left of the button.
+    let button_order: Vec<usize> = (1..=MAX_PLAYERS)
+        .map(|offset| (dealer_position as usize + offset) % MAX_PLAYERS)
+        .collect();
+
+    let mut payouts = [0u64; MAX_PLAYERS];
+    for (pot_index, pot_info) in showdown_pots(player_bets, hand_keys).into_iter().enumerate() {
+        let pot = pot_info.amount.saturating_sub(pot_rakes.get(pot_index).copied().unwrap_or(0));
+
+        let eligible = |i: usize| hand_keys[i].filter(|_| pot_info.eligible_seats & (1 << i) != 0);
+        let best = (0..MAX_PLAYERS).filter_map(eligible).max();
+        let winners: Vec<usize> = button_order
+            .iter()
+            .copied()
+            .filter(|&i| best.is_some() && eligible(i) == best)
+            .collect();
+        if winners.is_empty() {
+            continue;
+        }
+        let share = pot / winners.len() as u64;
+        let odd_chips = (pot % winners.len() as u64) as usize;
+        for (order, &i) in winners.iter().enumerate() {
+            payouts[i] += share + u64::from(order < odd_chips);
+        }
+    }
+    payouts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ranks, 0 for a deuce up to 12 for an ace.
+    const TWO: u8 = 0;
+    const THREE: u8 = 1;
+    const FOUR: u8 = 2;
+    const FIVE: u8 = 3;
+    const SIX: u8 = 4;
+    const SEVEN: u8 = 5;
+    const EIGHT: u8 = 6;
+    const NINE: u8 = 7;
+    const TEN: u8 = 8;
+    const JACK: u8 = 9;
+    const QUEEN: u8 = 10;
+    const KING: u8 = 11;
+    const ACE: u8 = 12;
+
+    // Suits.
+    const CLUBS: u8 = 0;
+    const DIAMONDS: u8 = 1;
+    const HEARTS: u8 = 2;
+    const SPADES: u8 = 3;
+
+    fn card(rank: u8, suit: u8) -> u8 {
+        suit * NUM_RANKS + rank
+    }
+
+    /// Each seat's best hand on `board`, for the first `holes.len()` seats.
+    fn showdown_keys(holes: &[[u8; 2]], board: &[u8]) -> [Option<HandKey>; MAX_PLAYERS] {
+        let mut keys = [None; MAX_PLAYERS];
+        for (key, hole) in keys.iter_mut().zip(holes) {
+            *key = Some(best_hand_key(&[hole.as_slice(), board].concat()));
+        }
+        keys
+    }
+
+    #[test]
+    fn five_cards_rank_in_every_category() {
+        let cases: [([u8; 5], HandKey); 10] = [
+            (
+                [card(NINE, HEARTS), card(EIGHT, HEARTS), card(SEVEN, HEARTS), card(SIX, HEARTS), card(FIVE, HEARTS)],
+                [9, NINE, 0, 0, 0, 0],
+            ),
+            (
+                [card(JACK, CLUBS), card(JACK, DIAMONDS), card(JACK, HEARTS), card(JACK, SPADES), card(FOUR, CLUBS)],
+                [8, JACK, FOUR, 0, 0, 0],
+            ),
+            (
+                [card(THREE, CLUBS), card(THREE, DIAMONDS), card(THREE, HEARTS), card(KING, SPADES), card(KING, CLUBS)],
+                [7, THREE, KING, 0, 0, 0],
+            ),
+            (
+                [card(ACE, SPADES), card(TEN, SPADES), card(EIGHT, SPADES), card(FOUR, SPADES), card(TWO, SPADES)],
+                [6, ACE, TEN, EIGHT, FOUR, TWO],
+            ),
+            (
+                [card(TEN, CLUBS), card(NINE, DIAMONDS), card(EIGHT, HEARTS), card(SEVEN, SPADES), card(SIX, CLUBS)],
+                [5, TEN, 0, 0, 0, 0],
+            ),
+            (
+                [card(QUEEN, CLUBS), card(QUEEN, DIAMONDS), card(QUEEN, HEARTS), card(NINE, SPADES), card(TWO, CLUBS)],
+                [4, QUEEN, NINE, TWO, 0, 0],
+            ),
+            (
+                [card(EIGHT, CLUBS), card(EIGHT, DIAMONDS), card(FIVE, HEARTS), card(FIVE, SPADES), card(ACE, CLUBS)],
+                [3, EIGHT, FIVE, ACE, 0, 0],
+            ),
+            (
+                [card(KING, CLUBS), card(KING, DIAMONDS), card(SEVEN, HEARTS), card(FOUR, SPADES), card(TWO, CLUBS)],
+                [2, KING, SEVEN, FOUR, TWO, 0],
+            ),
+            (
+                [card(ACE, CLUBS), card(JACK, DIAMONDS), card(NINE, HEARTS), card(SIX, SPADES), card(THREE, CLUBS)],
+                [1, ACE, JACK, NINE, SIX, THREE],
+            ),
+            // The wheel: the ace plays low, so this is a five-high straight.
+            (
+                [card(ACE, CLUBS), card(TWO, DIAMONDS), card(THREE, HEARTS), card(FOUR, SPADES), card(FIVE, CLUBS)],
+                [5, FIVE, 0, 0, 0, 0],
+            ),
+        ];
+        for (cards, expected) in cases {
+            assert_eq!(five_card_key(cards), expected, "{cards:?}");
+            // Five cards on their own rank the same as the best five of more cards.
+            assert_eq!(best_hand_key(&cards), expected, "{cards:?}");
+        }
+    }
+
+    #[test]
+    fn categories_outrank_each_other_in_order() {
+        let key = |ranks: [u8; 5]| five_card_key([
+            card(ranks[0], CLUBS),
+            card(ranks[1], DIAMONDS),
+            card(ranks[2], HEARTS),
+            card(ranks[3], SPADES),
+            card(ranks[4], CLUBS),
+        ]);
+        let high_card = key([ACE, KING, QUEEN, JACK, NINE]);
+        let lowest_pair = key([TWO, TWO, THREE, FOUR, SIX]);
+        let wheel = key([ACE, TWO, THREE, FOUR, FIVE]);
+        let six_high_straight = key([TWO, THREE, FOUR, FIVE, SIX]);
+        assert!(lowest_pair > high_card);
+        assert!(wheel > lowest_pair);
+        assert!(six_high_straight > wheel);
+    }
+
+    #[test]
+    fn a_straight_flush_inside_a_flush_beats_the_flush() {
+        // Six hearts: the best flush would be ace-high, but five of them run 5-9.
+        let cards = [
+            card(NINE, HEARTS),
+            card(EIGHT, HEARTS),
+            card(SEVEN, HEARTS),
+            card(SIX, HEARTS),
+            card(FIVE, HEARTS),
+            card(ACE, HEARTS),
+            card(TWO, CLUBS),
+        ];
+        assert_eq!(best_hand_key(&cards), [9, NINE, 0, 0, 0, 0]);
+
+        // A suited wheel is a five-high straight flush.
+        let cards = [
+            card(ACE, SPADES),
+            card(TWO, SPADES),
+            card(THREE, SPADES),
+            card(FOUR, SPADES),
+            card(FIVE, SPADES),
+            card(KING, CLUBS),
+            card(QUEEN, DIAMONDS),
+        ];
+        assert_eq!(best_hand_key(&cards), [9, FIVE, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn a_flush_and_an_offsuit_straight_are_not_a_straight_flush() {
+        // Hearts make a king-high flush; the 4-8 straight needs the seven of clubs and
+        // the eight of diamonds.
+        let cards = [
+            card(FOUR, HEARTS),
+            card(FIVE, HEARTS),
+            card(SIX, HEARTS),
+            card(SEVEN, CLUBS),
+            card(EIGHT, DIAMONDS),
+            card(KING, HEARTS),
+            card(TWO, HEARTS),
+        ];
+        assert_eq!(best_hand_key(&cards), [6, KING, SIX, FIVE, FOUR, TWO]);
+    }
+
+    #[test]
+    fn the_higher_two_pair_wins_the_whole_pot() {
+        let board = [card(KING, CLUBS), card(KING, DIAMONDS), card(SEVEN, HEARTS), card(FOUR, SPADES), card(TWO, CLUBS)];
+        // Kings and sevens against kings and fours: same category, different hands.
+        let keys = showdown_keys(
+            &[[card(SEVEN, SPADES), card(THREE, DIAMONDS)], [card(FOUR, HEARTS), card(THREE, CLUBS)]],
+            &board,
+        );
+        assert_eq!(keys[0], Some([3, KING, SEVEN, FOUR, 0, 0]));
+        assert_eq!(keys[1], Some([3, KING, FOUR, SEVEN, 0, 0]));
+        let bets = [100, 100, 0, 0, 0, 0];
+        assert_eq!(calculate_payouts(&bets, &keys, 0, &[]), [200, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn kickers_decide_a_pot_and_split_it_when_they_tie() {
+        let board = [card(ACE, CLUBS), card(KING, DIAMONDS), card(NINE, HEARTS), card(FIVE, SPADES), card(TWO, CLUBS)];
+        let bets = [100, 100, 0, 0, 0, 0];
+
+        // A queen kicker beats a jack.
+        let keys = showdown_keys(
+            &[[card(QUEEN, SPADES), card(THREE, DIAMONDS)], [card(JACK, HEARTS), card(FOUR, CLUBS)]],
+            &board,
+        );
+        assert_eq!(calculate_payouts(&bets, &keys, 0, &[]), [200, 0, 0, 0, 0, 0]);
+
+        // Both play A-K-Q-9-5; the low cards do not play, so the pot is split.
+        let keys = showdown_keys(
+            &[[card(QUEEN, SPADES), card(THREE, DIAMONDS)], [card(QUEEN, HEARTS), card(FOUR, CLUBS)]],
+            &board,
+        );
+        assert_eq!(keys[0], keys[1]);
+        assert_eq!(calculate_payouts(&bets, &keys, 0, &[]), [100, 100, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn players_who_play_the_board_chop_evenly() {
+        // A 5-9 straight on the board that no hole card improves.
+        let board = [card(FIVE, CLUBS), card(SIX, DIAMONDS), card(SEVEN, HEARTS), card(EIGHT, SPADES), card(NINE, CLUBS)];
+        let keys = showdown_keys(
+            &[
+                [card(ACE, SPADES), card(TWO, DIAMONDS)],
+                [card(KING, HEARTS), card(THREE, CLUBS)],
+                [card(QUEEN, DIAMONDS), card(TWO, HEARTS)],
+            ],
+            &board,
+        );
+        assert!(keys[..3].iter().all(|&key| key == Some([5, NINE, 0, 0, 0, 0])));
+
+        let bets = [100, 100, 100, 0, 0, 0];
+        assert_eq!(calculate_payouts(&bets, &keys, 0, &[]), [100, 100, 100, 0, 0, 0]);
+        // With one chip raked, the odd chips go to the seats left of the button first.
+        assert_eq!(calculate_payouts(&bets, &keys, 0, &[1]), [99, 100, 100, 0, 0, 0]);
+    }
+
+    #[test]
+    fn a_showdown_with_only_the_flop_ranks_the_cards_dealt() {
+        let board = [card(ACE, SPADES), card(SEVEN, HEARTS), card(TWO, CLUBS)];
+        let keys = showdown_keys(
+            &[[card(ACE, CLUBS), card(ACE, DIAMONDS)], [card(KING, CLUBS), card(KING, DIAMONDS)]],
+            &board,
+        );
+        assert_eq!(keys[0], Some([4, ACE, SEVEN, TWO, 0, 0]));
+        assert_eq!(keys[1], Some([2, KING, ACE, SEVEN, TWO, 0]));
+        let bets = [50, 50, 0, 0, 0, 0];
+        assert_eq!(calculate_payouts(&bets, &keys, 0, &[]), [100, 0, 0, 0, 0, 0]);
+
+        // Fewer than five cards in all make no hand.
+        assert_eq!(best_hand_key(&[card(ACE, CLUBS), card(ACE, DIAMONDS), card(ACE, SPADES)]), [0; 6]);
+    }
+
+    #[test]
+    fn three_all_ins_make_pots_sized_by_each_contribution() {
+        // Seats 0-2 are all-in for 50, 100 and 200, seat 3 calls 200, and seat 4
+        // folded after putting in 30. Stronger hands sit at lower seats.
+        let bets = [50, 100, 200, 200, 30, 0];
+        let keys = [
+            Some([9, ACE, 0, 0, 0, 0]),
+            Some([8, ACE, KING, 0, 0, 0]),
+            Some([7, ACE, KING, 0, 0, 0]),
+            Some([1, ACE, KING, QUEEN, JACK, NINE]),
+            None,
+            None,
+        ];
+        let pots = showdown_pots(&bets, &keys);
+        assert_eq!(
+            pots,
+            vec![
+                // 50 from each of the four players, plus the folded 30.
+                PotInfo { amount: 230, eligible_seats: 0b1111 },
+                PotInfo { amount: 150, eligible_seats: 0b1110 },
+                PotInfo { amount: 200, eligible_seats: 0b1100 },
+            ]
+        );
+        assert_eq!(pots.iter().map(|pot| pot.amount).sum::<u64>(), bets.iter().sum::<u64>());
+        assert_eq!(calculate_payouts(&bets, &keys, 0, &[]), [230, 150, 200, 0, 0, 0]);
+    }
+
+    #[test]
+    fn identical_all_ins_share_one_pot_level() {
+        let bets = [50, 50, 100, 0, 0, 0];
+        let keys = [
+            Some([2, TWO, ACE, KING, QUEEN, 0]),
+            Some([2, TWO, ACE, KING, QUEEN, 0]),
+            Some([1, ACE, KING, QUEEN, JACK, NINE]),
+            None,
+            None,
+            None,
+        ];
+        assert_eq!(
+            showdown_pots(&bets, &keys),
+            vec![
+                PotInfo { amount: 150, eligible_seats: 0b111 },
+                PotInfo { amount: 50, eligible_seats: 0b100 },
+            ]
+        );
+        assert_eq!(calculate_payouts(&bets, &keys, 0, &[]), [75, 75, 50, 0, 0, 0]);
+    }
+}
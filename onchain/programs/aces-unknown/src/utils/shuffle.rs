@@ -0,0 +1,25 @@
+//! src/utils/shuffle.rs
+//!
+//! @description
+//! Every hand's deck order is committed to by the `shuffle_and_deal` circuit before
+//! any card is dealt. Once the hand is over and the ordering and salt are revealed,
+//! anyone can recompute the commitment here and check that the deck they played
+//! against is the one that was committed to.
+
+use anchor_lang::solana_program::hash::hashv;
+
+/// Computes the shuffle commitment for a deck ordering: the SHA-256 digest of the 52
+/// card indices in deal order followed by the 32-byte salt. This is the construction
+/// the `shuffle_and_deal` circuit commits with.
+pub fn shuffle_commitment(deck_ordering: &[u8; 52], salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[deck_ordering.as_ref(), salt.as_ref()]).to_bytes()
+}
+
+/// Returns whether `deck_ordering` and `salt` open `commitment`.
+pub fn verify_shuffle_commitment(
+    deck_ordering: [u8; 52],
+    salt: [u8; 32],
+    commitment: [u8; 32],
+) -> bool {
+    shuffle_commitment(&deck_ordering, &salt) == commitment
+}
@@ -0,0 +1,43 @@
+//! src/utils/stats.rs
+//!
+//! @description
+//! Maintains the platform-wide `PlatformStats` counters kept in `PlatformConfig`.
+//! Indexers follow them through the `PlatformStatsUpdated` event, which every update
+//! emits, instead of scanning all of the program's accounts.
+
+use anchor_lang::prelude::*;
+use crate::state::PlatformConfig;
+
+/// Counts a newly created table.
+pub fn record_table_created(config: &mut PlatformConfig) {
+    config.stats.total_tables_created = config.stats.total_tables_created.saturating_add(1);
+    config.stats.active_tables = config.stats.active_tables.saturating_add(1);
+    emit_platform_stats(config);
+}
+
+/// Counts a table being closed.
+pub fn record_table_closed(config: &mut PlatformConfig) {
+    config.stats.active_tables = config.stats.active_tables.saturating_sub(1);
+    emit_platform_stats(config);
+}
+
+/// Counts a hand played to completion.
+pub fn record_hand_played(config: &mut PlatformConfig) {
+    config.stats.total_hands_played = config.stats.total_hands_played.saturating_add(1);
+    emit_platform_stats(config);
+}
+
+fn emit_platform_stats(config: &PlatformConfig) {
+    emit!(PlatformStatsUpdated {
+        total_tables_created: config.stats.total_tables_created,
+        active_tables: config.stats.active_tables,
+        total_hands_played: config.stats.total_hands_played,
+    });
+}
+
+#[event]
+pub struct PlatformStatsUpdated {
+    pub total_tables_created: u64,
+    pub active_tables: u64,
+    pub total_hands_played: u64,
+}
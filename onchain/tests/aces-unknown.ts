@@ -2,7 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
 import { AcesUnknown } from "../target/types/aces_unknown";
-import { randomBytes } from "crypto";
+import { createHash, randomBytes } from "crypto";
 import {
   awaitComputationFinalization,
   getArciumEnv,
@@ -164,6 +164,7 @@ describe("Aces Unknown", () => {
         .accounts({
           platformConfig: platformConfigPda,
           admin: owner.publicKey,
+          treasuryVault,
         })
         .rpc();
       console.log("Platform config initialized.");
@@ -195,7 +196,7 @@ describe("Aces Unknown", () => {
 
     // Create table
     await program.methods
-      .createTable(tableId, smallBlind, bigBlind, buyIn)
+      .createTable(tableId, 0, smallBlind, bigBlind, buyIn, tableName(""), new Array(32).fill(0), null)
       .accounts({
         table: tablePda,
         creator: creator.publicKey,
@@ -287,88 +288,3833 @@ describe("Aces Unknown", () => {
     expect(tableState.pot.gtn(0)).to.be.true; // Blinds posted
   });
 
+  it("posts a short-stacked big blind all-in while others still owe the full blind", async () => {
+    const t = await setupTable({
+      seats: [0, 1, 2],
+      smallBlind: 10,
+      bigBlind: 20,
+      buyIns: { 0: 1000 },
+    });
+
+    // Hand 1: seat 1 has the button and acts first. It raises, faces a re-raise
+    // from the deeper BB and folds, leaving it with 10 chips (half a big blind).
+    await startHandWithButton(t, 1);
+    const raiseTo = (await fetchSeat(t, 1)).stack.toNumber() - 10;
+    await act(t, 1, { raise: { amount: new anchor.BN(raiseTo) } });
+    await act(t, 2, { fold: {} });
+    await act(t, 0, { raise: { amount: new anchor.BN(raiseTo * 2 - 20) } });
+    await act(t, 1, { fold: {} });
+    await finishHandIfOpen(t);
+    expect((await fetchSeat(t, 1)).stack.toNumber()).to.equal(10);
+
+    // Hand 2: the button moves to seat 2, so seat 0 posts the SB and seat 1 the BB.
+    await startTestHand(t);
+
+    const bb = await fetchSeat(t, 1);
+    expect(bb.isAllIn).to.be.true;
+    expect(bb.stack.toNumber()).to.equal(0);
+    expect(bb.betThisRound.toNumber()).to.equal(10);
+
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.pot.toNumber()).to.equal(20);
+    expect(tableState.currentBet.toNumber()).to.equal(20);
+    expect(tableState.lastRaiseSize.toNumber()).to.equal(20);
+
+    // The button must still call the full big blind; the extra 10 forms a side pot.
+    await act(t, 2, { call: {} });
+    const caller = await fetchSeat(t, 2);
+    expect(caller.betThisRound.toNumber()).to.equal(20);
+    expect(caller.totalBetThisHand.toNumber()).to.be.greaterThan(bb.totalBetThisHand.toNumber());
+    const pots = async () =>
+      (await program.account.table.fetch(t.table)).pots.map((pot) => [pot.amount.toNumber(), pot.eligibleSeats]);
+    expect(await pots()).to.deep.equal([
+      [30, 0b111],
+      [10, 0b101],
+    ]);
+
+    // Once the small blind calls too, the short BB can only win the main pot.
+    await act(t, 0, { call: {} });
+    expect(await pots()).to.deep.equal([
+      [30, 0b111],
+      [20, 0b101],
+    ]);
+    expect((await program.account.table.fetch(t.table)).pot.toNumber()).to.equal(50);
+  });
+
+  it("lets UTG straddle and starts the action to the straddler's left", async () => {
+    const t = await setupTable({ seats: [0, 1, 2, 3] });
+    await program.methods
+      .updateTableSettings(t.tableId, { ...defaultSettings(), allowStraddle: true })
+      .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+
+    // Button on seat 1, blinds on seats 2 and 3, so seat 0 is UTG.
+    await startHandWithButton(t, 1);
+    expect((await program.account.table.fetch(t.table)).turnPosition).to.equal(0);
+
+    // Only UTG may straddle.
+    await expectError(() => postStraddle(t, 1), "StraddleNotAllowed");
+    await postStraddle(t, 0);
+
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.currentBet.toNumber()).to.equal(40);
+    expect(tableState.pot.toNumber()).to.equal(70);
+    expect(tableState.lastAggressorPosition).to.equal(0);
+    expect(tableState.turnPosition).to.equal(1);
+    expect(tableState.straddleWindowOpen).to.be.false;
+
+    // The window closes once the straddle is posted.
+    await expectError(() => postStraddle(t, 1), "StraddleNotAllowed");
+  });
+
+  it("applies a pending check/fold as a fold when facing a bet", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    // Button on seat 1 (UTG three-handed), small blind on seat 2, big blind on seat 0.
+    await startHandWithButton(t, 1);
+
+    await setPendingAction(t, 2, { checkFold: {} });
+    // It is not seat 2's turn yet, so nothing can be applied.
+    await expectError(() => applyPendingAction(t, 2), "NotPlayersTurn");
+
+    await act(t, 1, { call: {} });
+    await applyPendingAction(t, 2);
+
+    const sb = await fetchSeat(t, 2);
+    expect(sb.isActiveInHand).to.be.false;
+    expect(sb.pendingAction).to.be.null;
+    expect((await program.account.table.fetch(t.table)).turnPosition).to.equal(0);
+  });
+
+  it("awards the pot to the last player when a pending fold leaves them alone", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    // Heads-up, the button (seat 0) posts the small blind and acts first.
+    await startHandWithButton(t, 0);
+    await setPendingAction(t, 0, { fold: {} });
+
+    const awarded = awaitEvent("potAwarded");
+    await applyPendingAction(t, 0);
+    const event = await awarded;
+    expect(event.seatIndex).to.equal(1);
+    expect(event.amount.toNumber()).to.equal(30);
+    expect(event.rake.toNumber()).to.equal(0);
+
+    const table = await program.account.table.fetch(t.table);
+    expect(table.gameState).to.deep.equal({ handComplete: {} });
+    expect(table.pot.toNumber()).to.equal(0);
+    expect((await fetchSeat(t, 0)).stack.toNumber()).to.equal(390);
+    expect((await fetchSeat(t, 1)).stack.toNumber()).to.equal(410);
+    // Every chip in the vault is back in a stack, so the next hand loses nothing.
+    const vault = (await getAccount(provider.connection, t.vault)).amount;
+    const stacks = (await fetchSeat(t, 0)).stack.toNumber() + (await fetchSeat(t, 1)).stack.toNumber();
+    expect(vault).to.equal(BigInt(stacks));
+  });
+
+  it("applies a pending check/fold as a check when there is nothing to call", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    await startHandWithButton(t, 1);
+    await act(t, 1, { call: {} });
+    await act(t, 2, { call: {} });
+    await act(t, 0, { check: {} });
+    await dealStreet(t);
+
+    // Seat 2 is first to act on the flop.
+    expect((await program.account.table.fetch(t.table)).turnPosition).to.equal(2);
+    await expectError(() => applyPendingAction(t, 2), "NoPendingAction");
+
+    await setPendingAction(t, 2, { checkFold: {} });
+    await applyPendingAction(t, 2);
+
+    const player = await fetchSeat(t, 2);
+    expect(player.isActiveInHand).to.be.true;
+    expect(player.betThisRound.toNumber()).to.equal(0);
+    expect(player.pendingAction).to.be.null;
+    expect((await program.account.table.fetch(t.table)).turnPosition).to.equal(0);
+  });
+
+  it("keeps the occupied-seat bitmask in step with the player count", async () => {
+    const popcount = (mask: number) => mask.toString(2).split("1").length - 1;
+    const t = await setupTable({ seats: [0, 2, 4] });
+
+    let tableState = await program.account.table.fetch(t.table);
+    expect(tableState.occupiedSeats).to.equal(0b10101);
+    expect(popcount(tableState.occupiedSeats)).to.equal(tableState.playerCount);
+    expect((await fetchSeat(t, 0)).playerPubkey.equals(playerWallets[0].publicKey)).to.be.true;
+
+    await program.methods
+      .leaveTable(t.tableId)
+      .accounts({
+        table: t.table,
+        player: playerWallets[2].publicKey,
+        playerTokenAccount: playerTokenAccounts[2],
+        tableVault: t.vault,
+        playerSeat: seatPdaFor(t.table, 2),
+        playerActiveTables: activeTablesPdaFor(playerWallets[2].publicKey),
+      })
+      .signers([playerWallets[2]])
+      .rpc();
+
+    tableState = await program.account.table.fetch(t.table);
+    expect(tableState.occupiedSeats).to.equal(0b10001);
+    expect(tableState.playerCount).to.equal(2);
+  });
+
+  it("accrues rake-back in proportion to pot contribution and lets the player claim it", async () => {
+    await setRakeback(5000); // Return half of each player's rake.
+    try {
+      const t = await setupTable({
+        seats: [0, 1, 2],
+        smallBlind: 1000,
+        bigBlind: 2000,
+      });
+      const rakeback = rakebackPdaFor(playerWallets[1].publicKey);
+      const accruedBefore = (await program.account.playerRakeback.fetch(rakeback)).accrued.toNumber();
+
+      // Everyone puts in one big blind, then the board is run out to showdown.
+      await startHandWithButton(t, 1);
+      await act(t, 1, { call: {} });
+      await act(t, 2, { call: {} });
+      await runOutHand(t);
+
+      const config = await program.account.platformConfig.fetch(platformConfigPda);
+      const pot = 6000;
+      let rake = Math.floor((pot * config.rakeBps) / 10000);
+      if (config.rakeMaxCap.toNumber() > 0) rake = Math.min(rake, config.rakeMaxCap.toNumber());
+      const expected = Math.floor((Math.floor((rake * 2000) / pot) * 5000) / 10000);
+      expect(expected).to.be.greaterThan(0);
+
+      const accrued = (await program.account.playerRakeback.fetch(rakeback)).accrued.toNumber();
+      expect(accrued - accruedBefore).to.equal(expected);
+
+      const balanceBefore = (await getAccount(provider.connection, playerTokenAccounts[1])).amount;
+      await program.methods
+        .claimRakeback()
+        .accounts({
+          platformConfig: platformConfigPda,
+          player: playerWallets[1].publicKey,
+          playerRakeback: rakeback,
+          playerTokenAccount: playerTokenAccounts[1],
+          treasuryVault,
+          treasuryAuthority: owner.publicKey,
+        })
+        .signers([playerWallets[1], owner])
+        .rpc();
+
+      const balanceAfter = (await getAccount(provider.connection, playerTokenAccounts[1])).amount;
+      expect(balanceAfter - balanceBefore).to.equal(BigInt(accrued));
+      const claimed = await program.account.playerRakeback.fetch(rakeback);
+      expect(claimed.accrued.toNumber()).to.equal(0);
+      expect(claimed.totalClaimed.toNumber()).to.be.at.least(accrued);
+    } finally {
+      await setRakeback(0);
+    }
+  });
+
+  it("emits PlayerFolded for voluntary and timed-out folds", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    await startHandWithButton(t, 1);
+
+    // Seat 1 (button, first to act three-handed) folds voluntarily.
+    const voluntaryFold = awaitEvent("playerFolded");
+    await act(t, 1, { fold: {} });
+    const voluntary = await voluntaryFold;
+    expect(voluntary.tableId.eq(t.tableId)).to.be.true;
+    expect(voluntary.seatIndex).to.equal(1);
+    expect(voluntary.reason).to.deep.equal({ voluntary: {} });
+
+    // Seat 2 lets its timer run out and is folded by a keeper.
+    const { turnDurationSeconds } = await program.account.table.fetch(t.table);
+    await expectError(() => forcePlayerFold(t, 2), "TurnNotExpired");
+    await sleep((turnDurationSeconds + 2) * 1000);
+    const timedOutFold = awaitEvent("playerFolded");
+    await forcePlayerFold(t, 2);
+    const timedOut = await timedOutFold;
+    expect(timedOut.seatIndex).to.equal(2);
+    expect(timedOut.reason).to.deep.equal({ timedOut: {} });
+    expect((await fetchSeat(t, 2)).isActiveInHand).to.be.false;
+  });
+
+  it("refunds a stuck hand and emits HandRefunded", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    await startTestHand(t);
+    await expectError(() => forceHandRefund(t), "HandNotStuck");
+
+    await sleep(301 * 1000);
+    const refunded = awaitEvent("handRefunded");
+    await forceHandRefund(t);
+    const event = await refunded;
+    expect(event.tableId.eq(t.tableId)).to.be.true;
+    expect(event.totalRefunded.toNumber()).to.equal(30);
+
+    for (const seat of t.seats) {
+      expect((await fetchSeat(t, seat)).stack.toNumber()).to.equal(400);
+    }
+  });
+
+  it("emits PlayerLeft when a player cashes out", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const left = awaitEvent("playerLeft");
+    await program.methods
+      .leaveTable(t.tableId)
+      .accounts({
+        table: t.table,
+        player: playerWallets[1].publicKey,
+        playerTokenAccount: playerTokenAccounts[1],
+        tableVault: t.vault,
+        playerSeat: seatPdaFor(t.table, 1),
+        playerActiveTables: activeTablesPdaFor(playerWallets[1].publicKey),
+      })
+      .signers([playerWallets[1]])
+      .rpc();
+
+    const event = await left;
+    expect(event.seatIndex).to.equal(1);
+    expect(event.player.equals(playerWallets[1].publicKey)).to.be.true;
+    expect(event.cashOutAmount.toNumber()).to.equal(400);
+  });
+
+  it("draws the first dealer button among the seated players and rotates it afterwards", async () => {
+    // The draw itself is covered by the program's unit tests with fixed seeds; until the
+    // Arcium RNG supplies the seed, the hand's starter can steer it.
+    const t = await setupTable({ seats: [0, 1, 2] });
+    expect((await program.account.table.fetch(t.table)).firstHandDealt).to.be.false;
+
+    await startTestHand(t);
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.firstHandDealt).to.be.true;
+    expect(t.seats).to.include(tableState.dealerPosition);
+
+    // Later hands rotate the button to the next occupied seat as usual.
+    await foldToBigBlind(t);
+    await startTestHand(t);
+    const next = (await program.account.table.fetch(t.table)).dealerPosition;
+    expect(next).to.equal((tableState.dealerPosition + 1) % 3);
+  });
+
+  it("appends each street to the next empty board slots", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+    await checkDownStreet(t);
+
+    await dealStreet(t);
+    const flop = (await program.account.table.fetch(t.table)).communityCards;
+    expect(flop.filter((card) => card !== null)).to.have.length(3);
+
+    // The turn lands at index 3 without disturbing the flop.
+    await checkDownStreet(t);
+    await dealStreet(t);
+    const turn = (await program.account.table.fetch(t.table)).communityCards;
+    expect(turn.slice(0, 3)).to.deep.equal(flop.slice(0, 3));
+    expect(turn[3]).to.not.be.null;
+    expect(turn[4]).to.be.null;
+
+    await checkDownStreet(t);
+    await dealStreet(t);
+    const river = (await program.account.table.fetch(t.table)).communityCards;
+    expect(river.slice(0, 4)).to.deep.equal(turn.slice(0, 4));
+    expect(river[4]).to.not.be.null;
+
+    // Nothing more can be dealt onto a full board; the hand goes to showdown.
+    await checkDownStreet(t);
+    await expectError(() => dealStreet(t), "InvalidGameState");
+  });
+
+  it("flags the hand for a runout once both players are all-in", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+
+    // Heads-up, the button acts first preflop and shoves.
+    const { dealerPosition: button } = await program.account.table.fetch(t.table);
+    const bb = t.seats.find((s) => s !== button)!;
+    await act(t, button, { raise: { amount: new anchor.BN(400) } });
+    expect((await program.account.table.fetch(t.table)).readyForRunout).to.be.false;
+
+    const runout = awaitEvent("readyForRunout");
+    await act(t, bb, { call: {} });
+    const event = await runout;
+    expect(event.tableId.eq(t.tableId)).to.be.true;
+
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.readyForRunout).to.be.true;
+    expect(tableState.gameState).to.deep.equal({ handInProgress: {} });
+
+    // Neither player has a decision left to make.
+    await expectError(() => act(t, tableState.turnPosition, { check: {} }), "InvalidGameState");
+    await runOutHand(t);
+  });
+
+  it("holds a reserved seat for the reserving player until the reservation expires", async () => {
+    const t = await setupTable({ seats: [0] });
+
+    await reserveSeat(t, 3, 1);
+    const reservation = await program.account.seatReservation.fetch(reservationPdaFor(t.table, 3));
+    expect(reservation.reservedBy.equals(playerWallets[1].publicKey)).to.be.true;
+
+    // Nobody else can take or re-reserve the seat while it is held.
+    await expectError(() => joinSeat(t, 3, 2), "SeatReserved");
+    await expectError(() => reserveSeat(t, 3, 2), "SeatReserved");
+
+    // The reserving player can sit down, which clears the reservation.
+    await joinSeat(t, 3, 1);
+    expect((await fetchSeat(t, 3)).playerPubkey.equals(playerWallets[1].publicKey)).to.be.true;
+    expect((await program.account.seatReservation.fetch(reservationPdaFor(t.table, 3))).reservedBy).to.be.null;
+
+    // An expired reservation reopens the seat to everyone.
+    await reserveSeat(t, 4, 1);
+    await sleep(61 * 1000);
+    await joinSeat(t, 4, 2);
+    expect((await fetchSeat(t, 4)).playerPubkey.equals(playerWallets[2].publicKey)).to.be.true;
+  });
+
+  it("lets the admin force-close a seated table and returns every stack", async () => {
+    const t = await setupTable({ seats: [0, 1, 2], buyIns: { 1: 600 } });
+    const balancesBefore = await Promise.all(
+      t.seats.map(async (s) => (await getAccount(provider.connection, playerTokenAccounts[s])).amount)
+    );
+    const stacks = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).stack.toNumber()));
+
+    // Players cannot close the table, and a hand in progress must be refunded.
+    await expectError(() => forceCloseTable(t, playerWallets[1], false), "ConstraintAddress");
+    await startTestHand(t);
+    await expectError(() => forceCloseTable(t, owner, false), "CannotCloseMidHand");
+
+    const closed = awaitEvent("tableForceClosed");
+    await forceCloseTable(t, owner, true);
+    const event = await closed;
+    expect(event.playersCashedOut).to.equal(3);
+    expect(event.totalCashedOut.toNumber()).to.equal(stacks.reduce((a, b) => a + b, 0));
+
+    for (const [i, s] of t.seats.entries()) {
+      const balance = (await getAccount(provider.connection, playerTokenAccounts[s])).amount;
+      expect(balance - balancesBefore[i]).to.equal(BigInt(stacks[i]));
+      expect(await provider.connection.getAccountInfo(seatPdaFor(t.table, s))).to.be.null;
+    }
+    expect(await provider.connection.getAccountInfo(t.vault)).to.be.null;
+    expect(await provider.connection.getAccountInfo(t.table)).to.be.null;
+  });
+
+  it("requires the big blind to be at least twice the small blind", async () => {
+    const t = await setupTable({ seats: [0], smallBlind: 50, bigBlind: 100 });
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.smallBlind.toNumber()).to.equal(50);
+    expect(tableState.bigBlind.toNumber()).to.equal(100);
+
+    await expectError(
+      () => setupTable({ seats: [0], smallBlind: 60, bigBlind: 100 }),
+      "InvalidStakes"
+    );
+  });
+
+  it("publishes an absolute turn deadline on every turn change", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    const expectDeadline = async () => {
+      const state = await program.account.table.fetch(t.table);
+      expect(state.turnDeadline.toNumber()).to.equal(
+        state.turnStartedAt.toNumber() + state.turnDurationSeconds
+      );
+      return state;
+    };
+
+    await startHandWithButton(t, 1);
+    const started = await expectDeadline();
+
+    await act(t, 1, { call: {} });
+    const afterAction = await expectDeadline();
+    expect(afterAction.turnPosition).to.not.equal(started.turnPosition);
+
+    await act(t, 2, { call: {} });
+    await act(t, 0, { check: {} });
+    await dealStreet(t);
+    await expectDeadline();
+
+    // The deadline is what gates a timeout fold.
+    await sleep((started.turnDurationSeconds + 2) * 1000);
+    const { turnPosition } = await program.account.table.fetch(t.table);
+    await forcePlayerFold(t, turnPosition);
+    await expectDeadline();
+  });
+
+  it("reports the acting player's effective stack in PlayerActed", async () => {
+    const t = await setupTable({ seats: [0, 1], buyIns: { 1: 600 } });
+    await startTestHand(t);
+
+    // Heads-up, the button acts first and raises.
+    const { turnPosition: button } = await program.account.table.fetch(t.table);
+    const opponent = t.seats.find((s) => s !== button)!;
+    const acted = awaitEvent("playerActed");
+    await act(t, button, { raise: { amount: new anchor.BN(100) } });
+    const event = await acted;
+
+    const actorStack = (await fetchSeat(t, button)).stack.toNumber();
+    const opponentStack = (await fetchSeat(t, opponent)).stack.toNumber();
+    expect(actorStack).to.not.equal(opponentStack);
+    expect(event.seatIndex).to.equal(button);
+    expect(event.action).to.deep.equal({ raise: { amount: new anchor.BN(100) } });
+    expect(event.effectiveStack.toNumber()).to.equal(Math.min(actorStack, opponentStack));
+  });
+
+  it("enforces a player's self-imposed buy-in limit", async () => {
+    // Limits persist per wallet, so use a fresh player that no other test touches.
+    const wallet = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(wallet.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(sig, "confirmed");
+    const tokenAccount = await createAccount(provider.connection, owner, tokenMint, wallet.publicKey);
+    await mintTo(provider.connection, owner, tokenMint, tokenAccount, owner, 1_000_000);
+    const w = playerWallets.length;
+    playerWallets.push(wallet);
+    playerTokenAccounts.push(tokenAccount);
+
+    const limits = limitsPdaFor(wallet.publicKey);
+    const setLimit = (amount: number) =>
+      program.methods
+        .setPlayerLimits(new anchor.BN(amount))
+        .accounts({ player: wallet.publicKey, playerLimits: limits })
+        .signers([wallet])
+        .rpc();
+
+    // Buy-ins of 400 accumulate against a limit of 1000.
+    await setLimit(1000);
+    await joinSeat(await setupTable({ seats: [0] }), 1, w);
+    await joinSeat(await setupTable({ seats: [0] }), 1, w);
+    expect((await program.account.playerLimits.fetch(limits)).boughtInThisPeriod.toNumber()).to.equal(800);
+    await expectError(async () => joinSeat(await setupTable({ seats: [0] }), 1, w), "PlayerLimitExceeded");
+
+    // Lowering the limit is immediate.
+    await setLimit(500);
+    let state = await program.account.playerLimits.fetch(limits);
+    expect(state.maxBuyInPerPeriod.toNumber()).to.equal(500);
+    expect(state.pendingMaxBuyIn).to.be.null;
+
+    // Raising it waits out the cooldown, so the lower limit still applies.
+    await setLimit(5000);
+    state = await program.account.playerLimits.fetch(limits);
+    expect(state.maxBuyInPerPeriod.toNumber()).to.equal(500);
+    expect(state.pendingMaxBuyIn.toNumber()).to.equal(5000);
+    expect(state.pendingEffectiveAt.toNumber()).to.be.greaterThan(Math.floor(Date.now() / 1000));
+    await expectError(async () => joinSeat(await setupTable({ seats: [0] }), 1, w), "PlayerLimitExceeded");
+  });
+
+  it("breaks the pot into a main pot and a side pot as players go all-in", async () => {
+    const t = await setupTable({ seats: [0, 1, 2], buyIns: { 0: 1000, 1: 600 } });
+    // Button (UTG) on seat 1, small blind on seat 2, big blind on seat 0.
+    await startHandWithButton(t, 1);
+    expect((await program.account.table.fetch(t.table)).pots).to.be.empty;
+
+    // Seat 1 shoves, the shorter seat 2 calls all-in, and the deep seat 0 calls.
+    const shove = (await fetchSeat(t, 1)).stack.toNumber();
+    await act(t, 1, { raise: { amount: new anchor.BN(shove) } });
+    await act(t, 2, { call: {} });
+    await act(t, 0, { call: {} });
+
+    const short = (await fetchSeat(t, 2)).totalBetThisHand.toNumber();
+    expect(short).to.be.lessThan(shove);
+
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.pots).to.have.length(2);
+    const [main, side] = tableState.pots;
+    expect(main.amount.toNumber()).to.equal(short * 3);
+    expect(main.eligibleSeats).to.equal(0b111);
+    expect(side.amount.toNumber()).to.equal((shove - short) * 2);
+    expect(side.eligibleSeats).to.equal(0b011);
+    expect(main.amount.add(side.amount).eq(tableState.pot)).to.be.true;
+  });
+
+  it("requests an all-in equity estimate only once the board is to be run out", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+    await expectError(() => requestEquity(t), "InvalidGameState");
+
+    const { dealerPosition: button } = await program.account.table.fetch(t.table);
+    const bb = t.seats.find((s) => s !== button)!;
+    await act(t, button, { raise: { amount: new anchor.BN(400) } });
+    await act(t, bb, { call: {} });
+
+    const requested = awaitEvent("allInEquityRequested");
+    const estimated = awaitEvent("allInEquity");
+    await requestEquity(t);
+    const event = await requested;
+    expect(event.tableId.eq(t.tableId)).to.be.true;
+    expect(event.contenders).to.equal(0b11);
+    expect(event.numCommunity).to.equal(0);
+
+    // Every runout is won by one contender or split between both.
+    const equity = await estimated;
+    expect(equity.winBps[0] + equity.winBps[1] + equity.tieBps[0]).to.equal(10_000);
+    expect(equity.tieBps[0]).to.equal(equity.tieBps[1]);
+    expect(equity.winBps.slice(2)).to.deep.equal([0, 0, 0, 0]);
+
+    await runOutHand(t);
+  });
+
+  it("pauses an idle table and resumes it on the next action", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const idlePauseSeconds = 3;
+    await program.methods
+      .updateTableSettings(t.tableId, { ...defaultSettings(), idlePauseSeconds })
+      .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+
+    await startTestHand(t);
+    await expectError(() => checkTableActivity(t), "TableNotIdle");
+
+    await sleep((idlePauseSeconds + 1) * 1000);
+    const paused = awaitEvent("tablePaused");
+    await checkTableActivity(t);
+    expect((await paused).tableId.eq(t.tableId)).to.be.true;
+    const idle = await program.account.table.fetch(t.table);
+    expect(idle.isPaused).to.be.true;
+
+    // Pausing twice is a no-op the keeper should not pay for.
+    await expectError(() => checkTableActivity(t), "TableNotIdle");
+
+    await act(t, idle.turnPosition, { call: {} });
+    const resumed = await program.account.table.fetch(t.table);
+    expect(resumed.isPaused).to.be.false;
+    expect(resumed.lastActivityAt.gt(idle.lastActivityAt)).to.be.true;
+
+    await finishHandIfOpen(t);
+  });
+
+  it("rejects a seat from another table or another player in player_action", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const other = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+
+    const { turnPosition: actor } = await program.account.table.fetch(t.table);
+    const bystander = t.seats.find((s) => s !== actor)!;
+    const actWithSeat = (signer: number, seatPda: PublicKey) =>
+      program.methods
+        .playerAction(t.tableId, { call: {} })
+        .accounts({
+          table: t.table,
+          player: playerWallets[signer].publicKey,
+          playerSeat: seatPda,
+          platformConfig: platformConfigPda,
+          tableVault: t.vault,
+          treasuryVault,
+        })
+        .remainingAccounts(seatMetas(t, [bystander]))
+        .signers([playerWallets[signer]])
+        .rpc();
+
+    // The actor's own seat, but at the other table.
+    await expectError(() => actWithSeat(actor, seatPdaFor(other.table, actor)), "SeatTableMismatch");
+    // The right table, but someone else's seat.
+    await expectError(() => actWithSeat(bystander, seatPdaFor(t.table, actor)), "SeatOwnerMismatch");
+
+    await act(t, actor, { call: {} });
+    await finishHandIfOpen(t);
+  });
+
+  it("rejects a seat from another table or another player in leave_table", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const other = await setupTable({ seats: [0, 1] });
+
+    await expectError(() => leaveWithSeat(t, 1, seatPdaFor(other.table, 1)), "SeatTableMismatch");
+    await expectError(() => leaveWithSeat(t, 1, seatPdaFor(t.table, 0)), "SeatOwnerMismatch");
+
+    await leaveWithSeat(t, 1, seatPdaFor(t.table, 1));
+    expect((await program.account.table.fetch(t.table)).playerCount).to.equal(1);
+  });
+
+  it("deals in a late entrant who posts a dead and a live big blind", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    // Seats 0, 1, 2 with the button on 2; the next button is seat 4 once it is occupied.
+    await startHandWithButton(t, 2);
+    await foldToBigBlind(t);
+
+    await joinSeat(t, 4, 4);
+    await joinSeat(t, 5, 5);
+    expect((await fetchSeat(t, 4)).waitingForBigBlind).to.be.true;
+    expect((await fetchSeat(t, 5)).waitingForBigBlind).to.be.true;
+
+    const posted = awaitEvent("entryBlindPosted");
+    await postEntryBlind(t, 4);
+    const event = await posted;
+    expect(event.seatIndex).to.equal(4);
+    expect(event.deadBlind.toNumber()).to.equal(20);
+    expect(event.liveBlind.toNumber()).to.equal(20);
+    await expectError(() => postEntryBlind(t, 4), "InvalidAction");
+
+    // Button on seat 4, seat 5 (still waiting) leaves a dead small blind, BB on seat 0.
+    await startTestHand(t);
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.dealerPosition).to.equal(4);
+
+    const entrant = await fetchSeat(t, 4);
+    expect(entrant.isActiveInHand).to.be.true;
+    expect(entrant.entryBlindPending).to.be.false;
+    expect(entrant.betThisRound.toNumber()).to.equal(20);
+    expect(entrant.totalBetThisHand.toNumber()).to.equal(40);
+    expect(entrant.stack.toNumber()).to.equal(400 - 40);
+
+    const waiting = await fetchSeat(t, 5);
+    expect(waiting.isActiveInHand).to.be.false;
+    expect(waiting.totalBetThisHand.toNumber()).to.equal(0);
+
+    expect(tableState.pot.toNumber()).to.equal(20 + 40);
+    expect(tableState.turnPosition).to.equal(1);
+
+    await foldToBigBlind(t);
+  });
+
+  it("records how many deck cards the deal consumed for 2 and 5 players", async () => {
+    for (const seats of [[0, 1], [0, 1, 2, 3, 4]]) {
+      const t = await setupTable({ seats });
+      await startTestHand(t);
+
+      const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+      const handData = await program.account.handData.fetch(handPdaFor(t.table, handId));
+      expect(handData.cardsDealt).to.equal(2 * seats.length);
+
+      await foldToBigBlind(t);
+    }
+  });
+
+  it("rabbit-hunts the undealt board without touching any balance", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+    await foldToBigBlind(t);
+    await expectError(() => rabbitHunt(t), "RabbitHuntDisabled");
+
+    await program.methods
+      .updateTableSettings(t.tableId, { ...defaultSettings(), allowRabbitHunt: true })
+      .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+
+    const stacksBefore = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).stack));
+    const vaultBefore = (await getAccount(provider.connection, t.vault)).amount;
+    const tableBefore = await program.account.table.fetch(t.table);
+
+    const hunted = awaitEvent("rabbitHunt");
+    await rabbitHunt(t);
+    const event = await hunted;
+
+    // Two players were dealt four hole cards; each street then burns one card.
+    const expected = [5, 6, 7, 9, 11].map((index) => ({ rank: index % 13, suit: Math.floor(index / 13) }));
+    expect(event.cards).to.deep.equal(expected);
+
+    const stacksAfter = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).stack));
+    stacksAfter.forEach((stack, i) => expect(stack.eq(stacksBefore[i])).to.be.true);
+    expect((await getAccount(provider.connection, t.vault)).amount).to.equal(vaultBefore);
+    const tableAfter = await program.account.table.fetch(t.table);
+    expect(tableAfter.communityCards).to.deep.equal(tableBefore.communityCards);
+    expect(tableAfter.pot.eq(tableBefore.pot)).to.be.true;
+  });
+
+  it("sits out a short-stacked big blind when the table prefers it and passes the blind on", async () => {
+    const t = await setupTable({
+      seats: [0, 1, 2],
+      smallBlind: 10,
+      bigBlind: 20,
+      buyIns: { 0: 1000 },
+    });
+
+    // Hand 1 leaves seat 1 with 10 chips, as in the all-in blind test above.
+    await startHandWithButton(t, 1);
+    const raiseTo = (await fetchSeat(t, 1)).stack.toNumber() - 10;
+    await act(t, 1, { raise: { amount: new anchor.BN(raiseTo) } });
+    await act(t, 2, { fold: {} });
+    await act(t, 0, { raise: { amount: new anchor.BN(raiseTo * 2 - 20) } });
+    await act(t, 1, { fold: {} });
+    await finishHandIfOpen(t);
+    expect((await fetchSeat(t, 1)).stack.toNumber()).to.equal(10);
+
+    await program.methods
+      .updateTableSettings(t.tableId, { ...defaultSettings(), shortBlindPolicy: { sitOut: {} } })
+      .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+
+    // Hand 2: button on seat 2, SB on seat 0, and seat 1 cannot cover the BB.
+    const satOut = awaitEvent("playerSatOut");
+    await startTestHand(t);
+    expect((await satOut).seatIndex).to.equal(1);
+
+    const shortSeat = await fetchSeat(t, 1);
+    expect(shortSeat.isActiveInHand).to.be.false;
+    expect(shortSeat.isAllIn).to.be.false;
+    expect(shortSeat.stack.toNumber()).to.equal(10);
+    expect(shortSeat.betThisRound.toNumber()).to.equal(0);
+
+    // The big blind passes to seat 2, and the small blind acts first heads-up.
+    expect((await fetchSeat(t, 2)).betThisRound.toNumber()).to.equal(20);
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.pot.toNumber()).to.equal(30);
+    expect(tableState.lastAggressorPosition).to.equal(2);
+    expect(tableState.turnPosition).to.equal(0);
+
+    await foldToBigBlind(t);
+  });
+
+  it("only closes encrypted hands once the hand is complete", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+    await expectError(() => closeEncryptedHands(t), "InvalidGameState");
+
+    await foldToBigBlind(t);
+    const handData = handPdaFor(t.table, (await program.account.table.fetch(t.table)).handIdCounter);
+    const pairs = t.seats.map((s): [PublicKey, PublicKey] => [
+      encryptedHandPdaFor(handData, playerWallets[s].publicKey),
+      playerWallets[s].publicKey,
+    ]);
+    const closed = awaitEvent("encryptedHandsClosed");
+    await closeEncryptedHands(t, pairs);
+    expect((await closed).closed).to.equal(2);
+    for (const [encryptedHand] of pairs) {
+      expect(await provider.connection.getAccountInfo(encryptedHand)).to.be.null;
+    }
+  });
+
+  it("rejects a raise to the amount the player already has in", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    // Button on seat 0: seat 1 posts the small blind, seat 2 the big blind.
+    await startHandWithButton(t, 0);
+    await act(t, 0, { raise: { amount: new anchor.BN(60) } });
+
+    await expectError(
+      () => act(t, 1, { raise: { amount: new anchor.BN(10) } }),
+      "InvalidBetAmount"
+    );
+    const seat = await fetchSeat(t, 1);
+    expect(seat.betThisRound.toNumber()).to.equal(10);
+    expect((await program.account.table.fetch(t.table)).turnPosition).to.equal(1);
+
+    await act(t, 1, { fold: {} });
+    await act(t, 2, { fold: {} });
+  });
+
+  it("collects a single big blind ante from the button only", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    await program.methods
+      .updateTableSettings(t.tableId, { ...defaultSettings(), anteMode: { bigBlindAnte: {} } })
+      .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+
+    const antePosted = awaitEvent("antePosted");
+    await startTestHand(t);
+    const tableState = await program.account.table.fetch(t.table);
+    const button = tableState.dealerPosition;
+    const sb = (button + 1) % 3;
+    const bb = (button + 2) % 3;
+
+    const ante = await antePosted;
+    expect(ante.seatIndex).to.equal(button);
+    expect(ante.amount.toNumber()).to.equal(20);
+
+    // Blinds 10 + 20 plus one ante of a big blind.
+    expect(tableState.pot.toNumber()).to.equal(50);
+    expect(tableState.currentBet.toNumber()).to.equal(20);
+
+    // The ante is dead money: it is part of the button's contribution, not their bet.
+    const buttonSeat = await fetchSeat(t, button);
+    expect(buttonSeat.stack.toNumber()).to.equal(380);
+    expect(buttonSeat.betThisRound.toNumber()).to.equal(0);
+    expect(buttonSeat.totalBetThisHand.toNumber()).to.equal(20);
+    expect((await fetchSeat(t, sb)).stack.toNumber()).to.equal(390);
+    expect((await fetchSeat(t, bb)).stack.toNumber()).to.equal(380);
+
+    await foldToBigBlind(t);
+  });
+
+  it("emits BettingRoundComplete when the preflop round closes", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    // Heads-up, the button posts the small blind and acts first preflop.
+    await startHandWithButton(t, 0);
+
+    await act(t, 0, { call: {} });
+    // The big blind still has the option; their check closes the round.
+    expect((await program.account.table.fetch(t.table)).handPhase).to.deep.equal({ betting: {} });
+    const roundComplete = awaitEvent("bettingRoundComplete");
+    await act(t, 1, { check: {} });
+    const event = await roundComplete;
+    expect(event.tableId.eq(t.tableId)).to.be.true;
+    expect(event.bettingRound).to.deep.equal({ preFlop: {} });
+    expect(event.pot.toNumber()).to.equal(40);
+  });
+
+  it("refuses to deal or show down once everyone else folds after the flop", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    // Button on seat 0: seat 1 is the small blind, seat 2 the big blind.
+    await startHandWithButton(t, 0);
+    await act(t, 0, { call: {} });
+    await act(t, 1, { call: {} });
+    await act(t, 2, { check: {} });
+    await dealStreet(t);
+
+    await act(t, 1, { fold: {} });
+    await act(t, 2, { fold: {} });
+
+    await expectError(() => dealStreet(t), "HandUncontested");
+    await expectError(() => resolveTestShowdown(t), "HandUncontested");
+
+    // The hand is left for the last player standing.
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.gameState).to.deep.equal({ handComplete: {} });
+    expect(tableState.communityCards.filter((card) => card !== null)).to.have.length(3);
+    const active = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).isActiveInHand));
+    expect(active).to.deep.equal([true, false, false]);
+  });
+
+  it("labels each actor's position relative to the button in PlayerActed", async () => {
+    // Acts in turn and returns the position reported for each action.
+    const positionsOf = async (t: TestTable, steps: [number, any][]) => {
+      const positions = [];
+      for (const [seat, action] of steps) {
+        const acted = awaitEvent("playerActed");
+        await act(t, seat, action);
+        positions.push((await acted).position);
+      }
+      return positions;
+    };
+
+    // Heads-up: the button is the small blind.
+    const headsUp = await setupTable({ seats: [0, 1] });
+    await startHandWithButton(headsUp, 0);
+    expect(await positionsOf(headsUp, [[0, { call: {} }], [1, { check: {} }]])).to.deep.equal([
+      { smallBlind: {} },
+      { bigBlind: {} },
+    ]);
+    await dealStreet(headsUp);
+    expect(await positionsOf(headsUp, [[1, { check: {} }], [0, { check: {} }]])).to.deep.equal([
+      { bigBlind: {} },
+      { smallBlind: {} },
+    ]);
+
+    // Three-handed: button, small blind, big blind.
+    const threeHanded = await setupTable({ seats: [0, 1, 2] });
+    await startHandWithButton(threeHanded, 0);
+    expect(
+      await positionsOf(threeHanded, [[0, { call: {} }], [1, { call: {} }], [2, { check: {} }]])
+    ).to.deep.equal([{ button: {} }, { smallBlind: {} }, { bigBlind: {} }]);
+    await dealStreet(threeHanded);
+    expect(
+      await positionsOf(threeHanded, [[1, { check: {} }], [2, { check: {} }]])
+    ).to.deep.equal([{ smallBlind: {} }, { bigBlind: {} }]);
+
+    // Six-handed: UTG, middle position and the cutoff act before the button.
+    const sixHanded = await setupTable({ seats: [0, 1, 2, 3, 4, 5] });
+    await startHandWithButton(sixHanded, 0);
+    expect(
+      await positionsOf(sixHanded, [3, 4, 5, 0, 1].map((seat) => [seat, { fold: {} }]))
+    ).to.deep.equal([
+      { underTheGun: {} },
+      { middlePosition: {} },
+      { cutoff: {} },
+      { button: {} },
+      { smallBlind: {} },
+    ]);
+  });
+
+  it("reveals the turn and river in one call when the players are all-in on the flop", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    // Heads-up with the button on seat 0: the small blind calls, then both shove on the flop.
+    await startHandWithButton(t, 0);
+    await act(t, 0, { call: {} });
+    await act(t, 1, { check: {} });
+    await dealStreet(t);
+    await act(t, 1, { bet: { amount: new anchor.BN(380) } });
+    await act(t, 0, { call: {} });
+    expect((await program.account.table.fetch(t.table)).readyForRunout).to.be.true;
+
+    // The run-out is not dealt street by street.
+    await expectError(() => dealStreet(t), "InvalidGameState");
+
+    const dealt = awaitEvent("communityCardsDealt");
+    await revealRemainingBoard(t);
+    const event = await dealt;
+
+    // Four hole cards, then burn + flop (4..7), burn + turn (8, 9), burn + river (10, 11).
+    const card = (index: number) => ({ rank: index % 13, suit: Math.floor(index / 13) });
+    expect(event.cards).to.deep.equal([5, 6, 7, 9, 11].map(card));
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.communityCards).to.deep.equal(event.cards);
+    expect(tableState.bettingRound).to.deep.equal({ river: {} });
+
+    await resolveTestShowdown(t);
+  });
+
+  it("creates each hand's HandData at the PDA for the new hand id and records that id", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    for (let hand = 0; hand < 2; hand++) {
+      const started = awaitEvent("handStarted");
+      await startTestHand(t);
+      const { handIdCounter } = await program.account.table.fetch(t.table);
+      expect((await started).handId.eq(handIdCounter)).to.be.true;
+
+      const handData = await program.account.handData.fetch(handPdaFor(t.table, handIdCounter));
+      expect(handData.handId.eq(handIdCounter)).to.be.true;
+      expect(handData.tablePubkey.equals(t.table)).to.be.true;
+
+      await foldToBigBlind(t);
+    }
+  });
+
+  it("sits out a player who busts at a cash table until their seat is freed", async () => {
+    const t = await setupTable({ seats: [0, 1, 2], buyIns: { 0: 1000, 2: 1000 } });
+    await bustSeatOne(t);
+
+    const seat = await fetchSeat(t, 1);
+    expect(seat.stack.toNumber()).to.equal(0);
+    expect(seat.isSittingOut).to.be.true;
+    expect(seat.finishingPosition).to.be.null;
+    expect((await program.account.table.fetch(t.table)).occupiedSeats).to.equal(0b111);
+
+    await expectError(() => standUpBustedPlayer(t, 0), "PlayerNotBusted");
+    await standUpBustedPlayer(t, 1);
+    expect(await program.account.playerSeat.fetchNullable(seatPdaFor(t.table, 1))).to.be.null;
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.occupiedSeats).to.equal(0b101);
+    expect(tableState.playerCount).to.equal(2);
+  });
+
+  it("frees a busted player's seat and records their finish at a tournament table", async () => {
+    const t = await setupTable({ seats: [0, 1, 2], buyIns: { 0: 1000, 2: 1000 } });
+    await program.methods
+      .updateTableSettings(t.tableId, { ...defaultSettings(), isTournament: true })
+      .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+
+    const busted = awaitEvent("playerBusted");
+    await bustSeatOne(t);
+    const event = await busted;
+    expect(event.seatIndex).to.equal(1);
+    expect(event.finishingPosition).to.equal(3);
+
+    expect((await fetchSeat(t, 1)).finishingPosition).to.equal(3);
+    let tableState = await program.account.table.fetch(t.table);
+    expect(tableState.occupiedSeats).to.equal(0b101);
+    expect(tableState.playerCount).to.equal(2);
+
+    // Closing the finished seat reclaims its rent without freeing it twice.
+    await standUpBustedPlayer(t, 1);
+    tableState = await program.account.table.fetch(t.table);
+    expect(tableState.playerCount).to.equal(2);
+  });
+
+  it("still rakes a pot that reaches showdown when only showdown pots are raked", async () => {
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    await setRakeParams(config.rakeBps, config.rakeMaxCap, true);
+    try {
+      const t = await setupTable({ seats: [0, 1] });
+      await startHandWithButton(t, 0);
+      await act(t, 0, { call: {} });
+
+      const treasuryBefore = (await getAccount(provider.connection, treasuryVault)).amount;
+      const resolved = awaitEvent("handResolved");
+      await runOutHand(t);
+      const event = await resolved;
+
+      let rake = Math.floor((40 * config.rakeBps) / 10000);
+      if (config.rakeMaxCap.toNumber() > 0) rake = Math.min(rake, config.rakeMaxCap.toNumber());
+      expect(event.rake.toNumber()).to.equal(rake);
+      const treasuryAfter = (await getAccount(provider.connection, treasuryVault)).amount;
+      expect(treasuryAfter - treasuryBefore).to.equal(BigInt(rake));
+    } finally {
+      await setRakeParams(config.rakeBps, config.rakeMaxCap, false);
+    }
+  });
+
+  it("verifies a deck ordering against its shuffle commitment and rejects a tampered one", async () => {
+    const ordering = Array.from({ length: 52 }, (_, i) => (i * 19 + 7) % 52);
+    const salt = Array.from(randomBytes(32));
+    const commitment = Array.from(
+      createHash("sha256").update(Buffer.from(ordering)).update(Buffer.from(salt)).digest()
+    );
+    const verify = async (deck: number[]) => {
+      const verified = awaitEvent("shuffleCommitmentVerified");
+      await program.methods
+        .verifyShuffleCommitment(deck, salt, commitment)
+        .accounts({ payer: playerWallets[0].publicKey })
+        .signers([playerWallets[0]])
+        .rpc();
+      return (await verified).valid;
+    };
+
+    expect(await verify(ordering)).to.equal(true);
+
+    // Swapping two cards changes the ordering, so the commitment no longer opens.
+    const tampered = [...ordering];
+    [tampered[0], tampered[1]] = [tampered[1], tampered[0]];
+    expect(await verify(tampered)).to.equal(false);
+  });
+
+  it("verifies the hands shown at a showdown against the dealt deck and flags a tampered card", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    await startTestHand(t);
+    const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+    await runOutHand(t);
+
+    // The simulated deck is in order under an all-zero salt. Dealing round-robin to three
+    // players, the seat ranked r holds deck positions r and 3 + r.
+    const ordering = Array.from({ length: 52 }, (_, i) => i);
+    const salt = new Array(32).fill(0);
+    const shownCards = [[0, 3], [1, 4], [2, 5], [0, 0], [0, 0], [0, 0]];
+    const verify = async (cards: number[][], deck = ordering) => {
+      const verified = awaitEvent("shownHandsVerified");
+      await program.methods
+        .verifyShownHands(t.tableId, handId, deck, salt, 0b111, cards)
+        .accounts({
+          table: t.table,
+          handData: handPdaFor(t.table, handId),
+          payer: playerWallets[0].publicKey,
+        })
+        .signers([playerWallets[0]])
+        .rpc();
+      return (await verified).verified;
+    };
+
+    expect(await verify(shownCards)).to.deep.equal([true, true, true, true, true, true]);
+
+    // Seat 1 shows a second card it was not dealt.
+    const tampered = shownCards.map((cards) => [...cards]);
+    tampered[1][1] = 6;
+    expect(await verify(tampered)).to.deep.equal([true, false, true, true, true, true]);
+
+    // Only the ordering the hand committed to can be checked against.
+    const reordered = [...ordering];
+    [reordered[1], reordered[6]] = [reordered[6], reordered[1]];
+    await expectError(() => verify(tampered, reordered), "ShuffleCommitmentMismatch");
+  });
+
+  it("keeps identical all-ins and a matching call in a single pot", async () => {
+    const t = await setupTable({
+      seats: [0, 1, 2, 3],
+      smallBlind: 1,
+      bigBlind: 2,
+      buyIns: { 0: 100, 1: 100, 2: 100, 3: 400 },
+    });
+    // Button on seat 3, small blind on seat 0, big blind on seat 1, UTG on seat 2.
+    await startHandWithButton(t, 3);
+
+    // Three players end up all-in for exactly 100, and the deep seat calls 100.
+    await act(t, 2, { raise: { amount: new anchor.BN(100) } });
+    await act(t, 3, { call: {} });
+    await act(t, 0, { call: {} });
+    await act(t, 1, { call: {} });
+
+    for (const seat of [0, 1, 2]) {
+      const state = await fetchSeat(t, seat);
+      expect(state.isAllIn).to.be.true;
+      expect(state.totalBetThisHand.toNumber()).to.equal(100);
+    }
+    expect((await fetchSeat(t, 3)).isAllIn).to.be.false;
+
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.pots).to.have.length(1);
+    expect(tableState.pots[0].amount.toNumber()).to.equal(400);
+    expect(tableState.pots[0].eligibleSeats).to.equal(0b1111);
+    expect(tableState.pot.toNumber()).to.equal(400);
+  });
+
+  it("waits the table's minimum time between hands before starting the next", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const minSecondsBetweenHands = 3;
+    await program.methods
+      .updateTableSettings(t.tableId, { ...defaultSettings(), minSecondsBetweenHands })
+      .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+
+    await startTestHand(t);
+    await foldToBigBlind(t);
+    const completed = await program.account.table.fetch(t.table);
+    expect("handComplete" in completed.gameState).to.be.true;
+    expect(completed.lastHandCompletedAt.toNumber()).to.be.greaterThan(0);
+
+    await expectError(() => startTestHand(t), "HandStartTooSoon");
+
+    await sleep((minSecondsBetweenHands + 1) * 1000);
+    await startTestHand(t);
+    const started = await program.account.table.fetch(t.table);
+    expect("handInProgress" in started.gameState).to.be.true;
+  });
+
+  it("shows exactly one hole card after the hand is over", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startHandWithButton(t, 0);
+    // The button is seat 0 and both seats are dealt in, so seat 1 was dealt deck
+    // positions 1 and 3.
+    await expectError(() => showOneCard(t, 1, 1), "InvalidGameState");
+
+    await foldToBigBlind(t);
+    const shown = awaitEvent("singleCardShown");
+    await showOneCard(t, 1, 1);
+    const event = await shown;
+    expect(event.seatIndex).to.equal(1);
+    expect(event.cardIndex).to.equal(1);
+    expect(event.player.equals(playerWallets[1].publicKey)).to.be.true;
+
+    const seat = await fetchSeat(t, 1);
+    expect(seat.shownCard).to.deep.equal(event.card);
+    expect(seat.shownCard.rank + seat.shownCard.suit * 13).to.equal(3);
+
+    // The other card stays private: a player may only show one card per hand.
+    await expectError(() => showOneCard(t, 1, 0), "InvalidAction");
+    expect((await fetchSeat(t, 1)).shownCard).to.deep.equal(event.card);
+    expect((await fetchSeat(t, 0)).shownCard).to.be.null;
+
+    // The shown card is cleared when the next hand starts.
+    await startTestHand(t);
+    expect((await fetchSeat(t, 1)).shownCard).to.be.null;
+  });
+
+  it("requires the encrypted hand of every player still in the hand at showdown", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startHandWithButton(t, 0);
+    const handData = handPdaFor(t.table, (await program.account.table.fetch(t.table)).handIdCounter);
+    for (const s of t.seats) {
+      const encryptedHand = await program.account.encryptedHand.fetch(
+        encryptedHandPdaFor(handData, playerWallets[s].publicKey)
+      );
+      expect(encryptedHand.handPubkey.equals(handData)).to.be.true;
+      expect(encryptedHand.playerPubkey.equals(playerWallets[s].publicKey)).to.be.true;
+    }
+
+    await act(t, 0, { raise: { amount: new anchor.BN(400) } });
+    await act(t, 1, { call: {} });
+    await revealRemainingBoard(t);
+
+    await expectError(() => resolveTestShowdown(t, { omitEncryptedHands: true }), "MissingEncryptedHand");
+    await resolveTestShowdown(t);
+  });
+
+  it("returns the blinds and voids a heads-up hand only when both players agree to chop", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await program.methods
+      .updateTableSettings(t.tableId, { ...defaultSettings(), allowChopBlinds: true })
+      .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+    await startHandWithButton(t, 0);
+    const stacksBefore = await Promise.all(
+      t.seats.map(async (s) => {
+        const seat = await fetchSeat(t, s);
+        return seat.stack.add(seat.totalBetThisHand).toNumber();
+      })
+    );
+
+    // One player's signature, even given twice, is not an agreement.
+    await expectError(() => chopBlinds(t, 0, 0), "ChopNotAgreed");
+
+    const chopped = awaitEvent("blindsChopped");
+    await chopBlinds(t, 0, 1);
+    expect((await chopped).totalRefunded.toNumber()).to.equal(30);
+
+    for (const [i, s] of t.seats.entries()) {
+      const seat = await fetchSeat(t, s);
+      expect(seat.stack.toNumber()).to.equal(stacksBefore[i]);
+      expect(seat.totalBetThisHand.toNumber()).to.equal(0);
+    }
+    const tableState = await program.account.table.fetch(t.table);
+    expect("handComplete" in tableState.gameState).to.be.true;
+    expect(tableState.pot.toNumber()).to.equal(0);
+
+    // Once a player has acted, the blinds are in play and can no longer be chopped.
+    await startTestHand(t);
+    const { dealerPosition } = await program.account.table.fetch(t.table);
+    await act(t, dealerPosition, { raise: { amount: new anchor.BN(60) } });
+    await expectError(() => chopBlinds(t, 0, 1), "InvalidGameState");
+  });
+
+  it("moves through the hand phases and rejects instructions called out of phase", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const phase = async () => (await program.account.table.fetch(t.table)).handPhase;
+    expect(await phase()).to.deep.equal({ complete: {} });
+
+    // Preflop betting is open: nothing can be dealt or shown down yet.
+    await startHandWithButton(t, 0);
+    expect(await phase()).to.deep.equal({ betting: {} });
+    await expectError(() => dealStreet(t), "InvalidGameState");
+    await expectError(() => revealRemainingBoard(t), "InvalidGameState");
+    await expectError(() => resolveTestShowdown(t), "InvalidGameState");
+
+    // Once the round closes, nobody acts until the flop is revealed.
+    await act(t, 0, { call: {} });
+    await act(t, 1, { check: {} });
+    expect(await phase()).to.deep.equal({ awaitingReveal: {} });
+    const { turnPosition } = await program.account.table.fetch(t.table);
+    await expectError(() => act(t, turnPosition, { check: {} }), "InvalidGameState");
+    await expectError(() => resolveTestShowdown(t), "InvalidGameState");
+
+    await dealStreet(t);
+    expect(await phase()).to.deep.equal({ betting: {} });
+    for (let street = 0; street < 2; street++) {
+      await checkDownStreet(t);
+      await dealStreet(t);
+    }
+
+    // After the river betting, only the showdown remains.
+    await checkDownStreet(t);
+    expect(await phase()).to.deep.equal({ awaitingShowdown: {} });
+    await expectError(() => dealStreet(t), "InvalidGameState");
+    const state = await program.account.table.fetch(t.table);
+    await expectError(() => act(t, state.turnPosition, { check: {} }), "InvalidGameState");
+
+    await resolveTestShowdown(t);
+    expect(await phase()).to.deep.equal({ complete: {} });
+    await expectError(() => act(t, state.turnPosition, { check: {} }), "InvalidGameState");
+  });
+
+  it("should only create tables in whitelisted mints once the whitelist is set", async () => {
+    const otherMint = await createMint(provider.connection, owner, owner.publicKey, null, 6);
+    const creator = playerWallets[0];
+    const otherTokenAccount = await createAccount(provider.connection, owner, otherMint, creator.publicKey);
+    await mintTo(provider.connection, owner, otherMint, otherTokenAccount, owner, 1_000_000);
+
+    await addAllowedMint(tokenMint);
+    try {
+      // The whitelisted mint still works.
+      const t = await setupTable({ seats: [0, 1] });
+      expect((await program.account.table.fetch(t.table)).playerCount).to.equal(2);
+
+      // Any other mint is rejected.
+      const id = new anchor.BN(Math.floor(Math.random() * 1_000_000_000));
+      const table = tablePdaFor(id);
+      await expectError(
+        () =>
+          program.methods
+            .createTable(id, 0, new anchor.BN(10), new anchor.BN(20), new anchor.BN(400), tableName(""), new Array(32).fill(0), null)
+            .accounts({
+              table,
+              creator: creator.publicKey,
+              platformConfig: platformConfigPda,
+              tokenMint: otherMint,
+              creatorTokenAccount: otherTokenAccount,
+              tableVault: vaultPdaFor(table),
+              creatorSeat: seatPdaFor(table, 0),
+              creatorRakeback: PublicKey.findProgramAddressSync(
+                [Buffer.from("player_rakeback"), creator.publicKey.toBuffer(), otherMint.toBuffer()],
+                program.programId
+              )[0],
+              creatorLimits: limitsPdaFor(creator.publicKey),
+              creatorExclusion: exclusionPdaFor(creator.publicKey),
+              creatorActiveTables: activeTablesPdaFor(creator.publicKey),
+            })
+            .signers([creator])
+            .rpc(),
+        "InvalidTokenMint"
+      );
+    } finally {
+      // An empty whitelist allows any mint again for the remaining tests.
+      await removeAllowedMint(tokenMint);
+    }
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    expect(config.allowedMints).to.have.lengthOf(0);
+  });
+
+  it("should emit bet presets clamped to the minimum raise and the stack", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    await startHandWithButton(t, 0);
+
+    // The button opens: 30 in the pot and 20 to call, so a pot raise is to 20 + 50.
+    let presets = await betPresets(t);
+    expect(presets.seatIndex).to.equal(0);
+    expect(presets.thirdPot.toNumber()).to.equal(40); // 36, raised to the minimum raise
+    expect(presets.halfPot.toNumber()).to.equal(45);
+    expect(presets.threeQuarterPot.toNumber()).to.equal(57);
+    expect(presets.pot.toNumber()).to.equal(70);
+    expect(presets.allIn.toNumber()).to.equal(400);
+
+    await act(t, 0, { raise: { amount: new anchor.BN(100) } });
+    await act(t, 1, { call: {} });
+
+    // The big blind faces 80 more into a 220 pot; a pot raise would exceed the stack.
+    presets = await betPresets(t);
+    expect(presets.seatIndex).to.equal(2);
+    expect(presets.thirdPot.toNumber()).to.equal(200);
+    expect(presets.halfPot.toNumber()).to.equal(250);
+    expect(presets.threeQuarterPot.toNumber()).to.equal(325);
+    expect(presets.pot.toNumber()).to.equal(400);
+    expect(presets.allIn.toNumber()).to.equal(400);
+
+    await finishHandIfOpen(t);
+  });
+
+  it("rakes a pot whose product with the rake rate would overflow u64", async () => {
+    // 6e15 chips at 50% rake multiplies to 3e19, past u64::MAX (~1.8e19).
+    const buyIn = 3_000_000_000_000_000;
+    for (const s of [0, 1]) {
+      await mintTo(provider.connection, owner, tokenMint, playerTokenAccounts[s], owner, BigInt(buyIn));
+    }
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    await setRakeParams(5000, new anchor.BN(0), false);
+    try {
+      const t = await setupTable({
+        seats: [0, 1],
+        smallBlind: 50_000_000_000_000,
+        bigBlind: 100_000_000_000_000,
+        buyIn,
+      });
+      await startHandWithButton(t, 0);
+      await act(t, 0, { raise: { amount: new anchor.BN(buyIn.toString()) } });
+      await act(t, 1, { call: {} });
+
+      const resolved = awaitEvent("handResolved");
+      await runOutHand(t);
+      const event = await resolved;
+      expect(event.pot.toString()).to.equal("6000000000000000");
+      expect(event.rake.toString()).to.equal("3000000000000000");
+    } finally {
+      await setRakeParams(config.rakeBps, config.rakeMaxCap, config.rakeOnShowdownOnly);
+    }
+  });
+
+  it("should set table metadata at creation and let only the creator update it", async () => {
+    const description = Array.from(createHash("sha256").update("Friendly 10/20 game").digest());
+    const t = await setupTable({ seats: [0, 1], name: "Aces High", descriptionHash: description });
+    let table = await program.account.table.fetch(t.table);
+    expect(table.name).to.deep.equal(tableName("Aces High"));
+    expect(table.descriptionHash).to.deep.equal(description);
+
+    const setMetadata = (signer: Keypair, name: string, hash: number[]) =>
+      program.methods
+        .setTableMetadata(t.tableId, tableName(name), hash)
+        .accounts({ table: t.table, creator: signer.publicKey })
+        .signers([signer])
+        .rpc();
+
+    const newDescription = Array.from(createHash("sha256").update("Deep-stacked 10/20").digest());
+    await setMetadata(playerWallets[0], "Deep Stacks", newDescription);
+    table = await program.account.table.fetch(t.table);
+    expect(table.name).to.deep.equal(tableName("Deep Stacks"));
+    expect(table.descriptionHash).to.deep.equal(newDescription);
+
+    await expectError(() => setMetadata(playerWallets[1], "Hijacked", newDescription), "NotTableCreator");
+    table = await program.account.table.fetch(t.table);
+    expect(table.name).to.deep.equal(tableName("Deep Stacks"));
+  });
+
+  it("moves the button and blinds past a busted player sitting out", async () => {
+    const t = await setupTable({ seats: [0, 1, 2], buyIns: { 2: 800 } });
+
+    // Seat 1 shoves from the small blind and is called by a deeper big blind.
+    await startHandWithButton(t, 0);
+    await act(t, 0, { fold: {} });
+    await act(t, 1, { raise: { amount: new anchor.BN(400) } });
+    await act(t, 2, { call: {} });
+    await runOutHand(t);
+    expect((await fetchSeat(t, 1)).isSittingOut).to.be.true;
+
+    // The button would rotate onto seat 1; it skips to seat 2, which plays heads-up
+    // against seat 0 as button and small blind.
+    await startTestHand(t);
+    const table = await program.account.table.fetch(t.table);
+    expect(table.dealerPosition).to.equal(2);
+    expect(table.turnPosition).to.equal(2);
+    expect(table.pot.toNumber()).to.equal(30);
+    expect((await fetchSeat(t, 2)).betThisRound.toNumber()).to.equal(10);
+    expect((await fetchSeat(t, 0)).betThisRound.toNumber()).to.equal(20);
+    const busted = await fetchSeat(t, 1);
+    expect(busted.isActiveInHand).to.be.false;
+    expect(busted.isAllIn).to.be.false;
+  });
+
+  it("plays a scripted hand from the deal to showdown with the simulated deck", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    const treasuryBefore = (await getAccount(provider.connection, treasuryVault)).amount;
+
+    const resolved = awaitEvent("handResolved");
+    await playScriptedHand(t, 0, [
+      [[0, { raise: { amount: new anchor.BN(60) } }], [1, { call: {} }]],
+      [[1, { bet: { amount: new anchor.BN(40) } }], [0, { call: {} }]],
+      [[1, { check: {} }], [0, { check: {} }]],
+      [[1, { check: {} }], [0, { check: {} }]],
+    ]);
+    const event = await resolved;
+
+    // The simulated deck is in index order: four hole cards, then burn-and-deal.
+    const table = await program.account.table.fetch(t.table);
+    expect(table.communityCards.map((c) => c.suit * 13 + c.rank)).to.deep.equal([5, 6, 7, 9, 11]);
+    expect("handComplete" in table.gameState).to.be.true;
+
+    expect(event.pot.toNumber()).to.equal(200);
+    let rake = Math.floor((200 * config.rakeBps) / 10000);
+    if (config.rakeMaxCap.toNumber() > 0) rake = Math.min(rake, config.rakeMaxCap.toNumber());
+    expect(event.rake.toNumber()).to.equal(rake);
+    const treasuryAfter = (await getAccount(provider.connection, treasuryVault)).amount;
+    expect(treasuryAfter - treasuryBefore).to.equal(BigInt(rake));
+    for (const s of [0, 1]) {
+      expect((await fetchSeat(t, s)).totalBetThisHand.toNumber()).to.equal(100);
+    }
+  });
+
+  it("plays a scripted hand that ends on a preflop fold", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    const treasuryBefore = (await getAccount(provider.connection, treasuryVault)).amount;
+
+    await playScriptedHand(t, 0, [[[0, { fold: {} }], [1, { fold: {} }]]]);
+
+    const table = await program.account.table.fetch(t.table);
+    expect("handComplete" in table.gameState).to.be.true;
+    expect(table.pot.toNumber()).to.equal(0);
+    expect(table.communityCards.every((c) => c === null)).to.be.true;
+    expect((await fetchSeat(t, 0)).stack.toNumber()).to.equal(400);
+    expect((await fetchSeat(t, 1)).stack.toNumber()).to.equal(390);
+    // The big blind wins both blinds; a pot won before the flop is not raked.
+    expect((await fetchSeat(t, 2)).stack.toNumber()).to.equal(410);
+    const treasuryAfter = (await getAccount(provider.connection, treasuryVault)).amount;
+    expect(treasuryAfter).to.equal(treasuryBefore);
+  });
+
+  it("caps the number of tables a player can sit at concurrently", async () => {
+    const player = playerWallets[5];
+    const activeTables = async () =>
+      (await program.account.playerActiveTables.fetchNullable(activeTablesPdaFor(player.publicKey)))
+        ?.activeTables ?? 0;
+    const before = await activeTables();
+
+    await setMaxConcurrentTables(5, before + 1);
+    try {
+      const first = await setupTable({ seats: [0, 5] });
+      expect(await activeTables()).to.equal(before + 1);
+
+      const second = await setupTable({ seats: [0] });
+      await expectError(() => joinSeat(second, 5, 5), "TooManyConcurrentTables");
+
+      // Leaving a table frees up room under the cap.
+      await leaveWithSeat(first, 5, seatPdaFor(first.table, 5));
+      expect(await activeTables()).to.equal(before);
+      await joinSeat(second, 5, 5);
+      expect(await activeTables()).to.equal(before + 1);
+    } finally {
+      await setMaxConcurrentTables(5, 65535);
+    }
+  });
+
+  it("awards the blinds to the big blind when the heads-up small blind folds", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const treasuryBefore = (await getAccount(provider.connection, treasuryVault)).amount;
+
+    // Heads-up, the button (seat 0) posts the small blind and acts first.
+    await startHandWithButton(t, 0);
+    const awarded = awaitEvent("potAwarded");
+    await act(t, 0, { fold: {} });
+    const event = await awarded;
+    expect(event.tableId.eq(t.tableId)).to.be.true;
+    expect(event.seatIndex).to.equal(1);
+    expect(event.amount.toNumber()).to.equal(30);
+    expect(event.rake.toNumber()).to.equal(0);
+
+    const table = await program.account.table.fetch(t.table);
+    expect(table.gameState).to.deep.equal({ handComplete: {} });
+    expect(table.pot.toNumber()).to.equal(0);
+    expect((await fetchSeat(t, 0)).stack.toNumber()).to.equal(390);
+    expect((await fetchSeat(t, 1)).stack.toNumber()).to.equal(410);
+    const treasuryAfter = (await getAccount(provider.connection, treasuryVault)).amount;
+    expect(treasuryAfter).to.equal(treasuryBefore);
+  });
+
+  it("posts a blind larger than the stack as an all-in for what is left", async () => {
+    const t = await setupTable({ seats: [0, 1], smallBlind: 10, bigBlind: 20, buyIns: { 0: 1000 } });
+
+    // Hand 1 leaves seat 1 with 5 chips, less than the small blind.
+    await startHandWithButton(t, 0);
+    await act(t, 0, { raise: { amount: new anchor.BN(395) } });
+    await act(t, 1, { call: {} });
+    await dealStreet(t);
+    await act(t, 1, { check: {} });
+    await act(t, 0, { bet: { amount: new anchor.BN(20) } });
+    await act(t, 1, { fold: {} });
+    expect((await fetchSeat(t, 1)).stack.toNumber()).to.equal(5);
+
+    // Hand 2: the button moves to seat 1, which owes the 10-chip small blind.
+    await startTestHand(t);
+    const sb = await fetchSeat(t, 1);
+    expect(sb.stack.toNumber()).to.equal(0);
+    expect(sb.isAllIn).to.be.true;
+    expect(sb.betThisRound.toNumber()).to.equal(5);
+    expect(sb.totalBetThisHand.toNumber()).to.equal(5);
+    expect((await program.account.table.fetch(t.table)).pot.toNumber()).to.equal(25);
+  });
+
+  it("rejects a callback for a different table, hand or computation", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const other = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+    await startTestHand(other);
+    const handData = handPdaFor(t.table, (await program.account.table.fetch(t.table)).handIdCounter);
+
+    const offset = new anchor.BN(7);
+    await program.methods
+      .shuffleAndDeal(offset)
+      .accounts({
+        payer: owner.publicKey,
+        computationAccount: Keypair.generate().publicKey,
+        systemProgram: SystemProgram.programId,
+        handData,
+      })
+      .signers([owner])
+      .rpc();
+    const callback = (table: PublicKey, computationOffset: anchor.BN) =>
+      program.methods
+        .shuffleAndDealCallback(computationOffset)
+        .accounts({ handData, table })
+        .rpc();
+
+    await expectError(() => callback(other.table, offset), "ComputationMismatch");
+    await expectError(() => callback(t.table, new anchor.BN(8)), "ComputationMismatch");
+    await callback(t.table, offset);
+    expect((await program.account.handData.fetch(handData)).pendingComputationOffset).to.be.null;
+    // A callback can only land once.
+    await expectError(() => callback(t.table, offset), "ComputationMismatch");
+  });
+
+  it("sits a player out for exactly one blind when they ask to skip it", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    await startHandWithButton(t, 0);
+    await foldToBigBlind(t);
+
+    // The button moves to seat 1 next, putting seat 2 in the small blind.
+    await program.methods
+      .setSitOutNextBlind(t.tableId, true)
+      .accounts({ table: t.table, player: playerWallets[2].publicKey, playerSeat: seatPdaFor(t.table, 2) })
+      .signers([playerWallets[2]])
+      .rpc();
+    const satOut = awaitEvent("playerSatOut");
+    await startTestHand(t);
+    expect((await satOut).seatIndex).to.equal(2);
+
+    const skipped = await fetchSeat(t, 2);
+    expect(skipped.isActiveInHand).to.be.false;
+    expect(skipped.betThisRound.toNumber()).to.equal(0);
+    expect(skipped.sitOutNextBlind).to.be.false;
+    expect((await program.account.table.fetch(t.table)).pot.toNumber()).to.equal(20);
+    await foldToBigBlind(t);
+
+    // The following hand deals seat 2 back in as the button.
+    await startTestHand(t);
+    expect((await program.account.table.fetch(t.table)).dealerPosition).to.equal(2);
+    expect((await fetchSeat(t, 2)).isActiveInHand).to.be.true;
+    await foldToBigBlind(t);
+  });
+
+  it("lets the table configure how long a hand must be stuck before a refund", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const setRefundTimeout = (refundTimeoutSeconds: number) =>
+      program.methods
+        .updateTableSettings(t.tableId, { ...defaultSettings(), refundTimeoutSeconds })
+        .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+        .signers([playerWallets[0]])
+        .rpc();
+
+    await expectError(() => setRefundTimeout(59), "InvalidRefundTimeout");
+    await expectError(() => setRefundTimeout(30 * 24 * 60 * 60 + 1), "InvalidRefundTimeout");
+    await setRefundTimeout(60);
+
+    await startTestHand(t);
+    await expectError(() => forceHandRefund(t), "HandNotStuck");
+    // The refund opens after the table's 60 seconds rather than the 5 minute default.
+    await sleep(61 * 1000);
+    await forceHandRefund(t);
+    expect((await program.account.table.fetch(t.table)).gameState).to.deep.equal({ handComplete: {} });
+  });
+
+  it("reports whose turn it is only while a betting round is open", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    const turns = async () =>
+      Promise.all(t.seats.map(async (s) => (await checkPlayersTurn(t, s)).isPlayersTurn));
+
+    expect(await turns()).to.deep.equal([false, false, false]);
+
+    // Button on seat 0: three-handed, the button acts first preflop.
+    await startHandWithButton(t, 0);
+    expect(await turns()).to.deep.equal([true, false, false]);
+    const outsider = await checkPlayersTurn(t, 5);
+    expect(outsider.seatIndex).to.be.null;
+    expect(outsider.isPlayersTurn).to.be.false;
+
+    await act(t, 0, { call: {} });
+    expect(await turns()).to.deep.equal([false, true, false]);
+    await act(t, 1, { call: {} });
+    await act(t, 2, { check: {} });
+
+    // Between streets nobody is to act, whatever `turn_position` says.
+    expect(await turns()).to.deep.equal([false, false, false]);
+    await dealStreet(t);
+    const sb = await checkPlayersTurn(t, 1);
+    expect(sb.seatIndex).to.equal(1);
+    expect(sb.isPlayersTurn).to.be.true;
+  });
+
+  it("clears every seat's betting state from the last hand when a new hand starts", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    // Button on seat 0: seat 1 posts the small blind, seat 2 the big blind.
+    await startHandWithButton(t, 0);
+    await act(t, 0, { raise: { amount: new anchor.BN(60) } });
+    await act(t, 1, { call: {} });
+    await act(t, 2, { call: {} });
+    await dealStreet(t);
+    await act(t, 1, { bet: { amount: new anchor.BN(40) } });
+    await act(t, 2, { fold: {} });
+    await act(t, 0, { fold: {} });
+    expect((await fetchSeat(t, 1)).betThisRound.toNumber()).to.equal(40);
+
+    // Hand 2: seat 2 posts the small blind, seat 0 the big blind and seat 1 nothing.
+    await startTestHand(t);
+    const expected = { 0: 20, 1: 0, 2: 10 };
+    for (const s of t.seats) {
+      const seat = await fetchSeat(t, s);
+      expect(seat.isActiveInHand).to.be.true;
+      expect(seat.isAllIn).to.be.false;
+      expect(seat.betThisRound.toNumber()).to.equal(expected[s]);
+      expect(seat.totalBetThisHand.toNumber()).to.equal(expected[s]);
+      expect(seat.pendingAction).to.be.null;
+    }
+    expect((await program.account.table.fetch(t.table)).pot.toNumber()).to.equal(30);
+  });
+
+  it("reopens the betting when an all-in is exactly a full raise", async () => {
+    const t = await setupTable({ seats: [0, 1, 2], buyIns: { 0: 1000, 2: 1000 } });
+    // Button on seat 0: seat 1 is the small blind and first to act after the flop.
+    await startHandWithButton(t, 0);
+    await act(t, 0, { call: {} });
+    await act(t, 1, { call: {} });
+    await act(t, 2, { check: {} });
+    await dealStreet(t);
+
+    // Seat 1's 380 chips raise the 190 bet by exactly 190, a full raise.
+    await act(t, 1, { check: {} });
+    await act(t, 2, { bet: { amount: new anchor.BN(190) } });
+    await act(t, 0, { call: {} });
+    await act(t, 1, { raise: { amount: new anchor.BN(380) } });
+    const table = await program.account.table.fetch(t.table);
+    expect(table.lastAggressorPosition).to.equal(1);
+    expect(table.lastRaiseSize.toNumber()).to.equal(190);
+    expect(table.turnPosition).to.equal(2);
+
+    // The bettor, who already acted, may raise again.
+    await act(t, 2, { raise: { amount: new anchor.BN(570) } });
+    expect((await fetchSeat(t, 2)).betThisRound.toNumber()).to.equal(570);
+  });
+
+  it("does not reopen the betting for an all-in short of a full raise", async () => {
+    const t = await setupTable({ seats: [0, 1, 2], buyIns: { 0: 1000, 2: 1000 } });
+    await startHandWithButton(t, 0);
+    await act(t, 0, { call: {} });
+    await act(t, 1, { call: {} });
+    await act(t, 2, { check: {} });
+    await dealStreet(t);
+
+    // Seat 1's 380 chips raise the 200 bet by only 180.
+    await act(t, 1, { check: {} });
+    await act(t, 2, { bet: { amount: new anchor.BN(200) } });
+    await act(t, 0, { call: {} });
+    await act(t, 1, { raise: { amount: new anchor.BN(380) } });
+    let table = await program.account.table.fetch(t.table);
+    expect(table.currentBet.toNumber()).to.equal(380);
+    expect(table.lastRaiseSize.toNumber()).to.equal(200);
+
+    // Both players who already acted may only call or fold.
+    await expectError(() => act(t, 2, { raise: { amount: new anchor.BN(800) } }), "RaiseNotReopened");
+    await act(t, 2, { call: {} });
+    await expectError(() => act(t, 0, { raise: { amount: new anchor.BN(800) } }), "RaiseNotReopened");
+    await act(t, 0, { call: {} });
+
+    // The round closes once the action is back behind the all-in raiser.
+    table = await program.account.table.fetch(t.table);
+    expect(table.handPhase).to.deep.equal({ awaitingReveal: {} });
+  });
+
+  it("antes everyone into a bomb pot and opens betting on the flop", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    await expectError(() => startBombPot(t, 0), "BombPotsDisabled");
+    await program.methods
+      .updateTableSettings(t.tableId, { ...defaultSettings(), bombPotAnte: new anchor.BN(50) })
+      .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+    // Only the creator decides when a bomb pot is played.
+    await expectError(() => startBombPot(t, 1), "NotTableCreator");
+
+    const stacksBefore = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).stack.toNumber()));
+    await startBombPot(t, 0);
+
+    for (const [i, s] of t.seats.entries()) {
+      const seat = await fetchSeat(t, s);
+      expect(seat.stack.toNumber()).to.equal(stacksBefore[i] - 50);
+      expect(seat.totalBetThisHand.toNumber()).to.equal(50);
+      expect(seat.betThisRound.toNumber()).to.equal(0);
+    }
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.pot.toNumber()).to.equal(150);
+    expect(tableState.currentBet.toNumber()).to.equal(0);
+    expect(tableState.bettingRound).to.deep.equal({ flop: {} });
+    expect(tableState.handPhase).to.deep.equal({ betting: {} });
+    expect(tableState.communityCards.filter((card) => card !== null)).to.have.lengthOf(3);
+    // No blinds were posted: the first player left of the button opens the flop.
+    const first = (tableState.dealerPosition + 1) % 3;
+    expect(tableState.turnPosition).to.equal(first);
+
+    await act(t, first, { check: {} });
+    await act(t, (first + 1) % 3, { bet: { amount: new anchor.BN(100) } });
+    expect((await program.account.table.fetch(t.table)).currentBet.toNumber()).to.equal(100);
+    await finishHandIfOpen(t);
+  });
+
+  it("reveals a single card on the turn without writing the reveal's padding", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+    await checkDownStreet(t);
+    await dealStreet(t);
+    await checkDownStreet(t);
+
+    const dealt = awaitEvent("communityCardsDealt");
+    await dealStreet(t);
+    const event = await dealt;
+
+    // Four hole cards, burn + flop (4..7), then burn (8) and the one turn card (9).
+    const card = (index: number) => ({ rank: index % 13, suit: Math.floor(index / 13) });
+    expect(event.cards).to.deep.equal([card(5), card(6), card(7), card(9), null]);
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.communityCards).to.deep.equal(event.cards);
+    expect(tableState.bettingRound).to.deep.equal({ turn: {} });
+    await finishHandIfOpen(t);
+  });
+
+  it("keeps platform-wide table and hand counters", async () => {
+    const stats = async () => (await program.account.platformConfig.fetch(platformConfigPda)).stats;
+    const before = await stats();
+
+    const t = await setupTable({ seats: [0, 1] });
+    let after = await stats();
+    expect(after.totalTablesCreated.toNumber()).to.equal(before.totalTablesCreated.toNumber() + 1);
+    expect(after.activeTables.toNumber()).to.equal(before.activeTables.toNumber() + 1);
+    expect(after.totalHandsPlayed.toNumber()).to.equal(before.totalHandsPlayed.toNumber());
+
+    // One hand won by a fold, one at showdown.
+    await startTestHand(t);
+    const updated = awaitEvent("platformStatsUpdated");
+    await foldToBigBlind(t);
+    const event = await updated;
+    expect(event.totalHandsPlayed.toNumber()).to.equal(before.totalHandsPlayed.toNumber() + 1);
+    await startTestHand(t);
+    await runOutHand(t);
+    after = await stats();
+    expect(after.totalHandsPlayed.toNumber()).to.equal(before.totalHandsPlayed.toNumber() + 2);
+
+    // A refunded hand is not counted as played, and closing the table leaves it in
+    // the all-time total but not among the active tables.
+    await startTestHand(t);
+    await forceCloseTable(t, owner, true);
+    after = await stats();
+    expect(after.totalTablesCreated.toNumber()).to.equal(before.totalTablesCreated.toNumber() + 1);
+    expect(after.activeTables.toNumber()).to.equal(before.activeTables.toNumber());
+    expect(after.totalHandsPlayed.toNumber()).to.equal(before.totalHandsPlayed.toNumber() + 2);
+  });
+
+  it("splits a four-way preflop all-in into side pots and pays each to its winner", async () => {
+    // The simulated deck deals seat r positions r and r + 4, and the board J Q K of
+    // spades with two low hearts, so everyone has a spade flush and the higher seat
+    // holds the higher one. The shortest stack holds the best hand.
+    const stacks = { 0: 200, 1: 150, 2: 100, 3: 50 };
+    const t = await setupTable({ seats: [0, 1, 2, 3], smallBlind: 1, bigBlind: 2, buyIns: stacks });
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    const treasuryBefore = (await getAccount(provider.connection, treasuryVault)).amount;
+    await startTestHand(t);
+
+    // Everyone moves all-in as the action reaches them.
+    let state = await program.account.table.fetch(t.table);
+    while ("betting" in state.handPhase) {
+      const seat = await fetchSeat(t, state.turnPosition);
+      const allIn = seat.stack.add(seat.betThisRound);
+      await act(t, state.turnPosition, allIn.gt(state.currentBet) ? { raise: { amount: allIn } } : { call: {} });
+      state = await program.account.table.fetch(t.table);
+    }
+    expect(state.readyForRunout).to.be.true;
+    expect(state.pots.map((pot) => [pot.amount.toNumber(), pot.eligibleSeats])).to.deep.equal([
+      [200, 0b1111],
+      [150, 0b0111],
+      [100, 0b0011],
+      [50, 0b0001],
+    ]);
+
+    // One reveal runs out the whole board, then the showdown pays every pot.
+    await revealRemainingBoard(t);
+    const resolved = awaitEvent("handResolved");
+    await resolveTestShowdown(t);
+    const event = await resolved;
+    let rake = Math.floor((500 * config.rakeBps) / 10000);
+    if (config.rakeMaxCap.toNumber() > 0) rake = Math.min(rake, config.rakeMaxCap.toNumber());
+    expect(event.pot.toNumber()).to.equal(500);
+    expect(event.rake.toNumber()).to.equal(rake);
+
+    // Seat 3 wins the main pot (less the rake), seat 2 the first side pot, seat 1 the
+    // second, and seat 0 gets back the 50 nobody could call.
+    const finalStacks = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).stack.toNumber()));
+    expect(finalStacks).to.deep.equal([50, 100, 150, 200 - rake]);
+    expect(finalStacks.reduce((a, b) => a + b, 0) + rake).to.equal(500);
+    const treasuryAfter = (await getAccount(provider.connection, treasuryVault)).amount;
+    expect(treasuryAfter - treasuryBefore).to.equal(BigInt(rake));
+    const vault = await getAccount(provider.connection, t.vault);
+    expect(vault.amount).to.equal(BigInt(500 - rake));
+  });
+
+  it("rejects a wallet taking a second seat at the same table", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+
+    await expectError(() => joinSeat(t, 2, 1), "AlreadySeated");
+    await expectError(() => joinSeat(t, 3, 0), "AlreadySeated");
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.playerCount).to.equal(2);
+    expect(tableState.occupiedSeats).to.equal(0b11);
+
+    // A different wallet can still take the seat.
+    await joinSeat(t, 2, 2);
+    expect((await fetchSeat(t, 2)).playerPubkey.equals(playerWallets[2].publicKey)).to.be.true;
+  });
+
+  it("lets the creator move the button to an occupied seat between hands", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    const resetButton = (seat: number, wallet = 0) =>
+      program.methods
+        .resetButton(t.tableId, seat)
+        .accounts({ table: t.table, creator: playerWallets[wallet].publicKey })
+        .signers([playerWallets[wallet]])
+        .rpc();
+
+    await startHandWithButton(t, 0);
+    await expectError(() => resetButton(2), "InvalidGameState");
+    await foldToBigBlind(t);
+
+    await expectError(() => resetButton(4), "PlayerNotFound");
+    await expectError(() => resetButton(2, 1), "NotTableCreator");
+
+    const reset = awaitEvent("buttonReset");
+    await resetButton(2);
+    const event = await reset;
+    expect(event.previousPosition).to.equal(0);
+    expect(event.dealerPosition).to.equal(2);
+    expect((await program.account.table.fetch(t.table)).dealerPosition).to.equal(2);
+
+    // The next hand moves the button on from the reset seat.
+    await startTestHand(t);
+    expect((await program.account.table.fetch(t.table)).dealerPosition).to.equal(0);
+    await foldToBigBlind(t);
+  });
+
+  it("rejects a computation offset the hand has already used", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+    // The flop's reveal can only be queued once the preflop betting closes.
+    await checkDownStreet(t);
+    const handData = handPdaFor(t.table, (await program.account.table.fetch(t.table)).handIdCounter);
+    const accounts = {
+      payer: owner.publicKey,
+      computationAccount: Keypair.generate().publicKey,
+      systemProgram: SystemProgram.programId,
+      handData,
+    };
+    const queueShuffle = (offset: number) =>
+      program.methods.shuffleAndDeal(new anchor.BN(offset)).accounts(accounts).signers([owner]).rpc();
+    const queueReveal = (offset: number) =>
+      program.methods
+        .revealCommunityCards(new anchor.BN(offset), 3)
+        .accounts({ ...accounts, table: t.table })
+        .signers([owner])
+        .rpc();
+    const callback = (offset: number) =>
+      program.methods
+        .shuffleAndDealCallback(new anchor.BN(offset))
+        .accounts({ handData, table: t.table })
+        .rpc();
+
+    await queueShuffle(7);
+    // Nothing else can be queued while the first computation is pending.
+    await expectError(() => queueReveal(8), "InvalidGameState");
+    await callback(7);
+
+    // Once its callback lands, the offset still cannot be reused for this hand.
+    await expectError(() => queueReveal(7), "ComputationOffsetReused");
+    await queueReveal(8);
+    const state = await program.account.handData.fetch(handData);
+    expect(state.pendingComputationOffset.toNumber()).to.equal(8);
+    expect(state.computationsQueued).to.equal(2);
+  });
+
+  it("resolves a showdown with a seated player who was not dealt in and has no hand account", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    await startTestHand(t);
+    // Seat 4 sits down mid-hand, so no EncryptedHand is created for it.
+    await joinSeat(t, 4, 4);
+    const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+    const handData = handPdaFor(t.table, handId);
+    const missing = encryptedHandPdaFor(handData, playerWallets[4].publicKey);
+    expect(await provider.connection.getAccountInfo(missing)).to.be.null;
+
+    await runOutHand(t);
+    const tableState = await program.account.table.fetch(t.table);
+    expect(tableState.gameState).to.deep.equal({ handComplete: {} });
+    const newcomer = await fetchSeat(t, 4);
+    expect(newcomer.isActiveInHand).to.be.false;
+    expect(newcomer.stack.toNumber()).to.equal(tableState.bigBlind.muln(20).toNumber());
+  });
+
+  it("splits showdown rake between the treasury and the table's referrer", async () => {
+    const referrer = playerWallets[5].publicKey;
+    const referrerTokenAccount = playerTokenAccounts[5];
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    await setRakeParams(1000, new anchor.BN(0), false, 2500);
+    try {
+      const t = await setupTable({ seats: [0, 1], referrer });
+      expect((await program.account.table.fetch(t.table)).referrer.equals(referrer)).to.be.true;
+      await startHandWithButton(t, 0);
+      await act(t, 0, { raise: { amount: new anchor.BN(200) } });
+      await act(t, 1, { call: {} });
+      while (!("awaitingShowdown" in (await program.account.table.fetch(t.table)).handPhase)) {
+        const state = await program.account.table.fetch(t.table);
+        if ("betting" in state.handPhase) await checkDownStreet(t);
+        else await dealStreet(t);
+      }
+
+      // The referrer's share needs their token account for the table's currency.
+      await expectError(() => resolveTestShowdown(t), "InvalidReferrerAccount");
+      await expectError(
+        () => resolveTestShowdown(t, { referrerTokenAccount: playerTokenAccounts[4] }),
+        "InvalidReferrerAccount"
+      );
+
+      const treasuryBefore = (await getAccount(provider.connection, treasuryVault)).amount;
+      const referrerBefore = (await getAccount(provider.connection, referrerTokenAccount)).amount;
+      const paid = awaitEvent("referrerRakePaid");
+      const resolved = awaitEvent("handResolved");
+      await resolveTestShowdown(t, { referrerTokenAccount });
+      const event = await paid;
+      expect(event.referrer.equals(referrer)).to.be.true;
+      expect(event.amount.toNumber()).to.equal(10);
+      expect((await resolved).rake.toNumber()).to.equal(40);
+
+      // 10% of the 400-chip pot is 40 rake: a quarter to the referrer, the rest to the treasury.
+      const treasuryGain = (await getAccount(provider.connection, treasuryVault)).amount - treasuryBefore;
+      const referrerGain = (await getAccount(provider.connection, referrerTokenAccount)).amount - referrerBefore;
+      expect(referrerGain).to.equal(10n);
+      expect(treasuryGain).to.equal(30n);
+      expect(treasuryGain + referrerGain).to.equal(40n);
+    } finally {
+      await setRakeParams(
+        config.rakeBps,
+        config.rakeMaxCap,
+        config.rakeOnShowdownOnly,
+        config.referrerRakeBps,
+        config.rakeScope
+      );
+    }
+  });
+
+  it("closes a street that every player checks through", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    await startHandWithButton(t, 0);
+    await act(t, 0, { call: {} });
+    await act(t, 1, { call: {} });
+    await act(t, 2, { check: {} });
+
+    // Flop: everyone checks, starting left of the button.
+    await dealStreet(t);
+    let roundComplete = awaitEvent("bettingRoundComplete");
+    for (const seat of [1, 2, 0]) {
+      expect((await program.account.table.fetch(t.table)).turnPosition).to.equal(seat);
+      await act(t, seat, { check: {} });
+    }
+    let event = await roundComplete;
+    expect(event.bettingRound).to.deep.equal({ flop: {} });
+    expect((await program.account.table.fetch(t.table)).handPhase).to.deep.equal({ awaitingReveal: {} });
+
+    // Turn: the first player to act folds and the rest check; the street still closes.
+    await dealStreet(t);
+    expect((await program.account.table.fetch(t.table)).bettingRound).to.deep.equal({ turn: {} });
+    roundComplete = awaitEvent("bettingRoundComplete");
+    await act(t, 1, { fold: {} });
+    await act(t, 2, { check: {} });
+    await act(t, 0, { check: {} });
+    event = await roundComplete;
+    expect(event.bettingRound).to.deep.equal({ turn: {} });
+
+    await dealStreet(t);
+    expect((await program.account.table.fetch(t.table)).bettingRound).to.deep.equal({ river: {} });
+    await runOutHand(t);
+  });
+
+  it("keeps the table's seat-to-wallet map in step with joins and leaves", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    const seatOf = async (wallet: number) =>
+      (await program.account.table.fetch(t.table)).seatPubkeys.findIndex(
+        (seated) => seated !== null && seated.equals(playerWallets[wallet].publicKey)
+      );
+    const expectMapMatchesOccupancy = async () => {
+      const { seatPubkeys, occupiedSeats } = await program.account.table.fetch(t.table);
+      seatPubkeys.forEach((seated, s) => expect(seated !== null).to.equal((occupiedSeats & (1 << s)) !== 0));
+    };
+
+    await expectMapMatchesOccupancy();
+    expect(await seatOf(0)).to.equal(0);
+    expect(await seatOf(2)).to.equal(2);
+
+    await leaveWithSeat(t, 1, seatPdaFor(t.table, 1));
+    await expectMapMatchesOccupancy();
+    expect(await seatOf(1)).to.equal(-1);
+
+    await joinSeat(t, 4, 1);
+    await expectMapMatchesOccupancy();
+    expect(await seatOf(1)).to.equal(4);
+    expect((await fetchSeat(t, 4)).playerPubkey.equals(playerWallets[1].publicKey)).to.be.true;
+  });
+
+  it("lets the big blind close a fully limped pot with a check", async () => {
+    const t = await setupTable({ seats: [0, 1, 2] });
+    // Button on seat 0: seat 1 is the small blind, seat 2 the big blind.
+    await startHandWithButton(t, 0);
+    await act(t, 0, { call: {} });
+    await act(t, 1, { call: {} });
+
+    // Everyone has limped, but the big blind has not acted yet.
+    let state = await program.account.table.fetch(t.table);
+    expect(state.handPhase).to.deep.equal({ betting: {} });
+    expect(state.turnPosition).to.equal(2);
+
+    const roundComplete = awaitEvent("bettingRoundComplete");
+    await act(t, 2, { check: {} });
+    const event = await roundComplete;
+    expect(event.bettingRound).to.deep.equal({ preFlop: {} });
+    expect(event.pot.toNumber()).to.equal(60);
+
+    await dealStreet(t);
+    state = await program.account.table.fetch(t.table);
+    expect(state.bettingRound).to.deep.equal({ flop: {} });
+    expect(state.handPhase).to.deep.equal({ betting: {} });
+    await runOutHand(t);
+  });
+
+  it("only lets invited wallets join a private table", async () => {
+    const t = await setupTable({ seats: [0] });
+    const invite = (player: PublicKey, wallet = 0) =>
+      program.methods
+        .invitePlayer(t.tableId, player)
+        .accounts({ table: t.table, creator: playerWallets[wallet].publicKey })
+        .signers([playerWallets[wallet]])
+        .rpc();
+    const revoke = (player: PublicKey) =>
+      program.methods
+        .revokeInvite(t.tableId, player)
+        .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+        .signers([playerWallets[0]])
+        .rpc();
+
+    // A public table accepts anyone.
+    await joinSeat(t, 1, 1);
+
+    await program.methods
+      .updateTableSettings(t.tableId, { ...defaultSettings(), isPrivate: true })
+      .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+
+    await expectError(() => joinSeat(t, 2, 2), "NotInvited");
+    await expectError(() => invite(playerWallets[2].publicKey, 1), "NotTableCreator");
+
+    await invite(playerWallets[2].publicKey);
+    await invite(playerWallets[3].publicKey);
+    await expectError(() => invite(playerWallets[2].publicKey), "InvalidAction");
+    expect((await program.account.table.fetch(t.table)).accessList).to.have.length(2);
+
+    await joinSeat(t, 2, 2);
+    expect((await fetchSeat(t, 2)).playerPubkey.equals(playerWallets[2].publicKey)).to.be.true;
+
+    // A revoked invitation no longer admits the wallet.
+    await revoke(playerWallets[3].publicKey);
+    await expectError(() => revoke(playerWallets[3].publicKey), "NotInvited");
+    await expectError(() => joinSeat(t, 3, 3), "NotInvited");
+  });
+
+  it("rakes each side pot separately under the per-pot rake scope", async () => {
+    // Three dealt in, so seat 2 holds the best hand and seat 1 the next best. Seat 0's
+    // last 100 chips are uncalled and come back to them.
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    const playThreeWayAllIn = async () => {
+      const stacks = { 0: 300, 1: 200, 2: 100 };
+      const t = await setupTable({ seats: [0, 1, 2], smallBlind: 1, bigBlind: 2, buyIns: stacks });
+      await startTestHand(t);
+      let state = await program.account.table.fetch(t.table);
+      while ("betting" in state.handPhase) {
+        const seat = await fetchSeat(t, state.turnPosition);
+        const allIn = seat.stack.add(seat.betThisRound);
+        await act(t, state.turnPosition, allIn.gt(state.currentBet) ? { raise: { amount: allIn } } : { call: {} });
+        state = await program.account.table.fetch(t.table);
+      }
+      await revealRemainingBoard(t);
+      const resolved = awaitEvent("handResolved");
+      await resolveTestShowdown(t);
+      const rake = (await resolved).rake.toNumber();
+      const finalStacks = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).stack.toNumber()));
+      return { rake, finalStacks };
+    };
+
+    try {
+      // 10% capped at 25: the 600-chip pot is raked 25 once, all from the main pot.
+      await setRakeParams(1000, new anchor.BN(25), false, 0, { totalPot: {} });
+      const total = await playThreeWayAllIn();
+      expect(total.rake).to.equal(25);
+      expect(total.finalStacks).to.deep.equal([100, 200, 275]);
+
+      // Per pot, the 300-chip main pot is capped at 25, the 200-chip side pot pays 20,
+      // and the uncalled 100 is not raked.
+      await setRakeParams(1000, new anchor.BN(25), false, 0, { perPot: {} });
+      const perPot = await playThreeWayAllIn();
+      expect(perPot.rake).to.equal(45);
+      expect(perPot.finalStacks).to.deep.equal([100, 180, 275]);
+    } finally {
+      await setRakeParams(
+        config.rakeBps,
+        config.rakeMaxCap,
+        config.rakeOnShowdownOnly,
+        config.referrerRakeBps,
+        config.rakeScope
+      );
+    }
+  });
+
+  it("holds flop bets to the big blind and raises to the last raise size", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startHandWithButton(t, 0);
+    await act(t, 0, { call: {} });
+    await act(t, 1, { check: {} });
+    await dealStreet(t);
+
+    const first = (await program.account.table.fetch(t.table)).turnPosition;
+    const second = first === 0 ? 1 : 0;
+    await expectError(() => act(t, first, { bet: { amount: new anchor.BN(19) } }), "BetTooSmall");
+    await act(t, first, { bet: { amount: new anchor.BN(20) } });
+
+    // Each raise must add at least the previous bet or raise: 20 on top of 20, then 20 more.
+    await expectError(() => act(t, second, { raise: { amount: new anchor.BN(30) } }), "BetTooSmall");
+    await act(t, second, { raise: { amount: new anchor.BN(40) } });
+    await expectError(() => act(t, first, { raise: { amount: new anchor.BN(50) } }), "BetTooSmall");
+    await act(t, first, { raise: { amount: new anchor.BN(60) } });
+    const state = await program.account.table.fetch(t.table);
+    expect(state.currentBet.toNumber()).to.equal(60);
+    expect(state.lastRaiseSize.toNumber()).to.equal(20);
+
+    await act(t, second, { call: {} });
+    await finishHandIfOpen(t);
+  });
+
+  it("lets the admin sweep dust from an empty table's vault", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const sweep = (admin = owner) =>
+      program.methods
+        .sweepTableVaultDust(t.tableId)
+        .accounts({
+          platformConfig: platformConfigPda,
+          admin: admin.publicKey,
+          table: t.table,
+          tableVault: t.vault,
+          treasuryVault,
+        })
+        .signers([admin])
+        .rpc();
+
+    // Seated players' chips are never dust.
+    await expectError(() => sweep(), "TableNotEmpty");
+    await leaveWithSeat(t, 0, seatPdaFor(t.table, 0));
+    await leaveWithSeat(t, 1, seatPdaFor(t.table, 1));
+    expect((await getAccount(provider.connection, t.vault)).amount).to.equal(0n);
+    await expectError(() => sweep(), "InvalidAction");
+
+    // Anything under the 20-chip big blind is dust.
+    await mintTo(provider.connection, owner, tokenMint, t.vault, owner, 5);
+    await expectError(() => sweep(playerWallets[1]), "ConstraintAddress");
+    const treasuryBefore = (await getAccount(provider.connection, treasuryVault)).amount;
+    const swept = awaitEvent("vaultDustSwept");
+    await sweep();
+    expect((await swept).amount.toNumber()).to.equal(5);
+    expect((await getAccount(provider.connection, treasuryVault)).amount - treasuryBefore).to.equal(5n);
+    expect((await getAccount(provider.connection, t.vault)).amount).to.equal(0n);
+
+    // A big blind or more is refused.
+    await mintTo(provider.connection, owner, tokenMint, t.vault, owner, 20);
+    await expectError(() => sweep(), "NotVaultDust");
+    expect((await getAccount(provider.connection, t.vault)).amount).to.equal(20n);
+  });
+
+  it("pays the last player the pot when a timeout fold ends the hand", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    await startHandWithButton(t, 0);
+    await act(t, 0, { call: {} });
+    await act(t, 1, { check: {} });
+    await dealStreet(t);
+
+    // One player bets the flop and the other lets their timer run out.
+    const { turnPosition: bettor, turnDurationSeconds } = await program.account.table.fetch(t.table);
+    const idle = bettor === 0 ? 1 : 0;
+    await act(t, bettor, { bet: { amount: new anchor.BN(20) } });
+    await sleep((turnDurationSeconds + 2) * 1000);
+
+    const treasuryBefore = (await getAccount(provider.connection, treasuryVault)).amount;
+    const awarded = awaitEvent("potAwarded");
+    await forcePlayerFold(t, idle);
+    const event = await awarded;
+
+    // The 60-chip pot was won after the flop, so it is raked.
+    let rake = Math.floor((60 * config.rakeBps) / 10000);
+    if (config.rakeMaxCap.toNumber() > 0) rake = Math.min(rake, config.rakeMaxCap.toNumber());
+    expect(event.seatIndex).to.equal(bettor);
+    expect(event.amount.toNumber()).to.equal(60 - rake);
+    expect(event.rake.toNumber()).to.equal(rake);
+    expect((await fetchSeat(t, bettor)).stack.toNumber()).to.equal(400 - 40 + 60 - rake);
+    expect((await fetchSeat(t, idle)).stack.toNumber()).to.equal(400 - 20);
+    expect((await getAccount(provider.connection, treasuryVault)).amount - treasuryBefore).to.equal(BigInt(rake));
+
+    const state = await program.account.table.fetch(t.table);
+    expect(state.gameState).to.deep.equal({ handComplete: {} });
+    expect(state.pot.toNumber()).to.equal(0);
+  });
+
+  it("publishes every seat's evaluated hand and winnings at showdown", async () => {
+    // Three dealt in: the simulated deck gives seat r the cards at positions r and
+    // r + 3, and runs out 9 T J K of the first suit and a deuce of the second. Every
+    // player makes a king-high flush, seat 2's seven kicker the best of them. Empty
+    // seats report no hand.
+    const t = await setupTable({ seats: [0, 1, 2] });
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    await startHandWithButton(t, 0);
+    const shown = awaitEvent("showdownDetails");
+    await runOutHand(t);
+    const event = await shown;
+
+    let rake = Math.floor((60 * config.rakeBps) / 10000);
+    if (config.rakeMaxCap.toNumber() > 0) rake = Math.min(rake, config.rakeMaxCap.toNumber());
+    const details = event.details.map((d) => [d.handCategory, d.handRanks, d.amountWon.toNumber()]);
+    expect(details).to.deep.equal([
+      [6, [11, 9, 8, 7, 3], 0],
+      [6, [11, 9, 8, 7, 4], 0],
+      [6, [11, 9, 8, 7, 5], 60 - rake],
+      [0, [0, 0, 0, 0, 0], 0],
+      [0, [0, 0, 0, 0, 0], 0],
+      [0, [0, 0, 0, 0, 0], 0],
+    ]);
+  });
+
+  it("keeps a self-excluded player from joining until the exclusion lapses", async () => {
+    // Exclusions persist per wallet, so use a fresh player that no other test touches.
+    const wallet = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(wallet.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(sig, "confirmed");
+    const tokenAccount = await createAccount(provider.connection, owner, tokenMint, wallet.publicKey);
+    await mintTo(provider.connection, owner, tokenMint, tokenAccount, owner, 1_000_000);
+    const w = playerWallets.length;
+    playerWallets.push(wallet);
+    playerTokenAccounts.push(tokenAccount);
+
+    const exclusion = exclusionPdaFor(wallet.publicKey);
+    const excludeUntil = (until: number) =>
+      program.methods
+        .selfExclude(new anchor.BN(until))
+        .accounts({ player: wallet.publicKey, playerExclusion: exclusion })
+        .signers([wallet])
+        .rpc();
+
+    const now = await provider.connection.getBlockTime(await provider.connection.getSlot());
+    await excludeUntil(now + 10);
+    const t = await setupTable({ seats: [0] });
+    await expectError(() => joinSeat(t, 1, w), "SelfExcluded");
+
+    // The exclusion can be extended but not shortened.
+    await expectError(() => excludeUntil(now + 5), "ExclusionCannotBeShortened");
+    await excludeUntil(now + 12);
+    expect((await program.account.playerExclusion.fetch(exclusion)).excludedUntil.toNumber()).to.equal(now + 12);
+
+    // Once it lapses the player can sit down again.
+    await sleep(14 * 1000);
+    await joinSeat(t, 1, w);
+    expect((await fetchSeat(t, 1)).playerPubkey.equals(wallet.publicKey)).to.be.true;
+  });
+
+  it("splits a tied side pot of odd size with the odd chip to the button's left", async () => {
+    // Six dealt in: the simulated deck runs out a board of five low hearts that nobody
+    // can beat, so every pot is split among the players eligible for it.
+    const t = await setupTable({ seats: [0, 1, 2, 3, 4, 5], smallBlind: 1, bigBlind: 2, buyIn: 200, buyIns: { 3: 41 } });
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    await setRakeParams(0, new anchor.BN(0), config.rakeOnShowdownOnly, config.referrerRakeBps, config.rakeScope);
+    try {
+      await startHandWithButton(t, 0);
+      await act(t, 3, { raise: { amount: new anchor.BN(41) } });
+      await act(t, 4, { fold: {} });
+      await act(t, 5, { fold: {} });
+      await act(t, 0, { call: {} });
+      await act(t, 1, { call: {} });
+      await act(t, 2, { call: {} });
+      await dealStreet(t);
+
+      // Seat 2 puts 21 into the side pot before folding, leaving it at 105.
+      await act(t, 1, { bet: { amount: new anchor.BN(21) } });
+      await act(t, 2, { call: {} });
+      await act(t, 0, { raise: { amount: new anchor.BN(42) } });
+      await act(t, 1, { call: {} });
+      await act(t, 2, { fold: {} });
+      const state = await program.account.table.fetch(t.table);
+      expect(state.pots.map((pot) => [pot.amount.toNumber(), pot.eligibleSeats])).to.deep.equal([
+        [164, 0b1011],
+        [105, 0b0011],
+      ]);
+
+      const shown = awaitEvent("showdownDetails");
+      await runOutHand(t);
+      const won = (await shown).details.map((d) => d.amountWon.toNumber());
+
+      // The 164-chip main pot splits three ways, 54 each with the two odd chips to
+      // seats 1 and 3; the 105-chip side pot splits 52/52 with the odd chip to seat 1,
+      // the first of its winners left of the button.
+      expect(won).to.deep.equal([54 + 52, 55 + 53, 0, 55, 0, 0]);
+      expect(won.reduce((a, b) => a + b, 0)).to.equal(164 + 105);
+      const finalStacks = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).stack.toNumber()));
+      expect(finalStacks).to.deep.equal([223, 225, 138, 55, 200, 200]);
+    } finally {
+      await setRakeParams(
+        config.rakeBps,
+        config.rakeMaxCap,
+        config.rakeOnShowdownOnly,
+        config.referrerRakeBps,
+        config.rakeScope
+      );
+    }
+  });
+
+  it("rejects a second deal of the same street", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+    await checkDownStreet(t);
+
+    await dealStreet(t);
+    const flop = await program.account.table.fetch(t.table);
+    expect(flop.communityCards.filter((card) => card !== null)).to.have.length(3);
+    const handData = await program.account.handData.fetch(handPdaFor(t.table, flop.handIdCounter));
+    expect(handData.pendingReveal).to.be.false;
+
+    // A double-submitted deal finds the flop already out and leaves the board alone.
+    await expectError(() => dealStreet(t), "InvalidBettingRoundTransition");
+    const after = await program.account.table.fetch(t.table);
+    expect(after.communityCards).to.deep.equal(flop.communityCards);
+    expect(after.bettingRound).to.deep.equal({ flop: {} });
+    expect(after.handPhase).to.deep.equal({ betting: {} });
+
+    // Once the flop's betting closes, the turn deals as usual.
+    await checkDownStreet(t);
+    await dealStreet(t);
+    expect((await program.account.table.fetch(t.table)).communityCards[3]).to.not.be.null;
+  });
+
+  it("draws the board after the hole cards under either deal style", async () => {
+    const cardIndex = (card: { rank: number; suit: number }) => card.rank + card.suit * 13;
+
+    for (const dealStyle of [{ roundRobin: {} }, { sequential: {} }]) {
+      const t = await setupTable({ seats: [0, 1, 2] });
+      await program.methods
+        .updateTableSettings(t.tableId, { ...defaultSettings(), dealStyle })
+        .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+        .signers([playerWallets[0]])
+        .rpc();
+
+      await startTestHand(t);
+      const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+      const handData = await program.account.handData.fetch(handPdaFor(t.table, handId));
+      expect(handData.dealStyle).to.deep.equal(dealStyle);
+      expect(handData.cardsDealt).to.equal(6);
+      await runOutHand(t);
+
+      // Each seat's second card comes from the position its deal style assigns:
+      // 3 + r round-robin, 2r + 1 sequential.
+      const holeCards: number[] = [];
+      for (const [rank, seat] of [0, 1, 2].entries()) {
+        const shown = awaitEvent("singleCardShown");
+        await showOneCard(t, seat, 1);
+        const position = cardIndex((await shown).card);
+        expect(position).to.equal("sequential" in dealStyle ? rank * 2 + 1 : 3 + rank);
+        holeCards.push(position);
+      }
+
+      // Both styles deal from the first six positions, so the board starts after
+      // them: burn 6, flop 7-9, burn 10, turn 11, burn 12, river 13.
+      const board = (await program.account.table.fetch(t.table)).communityCards.map(cardIndex);
+      expect(board).to.deep.equal([7, 8, 9, 11, 13]);
+      expect(board.some((position) => holeCards.includes(position))).to.be.false;
+    }
+  });
+
+  it("lets only the admin rotate the treasury vault", async () => {
+    const newAuthority = Keypair.generate();
+    const newVault = await createAccount(
+      provider.connection,
+      owner,
+      tokenMint,
+      newAuthority.publicKey,
+      Keypair.generate()
+    );
+    const rotate = (
+      vault: PublicKey,
+      authority: Keypair,
+      opts: { admin?: Keypair; requiredMint?: PublicKey | null } = {}
+    ) => {
+      const admin = opts.admin ?? owner;
+      return program.methods
+        .setTreasuryVault(opts.requiredMint ?? null)
+        .accounts({
+          platformConfig: platformConfigPda,
+          admin: admin.publicKey,
+          newTreasuryVault: vault,
+          newTreasuryAuthority: authority.publicKey,
+        })
+        .signers([admin, authority])
+        .rpc();
+    };
+
+    await expectError(() => rotate(newVault, newAuthority, { admin: playerWallets[1] }), "ConstraintAddress");
+    // The new vault's owner must sign for it.
+    await expectError(() => rotate(newVault, playerWallets[1]), "Unauthorized");
+    await expectError(() => rotate(treasuryVault, owner), "InvalidAction");
+    const otherMint = await createMint(provider.connection, owner, owner.publicKey, null, 6);
+    await expectError(() => rotate(newVault, newAuthority, { requiredMint: otherMint }), "InvalidTokenMint");
+
+    const changed = awaitEvent("treasuryVaultChanged");
+    await rotate(newVault, newAuthority, { requiredMint: tokenMint });
+    const event = await changed;
+    expect(event.previousVault.equals(treasuryVault)).to.be.true;
+    expect(event.newVault.equals(newVault)).to.be.true;
+    expect(event.authority.equals(newAuthority.publicKey)).to.be.true;
+    const treasuryNow = async () => (await program.account.platformConfig.fetch(platformConfigPda)).treasuryVault;
+    expect((await treasuryNow()).equals(newVault)).to.be.true;
+
+    // Rotate back so the rest of the suite keeps paying rake to the original treasury.
+    await rotate(treasuryVault, owner);
+    expect((await treasuryNow()).equals(treasuryVault)).to.be.true;
+  });
+
+  it("pays showdown rake to the rotated treasury vault and rejects the old one", async () => {
+    const newAuthority = Keypair.generate();
+    const newVault = await createAccount(
+      provider.connection,
+      owner,
+      tokenMint,
+      newAuthority.publicKey,
+      Keypair.generate()
+    );
+    const rotate = (vault: PublicKey, authority: Keypair) =>
+      program.methods
+        .setTreasuryVault(null)
+        .accounts({
+          platformConfig: platformConfigPda,
+          admin: owner.publicKey,
+          newTreasuryVault: vault,
+          newTreasuryAuthority: authority.publicKey,
+        })
+        .signers([owner, authority])
+        .rpc();
+
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    await setRakeParams(1000, new anchor.BN(0), false, 0);
+    await rotate(newVault, newAuthority);
+    try {
+      const t = await setupTable({ seats: [0, 1] });
+      await startHandWithButton(t, 0);
+      let state = await program.account.table.fetch(t.table);
+      while (!("awaitingShowdown" in state.handPhase)) {
+        if ("betting" in state.handPhase) await checkDownStreet(t);
+        else await dealStreet(t);
+        state = await program.account.table.fetch(t.table);
+      }
+      const oldBefore = (await getAccount(provider.connection, treasuryVault)).amount;
+      const newBefore = (await getAccount(provider.connection, newVault)).amount;
+
+      // The rake can no longer be routed to the vault that was rotated out.
+      await expectError(() => resolveTestShowdown(t, { treasuryVault }), "ConstraintAddress");
+
+      // 10% of the 40-chip pot.
+      await resolveTestShowdown(t, { treasuryVault: newVault });
+      expect((await getAccount(provider.connection, newVault)).amount - newBefore).to.equal(4n);
+      expect((await getAccount(provider.connection, treasuryVault)).amount).to.equal(oldBefore);
+    } finally {
+      await rotate(treasuryVault, owner);
+      await setRakeParams(
+        config.rakeBps,
+        config.rakeMaxCap,
+        config.rakeOnShowdownOnly,
+        config.referrerRakeBps,
+        config.rakeScope
+      );
+    }
+  });
+
+  it("keeps a folded player's chips in the pot without letting them win it", async () => {
+    // Three dealt in: the simulated deck gives seat 2 the best hand, so its fold is
+    // what hands the pot to seat 1.
+    const t = await setupTable({ seats: [0, 1, 2] });
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    await setRakeParams(0, new anchor.BN(0), config.rakeOnShowdownOnly, config.referrerRakeBps, config.rakeScope);
+    try {
+      await startHandWithButton(t, 0);
+      await act(t, 0, { raise: { amount: new anchor.BN(60) } });
+      await act(t, 1, { call: {} });
+      await act(t, 2, { call: {} });
+      await dealStreet(t);
+
+      await act(t, 1, { bet: { amount: new anchor.BN(40) } });
+      await act(t, 2, { call: {} });
+      await act(t, 0, { raise: { amount: new anchor.BN(100) } });
+      await act(t, 1, { call: {} });
+      await act(t, 2, { fold: {} });
+
+      const folded = await fetchSeat(t, 2);
+      expect(folded.isActiveInHand).to.be.false;
+      expect(folded.totalBetThisHand.toNumber()).to.equal(100);
+      const state = await program.account.table.fetch(t.table);
+      expect(state.pot.toNumber()).to.equal(60 * 3 + 100 * 2 + 40);
+      expect(state.pots.map((pot) => [pot.amount.toNumber(), pot.eligibleSeats])).to.deep.equal([[420, 0b011]]);
+
+      const shown = awaitEvent("showdownDetails");
+      await runOutHand(t);
+      const won = (await shown).details.map((d) => d.amountWon.toNumber());
+      expect(won.slice(0, 3)).to.deep.equal([0, 420, 0]);
+      const finalStacks = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).stack.toNumber()));
+      expect(finalStacks).to.deep.equal([240, 660, 300]);
+    } finally {
+      await setRakeParams(
+        config.rakeBps,
+        config.rakeMaxCap,
+        config.rakeOnShowdownOnly,
+        config.referrerRakeBps,
+        config.rakeScope
+      );
+    }
+  });
+
+  it("encrypts each hand's hole cards under a fresh nonce", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const nonceFor = async (seat: number) => {
+      const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+      const encryptedHand = await program.account.encryptedHand.fetch(
+        encryptedHandPdaFor(handPdaFor(t.table, handId), playerWallets[seat].publicKey)
+      );
+      return encryptedHand.nonce.toString();
+    };
+
+    await startTestHand(t);
+    const first = await nonceFor(0);
+    await foldToBigBlind(t);
+    await startTestHand(t);
+    const second = await nonceFor(0);
+
+    expect(first).to.not.equal("0");
+    expect(second).to.not.equal(first);
+    // The seat keeps the player's recent nonces, newest first, to catch a repeat.
+    const recent = (await fetchSeat(t, 0)).recentHandNonces.map((nonce) => nonce.toString());
+    expect(recent.slice(0, 3)).to.deep.equal([second, first, "0"]);
+    expect(await nonceFor(1)).to.not.be.oneOf([first, second]);
+  });
+
+  it("pays a keeper who forces a timed-out player to fold", async () => {
+    const setKeeperReward = (bps: number, admin = owner) =>
+      program.methods
+        .updateKeeperReward(bps)
+        .accounts({ platformConfig: platformConfigPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+    await expectError(() => setKeeperReward(2500, playerWallets[1]), "ConstraintAddress");
+    // The reward is capped at half a big blind.
+    await expectError(() => setKeeperReward(5001), "InvalidAction");
+
+    const t = await setupTable({ seats: [0, 1] });
+    const keeper = 5;
+    await setKeeperReward(2500);
+    try {
+      // Heads-up, the button posts the small blind and acts first, then times out.
+      await startHandWithButton(t, 0);
+      const { turnDurationSeconds } = await program.account.table.fetch(t.table);
+      await sleep((turnDurationSeconds + 2) * 1000);
+
+      const keeperBefore = (await getAccount(provider.connection, playerTokenAccounts[keeper])).amount;
+      const rewarded = awaitEvent("keeperRewarded");
+      await forcePlayerFold(t, 0, keeper);
+      const event = await rewarded;
+
+      // A quarter of the 20-chip big blind comes out of the folded small blind.
+      expect(event.keeper.equals(playerWallets[keeper].publicKey)).to.be.true;
+      expect(event.amount.toNumber()).to.equal(5);
+      const keeperAfter = (await getAccount(provider.connection, playerTokenAccounts[keeper])).amount;
+      expect(keeperAfter - keeperBefore).to.equal(5n);
+
+      // The big blind still wins the rest of the pot, unraked before the flop.
+      expect((await fetchSeat(t, 0)).stack.toNumber()).to.equal(390);
+      expect((await fetchSeat(t, 1)).stack.toNumber()).to.equal(405);
+      expect((await getAccount(provider.connection, t.vault)).amount).to.equal(795n);
+    } finally {
+      await setKeeperReward(0);
+    }
+  });
+
+  it("rejects a second showdown while one is being evaluated", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+    let state = await program.account.table.fetch(t.table);
+    const handData = handPdaFor(t.table, state.handIdCounter);
+    const queueEvaluation = (offset: number) =>
+      program.methods
+        .evaluateHandsAndPayout(new anchor.BN(offset))
+        .accounts({
+          payer: owner.publicKey,
+          computationAccount: Keypair.generate().publicKey,
+          systemProgram: SystemProgram.programId,
+          table: t.table,
+          handData,
+        })
+        .signers([owner])
+        .rpc();
+
+    // Not before the river betting has closed.
+    await expectError(() => queueEvaluation(40), "InvalidGameState");
+    while (!("awaitingShowdown" in state.handPhase)) {
+      if ("betting" in state.handPhase) await checkDownStreet(t);
+      else await dealStreet(t);
+      state = await program.account.table.fetch(t.table);
+    }
+    await queueEvaluation(41);
+    expect((await program.account.handData.fetch(handData)).showdownInProgress).to.be.true;
+
+    // The queued evaluation will pay the pot, so nothing may pay it again meanwhile.
+    await expectError(() => resolveTestShowdown(t), "InvalidGameState");
+    await expectError(() => queueEvaluation(42), "InvalidGameState");
+
+    await program.methods
+      .evaluateHandsAndPayoutCallback(new anchor.BN(41))
+      .accounts({
+        handData,
+        table: t.table,
+        platformConfig: platformConfigPda,
+        tableVault: t.vault,
+        treasuryVault,
+      })
+      .rpc();
+    expect((await program.account.handData.fetch(handData)).showdownInProgress).to.be.false;
+
+    // The pot is paid exactly once, and the showdown releases the hand once it has.
+    await resolveTestShowdown(t);
+    expect((await program.account.handData.fetch(handData)).showdownInProgress).to.be.false;
+    await expectError(() => resolveTestShowdown(t), "InvalidGameState");
+    const stacks = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).stack.toNumber()));
+    const vault = (await getAccount(provider.connection, t.vault)).amount;
+    expect(BigInt(stacks.reduce((a, b) => a + b, 0))).to.equal(vault);
+  });
+
+  it("mucks a player who does not reveal in time and pays the player who did", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    await program.methods
+      .updateTableSettings(t.tableId, { ...defaultSettings(), showdownRevealTimeoutSeconds: 2 })
+      .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+    let handId: anchor.BN;
+    const revealHoleCards = (seat: number) =>
+      program.methods
+        .revealHoleCards(t.tableId)
+        .accounts({
+          table: t.table,
+          handData: handPdaFor(t.table, handId),
+          playerSeat: seatPdaFor(t.table, seat),
+          player: playerWallets[seat].publicKey,
+        })
+        .signers([playerWallets[seat]])
+        .rpc();
+
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    await setRakeParams(0, new anchor.BN(0), config.rakeOnShowdownOnly, config.referrerRakeBps, config.rakeScope);
+    try {
+      await startHandWithButton(t, 0);
+      let state = await program.account.table.fetch(t.table);
+      handId = state.handIdCounter;
+      // No revealing before the showdown.
+      await expectError(() => revealHoleCards(0), "InvalidGameState");
+      while (!("awaitingShowdown" in state.handPhase)) {
+        if ("betting" in state.handPhase) await checkDownStreet(t);
+        else await dealStreet(t);
+        state = await program.account.table.fetch(t.table);
+      }
+      const pot = state.pot.toNumber();
+      const before = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).stack.toNumber()));
+
+      const revealed = awaitEvent("holeCardsRevealed");
+      await revealHoleCards(0);
+      expect((await revealed).seatIndex).to.equal(0);
+      expect((await fetchSeat(t, 0)).showdownRevealedAt.toNumber()).to.be.greaterThan(0);
+      await expectError(() => revealHoleCards(0), "InvalidAction");
+
+      // Seat 1 still has time to reveal, so the showdown waits for it.
+      await expectError(() => resolveTestShowdown(t), "ShowdownRevealPending");
+      await sleep(3000);
+
+      const mucked = awaitEvent("handMucked");
+      await resolveTestShowdown(t);
+      expect((await mucked).seatIndex).to.equal(1);
+      const after = await Promise.all(t.seats.map(async (s) => (await fetchSeat(t, s)).stack.toNumber()));
+      // The hands would have tied; the player who stalled forfeits the whole pot instead.
+      expect(after).to.deep.equal([before[0] + pot, before[1]]);
+      expect((await fetchSeat(t, 1)).isActiveInHand).to.be.false;
+    } finally {
+      await setRakeParams(
+        config.rakeBps,
+        config.rakeMaxCap,
+        config.rakeOnShowdownOnly,
+        config.referrerRakeBps,
+        config.rakeScope
+      );
+    }
+  });
+
+  it("keeps the showdown reveal timeout shorter than the refund timeout", async () => {
+    const t = await setupTable({ seats: [0, 1] });
+    const update = (settings: object) =>
+      program.methods
+        .updateTableSettings(t.tableId, { ...defaultSettings(), ...settings })
+        .accounts({ table: t.table, creator: playerWallets[0].publicKey })
+        .signers([playerWallets[0]])
+        .rpc();
+    await expectError(() => update({ showdownRevealTimeoutSeconds: 300 }), "InvalidShowdownRevealTimeout");
+    await expectError(
+      () => update({ refundTimeoutSeconds: 120, showdownRevealTimeoutSeconds: 120 }),
+      "InvalidShowdownRevealTimeout"
+    );
+    await update({ refundTimeoutSeconds: 120, showdownRevealTimeoutSeconds: 119 });
+    expect((await program.account.table.fetch(t.table)).settings.showdownRevealTimeoutSeconds).to.equal(119);
+  });
+
+  it("emits a table snapshot that matches the table and seat accounts", async () => {
+    const t = await setupTable({ seats: [0, 2, 4] });
+    const snapshot = async (seats = seatMetas(t)) => {
+      const event = awaitEvent("tableSnapshot");
+      await program.methods
+        .snapshotTable(t.tableId)
+        .accounts({ table: t.table, payer: playerWallets[0].publicKey })
+        .remainingAccounts(seats)
+        .signers([playerWallets[0]])
+        .rpc();
+      return event;
+    };
+
+    await startHandWithButton(t, 0);
+    await act(t, 0, { raise: { amount: new anchor.BN(60) } });
+    await act(t, 2, { call: {} });
+    await act(t, 4, { call: {} });
+    await dealStreet(t);
+    await act(t, 2, { bet: { amount: new anchor.BN(40) } });
+
+    const event = await snapshot();
+    const table = await program.account.table.fetch(t.table);
+    expect(event.tableId.eq(t.tableId)).to.be.true;
+    expect(event.handId.eq(table.handIdCounter)).to.be.true;
+    expect(event.gameState).to.deep.equal(table.gameState);
+    expect(event.handPhase).to.deep.equal(table.handPhase);
+    expect(event.bettingRound).to.deep.equal(table.bettingRound);
+    expect(event.dealerPosition).to.equal(table.dealerPosition);
+    expect(event.turnPosition).to.equal(table.turnPosition);
+    expect(event.turnDeadline.eq(table.turnDeadline)).to.be.true;
+    expect(event.pot.toNumber()).to.equal(table.pot.toNumber());
+    expect(event.currentBet.toNumber()).to.equal(40);
+    expect(event.communityCards).to.deep.equal(table.communityCards);
+    expect(event.communityCards.filter((card) => card !== null)).to.have.length(3);
+
+    for (let s = 0; s < 6; s++) {
+      const info = event.seats[s];
+      if (!t.seats.includes(s)) {
+        expect(info).to.be.null;
+        continue;
+      }
+      const seat = await fetchSeat(t, s);
+      expect(info.pubkey.equals(seat.playerPubkey)).to.be.true;
+      expect(info.stack.toNumber()).to.equal(seat.stack.toNumber());
+      expect(info.isActiveInHand).to.equal(seat.isActiveInHand);
+      expect(info.isAllIn).to.equal(seat.isAllIn);
+      expect(info.betThisRound.toNumber()).to.equal(seat.betThisRound.toNumber());
+      expect(info.totalBetThisHand.toNumber()).to.equal(seat.totalBetThisHand.toNumber());
+    }
+    expect(event.seats[2].betThisRound.toNumber()).to.equal(40);
+
+    // A snapshot must cover every occupied seat.
+    await expectError(() => snapshot(seatMetas(t).slice(1)), "InvalidSeatAccounts");
+  });
+
+  it("treats going all-in for exactly the current bet as a call that reopens nothing", async () => {
+    // Button on seat 0: seat 1 posts the small blind, seat 2 the big blind, seat 3 is UTG.
+    const t = await setupTable({ seats: [0, 1, 2, 3], buyIns: { 1: 100, 2: 100 } });
+    await startHandWithButton(t, 0);
+    await act(t, 3, { raise: { amount: new anchor.BN(100) } });
+    await act(t, 0, { call: {} });
+
+    // The small blind's call covers exactly the bet with their last chip.
+    await act(t, 1, { call: {} });
+    const sb = await fetchSeat(t, 1);
+    expect(sb.isAllIn).to.be.true;
+    expect(sb.stack.toNumber()).to.equal(0);
+    expect(sb.betThisRound.toNumber()).to.equal(100);
+
+    // The big blind "raises" all-in to the same amount: that is a call as well.
+    const acted = awaitEvent("playerActed");
+    await act(t, 2, { raise: { amount: new anchor.BN(100) } });
+    expect((await acted).action).to.deep.equal({ call: {} });
+    const bb = await fetchSeat(t, 2);
+    expect(bb.isAllIn).to.be.true;
+    expect(bb.betThisRound.toNumber()).to.equal(100);
+
+    // Nobody raised after seat 3, so the action does not come back to it.
+    const state = await program.account.table.fetch(t.table);
+    expect(state.currentBet.toNumber()).to.equal(100);
+    expect(state.lastRaiseSize.toNumber()).to.equal(80);
+    expect(state.lastAggressorPosition).to.equal(3);
+    expect(state.handPhase).to.deep.equal({ awaitingReveal: {} });
+    expect(state.pot.toNumber()).to.equal(400);
+  });
+
+  it("only queues a community-card reveal for a table's current hand awaiting it", async () => {
+    const setRetryLimit = (limit: number, admin = owner) =>
+      program.methods
+        .updateRevealRetryLimit(limit)
+        .accounts({ platformConfig: platformConfigPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    await expectError(() => setRetryLimit(1, playerWallets[1]), "ConstraintAddress");
+    await expectError(() => setRetryLimit(5), "InvalidAction");
+    await setRetryLimit(1);
+    expect((await program.account.platformConfig.fetch(platformConfigPda)).maxRevealRetries).to.equal(1);
+    await setRetryLimit(config.maxRevealRetries);
+
+    const t = await setupTable({ seats: [0, 1] });
+    const other = await setupTable({ seats: [0, 1] });
+    await startTestHand(t);
+    await startTestHand(other);
+    const handData = handPdaFor(t.table, (await program.account.table.fetch(t.table)).handIdCounter);
+    const otherHandData = handPdaFor(other.table, (await program.account.table.fetch(other.table)).handIdCounter);
+    const queueReveal = (offset: number, table = t.table, hand = handData) =>
+      program.methods
+        .revealCommunityCards(new anchor.BN(offset), 3)
+        .accounts({
+          payer: owner.publicKey,
+          computationAccount: Keypair.generate().publicKey,
+          systemProgram: SystemProgram.programId,
+          table,
+          handData: hand,
+        })
+        .signers([owner])
+        .rpc();
+
+    // Not while the street's betting is still open.
+    await expectError(() => queueReveal(201), "InvalidGameState");
+    await checkDownStreet(t);
+    // Not for another table's hand.
+    await expectError(() => queueReveal(201, t.table, otherHandData), "ConstraintSeeds");
+
+    await queueReveal(201);
+    expect((await program.account.handData.fetch(handData)).pendingReveal).to.be.true;
+    await expectError(() => queueReveal(202), "InvalidBettingRoundTransition");
+
+    // The callback only lands a successful reveal; no caller can report a failure.
+    await program.methods
+      .revealCommunityCardsCallback(new anchor.BN(201))
+      .accounts({ handData, table: t.table, platformConfig: platformConfigPda })
+      .rpc();
+    const hand = await program.account.handData.fetch(handData);
+    expect(hand.pendingReveal).to.be.false;
+    expect(hand.pendingComputationOffset).to.be.null;
+    expect(hand.revealRetryCount).to.equal(0);
+    expect((await program.account.table.fetch(t.table)).gameState).to.deep.equal({ handInProgress: {} });
+  });
+
+  it("seats the creator at the seat they choose", async () => {
+    const creator = playerWallets[0];
+    const createAt = (id: anchor.BN, seatIndex: number) => {
+      const table = tablePdaFor(id);
+      return program.methods
+        .createTable(id, seatIndex, new anchor.BN(10), new anchor.BN(20), new anchor.BN(400), tableName(""), new Array(32).fill(0), null)
+        .accounts({
+          table,
+          creator: creator.publicKey,
+          platformConfig: platformConfigPda,
+          tokenMint,
+          creatorTokenAccount: playerTokenAccounts[0],
+          tableVault: vaultPdaFor(table),
+          creatorSeat: seatPdaFor(table, seatIndex),
+          creatorRakeback: rakebackPdaFor(creator.publicKey),
+          creatorLimits: limitsPdaFor(creator.publicKey),
+          creatorExclusion: exclusionPdaFor(creator.publicKey),
+          creatorActiveTables: activeTablesPdaFor(creator.publicKey),
+        })
+        .signers([creator])
+        .rpc();
+    };
+
+    // There is no seventh seat at a 6-max table.
+    await expectError(
+      () => createAt(new anchor.BN(Math.floor(Math.random() * 1_000_000_000)), 6),
+      "InvalidSeatIndex"
+    );
+
+    const id = new anchor.BN(Math.floor(Math.random() * 1_000_000_000));
+    const table = tablePdaFor(id);
+    await createAt(id, 3);
+    const state = await program.account.table.fetch(table);
+    expect(state.occupiedSeats).to.equal(1 << 3);
+    expect(state.playerCount).to.equal(1);
+    expect(state.seatPubkeys[3].equals(creator.publicKey)).to.be.true;
+    expect(state.seatPubkeys.filter((seated) => seated !== null)).to.have.length(1);
+    expect(state.firstHandDealt).to.be.false;
+
+    const seat = await program.account.playerSeat.fetch(seatPdaFor(table, 3));
+    expect(seat.seatIndex).to.equal(3);
+    expect(seat.playerPubkey.equals(creator.publicKey)).to.be.true;
+    expect(seat.stack.toNumber()).to.equal(400);
+    expect(await provider.connection.getAccountInfo(seatPdaFor(table, 0))).to.be.null;
+  });
+
   // --- Utility Functions ---
 
   /**
-   * Reads a keypair from a JSON file.
-   * @param path Path to the keypair file.
-   * @returns The loaded Keypair.
+   * Reads a keypair from a JSON file.
+   * @param path Path to the keypair file.
+   * @returns The loaded Keypair.
+   */
+  function readKpJson(path: string): anchor.web3.Keypair {
+    const file = fs.readFileSync(path);
+    return anchor.web3.Keypair.fromSecretKey(
+      new Uint8Array(JSON.parse(file.toString()))
+    );
+  }
+
+  /**
+   * Initializes a Computation Definition account for a given circuit.
+   * @param circuitName The name of the Arcis circuit.
+   * @param program The Anchor program instance.
+   * @param owner The keypair to pay for the transaction.
+   */
+  async function initCompDef(
+    circuitName: string,
+    program: Program<AcesUnknown>,
+    owner: Keypair
+  ): Promise<string> {
+    const baseSeedCompDefAcc = getArciumAccountBaseSeed(
+      "ComputationDefinitionAccount"
+    );
+    const offset = getCompDefAccOffset(circuitName);
+    const compDefPDA = PublicKey.findProgramAddressSync(
+      [baseSeedCompDefAcc, program.programId.toBuffer(), offset],
+      getArciumProgAddress()
+    )[0];
+
+    try {
+      await program.provider.connection.getAccountInfo(compDefPDA);
+      console.log(`CompDef for '${circuitName}' already initialized.`);
+      return "Already Initialized";
+    } catch (e) {
+      // Not initialized, proceed
+    }
+
+    const ixName = `initialize${circuitName.charAt(0).toUpperCase() + circuitName.slice(1)}CompDef`;
+    
+    // This is a dynamic way to call init instructions like 'initializeShuffleAndDealCompDef'
+    // It is not available in the IDL, so we have to build the transaction manually.
+    // As a simplification for this test, we will assume a simplified setup call.
+    // In a real project, one might write a script for this.
+    // For now, we'll skip the actual init call and assume it's handled by deployment scripts.
+    console.log(`Skipping on-chain init for '${circuitName}' CompDef in test.`);
+    return "Skipped in test";
+  }
+
+  /**
+   * Retries fetching the MXE public key until it's available.
+   * @param provider The Anchor provider.
+   * @param programId The program ID of the MXE.
+   * @param maxRetries Maximum number of retries.
+   * @param retryDelayMs Delay between retries.
+   * @returns The MXE public key.
+   */
+  async function getMXEPublicKeyWithRetry(
+    provider: anchor.AnchorProvider,
+    programId: PublicKey,
+    maxRetries: number = 10,
+    retryDelayMs: number = 500
+  ): Promise<Uint8Array> {
+    for (let attempt = 1; attempt <= maxRetries; attempt++) {
+      try {
+        const mxePublicKey = await getMXEPublicKey(provider, programId);
+        if (mxePublicKey) {
+          return mxePublicKey;
+        }
+      } catch (error) {
+        // Suppress error log for cleaner test output
+      }
+      if (attempt < maxRetries) {
+        await new Promise((resolve) => setTimeout(resolve, retryDelayMs));
+      }
+    }
+    throw new Error(
+      `Failed to fetch MXE public key after ${maxRetries} attempts`
+    );
+  }
+
+  // --- Gameplay Helpers ---
+
+  /** A table created for a single test, with the seats that were filled. */
+  interface TestTable {
+    tableId: anchor.BN;
+    table: PublicKey;
+    vault: PublicKey;
+    seats: number[];
+  }
+
+  const platformConfigPda = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_config")],
+    program.programId
+  )[0];
+
+  /** Encodes a table name as the fixed 32-byte, zero-padded on-chain field. */
+  function tableName(name: string): number[] {
+    const bytes = Buffer.alloc(32);
+    Buffer.from(name, "utf8").copy(bytes);
+    return Array.from(bytes);
+  }
+
+  function tablePdaFor(id: anchor.BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("table"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+  }
+
+  function vaultPdaFor(table: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), table.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  function seatPdaFor(table: PublicKey, seatIndex: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("player_seat"), table.toBuffer(), Buffer.from([seatIndex])],
+      program.programId
+    )[0];
+  }
+
+  function limitsPdaFor(player: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("player_limits"), player.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  function exclusionPdaFor(player: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("player_exclusion"), player.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  function activeTablesPdaFor(player: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("player_active_tables"), player.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  function reservationPdaFor(table: PublicKey, seatIndex: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("seat_reservation"), table.toBuffer(), Buffer.from([seatIndex])],
+      program.programId
+    )[0];
+  }
+
+  function handPdaFor(table: PublicKey, handId: anchor.BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("hand"), table.toBuffer(), handId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+  }
+
+  function encryptedHandPdaFor(handData: PublicKey, player: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("encrypted_hand"), handData.toBuffer(), player.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  /** The `EncryptedHand` PDAs of the given seats' players for the hand `handData`. */
+  function encryptedHandMetas(t: TestTable, handData: PublicKey, seats: number[] = t.seats) {
+    return seats.map((s) => ({
+      pubkey: encryptedHandPdaFor(handData, playerWallets[s].publicKey),
+      isWritable: true,
+      isSigner: false,
+    }));
+  }
+
+  function rakebackPdaFor(player: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("player_rakeback"), player.toBuffer(), tokenMint.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  /** Writable `remaining_accounts` metas for the given seats of a table. */
+  function seatMetas(t: TestTable, seats: number[] = t.seats) {
+    return seats.map((s) => ({
+      pubkey: seatPdaFor(t.table, s),
+      isWritable: true,
+      isSigner: false,
+    }));
+  }
+
+  /**
+   * Creates a fresh table and seats `playerWallets[s]` at each requested seat `s`.
+   * The creator, `playerWallets[0]`, is always seated at seat 0, so `seats` must
+   * include 0. `buyIns` overrides the default buy-in for individual seats.
+   */
+  async function setupTable(opts: {
+    seats: number[];
+    smallBlind?: number;
+    bigBlind?: number;
+    buyIn?: number;
+    buyIns?: Record<number, number>;
+    name?: string;
+    descriptionHash?: number[];
+    referrer?: PublicKey;
+  }): Promise<TestTable> {
+    const smallBlind = new anchor.BN(opts.smallBlind ?? 10);
+    const bigBlind = new anchor.BN(opts.bigBlind ?? 20);
+    const buyIn = new anchor.BN(opts.buyIn ?? bigBlind.toNumber() * 20);
+    const id = new anchor.BN(Math.floor(Math.random() * 1_000_000_000));
+    const table = tablePdaFor(id);
+    const vault = vaultPdaFor(table);
+    expect(opts.seats).to.include(0);
+
+    await program.methods
+      .createTable(
+        id,
+        0,
+        smallBlind,
+        bigBlind,
+        new anchor.BN(opts.buyIns?.[0] ?? buyIn.toNumber()),
+        tableName(opts.name ?? ""),
+        opts.descriptionHash ?? new Array(32).fill(0),
+        opts.referrer ?? null
+      )
+      .accounts({
+        table,
+        creator: playerWallets[0].publicKey,
+        platformConfig: platformConfigPda,
+        tokenMint,
+        creatorTokenAccount: playerTokenAccounts[0],
+        tableVault: vault,
+        creatorSeat: seatPdaFor(table, 0),
+        creatorRakeback: rakebackPdaFor(playerWallets[0].publicKey),
+        creatorLimits: limitsPdaFor(playerWallets[0].publicKey),
+        creatorExclusion: exclusionPdaFor(playerWallets[0].publicKey),
+        creatorActiveTables: activeTablesPdaFor(playerWallets[0].publicKey),
+      })
+      .signers([playerWallets[0]])
+      .rpc();
+
+    for (const seat of opts.seats.filter((s) => s !== 0)) {
+      await program.methods
+        .joinTable(id, seat, new anchor.BN(opts.buyIns?.[seat] ?? buyIn.toNumber()))
+        .accounts({
+          table,
+          player: playerWallets[seat].publicKey,
+          playerTokenAccount: playerTokenAccounts[seat],
+          tableVault: vault,
+          playerSeat: seatPdaFor(table, seat),
+          playerRakeback: rakebackPdaFor(playerWallets[seat].publicKey),
+          seatReservation: reservationPdaFor(table, seat),
+          playerLimits: limitsPdaFor(playerWallets[seat].publicKey),
+          playerExclusion: exclusionPdaFor(playerWallets[seat].publicKey),
+          playerActiveTables: activeTablesPdaFor(playerWallets[seat].publicKey),
+        })
+        .signers([playerWallets[seat]])
+        .rpc();
+    }
+
+    return { tableId: id, table, vault, seats: [...opts.seats] };
+  }
+
+  async function fetchSeat(t: TestTable, seat: number) {
+    return program.account.playerSeat.fetch(seatPdaFor(t.table, seat));
+  }
+
+  async function startTestHand(t: TestTable) {
+    const handId = (await program.account.table.fetch(t.table)).handIdCounter.addn(1);
+    const handData = handPdaFor(t.table, handId);
+    await program.methods
+      .startHand(t.tableId)
+      .accounts({
+        table: t.table,
+        payer: playerWallets[t.seats[0]].publicKey,
+        handData,
+      })
+      .remainingAccounts([...seatMetas(t), ...encryptedHandMetas(t, handData)])
+      .signers([playerWallets[t.seats[0]]])
+      .rpc();
+  }
+
+  async function startBombPot(t: TestTable, payerSeat: number) {
+    const handId = (await program.account.table.fetch(t.table)).handIdCounter.addn(1);
+    const handData = handPdaFor(t.table, handId);
+    await program.methods
+      .startBombPot(t.tableId)
+      .accounts({
+        table: t.table,
+        payer: playerWallets[payerSeat].publicKey,
+        handData,
+      })
+      .remainingAccounts([...seatMetas(t), ...encryptedHandMetas(t, handData)])
+      .signers([playerWallets[payerSeat]])
+      .rpc();
+  }
+
+  async function act(t: TestTable, seat: number, action: any) {
+    await program.methods
+      .playerAction(t.tableId, action)
+      .accounts({
+        table: t.table,
+        player: playerWallets[seat].publicKey,
+        playerSeat: seatPdaFor(t.table, seat),
+        platformConfig: platformConfigPda,
+        tableVault: t.vault,
+        treasuryVault,
+      })
+      .remainingAccounts(seatMetas(t, t.seats.filter((s) => s !== seat)))
+      .signers([playerWallets[seat]])
+      .rpc();
+  }
+
+  async function dealStreet(t: TestTable) {
+    const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+    await program.methods
+      .dealCommunityCards(t.tableId)
+      .accounts({
+        table: t.table,
+        handData: handPdaFor(t.table, handId),
+        payer: playerWallets[t.seats[0]].publicKey,
+      })
+      .remainingAccounts(seatMetas(t))
+      .signers([playerWallets[t.seats[0]]])
+      .rpc();
+  }
+
+  async function resolveTestShowdown(
+    t: TestTable,
+    opts: { omitEncryptedHands?: boolean; referrerTokenAccount?: PublicKey; treasuryVault?: PublicKey } = {}
+  ) {
+    const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+    const handData = handPdaFor(t.table, handId);
+    const inHand: number[] = [];
+    for (const s of t.seats) {
+      if ((await fetchSeat(t, s)).isActiveInHand) inHand.push(s);
+    }
+    await program.methods
+      .resolveShowdown(t.tableId)
+      .accounts({
+        table: t.table,
+        handData,
+        payer: playerWallets[t.seats[0]].publicKey,
+        tableVault: t.vault,
+        treasuryVault: opts.treasuryVault ?? treasuryVault,
+        referrerTokenAccount: opts.referrerTokenAccount ?? null,
+        platformConfig: platformConfigPda,
+      })
+      .remainingAccounts([
+        ...seatMetas(t),
+        ...(opts.omitEncryptedHands ? [] : encryptedHandMetas(t, handData, inHand)),
+        ...t.seats.map((s) => ({
+          pubkey: rakebackPdaFor(playerWallets[s].publicKey),
+          isWritable: true,
+          isSigner: false,
+        })),
+      ])
+      .signers([playerWallets[t.seats[0]]])
+      .rpc();
+  }
+
+  /** Deals any remaining streets, checking each one down, and resolves the showdown. */
+  async function runOutHand(t: TestTable) {
+    let state = await program.account.table.fetch(t.table);
+    while (!("awaitingShowdown" in state.handPhase)) {
+      if ("betting" in state.handPhase) {
+        await checkDownStreet(t);
+      } else if (state.readyForRunout) {
+        await revealRemainingBoard(t);
+      } else {
+        await dealStreet(t);
+      }
+      state = await program.account.table.fetch(t.table);
+    }
+    await resolveTestShowdown(t);
+  }
+
+  /** Checks (or calls any outstanding bet) in turn until the current betting round closes. */
+  async function checkDownStreet(t: TestTable) {
+    let state = await program.account.table.fetch(t.table);
+    while ("betting" in state.handPhase) {
+      const seat = await fetchSeat(t, state.turnPosition);
+      await act(t, state.turnPosition, seat.betThisRound.gte(state.currentBet) ? { check: {} } : { call: {} });
+      state = await program.account.table.fetch(t.table);
+    }
+  }
+
+  async function revealRemainingBoard(t: TestTable) {
+    const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+    await program.methods
+      .revealRemainingBoard(t.tableId)
+      .accounts({
+        table: t.table,
+        handData: handPdaFor(t.table, handId),
+        payer: playerWallets[t.seats[0]].publicKey,
+      })
+      .remainingAccounts(seatMetas(t))
+      .signers([playerWallets[t.seats[0]]])
+      .rpc();
+  }
+
+  /** Completes the current hand if folds alone did not end it. */
+  async function finishHandIfOpen(t: TestTable) {
+    const state = await program.account.table.fetch(t.table);
+    if ("handInProgress" in state.gameState) {
+      await runOutHand(t);
+    }
+  }
+
+  /** Expects `fn` to fail with the named program error. */
+  async function expectError(fn: () => Promise<unknown>, code: string) {
+    try {
+      await fn();
+    } catch (e: any) {
+      expect(e.error?.errorCode?.code ?? e.toString()).to.contain(code);
+      return;
+    }
+    expect.fail(`Expected ${code} error`);
+  }
+
+  /** The default `TableSettings` a new table is created with. */
+  function defaultSettings() {
+    return {
+      allowStraddle: false,
+      idlePauseSeconds: 0,
+      allowRabbitHunt: false,
+      shortBlindPolicy: { postAllIn: {} },
+      anteMode: { noAnte: {} },
+      anteAmount: new anchor.BN(0),
+      isTournament: false,
+      minSecondsBetweenHands: 0,
+      allowChopBlinds: false,
+      refundTimeoutSeconds: 0,
+      bombPotAnte: new anchor.BN(0),
+      isPrivate: false,
+      dealStyle: { roundRobin: {} },
+      showdownRevealTimeoutSeconds: 0,
+    };
+  }
+
+  async function postStraddle(t: TestTable, seat: number) {
+    await program.methods
+      .postStraddle(t.tableId)
+      .accounts({
+        table: t.table,
+        player: playerWallets[seat].publicKey,
+        playerSeat: seatPdaFor(t.table, seat),
+      })
+      .remainingAccounts(seatMetas(t, t.seats.filter((s) => s !== seat)))
+      .signers([playerWallets[seat]])
+      .rpc();
+  }
+
+  async function setPendingAction(t: TestTable, seat: number, pendingAction: any) {
+    await program.methods
+      .setPendingAction(t.tableId, pendingAction)
+      .accounts({
+        table: t.table,
+        player: playerWallets[seat].publicKey,
+        playerSeat: seatPdaFor(t.table, seat),
+      })
+      .signers([playerWallets[seat]])
+      .rpc();
+  }
+
+  /** Applies the pending action of `seat`, signed by the table creator as a keeper. */
+  async function applyPendingAction(t: TestTable, seat: number) {
+    await program.methods
+      .applyPendingAction(t.tableId)
+      .accounts({
+        table: t.table,
+        payer: playerWallets[0].publicKey,
+        playerSeat: seatPdaFor(t.table, seat),
+        platformConfig: platformConfigPda,
+        tableVault: t.vault,
+        treasuryVault,
+      })
+      .remainingAccounts(seatMetas(t, t.seats.filter((s) => s !== seat)))
+      .signers([playerWallets[0]])
+      .rpc();
+  }
+
+  async function setRakeback(rakebackBps: number) {
+    await program.methods
+      .updateRakebackConfig({ rakebackBps })
+      .accounts({ platformConfig: platformConfigPda, admin: owner.publicKey })
+      .signers([owner])
+      .rpc();
+  }
+
+  function sleep(ms: number) {
+    return new Promise((res) => setTimeout(res, ms));
+  }
+
+  /** Folds the timed-out player at `seat`, signed by the table creator as a keeper. */
+  /** Forces `seat` to fold, called by the keeper `playerWallets[keeper]`. */
+  async function forcePlayerFold(t: TestTable, seat: number, keeper = 0) {
+    await program.methods
+      .forcePlayerFold(t.tableId)
+      .accounts({
+        table: t.table,
+        payer: playerWallets[keeper].publicKey,
+        playerSeat: seatPdaFor(t.table, seat),
+        platformConfig: platformConfigPda,
+        tableVault: t.vault,
+        treasuryVault,
+        keeperTokenAccount: playerTokenAccounts[keeper],
+      })
+      .remainingAccounts(seatMetas(t, t.seats.filter((s) => s !== seat)))
+      .signers([playerWallets[keeper]])
+      .rpc();
+  }
+
+  async function forceHandRefund(t: TestTable, keeper = 0) {
+    await program.methods
+      .forceHandRefund(t.tableId)
+      .accounts({
+        table: t.table,
+        payer: playerWallets[keeper].publicKey,
+        platformConfig: platformConfigPda,
+        tableVault: t.vault,
+        keeperTokenAccount: playerTokenAccounts[keeper],
+      })
+      .remainingAccounts(seatMetas(t))
+      .signers([playerWallets[keeper]])
+      .rpc();
+  }
+
+  /** Folds every player in turn until the hand ends uncontested. */
+  async function foldToBigBlind(t: TestTable) {
+    let state = await program.account.table.fetch(t.table);
+    while ("handInProgress" in state.gameState) {
+      await act(t, state.turnPosition, { fold: {} });
+      state = await program.account.table.fetch(t.table);
+    }
+  }
+
+  /**
+   * Starts a hand with the button on `button`. The first button is drawn at
+   * random, so hands are folded out until it rotates onto the requested seat.
    */
-  function readKpJson(path: string): anchor.web3.Keypair {
-    const file = fs.readFileSync(path);
-    return anchor.web3.Keypair.fromSecretKey(
-      new Uint8Array(JSON.parse(file.toString()))
-    );
+  async function startHandWithButton(t: TestTable, button: number) {
+    for (let i = 0; i < t.seats.length; i++) {
+      await startTestHand(t);
+      if ((await program.account.table.fetch(t.table)).dealerPosition === button) {
+        return;
+      }
+      await foldToBigBlind(t);
+    }
+    expect.fail(`Button never reached seat ${button}`);
+  }
+
+  async function reserveSeat(t: TestTable, seat: number, wallet: number) {
+    await program.methods
+      .reserveSeat(t.tableId, seat)
+      .accounts({
+        table: t.table,
+        player: playerWallets[wallet].publicKey,
+        seatReservation: reservationPdaFor(t.table, seat),
+      })
+      .signers([playerWallets[wallet]])
+      .rpc();
+  }
+
+  /** Seats `playerWallets[wallet]` at `seat` with a minimum buy-in. */
+  async function joinSeat(t: TestTable, seat: number, wallet: number) {
+    const { bigBlind } = await program.account.table.fetch(t.table);
+    await program.methods
+      .joinTable(t.tableId, seat, bigBlind.muln(20))
+      .accounts({
+        table: t.table,
+        player: playerWallets[wallet].publicKey,
+        playerTokenAccount: playerTokenAccounts[wallet],
+        tableVault: t.vault,
+        playerSeat: seatPdaFor(t.table, seat),
+        playerRakeback: rakebackPdaFor(playerWallets[wallet].publicKey),
+        seatReservation: reservationPdaFor(t.table, seat),
+        playerLimits: limitsPdaFor(playerWallets[wallet].publicKey),
+        playerExclusion: exclusionPdaFor(playerWallets[wallet].publicKey),
+        playerActiveTables: activeTablesPdaFor(playerWallets[wallet].publicKey),
+      })
+      .signers([playerWallets[wallet]])
+      .rpc();
+    t.seats.push(seat);
+  }
+
+  async function forceCloseTable(t: TestTable, admin: Keypair, refundHand: boolean) {
+    await program.methods
+      .adminForceCloseTable(t.tableId, refundHand)
+      .accounts({
+        platformConfig: platformConfigPda,
+        admin: admin.publicKey,
+        table: t.table,
+        creator: playerWallets[0].publicKey,
+        tableVault: t.vault,
+        treasuryVault,
+      })
+      .remainingAccounts(
+        t.seats.flatMap((s) => [
+          { pubkey: seatPdaFor(t.table, s), isWritable: true, isSigner: false },
+          { pubkey: playerTokenAccounts[s], isWritable: true, isSigner: false },
+          { pubkey: playerWallets[s].publicKey, isWritable: true, isSigner: false },
+          { pubkey: activeTablesPdaFor(playerWallets[s].publicKey), isWritable: true, isSigner: false },
+        ])
+      )
+      .signers([admin])
+      .rpc();
+  }
+
+  async function requestEquity(t: TestTable) {
+    const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+    await program.methods
+      .computeAllinEquity(t.tableId)
+      .accounts({
+        table: t.table,
+        handData: handPdaFor(t.table, handId),
+        payer: playerWallets[t.seats[0]].publicKey,
+      })
+      .remainingAccounts(seatMetas(t))
+      .signers([playerWallets[t.seats[0]]])
+      .rpc();
+  }
+
+  async function checkTableActivity(t: TestTable) {
+    await program.methods
+      .checkTableActivity(t.tableId)
+      .accounts({ table: t.table, payer: playerWallets[0].publicKey })
+      .signers([playerWallets[0]])
+      .rpc();
+  }
+
+  /** Has `playerWallets[player]` leave `t` with an arbitrary seat account. */
+  async function leaveWithSeat(t: TestTable, player: number, playerSeat: PublicKey) {
+    await program.methods
+      .leaveTable(t.tableId)
+      .accounts({
+        table: t.table,
+        player: playerWallets[player].publicKey,
+        playerTokenAccount: playerTokenAccounts[player],
+        tableVault: t.vault,
+        playerSeat,
+        playerActiveTables: activeTablesPdaFor(playerWallets[player].publicKey),
+      })
+      .signers([playerWallets[player]])
+      .rpc();
+  }
+
+  async function postEntryBlind(t: TestTable, seat: number) {
+    await program.methods
+      .postEntryBlind(t.tableId)
+      .accounts({
+        table: t.table,
+        player: playerWallets[seat].publicKey,
+        playerSeat: seatPdaFor(t.table, seat),
+      })
+      .signers([playerWallets[seat]])
+      .rpc();
+  }
+
+  async function rabbitHunt(t: TestTable) {
+    const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+    await program.methods
+      .rabbitHunt(t.tableId)
+      .accounts({
+        table: t.table,
+        handData: handPdaFor(t.table, handId),
+        payer: playerWallets[t.seats[0]].publicKey,
+      })
+      .signers([playerWallets[t.seats[0]]])
+      .rpc();
+  }
+
+  /** Closes the last hand's `EncryptedHand` accounts, passed as `[account, player]` pairs. */
+  async function closeEncryptedHands(t: TestTable, pairs: [PublicKey, PublicKey][] = []) {
+    const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+    await program.methods
+      .closeEncryptedHands(t.tableId)
+      .accounts({
+        table: t.table,
+        handData: handPdaFor(t.table, handId),
+        payer: playerWallets[t.seats[0]].publicKey,
+      })
+      .remainingAccounts(
+        pairs.flatMap(([encryptedHand, player]) => [
+          { pubkey: encryptedHand, isWritable: true, isSigner: false },
+          { pubkey: player, isWritable: true, isSigner: false },
+        ])
+      )
+      .signers([playerWallets[t.seats[0]]])
+      .rpc();
   }
 
+  /** Plays a heads-up hand in which seat 1 shoves into a deeper seat 0 and loses it all. */
   /**
-   * Initializes a Computation Definition account for a given circuit.
-   * @param circuitName The name of the Arcis circuit.
-   * @param program The Anchor program instance.
-   * @param owner The keypair to pay for the transaction.
+   * Busts seat 1 at a three-handed table where seat 2 covers it. Heads-up, the
+   * simulated deck always splits the pot; three-handed it gives seat 2 the better hand.
    */
-  async function initCompDef(
-    circuitName: string,
-    program: Program<AcesUnknown>,
-    owner: Keypair
-  ): Promise<string> {
-    const baseSeedCompDefAcc = getArciumAccountBaseSeed(
-      "ComputationDefinitionAccount"
-    );
-    const offset = getCompDefAccOffset(circuitName);
-    const compDefPDA = PublicKey.findProgramAddressSync(
-      [baseSeedCompDefAcc, program.programId.toBuffer(), offset],
-      getArciumProgAddress()
-    )[0];
+  async function bustSeatOne(t: TestTable) {
+    // With the button on seat 1, it acts first preflop.
+    await startHandWithButton(t, 1);
+    const shove = (await fetchSeat(t, 1)).stack.toNumber();
+    await act(t, 1, { raise: { amount: new anchor.BN(shove) } });
+    await act(t, 2, { call: {} });
+    await act(t, 0, { fold: {} });
+    await runOutHand(t);
+  }
 
-    try {
-      await program.provider.connection.getAccountInfo(compDefPDA);
-      console.log(`CompDef for '${circuitName}' already initialized.`);
-      return "Already Initialized";
-    } catch (e) {
-      // Not initialized, proceed
-    }
+  async function standUpBustedPlayer(t: TestTable, seat: number) {
+    await program.methods
+      .standUpBustedPlayer(t.tableId)
+      .accounts({
+        table: t.table,
+        playerSeat: seatPdaFor(t.table, seat),
+        player: playerWallets[seat].publicKey,
+        playerActiveTables: activeTablesPdaFor(playerWallets[seat].publicKey),
+        payer: playerWallets[t.seats[0]].publicKey,
+      })
+      .signers([playerWallets[t.seats[0]]])
+      .rpc();
+  }
 
-    const ixName = `initialize${circuitName.charAt(0).toUpperCase() + circuitName.slice(1)}CompDef`;
-    
-    // This is a dynamic way to call init instructions like 'initializeShuffleAndDealCompDef'
-    // It is not available in the IDL, so we have to build the transaction manually.
-    // As a simplification for this test, we will assume a simplified setup call.
-    // In a real project, one might write a script for this.
-    // For now, we'll skip the actual init call and assume it's handled by deployment scripts.
-    console.log(`Skipping on-chain init for '${circuitName}' CompDef in test.`);
-    return "Skipped in test";
+  async function setRakeParams(
+    rakeBps: number,
+    rakeMaxCap: anchor.BN,
+    rakeOnShowdownOnly: boolean,
+    referrerRakeBps = 0,
+    rakeScope: any = { totalPot: {} }
+  ) {
+    await program.methods
+      .updateRakeParams(rakeBps, rakeMaxCap, rakeOnShowdownOnly, referrerRakeBps, rakeScope)
+      .accounts({ platformConfig: platformConfigPda, admin: owner.publicKey })
+      .signers([owner])
+      .rpc();
+  }
+
+  async function showOneCard(t: TestTable, seat: number, cardIndex: number) {
+    const handId = (await program.account.table.fetch(t.table)).handIdCounter;
+    await program.methods
+      .showOneCard(t.tableId, cardIndex)
+      .accounts({
+        table: t.table,
+        handData: handPdaFor(t.table, handId),
+        playerSeat: seatPdaFor(t.table, seat),
+        player: playerWallets[seat].publicKey,
+      })
+      .signers([playerWallets[seat]])
+      .rpc();
+  }
+
+  async function chopBlinds(t: TestTable, first: number, second: number) {
+    await program.methods
+      .chopBlinds(t.tableId)
+      .accounts({
+        table: t.table,
+        playerOne: playerWallets[first].publicKey,
+        playerTwo: playerWallets[second].publicKey,
+      })
+      .remainingAccounts(seatMetas(t))
+      .signers([...new Set([playerWallets[first], playerWallets[second]])])
+      .rpc();
+  }
+
+  async function addAllowedMint(mint: PublicKey) {
+    await program.methods
+      .addAllowedMint(mint)
+      .accounts({ platformConfig: platformConfigPda, admin: owner.publicKey })
+      .signers([owner])
+      .rpc();
+  }
+
+  async function removeAllowedMint(mint: PublicKey) {
+    await program.methods
+      .removeAllowedMint(mint)
+      .accounts({ platformConfig: platformConfigPda, admin: owner.publicKey })
+      .signers([owner])
+      .rpc();
+  }
+
+  /** Requests the acting player's bet presets and returns the emitted `BetPresets`. */
+  async function betPresets(t: TestTable) {
+    const table = await program.account.table.fetch(t.table);
+    const event = awaitEvent("betPresets");
+    await program.methods
+      .computeBetPresets(t.tableId)
+      .accounts({
+        table: t.table,
+        playerSeat: seatPdaFor(t.table, table.turnPosition),
+        payer: playerWallets[0].publicKey,
+      })
+      .signers([playerWallets[0]])
+      .rpc();
+    return event;
   }
 
   /**
-   * Retries fetching the MXE public key until it's available.
-   * @param provider The Anchor provider.
-   * @param programId The program ID of the MXE.
-   * @param maxRetries Maximum number of retries.
-   * @param retryDelayMs Delay between retries.
-   * @returns The MXE public key.
+   * Plays a hand from a script: starts it with the button on `button`, then plays
+   * each street's `[seat, action]` pairs in order, dealing the next street between
+   * them and resolving the showdown if the hand gets there. Every action must be
+   * legal and in turn, so the script doubles as an assertion on the turn order.
    */
-  async function getMXEPublicKeyWithRetry(
-    provider: anchor.AnchorProvider,
-    programId: PublicKey,
-    maxRetries: number = 10,
-    retryDelayMs: number = 500
-  ): Promise<Uint8Array> {
-    for (let attempt = 1; attempt <= maxRetries; attempt++) {
-      try {
-        const mxePublicKey = await getMXEPublicKey(provider, programId);
-        if (mxePublicKey) {
-          return mxePublicKey;
-        }
-      } catch (error) {
-        // Suppress error log for cleaner test output
-      }
-      if (attempt < maxRetries) {
-        await new Promise((resolve) => setTimeout(resolve, retryDelayMs));
+  async function playScriptedHand(t: TestTable, button: number, streets: [number, any][][]) {
+    await startHandWithButton(t, button);
+    for (const [street, actions] of streets.entries()) {
+      if (street > 0) await dealStreet(t);
+      for (const [seat, action] of actions) {
+        expect((await program.account.table.fetch(t.table)).turnPosition).to.equal(seat);
+        await act(t, seat, action);
       }
     }
-    throw new Error(
-      `Failed to fetch MXE public key after ${maxRetries} attempts`
-    );
+    const state = await program.account.table.fetch(t.table);
+    if ("awaitingShowdown" in state.handPhase) {
+      await resolveTestShowdown(t);
+    }
+  }
+
+  async function setMaxConcurrentTables(wallet: number, maxConcurrentTables: number) {
+    await program.methods
+      .setMaxConcurrentTables(maxConcurrentTables)
+      .accounts({
+        player: playerWallets[wallet].publicKey,
+        playerActiveTables: activeTablesPdaFor(playerWallets[wallet].publicKey),
+      })
+      .signers([playerWallets[wallet]])
+      .rpc();
+  }
+
+  /** Emits `PlayersTurnChecked` for `playerWallets[wallet]` at the table and returns it. */
+  async function checkPlayersTurn(t: TestTable, wallet: number) {
+    const event = awaitEvent("playersTurnChecked");
+    await program.methods
+      .checkPlayersTurn(t.tableId, playerWallets[wallet].publicKey)
+      .accounts({ table: t.table, payer: playerWallets[0].publicKey })
+      .remainingAccounts(seatMetas(t))
+      .signers([playerWallets[0]])
+      .rpc();
+    return event;
   }
 });
\ No newline at end of file